@@ -10,7 +10,7 @@ use chromiumoxide::cdp::browser_protocol::page::{
 };
 use chromiumoxide::page::ScreenshotParams;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 /// Format for captures
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -52,6 +52,67 @@ pub struct CaptureOptions {
     /// Return as base64 instead of bytes
     #[serde(default)]
     pub as_base64: bool,
+    /// For PDF: strip page chrome and print only the readable main content
+    #[serde(default)]
+    pub readable: bool,
+    /// Delay in milliseconds after readiness, before capturing
+    ///
+    /// Lets animated/fading content settle. Distinct from the human-like
+    /// navigation delay; defaults to 0.
+    #[serde(default)]
+    pub pre_capture_delay_ms: u64,
+}
+
+/// Default cap on bytes inlined per asset in self-contained HTML capture
+pub const DEFAULT_MAX_INLINE_BYTES: usize = 256 * 1024;
+
+/// Substrings identifying tracking and consent-management scripts
+///
+/// Matched case-insensitively against each `<script>`'s `src` and inline
+/// text when [`HtmlInlineOptions::strip_trackers`] is set.
+pub const TRACKER_SCRIPT_PATTERNS: &[&str] = &[
+    "googletagmanager.com",
+    "google-analytics.com",
+    "gtag(",
+    "datalayer",
+    "connect.facebook.net",
+    "hotjar.com",
+    "segment.com",
+    "matomo",
+    "cookiebot.com",
+    "cookielaw.org",
+    "onetrust",
+    "usercentrics",
+    "consentmanager",
+    "quantserve.com",
+];
+
+/// Options for self-contained HTML capture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HtmlInlineOptions {
+    /// Maximum size of a single stylesheet or image to inline, in bytes
+    ///
+    /// Larger assets keep an absolute URL instead of being embedded.
+    #[serde(default = "default_max_inline_bytes")]
+    pub max_inline_bytes: usize,
+    /// Remove `<script>` tags matching [`TRACKER_SCRIPT_PATTERNS`]
+    /// (analytics, tag managers, consent CMPs) from the captured HTML
+    /// (default: false)
+    #[serde(default)]
+    pub strip_trackers: bool,
+}
+
+fn default_max_inline_bytes() -> usize {
+    DEFAULT_MAX_INLINE_BYTES
+}
+
+impl Default for HtmlInlineOptions {
+    fn default() -> Self {
+        Self {
+            max_inline_bytes: DEFAULT_MAX_INLINE_BYTES,
+            strip_trackers: false,
+        }
+    }
 }
 
 fn default_quality() -> u8 {
@@ -72,6 +133,8 @@ impl Default for CaptureOptions {
             height: None,
             clip_selector: None,
             as_base64: false,
+            readable: false,
+            pre_capture_delay_ms: 0,
         }
     }
 }
@@ -159,7 +222,24 @@ pub struct CaptureResult {
 }
 
 impl CaptureResult {
-    /// Get data as base64
+    /// Get the captured bytes
+    pub fn bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get data as base64, reusing the pre-encoded form when
+    /// [`CaptureOptions::as_base64`] already produced one
+    ///
+    /// Unlike the [`base64`](CaptureResult::base64) field, this never
+    /// requires callers to handle the absent case.
+    pub fn base64(&self) -> String {
+        match &self.base64 {
+            Some(encoded) => encoded.clone(),
+            None => self.to_base64(),
+        }
+    }
+
+    /// Get data as base64, always encoding from the bytes
     pub fn to_base64(&self) -> String {
         BASE64.encode(&self.data)
     }
@@ -196,6 +276,16 @@ impl PageCapture {
     /// Capture a page with the given options
     #[instrument(skip(page))]
     pub async fn capture(page: &PageHandle, options: &CaptureOptions) -> Result<CaptureResult> {
+        // Let animated/fading content settle before capturing
+        if options.pre_capture_delay_ms > 0 {
+            debug!(
+                "Waiting {}ms before capture",
+                options.pre_capture_delay_ms
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(options.pre_capture_delay_ms))
+                .await;
+        }
+
         match options.format {
             CaptureFormat::Png | CaptureFormat::Jpeg | CaptureFormat::Webp => {
                 Self::screenshot(page, options).await
@@ -230,11 +320,32 @@ impl PageCapture {
 
         let params = params_builder.build();
 
-        let data = page
-            .page
-            .screenshot(params)
-            .await
-            .map_err(|e| CaptureError::ScreenshotFailed(e.to_string()))?;
+        let mut actual_format = options.format;
+        let data = match page.page.screenshot(params).await {
+            Ok(data) => data,
+            Err(e) => {
+                // Some Chromium builds cannot encode WebP; fall back to PNG
+                // instead of failing the capture
+                let Some(fallback) = Self::fallback_format(options.format, &e.to_string()) else {
+                    return Err(CaptureError::ScreenshotFailed(e.to_string()).into());
+                };
+                warn!(
+                    "WebP encoding failed ({}); falling back to {:?}",
+                    e, fallback
+                );
+                actual_format = fallback;
+
+                let params = ScreenshotParams::builder()
+                    .format(CaptureScreenshotFormat::Png)
+                    .from_surface(true)
+                    .capture_beyond_viewport(options.full_page)
+                    .build();
+                page.page
+                    .screenshot(params)
+                    .await
+                    .map_err(|e| CaptureError::ScreenshotFailed(e.to_string()))?
+            }
+        };
 
         let size = data.len();
         debug!("Screenshot captured: {} bytes", size);
@@ -247,7 +358,7 @@ impl PageCapture {
 
         Ok(CaptureResult {
             data,
-            format: options.format,
+            format: actual_format,
             base64,
             width: options.width,
             height: options.height,
@@ -255,11 +366,37 @@ impl PageCapture {
         })
     }
 
+    /// Decide the fallback format for a failed screenshot, if any
+    ///
+    /// Only WebP captures failing with an encoding-related error fall back
+    /// (to PNG); every other failure propagates unchanged.
+    pub fn fallback_format(requested: CaptureFormat, error: &str) -> Option<CaptureFormat> {
+        if requested == CaptureFormat::Webp && Self::is_webp_encoding_failure(error) {
+            Some(CaptureFormat::Png)
+        } else {
+            None
+        }
+    }
+
+    /// Whether a screenshot error message indicates a WebP encoding failure
+    fn is_webp_encoding_failure(message: &str) -> bool {
+        let lower = message.to_lowercase();
+        lower.contains("webp")
+            || lower.contains("invalid image format")
+            || lower.contains("unsupported image format")
+            || lower.contains("encoding failed")
+    }
+
     /// Generate a PDF
     #[instrument(skip(page))]
     pub async fn pdf(page: &PageHandle, options: &CaptureOptions) -> Result<CaptureResult> {
         info!("Generating PDF");
 
+        // Strip page chrome first if a readable PDF was requested
+        if options.readable {
+            Self::isolate_readable_content(page).await?;
+        }
+
         let mut params_builder = PrintToPdfParams::builder()
             .print_background(true)
             .prefer_css_page_size(true);
@@ -298,6 +435,39 @@ impl PageCapture {
         })
     }
 
+    /// Replace the page body with only the readable main content
+    ///
+    /// Runs the readability extraction and swaps the body for the cleaned
+    /// article HTML. Stylesheets in the head are left in place so the
+    /// article region keeps its original styling where possible.
+    async fn isolate_readable_content(page: &PageHandle) -> Result<()> {
+        let content = crate::extraction::ContentExtractor::extract_main_content(page)
+            .await
+            .map_err(|e| CaptureError::PdfFailed(format!("Readability extraction failed: {}", e)))?;
+
+        let escaped_html = serde_json::to_string(&content.html)
+            .map_err(|e| CaptureError::PdfFailed(e.to_string()))?;
+
+        let script = format!(
+            r#"
+            (() => {{
+                document.body.innerHTML = '<main>' + {escaped_html} + '</main>';
+                const main = document.body.querySelector('main');
+                main.style.maxWidth = '50em';
+                main.style.margin = '0 auto';
+            }})()
+            "#
+        );
+
+        page.page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| CaptureError::PdfFailed(e.to_string()))?;
+
+        debug!("Replaced page body with readable content for PDF");
+        Ok(())
+    }
+
     /// Capture MHTML archive
     #[instrument(skip(page))]
     pub async fn mhtml(page: &PageHandle) -> Result<CaptureResult> {
@@ -307,11 +477,15 @@ impl PageCapture {
             .format(CaptureSnapshotFormat::Mhtml)
             .build();
 
-        let result = page
-            .page
-            .execute(params)
-            .await
-            .map_err(|e| CaptureError::MhtmlFailed(e.to_string()))?;
+        let result = page.page.execute(params).await.map_err(|e| {
+            let message = e.to_string();
+            crate::error::Error::protocol_unsupported(
+                "Page.captureSnapshot",
+                &message,
+                page.chrome_version(),
+            )
+            .unwrap_or_else(|| CaptureError::MhtmlFailed(message).into())
+        })?;
 
         let data = result.data.clone().into_bytes();
         let size = data.len();
@@ -354,6 +528,146 @@ impl PageCapture {
         })
     }
 
+    /// Capture the rendered DOM as a single self-contained HTML string
+    ///
+    /// Serializes the live DOM, inlines stylesheets as `<style>` blocks and
+    /// small images as data URIs (fetched from within the page, so cookies
+    /// apply), and rewrites remaining asset URLs to absolute. Assets larger
+    /// than `max_inline_bytes` are left as absolute URLs.
+    #[instrument(skip(page, options))]
+    pub async fn self_contained_html(
+        page: &PageHandle,
+        options: &HtmlInlineOptions,
+    ) -> Result<CaptureResult> {
+        info!("Capturing self-contained HTML");
+
+        let script = Self::self_contained_html_script(options);
+        let html: String = page
+            .page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| CaptureError::HtmlFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| CaptureError::HtmlFailed(e.to_string()))?;
+
+        let data = html.into_bytes();
+        let size = data.len();
+        debug!("Self-contained HTML captured: {} bytes", size);
+
+        Ok(CaptureResult {
+            data,
+            format: CaptureFormat::Html,
+            base64: None,
+            width: None,
+            height: None,
+            size,
+        })
+    }
+
+    /// Build the in-page script that serializes and inlines the DOM
+    ///
+    /// Exposed for testability; the async script resolves to the final HTML
+    /// string.
+    pub fn self_contained_html_script(options: &HtmlInlineOptions) -> String {
+        let max_inline = options.max_inline_bytes;
+        // Empty when stripping is off, so the removal loop never runs
+        let tracker_patterns = if options.strip_trackers {
+            serde_json::to_string(TRACKER_SCRIPT_PATTERNS).unwrap_or_else(|_| "[]".to_string())
+        } else {
+            "[]".to_string()
+        };
+
+        format!(
+            r#"
+            (async () => {{
+                const MAX_INLINE = {max_inline};
+                const TRACKER_PATTERNS = {tracker_patterns};
+                const doc = document.documentElement.cloneNode(true);
+
+                // Drop analytics/consent scripts so consumers don't re-run them
+                for (const script of Array.from(doc.querySelectorAll('script'))) {{
+                    const target = ((script.getAttribute('src') || '') + ' ' +
+                        (script.textContent || '')).toLowerCase();
+                    if (TRACKER_PATTERNS.some(p => target.includes(p))) {{
+                        script.remove();
+                    }}
+                }}
+
+                const toAbsolute = (value, base) => {{
+                    try {{
+                        return new URL(value, base || document.baseURI).href;
+                    }} catch (e) {{
+                        return value;
+                    }}
+                }};
+
+                const toDataUri = (blob) => new Promise((resolve, reject) => {{
+                    const reader = new FileReader();
+                    reader.onload = () => resolve(reader.result);
+                    reader.onerror = () => reject(reader.error);
+                    reader.readAsDataURL(blob);
+                }});
+
+                // Inline stylesheets as <style> blocks
+                for (const link of Array.from(doc.querySelectorAll('link[rel="stylesheet"]'))) {{
+                    const href = link.getAttribute('href');
+                    if (!href) continue;
+                    const url = toAbsolute(href);
+                    try {{
+                        const response = await fetch(url);
+                        if (response.ok) {{
+                            let css = await response.text();
+                            if (css.length <= MAX_INLINE) {{
+                                // Resolve relative url(...) refs against the stylesheet URL
+                                css = css.replace(
+                                    /url\((['"]?)(?!data:|https?:|\/\/)([^'")]+)\1\)/g,
+                                    (_, quote, ref) =>
+                                        'url(' + quote + toAbsolute(ref, url) + quote + ')'
+                                );
+                                const style = document.createElement('style');
+                                style.textContent = css;
+                                link.replaceWith(style);
+                                continue;
+                            }}
+                        }}
+                    }} catch (e) {{}}
+                    link.setAttribute('href', url);
+                }}
+
+                // Inline small images as data URIs
+                for (const img of Array.from(doc.querySelectorAll('img[src]'))) {{
+                    const src = img.getAttribute('src');
+                    if (!src || src.startsWith('data:')) continue;
+                    const url = toAbsolute(src);
+                    try {{
+                        const response = await fetch(url);
+                        if (response.ok) {{
+                            const blob = await response.blob();
+                            if (blob.size <= MAX_INLINE) {{
+                                img.setAttribute('src', await toDataUri(blob));
+                                img.removeAttribute('srcset');
+                                continue;
+                            }}
+                        }}
+                    }} catch (e) {{}}
+                    img.setAttribute('src', url);
+                }}
+
+                // Make remaining references absolute so they resolve standalone
+                for (const el of Array.from(doc.querySelectorAll('[href], [src]'))) {{
+                    for (const attr of ['href', 'src']) {{
+                        const value = el.getAttribute(attr);
+                        if (!value || value.startsWith('data:') || value.startsWith('#')) continue;
+                        el.setAttribute(attr, toAbsolute(value));
+                    }}
+                }}
+
+                return '<!DOCTYPE html>\n' + doc.outerHTML;
+            }})()
+            "#
+        )
+    }
+
     /// Capture a specific element
     #[instrument(skip(page))]
     pub async fn element_screenshot(
@@ -413,6 +727,21 @@ mod tests {
         assert!(opts.width.is_none());
         assert!(opts.height.is_none());
         assert!(opts.clip_selector.is_none());
+        assert!(!opts.readable);
+    }
+
+    #[test]
+    fn test_capture_options_readable_pdf() {
+        let opts = CaptureOptions {
+            readable: true,
+            ..CaptureOptions::pdf()
+        };
+        assert_eq!(opts.format, CaptureFormat::Pdf);
+        assert!(opts.readable);
+
+        // readable defaults to false when deserialized from older payloads
+        let parsed: CaptureOptions = serde_json::from_str("{\"format\":\"pdf\"}").unwrap();
+        assert!(!parsed.readable);
     }
 
     #[test]
@@ -444,6 +773,8 @@ mod tests {
             height: Some(1080),
             clip_selector: None,
             as_base64: false,
+            readable: false,
+            pre_capture_delay_ms: 0,
         };
         assert!(opts.validate().is_ok());
     }
@@ -454,6 +785,19 @@ mod tests {
         assert!(opts.validate().is_ok());
     }
 
+    #[test]
+    fn test_pre_capture_delay_defaults_to_zero() {
+        assert_eq!(CaptureOptions::default().pre_capture_delay_ms, 0);
+
+        // Absent from JSON means no delay
+        let opts: CaptureOptions = serde_json::from_str(r#"{"format":"png"}"#).unwrap();
+        assert_eq!(opts.pre_capture_delay_ms, 0);
+
+        let opts: CaptureOptions =
+            serde_json::from_str(r#"{"format":"png","pre_capture_delay_ms":400}"#).unwrap();
+        assert_eq!(opts.pre_capture_delay_ms, 400);
+    }
+
     #[test]
     fn test_validate_capture_request_quality_too_high() {
         let opts = CaptureOptions {
@@ -497,6 +841,57 @@ mod tests {
         assert!(opts.validate().is_ok());
     }
 
+    // ========================================================================
+    // WebP Fallback Tests
+    // ========================================================================
+
+    #[test]
+    fn test_webp_encoding_failure_falls_back_to_png() {
+        let fallback = PageCapture::fallback_format(
+            CaptureFormat::Webp,
+            "Screenshot failed: WebP image encoding is not supported",
+        );
+        assert_eq!(fallback, Some(CaptureFormat::Png));
+
+        let fallback =
+            PageCapture::fallback_format(CaptureFormat::Webp, "Invalid image format specified");
+        assert_eq!(fallback, Some(CaptureFormat::Png));
+    }
+
+    #[test]
+    fn test_non_encoding_webp_failure_does_not_fall_back() {
+        let fallback = PageCapture::fallback_format(CaptureFormat::Webp, "Navigation timeout");
+        assert_eq!(fallback, None);
+    }
+
+    #[test]
+    fn test_non_webp_failures_never_fall_back() {
+        let fallback =
+            PageCapture::fallback_format(CaptureFormat::Png, "Invalid image format specified");
+        assert_eq!(fallback, None);
+
+        let fallback = PageCapture::fallback_format(CaptureFormat::Jpeg, "webp error");
+        assert_eq!(fallback, None);
+    }
+
+    #[test]
+    fn test_fallback_result_reports_png_mime_type() {
+        // Simulates a WebP capture that fell back: the result must carry the
+        // actual format so consumers get the right mime type
+        let format =
+            PageCapture::fallback_format(CaptureFormat::Webp, "webp encoding failed").unwrap();
+        let result = CaptureResult {
+            data: vec![0x89, 0x50, 0x4E, 0x47],
+            format,
+            base64: None,
+            width: None,
+            height: None,
+            size: 4,
+        };
+        assert_eq!(result.mime_type(), "image/png");
+        assert_eq!(result.extension(), "png");
+    }
+
     // ========================================================================
     // CaptureResult Tests
     // ========================================================================
@@ -562,6 +957,33 @@ mod tests {
         assert_eq!(result.to_base64(), "aGVsbG8=");
     }
 
+    #[test]
+    fn test_capture_result_base64_encodes_when_not_prepopulated() {
+        let result = CaptureResult {
+            data: b"hello".to_vec(),
+            format: CaptureFormat::Png,
+            base64: None,
+            width: None,
+            height: None,
+            size: 5,
+        };
+        assert_eq!(result.base64(), "aGVsbG8=");
+        assert_eq!(result.bytes(), b"hello");
+    }
+
+    #[test]
+    fn test_capture_result_base64_reuses_prepopulated() {
+        let result = CaptureResult {
+            data: b"hello".to_vec(),
+            format: CaptureFormat::Png,
+            base64: Some("aGVsbG8=".to_string()),
+            width: None,
+            height: None,
+            size: 5,
+        };
+        assert_eq!(result.base64(), "aGVsbG8=");
+    }
+
     #[test]
     fn test_capture_result_base64_empty() {
         let result = CaptureResult {
@@ -687,6 +1109,8 @@ mod tests {
             height: Some(600),
             clip_selector: Some("#main".to_string()),
             as_base64: true,
+            readable: false,
+            pre_capture_delay_ms: 0,
         };
 
         let json = serde_json::to_string(&opts).unwrap();
@@ -723,4 +1147,56 @@ mod tests {
         assert_eq!(opts_max.quality, 100);
         assert!(opts_max.validate().is_ok());
     }
+
+    // ========================================================================
+    // Self-Contained HTML Tests
+    // ========================================================================
+
+    #[test]
+    fn test_html_inline_options_default() {
+        let options = HtmlInlineOptions::default();
+        assert_eq!(options.max_inline_bytes, DEFAULT_MAX_INLINE_BYTES);
+        assert!(!options.strip_trackers);
+    }
+
+    #[test]
+    fn test_html_inline_options_deserialize_with_defaults() {
+        let options: HtmlInlineOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(options.max_inline_bytes, DEFAULT_MAX_INLINE_BYTES);
+        assert!(!options.strip_trackers);
+
+        let options: HtmlInlineOptions =
+            serde_json::from_str(r#"{"max_inline_bytes": 1024, "strip_trackers": true}"#).unwrap();
+        assert_eq!(options.max_inline_bytes, 1024);
+        assert!(options.strip_trackers);
+    }
+
+    #[test]
+    fn test_self_contained_html_script_embeds_cap() {
+        let options = HtmlInlineOptions {
+            max_inline_bytes: 4096,
+            ..Default::default()
+        };
+        let script = PageCapture::self_contained_html_script(&options);
+
+        assert!(script.contains("const MAX_INLINE = 4096;"));
+        assert!(script.contains("link[rel=\"stylesheet\"]"));
+        assert!(script.contains("img[src]"));
+        assert!(script.contains("<!DOCTYPE html>"));
+    }
+
+    #[test]
+    fn test_self_contained_html_script_tracker_patterns() {
+        // Off by default: the pattern list stays empty
+        let script = PageCapture::self_contained_html_script(&HtmlInlineOptions::default());
+        assert!(script.contains("const TRACKER_PATTERNS = [];"));
+
+        let options = HtmlInlineOptions {
+            strip_trackers: true,
+            ..Default::default()
+        };
+        let script = PageCapture::self_contained_html_script(&options);
+        assert!(script.contains("googletagmanager.com"));
+        assert!(script.contains("cookiebot.com"));
+    }
 }
@@ -0,0 +1,135 @@
+//! Main-document content-type checking
+//!
+//! Navigating to a PDF, a zip file, or a bare image "succeeds" as far as
+//! Chrome is concerned, but feeding the result to the HTML extractors
+//! produces garbage. This module records the MIME type of the main frame's
+//! document response so navigation can reject anything outside a
+//! caller-supplied allowlist with a typed error.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::network::{EventResponseReceived, ResourceType};
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+/// Active content-type monitoring on a page
+///
+/// Watches `Network.responseReceived` for main-frame document responses and
+/// keeps the MIME type of the most recent one, so redirect chains report the
+/// final document. Dropping the monitor stops collection.
+pub struct ContentTypeMonitor {
+    task: JoinHandle<()>,
+    mime_type: Arc<RwLock<Option<String>>>,
+}
+
+impl ContentTypeMonitor {
+    /// Install content-type monitoring on a page
+    ///
+    /// Install before navigating so the initial document response is seen.
+    pub async fn install(page: &PageHandle) -> Result<Self> {
+        info!("Installing content-type monitor");
+
+        let mut responses = page
+            .page
+            .event_listener::<EventResponseReceived>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        // Iframe documents carry their own types (ads, embeds); only the
+        // main frame matters. Unknown before the first navigation commits,
+        // in which case every document response is treated as main-frame.
+        let main_frame = page.page.mainframe().await.ok().flatten();
+
+        let mime_type = Arc::new(RwLock::new(None));
+        let task_mime = Arc::clone(&mime_type);
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = responses.next().await {
+                if event.r#type != ResourceType::Document {
+                    continue;
+                }
+                let main_frame_response = match (&main_frame, &event.frame_id) {
+                    (Some(expected), Some(actual)) => expected == actual,
+                    _ => true,
+                };
+                if !main_frame_response {
+                    continue;
+                }
+                *task_mime.write().await = Some(event.response.mime_type.clone());
+            }
+        });
+
+        Ok(Self { task, mime_type })
+    }
+
+    /// MIME type of the latest main-frame document response, if any was seen
+    pub async fn document_mime_type(&self) -> Option<String> {
+        self.mime_type.read().await.clone()
+    }
+
+    /// Whether a MIME type matches an allowlist entry
+    ///
+    /// Comparison is case-insensitive and ignores parameters such as
+    /// `charset` on either side.
+    pub fn is_allowed(mime_type: &str, allowlist: &[String]) -> bool {
+        let essence = Self::essence(mime_type);
+        allowlist.iter().any(|allowed| Self::essence(allowed) == essence)
+    }
+
+    /// Reduce a MIME type to its lowercased `type/subtype` essence
+    fn essence(mime_type: &str) -> String {
+        mime_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    }
+}
+
+impl Drop for ContentTypeMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowlist() -> Vec<String> {
+        vec![
+            "text/html".to_string(),
+            "application/xhtml+xml".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_is_allowed_exact_match() {
+        assert!(ContentTypeMonitor::is_allowed("text/html", &allowlist()));
+        assert!(ContentTypeMonitor::is_allowed(
+            "application/xhtml+xml",
+            &allowlist()
+        ));
+        assert!(!ContentTypeMonitor::is_allowed(
+            "application/zip",
+            &allowlist()
+        ));
+        assert!(!ContentTypeMonitor::is_allowed("", &allowlist()));
+    }
+
+    #[test]
+    fn test_is_allowed_ignores_case_and_parameters() {
+        assert!(ContentTypeMonitor::is_allowed(
+            "Text/HTML; charset=utf-8",
+            &allowlist()
+        ));
+        assert!(ContentTypeMonitor::is_allowed(
+            "text/html",
+            &["TEXT/HTML; charset=iso-8859-1".to_string()]
+        ));
+    }
+}
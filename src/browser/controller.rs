@@ -4,13 +4,68 @@
 
 use crate::error::{BrowserError, Error, Result};
 use chromiumoxide::browser::{Browser, BrowserConfig as CdpBrowserConfig};
+use chromiumoxide::cdp::browser_protocol::page::AddScriptToEvaluateOnNewDocumentParams;
 use chromiumoxide::Page;
-use futures::StreamExt;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio::task::JoinHandle;
-use tracing::{debug, info, instrument, warn};
+use tracing::{debug, info, instrument};
+
+/// How long `warmup` waits for preconnects to establish before closing
+/// its temporary page
+const WARMUP_SETTLE_MS: u64 = 300;
+
+/// A script or stylesheet injected into every new page
+///
+/// Injections are registered via `Page.addScriptToEvaluateOnNewDocument`, so
+/// they run before any of the page's own scripts. Stealth scripts are always
+/// applied first, followed by injections in registration order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageInjection {
+    /// JavaScript source evaluated on every new document
+    Script(String),
+    /// CSS inserted as a `<style>` element before page scripts run
+    Style(String),
+}
+
+impl PageInjection {
+    /// Get the JavaScript source to register for this injection
+    ///
+    /// Styles are wrapped in a script that appends a `<style>` element as soon
+    /// as the document element exists.
+    pub fn to_script(&self) -> String {
+        match self {
+            Self::Script(source) => source.clone(),
+            Self::Style(css) => {
+                // serde_json escaping gives us a valid JS string literal
+                let escaped = serde_json::to_string(css).unwrap_or_else(|_| "\"\"".to_string());
+                format!(
+                    r#"
+                    (() => {{
+                        const css = {escaped};
+                        const inject = () => {{
+                            const style = document.createElement('style');
+                            style.textContent = css;
+                            (document.head || document.documentElement).appendChild(style);
+                        }};
+                        if (document.documentElement) {{
+                            inject();
+                        }} else {{
+                            new MutationObserver((_, observer) => {{
+                                if (document.documentElement) {{
+                                    observer.disconnect();
+                                    inject();
+                                }}
+                            }}).observe(document, {{ childList: true }});
+                        }}
+                    }})();
+                    "#
+                )
+            }
+        }
+    }
+}
 
 /// Configuration for browser launch
 #[derive(Debug, Clone)]
@@ -31,8 +86,24 @@ pub struct BrowserConfig {
     pub chrome_path: Option<String>,
     /// Enable stealth mode (default: true)
     pub stealth: bool,
+    /// Stealth techniques to apply when stealth is enabled (default: standard preset)
+    pub stealth_mode: super::stealth::StealthMode,
     /// Additional Chrome arguments
     pub extra_args: Vec<String>,
+    /// Scripts/styles injected into every new page, after stealth scripts,
+    /// in registration order
+    pub injections: Vec<PageInjection>,
+    /// Maximum number of concurrently open pages (default: 8)
+    ///
+    /// Callers exceeding the cap queue in `new_page` until a page is closed.
+    pub max_concurrent_pages: usize,
+    /// Allow https pages to load http subresources (default: false)
+    ///
+    /// Chrome exposes no runtime switch for this, so it maps to the
+    /// `--allow-running-insecure-content` launch flag and applies to the
+    /// whole browser. See [`super::MixedContentMode`] for per-navigation
+    /// reporting of what Chrome blocked.
+    pub allow_insecure_content: bool,
 }
 
 impl Default for BrowserConfig {
@@ -46,7 +117,11 @@ impl Default for BrowserConfig {
             timeout_ms: 30000,
             chrome_path: None,
             stealth: true,
+            stealth_mode: super::stealth::StealthMode::standard(),
             extra_args: Vec::new(),
+            injections: Vec::new(),
+            max_concurrent_pages: 8,
+            allow_insecure_content: false,
         }
     }
 }
@@ -108,12 +183,45 @@ impl BrowserConfigBuilder {
         self
     }
 
+    /// Set the stealth techniques to apply (implies enabling stealth)
+    pub fn stealth_mode(mut self, mode: super::stealth::StealthMode) -> Self {
+        self.config.stealth = true;
+        self.config.stealth_mode = mode;
+        self
+    }
+
     /// Add extra Chrome argument
     pub fn arg<S: Into<String>>(mut self, arg: S) -> Self {
         self.config.extra_args.push(arg.into());
         self
     }
 
+    /// Inject a script into every new page before page scripts run
+    pub fn inject_script<S: Into<String>>(mut self, source: S) -> Self {
+        self.config.injections.push(PageInjection::Script(source.into()));
+        self
+    }
+
+    /// Inject a stylesheet into every new page before page scripts run
+    pub fn inject_style<S: Into<String>>(mut self, css: S) -> Self {
+        self.config.injections.push(PageInjection::Style(css.into()));
+        self
+    }
+
+    /// Set the maximum number of concurrently open pages
+    ///
+    /// Values below 1 are clamped to 1.
+    pub fn max_concurrent_pages(mut self, max: usize) -> Self {
+        self.config.max_concurrent_pages = max.max(1);
+        self
+    }
+
+    /// Allow https pages to load http subresources (browser-wide launch flag)
+    pub fn allow_insecure_content(mut self, allow: bool) -> Self {
+        self.config.allow_insecure_content = allow;
+        self
+    }
+
     /// Build the config
     pub fn build(self) -> BrowserConfig {
         self.config
@@ -125,6 +233,10 @@ impl BrowserConfigBuilder {
 pub struct PageHandle {
     pub(crate) page: Page,
     pub(crate) url: Arc<RwLock<String>>,
+    /// Product string of the owning browser, for protocol mismatch errors
+    pub(crate) chrome_version: Option<String>,
+    /// Concurrency permit released when the last handle clone drops
+    pub(crate) _permit: Arc<OwnedSemaphorePermit>,
 }
 
 impl PageHandle {
@@ -133,6 +245,12 @@ impl PageHandle {
         &self.page
     }
 
+    /// Product string of the Chrome this page belongs to (e.g.
+    /// `Chrome/128.0.6613.84`), when it could be determined at launch
+    pub fn chrome_version(&self) -> Option<&str> {
+        self.chrome_version.as_deref()
+    }
+
     /// Get the current URL
     pub async fn url(&self) -> String {
         self.url.read().await.clone()
@@ -142,14 +260,81 @@ impl PageHandle {
     pub(crate) async fn set_url(&self, url: String) {
         *self.url.write().await = url;
     }
+
+    /// Wait until the document reaches the given load state
+    ///
+    /// Resolves immediately if `document.readyState` has already reached the
+    /// requested state; otherwise listens for the corresponding readyState
+    /// transition. Useful for session-based flows that need to await a load
+    /// state independently of a navigation call.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NavigationError::Timeout` if the state is not reached within
+    /// `timeout_ms`.
+    pub async fn wait_for_load_state(
+        &self,
+        state: super::navigation::LoadState,
+        timeout_ms: u64,
+    ) -> Result<()> {
+        use super::navigation::LoadState;
+
+        let script = match state {
+            // 'interactive' fires with DOMContentLoaded; 'complete' also counts
+            LoadState::DomContentLoaded => {
+                r#"
+                    new Promise(resolve => {
+                        if (document.readyState !== 'loading') {
+                            resolve(document.readyState);
+                        } else {
+                            document.addEventListener('readystatechange', function handler() {
+                                if (document.readyState !== 'loading') {
+                                    document.removeEventListener('readystatechange', handler);
+                                    resolve(document.readyState);
+                                }
+                            });
+                        }
+                    })
+                "#
+            }
+            LoadState::Complete => {
+                r#"
+                    new Promise(resolve => {
+                        if (document.readyState === 'complete') {
+                            resolve(document.readyState);
+                        } else {
+                            document.addEventListener('readystatechange', function handler() {
+                                if (document.readyState === 'complete') {
+                                    document.removeEventListener('readystatechange', handler);
+                                    resolve(document.readyState);
+                                }
+                            });
+                        }
+                    })
+                "#
+            }
+        };
+
+        tokio::time::timeout(Duration::from_millis(timeout_ms), self.page.evaluate(script))
+            .await
+            .map_err(|_| crate::error::NavigationError::Timeout(timeout_ms))?
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        Ok(())
+    }
 }
 
 /// High-level browser controller
 pub struct BrowserController {
     browser: Browser,
     handler: JoinHandle<()>,
+    handler_stats: Arc<super::HandlerStats>,
     config: BrowserConfig,
     pages: Arc<RwLock<Vec<PageHandle>>>,
+    /// Caps concurrently open pages at `config.max_concurrent_pages`
+    page_permits: Arc<Semaphore>,
+    /// Product string reported by the browser at launch, when available
+    chrome_version: Option<String>,
 }
 
 impl BrowserController {
@@ -194,6 +379,11 @@ impl BrowserController {
             builder = builder.chrome_executable(path);
         }
 
+        // Mixed content
+        if config.allow_insecure_content {
+            builder = builder.arg("--allow-running-insecure-content");
+        }
+
         // Extra args
         for arg in &config.extra_args {
             builder = builder.arg(arg);
@@ -210,48 +400,87 @@ impl BrowserController {
             .build()
             .map_err(|e| BrowserError::ConfigError(e.to_string()))?;
 
-        let (browser, mut handler) = Browser::launch(cdp_config)
+        let (browser, handler) = Browser::launch(cdp_config)
             .await
             .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?;
 
-        // Spawn handler task
-        let handler_task = tokio::spawn(async move {
-            while let Some(event) = handler.next().await {
-                if event.is_err() {
-                    warn!("Browser handler event error");
-                    break;
-                }
-            }
-            debug!("Browser handler finished");
-        });
+        // Drain the CDP handler stream; see `events` for backpressure behavior
+        let (handler_task, handler_stats) = super::events::spawn_driver(handler);
 
-        info!("Browser launched successfully");
+        // Record the product string so protocol mismatch errors can name
+        // the Chrome build that rejected a method
+        let chrome_version = browser.version().await.ok().map(|v| v.product);
+
+        info!(
+            "Browser launched successfully: {}",
+            chrome_version.as_deref().unwrap_or("unknown version")
+        );
+
+        let page_permits = Arc::new(Semaphore::new(config.max_concurrent_pages.max(1)));
 
         Ok(Self {
             browser,
             handler: handler_task,
+            handler_stats,
             config,
             pages: Arc::new(RwLock::new(Vec::new())),
+            page_permits,
+            chrome_version,
         })
     }
 
+    /// Product string reported by the browser at launch, when available
+    pub fn chrome_version(&self) -> Option<&str> {
+        self.chrome_version.as_deref()
+    }
+
     /// Create a new page/tab
+    ///
+    /// When `max_concurrent_pages` pages are already open, this queues until
+    /// one is closed via [`close_page`](Self::close_page) rather than opening
+    /// an unbounded number of tabs.
     #[instrument(skip(self))]
     pub async fn new_page(&self) -> Result<PageHandle> {
+        let permit = self
+            .page_permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
+
         let page = self
             .browser
             .new_page("about:blank")
             .await
             .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
 
-        // Apply stealth mode if enabled
+        // Apply stealth mode if enabled (always before user injections so
+        // ordering is deterministic); a hung cloak step surfaces as a typed
+        // stealth error instead of stalling the whole request
         if self.config.stealth {
-            super::stealth::StealthMode::apply(&page).await?;
+            self.config
+                .stealth_mode
+                .apply_with_timeout(&page, super::stealth::DEFAULT_CLOAK_TIMEOUT_MS)
+                .await?;
+        }
+
+        // Register user injections in configuration order
+        for injection in &self.config.injections {
+            let params = AddScriptToEvaluateOnNewDocumentParams::builder()
+                .source(injection.to_script())
+                .build()
+                .map_err(|e| Error::cdp(format!("Failed to build injection params: {}", e)))?;
+
+            page.execute(params)
+                .await
+                .map_err(|e| Error::cdp(format!("Failed to register injection: {}", e)))?;
         }
 
         let handle = PageHandle {
             page,
             url: Arc::new(RwLock::new("about:blank".to_string())),
+            chrome_version: self.chrome_version.clone(),
+            _permit: Arc::new(permit),
         };
 
         self.pages.write().await.push(handle.clone());
@@ -260,24 +489,307 @@ impl BrowserController {
         Ok(handle)
     }
 
+    /// Create a new page directly at a URL, skipping the blank step
+    ///
+    /// The target is created already pointed at `url`, saving the
+    /// `about:blank` document plus the separate navigation round trip. Only
+    /// safe when no per-page scripts are configured: stealth overrides and
+    /// injections register via `addScriptToEvaluateOnNewDocument`, which
+    /// must happen before the first document exists.
+    async fn new_page_direct(&self, url: &str) -> Result<PageHandle> {
+        let permit = self
+            .page_permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
+
+        let page = self
+            .browser
+            .new_page(url)
+            .await
+            .map_err(|e| BrowserError::PageCreationFailed(e.to_string()))?;
+
+        let handle = PageHandle {
+            page,
+            url: Arc::new(RwLock::new(url.to_string())),
+            chrome_version: self.chrome_version.clone(),
+            _permit: Arc::new(permit),
+        };
+
+        self.pages.write().await.push(handle.clone());
+        debug!(
+            "Created new page at {}",
+            crate::logging::sanitize_url(url)
+        );
+
+        Ok(handle)
+    }
+
     /// Navigate to URL and return page handle
+    ///
+    /// With stealth or injections configured (the default), the page starts
+    /// at `about:blank` so those scripts are registered before the first
+    /// real document — they apply to the first navigation. Without per-page
+    /// scripts the page is created directly at the target URL instead,
+    /// skipping the blank round trip.
     #[instrument(skip(self))]
     pub async fn navigate(&self, url: &str) -> Result<PageHandle> {
+        if !self.config.stealth && self.config.injections.is_empty() {
+            if let Err(msg) = super::navigation::UrlValidator::validate(url) {
+                return Err(crate::error::NavigationError::InvalidUrl(msg).into());
+            }
+            let page_handle = self.new_page_direct(url).await?;
+            page_handle
+                .wait_for_load_state(
+                    super::navigation::LoadState::Complete,
+                    crate::config::DEFAULT_TIMEOUT_MS,
+                )
+                .await?;
+            return Ok(page_handle);
+        }
+
         let page_handle = self.new_page().await?;
         super::navigation::PageNavigator::goto(&page_handle, url, None).await?;
         Ok(page_handle)
     }
 
+    /// Navigate to a file-serving URL and capture the resulting download
+    ///
+    /// Opens a fresh page, redirects downloads into a temporary directory,
+    /// and returns the first download's bytes. See
+    /// [`super::DownloadOptions`] for the size cap and click support.
+    #[instrument(skip(self, options))]
+    pub async fn download(
+        &self,
+        url: &str,
+        options: &super::DownloadOptions,
+    ) -> Result<super::DownloadedFile> {
+        let page = self.new_page().await?;
+        let result =
+            super::downloads::DownloadCapturer::capture(&self.browser, &page, url, options).await;
+        let _ = self.close_page(page).await;
+        result
+    }
+
+    /// Navigate to a URL and capture a matching network response body
+    ///
+    /// Opens a fresh page, watches network traffic while the page loads, and
+    /// returns the first finished response whose URL matches `pattern`. Fails
+    /// with a timeout if no match finishes within `timeout_ms` after the
+    /// navigation completes.
+    #[instrument(skip(self, pattern))]
+    pub async fn capture_response(
+        &self,
+        url: &str,
+        pattern: regex::Regex,
+        timeout_ms: u64,
+    ) -> Result<super::CapturedResponse> {
+        let page = self.new_page().await?;
+        let result = async {
+            let capturer = super::ResponseCapturer::install(&page, pattern).await?;
+            super::navigation::PageNavigator::goto(&page, url, None).await?;
+            capturer.wait_for_body(&page, timeout_ms).await
+        }
+        .await;
+        let _ = self.close_page(page).await;
+        result
+    }
+
+    /// Snapshot cookies and per-origin web storage for later reuse
+    ///
+    /// Cookies come from the whole browser via `Network.getCookies`; local
+    /// and session storage are read from every currently open page, one
+    /// entry per distinct origin. Authenticate once, save the state, and
+    /// feed it to [`load_storage_state`](Self::load_storage_state) in a
+    /// fresh browser to skip the login.
+    #[instrument(skip(self))]
+    pub async fn save_storage_state(&self) -> Result<super::StorageState> {
+        let cookies = self
+            .browser
+            .get_cookies()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?
+            .iter()
+            .map(super::StorageCookie::from_cookie)
+            .collect();
+
+        let mut state = super::StorageState {
+            cookies,
+            origins: Vec::new(),
+        };
+
+        let pages = self.pages.read().await.clone();
+        for page in &pages {
+            if let Some(storage) = super::storage_state::OriginStorage::capture(page).await {
+                state.push_origin(storage);
+            }
+        }
+
+        info!(
+            "Saved storage state: {} cookies, {} origins",
+            state.cookies.len(),
+            state.origins.len()
+        );
+        Ok(state)
+    }
+
+    /// Restore a saved storage state into this browser
+    ///
+    /// Cookies are set browser-wide in one call. Web storage is
+    /// origin-scoped, so each origin entry is applied by briefly navigating
+    /// a temporary page to that origin, writing the values, and closing the
+    /// page again. Call this before navigating to authenticated pages.
+    #[instrument(skip(self, state))]
+    pub async fn load_storage_state(&self, state: &super::StorageState) -> Result<()> {
+        if !state.cookies.is_empty() {
+            let params = state
+                .cookies
+                .iter()
+                .map(super::StorageCookie::to_cookie_param)
+                .collect();
+            self.browser
+                .set_cookies(params)
+                .await
+                .map_err(|e| Error::cdp(e.to_string()))?;
+        }
+
+        for origin in &state.origins {
+            let page = self.navigate(&origin.origin).await?;
+            let result = origin.apply(&page).await;
+            let _ = self.close_page(page).await;
+            result?;
+        }
+
+        info!(
+            "Loaded storage state: {} cookies, {} origins",
+            state.cookies.len(),
+            state.origins.len()
+        );
+        Ok(())
+    }
+
     /// Get the browser configuration
     pub fn config(&self) -> &BrowserConfig {
         &self.config
     }
 
+    /// Counters from the CDP handler drain loop
+    pub fn handler_stats(&self) -> &super::HandlerStats {
+        &self.handler_stats
+    }
+
     /// Get the number of open pages
     pub async fn page_count(&self) -> usize {
         self.pages.read().await.len()
     }
 
+    /// Preconnect to hosts to reduce first-navigation latency
+    ///
+    /// Opens a temporary page and registers `<link rel="preconnect">` hints
+    /// for each host, so DNS/TCP/TLS setup happens before the first real
+    /// navigation. Hosts may be bare hostnames (https assumed) or http(s)
+    /// URLs; invalid entries are skipped. Intended for crawl/batch scenarios
+    /// where target hosts are known up front.
+    #[instrument(skip(self))]
+    pub async fn warmup(&self, hosts: &[&str]) -> Result<()> {
+        let origins: Vec<String> = hosts
+            .iter()
+            .filter_map(|host| Self::normalize_warmup_host(host))
+            .collect();
+
+        if origins.is_empty() {
+            debug!("No valid hosts to warm up");
+            return Ok(());
+        }
+
+        info!("Warming up {} host(s)", origins.len());
+
+        let page = self.new_page().await?;
+
+        // serde_json escaping gives us a valid JS array literal
+        let origins_literal =
+            serde_json::to_string(&origins).unwrap_or_else(|_| "[]".to_string());
+        let script = format!(
+            r#"
+            (() => {{
+                for (const origin of {origins_literal}) {{
+                    const link = document.createElement('link');
+                    link.rel = 'preconnect';
+                    link.href = origin;
+                    link.crossOrigin = 'anonymous';
+                    document.head.appendChild(link);
+                }}
+            }})()
+            "#
+        );
+
+        page.page
+            .evaluate(script)
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        // Give the network service a moment to establish the connections;
+        // they stay pooled after the page closes
+        tokio::time::sleep(Duration::from_millis(WARMUP_SETTLE_MS)).await;
+
+        self.close_page(page).await
+    }
+
+    /// Normalize a warmup host into a preconnect origin
+    ///
+    /// Bare hostnames get an `https://` scheme; http(s) URLs are reduced to
+    /// their origin. Other schemes, empty input, and whitespace yield `None`.
+    pub fn normalize_warmup_host(host: &str) -> Option<String> {
+        let trimmed = host.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+
+        let (scheme, rest) = if let Some(rest) = trimmed.strip_prefix("https://") {
+            ("https", rest)
+        } else if let Some(rest) = trimmed.strip_prefix("http://") {
+            ("http", rest)
+        } else if trimmed.contains("://") {
+            return None;
+        } else {
+            ("https", trimmed)
+        };
+
+        let authority = rest.split('/').next().unwrap_or("");
+        if authority.is_empty() || authority.contains(char::is_whitespace) {
+            return None;
+        }
+
+        Some(format!("{}://{}", scheme, authority))
+    }
+
+    /// Number of pages that can still be opened before `new_page` queues
+    pub fn available_page_slots(&self) -> usize {
+        self.page_permits.available_permits()
+    }
+
+    /// Close a page and release its concurrency slot
+    ///
+    /// The slot is freed once every clone of the handle is dropped, so drop
+    /// other clones to unblock queued `new_page` callers.
+    #[instrument(skip(self, handle))]
+    pub async fn close_page(&self, handle: PageHandle) -> Result<()> {
+        self.pages
+            .write()
+            .await
+            .retain(|p| p.page.target_id() != handle.page.target_id());
+
+        handle
+            .page
+            .close()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        debug!("Closed page");
+        Ok(())
+    }
+
     /// Close the browser
     #[instrument(skip(self))]
     pub async fn close(mut self) -> Result<()> {
@@ -336,4 +848,93 @@ mod tests {
         assert!(!config.stealth);
         assert_eq!(config.extra_args, vec!["--disable-gpu"]);
     }
+
+    #[test]
+    fn test_browser_config_injections_preserve_order() {
+        let config = BrowserConfig::builder()
+            .inject_script("window.__INJECTED__ = true;")
+            .inject_style("body { margin: 0; }")
+            .inject_script("window.__SECOND__ = true;")
+            .build();
+
+        assert_eq!(
+            config.injections,
+            vec![
+                PageInjection::Script("window.__INJECTED__ = true;".to_string()),
+                PageInjection::Style("body { margin: 0; }".to_string()),
+                PageInjection::Script("window.__SECOND__ = true;".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_page_injection_script_passthrough() {
+        let injection = PageInjection::Script("window.__INJECTED__ = true;".to_string());
+        assert_eq!(injection.to_script(), "window.__INJECTED__ = true;");
+    }
+
+    #[test]
+    fn test_page_injection_style_wrapped() {
+        let injection = PageInjection::Style("body { color: \"red\"; }".to_string());
+        let script = injection.to_script();
+
+        // CSS is embedded as an escaped JS string literal
+        assert!(script.contains(r#""body { color: \"red\"; }""#));
+        assert!(script.contains("document.createElement('style')"));
+    }
+
+    #[test]
+    fn test_browser_config_default_has_no_injections() {
+        assert!(BrowserConfig::default().injections.is_empty());
+    }
+
+    #[test]
+    fn test_browser_config_max_concurrent_pages() {
+        assert_eq!(BrowserConfig::default().max_concurrent_pages, 8);
+
+        let config = BrowserConfig::builder().max_concurrent_pages(2).build();
+        assert_eq!(config.max_concurrent_pages, 2);
+    }
+
+    #[test]
+    fn test_browser_config_max_concurrent_pages_clamped_to_one() {
+        let config = BrowserConfig::builder().max_concurrent_pages(0).build();
+        assert_eq!(config.max_concurrent_pages, 1);
+    }
+
+    #[test]
+    fn test_normalize_warmup_host_bare_hostname() {
+        assert_eq!(
+            BrowserController::normalize_warmup_host("example.com"),
+            Some("https://example.com".to_string())
+        );
+        assert_eq!(
+            BrowserController::normalize_warmup_host("cdn.example.com:8443"),
+            Some("https://cdn.example.com:8443".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_warmup_host_urls_reduced_to_origin() {
+        assert_eq!(
+            BrowserController::normalize_warmup_host("https://example.com/some/path"),
+            Some("https://example.com".to_string())
+        );
+        assert_eq!(
+            BrowserController::normalize_warmup_host("http://example.com"),
+            Some("http://example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_warmup_host_rejects_invalid() {
+        assert_eq!(BrowserController::normalize_warmup_host(""), None);
+        assert_eq!(BrowserController::normalize_warmup_host("   "), None);
+        assert_eq!(BrowserController::normalize_warmup_host("ftp://example.com"), None);
+        assert_eq!(BrowserController::normalize_warmup_host("https://"), None);
+        assert_eq!(
+            BrowserController::normalize_warmup_host("not a hostname"),
+            None
+        );
+    }
 }
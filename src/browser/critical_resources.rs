@@ -0,0 +1,172 @@
+//! Critical subresource monitoring
+//!
+//! Pages routinely lose a tracking pixel or an ad script without any real
+//! harm, so subresource failures must not fail a navigation wholesale. Some
+//! resources are load-bearing, though: an app bundle or an API call the page
+//! cannot render without. This module watches network traffic for failures
+//! of resources matching configured patterns so the navigation can be
+//! failed for exactly those.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::network::{
+    EventLoadingFailed, EventRequestWillBeSent, EventResponseReceived, RequestId,
+};
+use futures::StreamExt;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+/// A critical resource that failed to load
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedResource {
+    /// URL of the failed resource
+    pub url: String,
+    /// Why it failed (network error text or `HTTP <status>`)
+    pub reason: String,
+}
+
+/// Active critical-resource monitoring on a page
+///
+/// Records network failures (`Network.loadingFailed`) and HTTP error
+/// responses (status >= 400) for URLs matching the configured patterns.
+/// Everything else fails silently, as it would in a real browser session.
+/// Dropping the monitor stops collection.
+pub struct CriticalResourceMonitor {
+    task: JoinHandle<()>,
+    failures: Arc<RwLock<Vec<FailedResource>>>,
+}
+
+impl CriticalResourceMonitor {
+    /// Compile pattern strings into regexes, rejecting invalid ones
+    pub fn compile_patterns(patterns: &[String]) -> Result<Vec<Regex>> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| {
+                    Error::generic(format!(
+                        "Invalid critical resource pattern '{}': {}",
+                        pattern, e
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Install critical-resource monitoring on a page
+    pub async fn install(page: &PageHandle, patterns: Vec<Regex>) -> Result<Self> {
+        info!(
+            "Installing critical-resource monitor ({} patterns)",
+            patterns.len()
+        );
+
+        let mut requests = page
+            .page
+            .event_listener::<EventRequestWillBeSent>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let mut network_failures = page
+            .page
+            .event_listener::<EventLoadingFailed>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let mut responses = page
+            .page
+            .event_listener::<EventResponseReceived>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let failures = Arc::new(RwLock::new(Vec::new()));
+        let task_failures = Arc::clone(&failures);
+
+        let task = tokio::spawn(async move {
+            let is_critical = |url: &str| patterns.iter().any(|pattern| pattern.is_match(url));
+            let mut urls: HashMap<RequestId, String> = HashMap::new();
+            loop {
+                tokio::select! {
+                    Some(event) = requests.next() => {
+                        urls.insert(event.request_id.clone(), event.request.url.clone());
+                    }
+                    Some(event) = network_failures.next() => {
+                        let url = urls
+                            .get(&event.request_id)
+                            .cloned()
+                            .unwrap_or_default();
+                        if !is_critical(&url) {
+                            continue;
+                        }
+                        task_failures.write().await.push(FailedResource {
+                            url,
+                            reason: event.error_text.clone(),
+                        });
+                    }
+                    Some(event) = responses.next() => {
+                        if event.response.status < 400 || !is_critical(&event.response.url) {
+                            continue;
+                        }
+                        task_failures.write().await.push(FailedResource {
+                            url: event.response.url.clone(),
+                            reason: format!("HTTP {}", event.response.status),
+                        });
+                    }
+                    else => break,
+                }
+            }
+        });
+
+        Ok(Self { task, failures })
+    }
+
+    /// Critical resources that failed so far, in order of failure
+    pub async fn failures(&self) -> Vec<FailedResource> {
+        self.failures.read().await.clone()
+    }
+}
+
+impl Drop for CriticalResourceMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_patterns_valid() {
+        let patterns = CriticalResourceMonitor::compile_patterns(&[
+            "/api/".to_string(),
+            r"bundle\.js$".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(patterns.len(), 2);
+        assert!(patterns[1].is_match("https://example.com/app/bundle.js"));
+    }
+
+    #[test]
+    fn test_compile_patterns_invalid() {
+        let err = CriticalResourceMonitor::compile_patterns(&["(unclosed".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("(unclosed"));
+    }
+
+    #[test]
+    fn test_failed_resource_serialization() {
+        let failure = FailedResource {
+            url: "https://example.com/app/bundle.js".to_string(),
+            reason: "HTTP 404".to_string(),
+        };
+
+        let json = serde_json::to_value(&failure).unwrap();
+        assert_eq!(json["url"], "https://example.com/app/bundle.js");
+        assert_eq!(json["reason"], "HTTP 404");
+
+        let back: FailedResource = serde_json::from_value(json).unwrap();
+        assert_eq!(back, failure);
+    }
+}
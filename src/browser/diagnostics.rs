@@ -0,0 +1,178 @@
+//! Failure diagnostics
+//!
+//! When a navigation fails, the error alone rarely explains what the page
+//! looked like. This module records console output while a navigation is in
+//! flight and, on failure, writes a screenshot, the current HTML, and the
+//! collected console log into a debug directory so the error can point at
+//! concrete artifacts.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::js_protocol::runtime::EventConsoleApiCalled;
+use chromiumoxide::cdp::browser_protocol::page::CaptureScreenshotFormat;
+use chromiumoxide::page::ScreenshotParams;
+use futures::StreamExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Artifacts written for one failed navigation
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticArtifacts {
+    /// PNG screenshot of the page at failure time
+    pub screenshot: Option<PathBuf>,
+    /// HTML of the document at failure time
+    pub html: Option<PathBuf>,
+    /// Console output collected since the recorder was installed
+    pub console: Option<PathBuf>,
+}
+
+impl DiagnosticArtifacts {
+    /// Short description of the written artifacts for error messages
+    pub fn summary(&self) -> String {
+        let paths: Vec<String> = [&self.screenshot, &self.html, &self.console]
+            .iter()
+            .filter_map(|p| p.as_ref().map(|p| p.display().to_string()))
+            .collect();
+        paths.join(", ")
+    }
+}
+
+/// Records console output and captures failure artifacts on demand
+///
+/// Install before navigating; console messages cannot be recovered
+/// retroactively. Dropping the recorder stops collection.
+pub struct DiagnosticsRecorder {
+    task: JoinHandle<()>,
+    console: Arc<RwLock<Vec<String>>>,
+}
+
+impl DiagnosticsRecorder {
+    /// Install console recording on a page
+    pub async fn install(page: &PageHandle) -> Result<Self> {
+        let mut events = page
+            .page
+            .event_listener::<EventConsoleApiCalled>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let console = Arc::new(RwLock::new(Vec::new()));
+        let task_console = Arc::clone(&console);
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let args: Vec<String> = event
+                    .args
+                    .iter()
+                    .map(|arg| match &arg.value {
+                        Some(value) => value.to_string(),
+                        None => arg.description.clone().unwrap_or_default(),
+                    })
+                    .collect();
+                task_console
+                    .write()
+                    .await
+                    .push(format!("[{}] {}", event.r#type.as_ref(), args.join(" ")));
+            }
+        });
+
+        Ok(Self { task, console })
+    }
+
+    /// Capture a screenshot, the current HTML, and the console log into `dir`
+    ///
+    /// Each artifact is best-effort: a page too broken to screenshot still
+    /// yields whatever can be captured. Fails only if `dir` cannot be
+    /// created.
+    pub async fn capture(&self, page: &PageHandle, dir: &Path) -> Result<DiagnosticArtifacts> {
+        tokio::fs::create_dir_all(dir)
+            .await
+            .map_err(|e| Error::generic(format!("Failed to create diagnostics dir: {}", e)))?;
+
+        let stem = Self::artifact_stem();
+        let mut artifacts = DiagnosticArtifacts::default();
+
+        let params = ScreenshotParams::builder()
+            .format(CaptureScreenshotFormat::Png)
+            .from_surface(true)
+            .build();
+        match page.page.screenshot(params).await {
+            Ok(data) => {
+                let path = dir.join(format!("{}.png", stem));
+                if tokio::fs::write(&path, &data).await.is_ok() {
+                    artifacts.screenshot = Some(path);
+                }
+            }
+            Err(e) => warn!("Diagnostic screenshot failed: {}", e),
+        }
+
+        match page.page.content().await {
+            Ok(html) => {
+                let path = dir.join(format!("{}.html", stem));
+                if tokio::fs::write(&path, &html).await.is_ok() {
+                    artifacts.html = Some(path);
+                }
+            }
+            Err(e) => warn!("Diagnostic HTML capture failed: {}", e),
+        }
+
+        let console = self.console.read().await;
+        if !console.is_empty() {
+            let path = dir.join(format!("{}.console.log", stem));
+            if tokio::fs::write(&path, console.join("\n")).await.is_ok() {
+                artifacts.console = Some(path);
+            }
+        }
+
+        info!("Wrote failure diagnostics: {}", artifacts.summary());
+        Ok(artifacts)
+    }
+
+    /// Unique filename stem for one failure's artifacts
+    fn artifact_stem() -> String {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        format!("nav_failure_{}", millis)
+    }
+}
+
+impl Drop for DiagnosticsRecorder {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artifact_stem_format() {
+        let stem = DiagnosticsRecorder::artifact_stem();
+        assert!(stem.starts_with("nav_failure_"));
+        assert!(stem["nav_failure_".len()..].chars().all(|c| c.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_summary_lists_written_artifacts() {
+        let artifacts = DiagnosticArtifacts {
+            screenshot: Some(PathBuf::from("/tmp/nav_failure_1.png")),
+            html: None,
+            console: Some(PathBuf::from("/tmp/nav_failure_1.console.log")),
+        };
+
+        let summary = artifacts.summary();
+        assert!(summary.contains("nav_failure_1.png"));
+        assert!(summary.contains("nav_failure_1.console.log"));
+        assert!(!summary.contains(".html"));
+    }
+
+    #[test]
+    fn test_summary_empty_without_artifacts() {
+        assert_eq!(DiagnosticArtifacts::default().summary(), "");
+    }
+}
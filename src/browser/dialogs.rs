@@ -0,0 +1,235 @@
+//! JavaScript dialog handling
+//!
+//! This module answers `alert`/`confirm`/`prompt`/`beforeunload` dialogs with
+//! a per-type policy instead of one global action, recording each dialog's
+//! message so callers can inspect what the page tried to show.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::page::{
+    DialogType, EventJavascriptDialogOpening, HandleJavaScriptDialogParams,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// What to do with a dialog of a given type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogAction {
+    /// Accept the dialog (OK / leave page)
+    Accept,
+    /// Dismiss the dialog (Cancel / stay on page)
+    Dismiss,
+    /// Accept a prompt with the given text; other types treat this as Accept
+    AcceptWithText(String),
+}
+
+/// Per-type dialog policies
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogPolicy {
+    /// Policy for `alert` dialogs
+    pub alert: DialogAction,
+    /// Policy for `confirm` dialogs
+    pub confirm: DialogAction,
+    /// Policy for `prompt` dialogs
+    pub prompt: DialogAction,
+    /// Policy for `beforeunload` dialogs
+    pub beforeunload: DialogAction,
+}
+
+impl Default for DialogPolicy {
+    /// Acknowledge alerts, decline everything that could change flow, and
+    /// allow leaving the page
+    fn default() -> Self {
+        Self {
+            alert: DialogAction::Accept,
+            confirm: DialogAction::Dismiss,
+            prompt: DialogAction::Dismiss,
+            beforeunload: DialogAction::Accept,
+        }
+    }
+}
+
+impl DialogPolicy {
+    /// Accept every dialog type
+    pub fn accept_all() -> Self {
+        Self {
+            alert: DialogAction::Accept,
+            confirm: DialogAction::Accept,
+            prompt: DialogAction::Accept,
+            beforeunload: DialogAction::Accept,
+        }
+    }
+
+    /// Dismiss every dialog type
+    pub fn dismiss_all() -> Self {
+        Self {
+            alert: DialogAction::Dismiss,
+            confirm: DialogAction::Dismiss,
+            prompt: DialogAction::Dismiss,
+            beforeunload: DialogAction::Dismiss,
+        }
+    }
+
+    /// The action configured for a dialog type
+    pub fn action_for(&self, dialog_type: DialogType) -> &DialogAction {
+        match dialog_type {
+            DialogType::Alert => &self.alert,
+            DialogType::Confirm => &self.confirm,
+            DialogType::Prompt => &self.prompt,
+            DialogType::Beforeunload => &self.beforeunload,
+        }
+    }
+}
+
+/// Record of a dialog the handler answered
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogRecord {
+    /// Dialog type: `alert`, `confirm`, `prompt`, or `beforeunload`
+    pub dialog_type: String,
+    /// Message the page tried to display
+    pub message: String,
+    /// Whether the dialog was accepted
+    pub accepted: bool,
+}
+
+/// Active dialog handling on a page
+///
+/// Holds the background task that answers dialogs. Dropping the handler
+/// stops handling; unanswered dialogs after that point stall the page, so
+/// keep it alive while dialogs may fire.
+pub struct DialogHandler {
+    task: JoinHandle<()>,
+    records: Arc<RwLock<Vec<DialogRecord>>>,
+}
+
+impl DialogHandler {
+    /// Install dialog handling on a page with the given per-type policy
+    pub async fn install(page: &PageHandle, policy: DialogPolicy) -> Result<Self> {
+        info!("Installing dialog handler");
+
+        let mut events = page
+            .page
+            .event_listener::<EventJavascriptDialogOpening>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let cdp_page = page.page.clone();
+        let records = Arc::new(RwLock::new(Vec::new()));
+        let task_records = Arc::clone(&records);
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let action = policy.action_for(event.r#type.clone());
+                let (accept, prompt_text) = match action {
+                    DialogAction::Accept => (true, None),
+                    DialogAction::Dismiss => (false, None),
+                    DialogAction::AcceptWithText(text) => (true, Some(text.clone())),
+                };
+
+                debug!(
+                    "Handling {} dialog (accept={}): {}",
+                    event.r#type.as_ref(),
+                    accept,
+                    event.message
+                );
+
+                let params = HandleJavaScriptDialogParams {
+                    accept,
+                    prompt_text,
+                };
+                if let Err(e) = cdp_page.execute(params).await {
+                    warn!("Failed to handle dialog: {}", e);
+                    continue;
+                }
+
+                task_records.write().await.push(DialogRecord {
+                    dialog_type: event.r#type.as_ref().to_string(),
+                    message: event.message.clone(),
+                    accepted: accept,
+                });
+            }
+        });
+
+        Ok(Self { task, records })
+    }
+
+    /// Dialogs answered so far, in order of appearance
+    pub async fn records(&self) -> Vec<DialogRecord> {
+        self.records.read().await.clone()
+    }
+}
+
+impl Drop for DialogHandler {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy() {
+        let policy = DialogPolicy::default();
+        assert_eq!(policy.alert, DialogAction::Accept);
+        assert_eq!(policy.confirm, DialogAction::Dismiss);
+        assert_eq!(policy.prompt, DialogAction::Dismiss);
+        assert_eq!(policy.beforeunload, DialogAction::Accept);
+    }
+
+    #[test]
+    fn test_per_type_policies_applied_independently() {
+        // Accept beforeunload but dismiss alerts, per the per-type config
+        let policy = DialogPolicy {
+            alert: DialogAction::Dismiss,
+            confirm: DialogAction::Accept,
+            prompt: DialogAction::AcceptWithText("typed".to_string()),
+            beforeunload: DialogAction::Accept,
+        };
+
+        assert_eq!(policy.action_for(DialogType::Alert), &DialogAction::Dismiss);
+        assert_eq!(
+            policy.action_for(DialogType::Confirm),
+            &DialogAction::Accept
+        );
+        assert_eq!(
+            policy.action_for(DialogType::Prompt),
+            &DialogAction::AcceptWithText("typed".to_string())
+        );
+        assert_eq!(
+            policy.action_for(DialogType::Beforeunload),
+            &DialogAction::Accept
+        );
+    }
+
+    #[test]
+    fn test_accept_all_and_dismiss_all() {
+        let accept = DialogPolicy::accept_all();
+        assert_eq!(accept.action_for(DialogType::Confirm), &DialogAction::Accept);
+
+        let dismiss = DialogPolicy::dismiss_all();
+        assert_eq!(
+            dismiss.action_for(DialogType::Beforeunload),
+            &DialogAction::Dismiss
+        );
+    }
+
+    #[test]
+    fn test_dialog_record_serialization() {
+        let record = DialogRecord {
+            dialog_type: "confirm".to_string(),
+            message: "Really?".to_string(),
+            accepted: false,
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["dialog_type"], "confirm");
+        assert_eq!(json["message"], "Really?");
+        assert_eq!(json["accepted"], false);
+    }
+}
@@ -0,0 +1,254 @@
+//! Download capture
+//!
+//! Some target URLs are really files behind a link or redirect. Instead of
+//! letting Chrome drop the download, this module redirects it into a
+//! temporary directory via `Browser.setDownloadBehavior`, follows the
+//! `downloadWillBegin`/`downloadProgress` events, and returns the bytes
+//! with the suggested filename.
+
+use crate::browser::PageHandle;
+use crate::error::{BrowserError, Error, Result};
+use chromiumoxide::cdp::browser_protocol::browser::{
+    DownloadProgressState, EventDownloadProgress, EventDownloadWillBegin,
+    SetDownloadBehaviorBehavior, SetDownloadBehaviorParams,
+};
+use chromiumoxide::Browser;
+use futures::StreamExt;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{debug, info, instrument};
+
+/// Default size cap for captured downloads (16 MiB)
+pub const DEFAULT_MAX_DOWNLOAD_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Options for capturing a download
+#[derive(Debug, Clone)]
+pub struct DownloadOptions {
+    /// Maximum file size in bytes (default: 16 MiB)
+    pub max_bytes: u64,
+    /// Timeout for the whole capture in milliseconds (default: 30000)
+    pub timeout_ms: u64,
+    /// CSS selector to click after navigation, for downloads behind a
+    /// link rather than served by the URL itself (default: none)
+    pub click_selector: Option<String>,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            max_bytes: DEFAULT_MAX_DOWNLOAD_BYTES,
+            timeout_ms: 30000,
+            click_selector: None,
+        }
+    }
+}
+
+/// A captured download
+#[derive(Debug, Clone)]
+pub struct DownloadedFile {
+    /// Filename suggested by the server or derived from the URL
+    pub suggested_filename: String,
+    /// URL the bytes were actually downloaded from
+    pub url: String,
+    /// The file contents
+    pub data: Vec<u8>,
+}
+
+/// Download capture functionality
+pub struct DownloadCapturer;
+
+impl DownloadCapturer {
+    /// Capture the first download triggered by navigating to `url`
+    /// (and clicking `options.click_selector`, when set)
+    #[instrument(skip(browser, page, options))]
+    pub async fn capture(
+        browser: &Browser,
+        page: &PageHandle,
+        url: &str,
+        options: &DownloadOptions,
+    ) -> Result<DownloadedFile> {
+        info!(
+            "Capturing download from: {}",
+            crate::logging::sanitize_url(url)
+        );
+
+        let dir = Self::capture_dir();
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| BrowserError::DownloadFailed(e.to_string()))?;
+
+        let result = Self::capture_into(browser, page, url, options, &dir).await;
+
+        // Best-effort cleanup; the capture result matters more
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        let _ = browser
+            .execute(SetDownloadBehaviorParams {
+                behavior: SetDownloadBehaviorBehavior::Default,
+                browser_context_id: None,
+                download_path: None,
+                events_enabled: Some(false),
+            })
+            .await;
+
+        result
+    }
+
+    /// Run the capture with downloads redirected into `dir`
+    async fn capture_into(
+        browser: &Browser,
+        page: &PageHandle,
+        url: &str,
+        options: &DownloadOptions,
+        dir: &std::path::Path,
+    ) -> Result<DownloadedFile> {
+        browser
+            .execute(SetDownloadBehaviorParams {
+                behavior: SetDownloadBehaviorBehavior::AllowAndName,
+                browser_context_id: None,
+                download_path: Some(dir.to_string_lossy().into_owned()),
+                events_enabled: Some(true),
+            })
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let mut begins = browser
+            .event_listener::<EventDownloadWillBegin>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let mut progress = browser
+            .event_listener::<EventDownloadProgress>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        // Navigation to a file-serving URL aborts once Chrome hands it to
+        // the download pipeline, so goto errors are expected here
+        let _ = page.page.goto(url).await;
+
+        if let Some(selector) = &options.click_selector {
+            // Overlays and disabled states make clicks silently miss; wait
+            // until the target would actually receive the click
+            crate::browser::PageNavigator::wait_for_clickable(page, selector, options.timeout_ms)
+                .await
+                .map_err(|e| {
+                    BrowserError::DownloadFailed(format!("Click target not clickable: {}", e))
+                })?;
+            page.page
+                .find_element(selector.as_str())
+                .await
+                .map_err(|e| {
+                    BrowserError::DownloadFailed(format!("Click target not found: {}", e))
+                })?
+                .click()
+                .await
+                .map_err(|e| BrowserError::DownloadFailed(format!("Click failed: {}", e)))?;
+        }
+
+        let max_bytes = options.max_bytes;
+        let begin = tokio::time::timeout(Duration::from_millis(options.timeout_ms), async {
+            let begin = begins.next().await.ok_or_else(|| {
+                Error::from(BrowserError::DownloadFailed(
+                    "No download started".to_string(),
+                ))
+            })?;
+            debug!(
+                "Download began: {} ({})",
+                begin.suggested_filename, begin.url
+            );
+
+            loop {
+                let event = progress.next().await.ok_or_else(|| {
+                    Error::from(BrowserError::DownloadFailed(
+                        "Download event stream closed".to_string(),
+                    ))
+                })?;
+                if event.guid != begin.guid {
+                    continue;
+                }
+
+                let reported = event.total_bytes.max(event.received_bytes) as u64;
+                if reported > max_bytes {
+                    return Err(Error::from(BrowserError::DownloadTooLarge {
+                        actual: reported,
+                        max: max_bytes,
+                    }));
+                }
+
+                match event.state {
+                    DownloadProgressState::Completed => return Ok(begin),
+                    DownloadProgressState::Canceled => {
+                        return Err(Error::from(BrowserError::DownloadFailed(
+                            "Download canceled".to_string(),
+                        )))
+                    }
+                    DownloadProgressState::InProgress => continue,
+                }
+            }
+        })
+        .await
+        .map_err(|_| Error::from(BrowserError::Timeout(options.timeout_ms)))??;
+
+        // AllowAndName stores the file under its download guid
+        let path = dir.join(&begin.guid);
+        let data = tokio::fs::read(&path).await.map_err(|e| {
+            BrowserError::DownloadFailed(format!("Failed to read downloaded file: {}", e))
+        })?;
+        if data.len() as u64 > max_bytes {
+            return Err(BrowserError::DownloadTooLarge {
+                actual: data.len() as u64,
+                max: max_bytes,
+            }
+            .into());
+        }
+
+        Ok(DownloadedFile {
+            suggested_filename: begin.suggested_filename.clone(),
+            url: begin.url.clone(),
+            data,
+        })
+    }
+
+    /// Unique temporary directory for one capture
+    fn capture_dir() -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        std::env::temp_dir().join(format!("reasonkit_download_{}_{}", std::process::id(), nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_download_options_defaults() {
+        let options = DownloadOptions::default();
+        assert_eq!(options.max_bytes, DEFAULT_MAX_DOWNLOAD_BYTES);
+        assert_eq!(options.timeout_ms, 30000);
+        assert!(options.click_selector.is_none());
+    }
+
+    #[test]
+    fn test_capture_dirs_are_unique() {
+        let first = DownloadCapturer::capture_dir();
+        let second = DownloadCapturer::capture_dir();
+        assert_ne!(first, second);
+        assert!(first
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("reasonkit_download_"));
+    }
+
+    #[test]
+    fn test_download_too_large_error_message() {
+        let err = crate::error::Error::from(BrowserError::DownloadTooLarge {
+            actual: 2048,
+            max: 1024,
+        });
+        let msg = err.to_string();
+        assert!(msg.contains("2048"));
+        assert!(msg.contains("1024"));
+    }
+}
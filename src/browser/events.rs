@@ -0,0 +1,153 @@
+//! CDP handler draining
+//!
+//! The chromiumoxide handler stream drives the entire CDP connection:
+//! command responses and page event listeners all stall unless it is polled
+//! promptly. The previous loop aborted on the first error, silently killing
+//! the connection. This module drains the stream continuously, survives
+//! per-event errors, yields periodically so a flooding page cannot starve
+//! the runtime, and warns (with counters) when the handler falls behind.
+
+use futures::{FutureExt, Stream, StreamExt};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+/// Back-to-back ready events that count as the handler lagging
+pub const HANDLER_BURST_WARN_THRESHOLD: usize = 512;
+
+/// Drained events between cooperative yields during a burst
+const YIELD_EVERY: usize = 64;
+
+/// Errors between repeated log lines (the first error always logs)
+const ERROR_LOG_INTERVAL: u64 = 100;
+
+/// Counters from the CDP handler drain loop
+///
+/// All counters are monotonic and use relaxed ordering; they exist for
+/// observability, not synchronization.
+#[derive(Debug, Default)]
+pub struct HandlerStats {
+    events: AtomicU64,
+    errors: AtomicU64,
+    burst_warnings: AtomicU64,
+}
+
+impl HandlerStats {
+    /// Total handler items drained, including errored ones
+    pub fn events(&self) -> u64 {
+        self.events.load(Ordering::Relaxed)
+    }
+
+    /// Handler items that carried an error
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// Times the drain loop warned about a large event burst
+    pub fn burst_warnings(&self) -> u64 {
+        self.burst_warnings.load(Ordering::Relaxed)
+    }
+}
+
+/// Spawn the drain task for a browser's CDP handler stream
+pub(crate) fn spawn_driver<S, E>(handler: S) -> (JoinHandle<()>, Arc<HandlerStats>)
+where
+    S: Stream<Item = std::result::Result<(), E>> + Unpin + Send + 'static,
+    E: std::fmt::Debug + Send + 'static,
+{
+    let stats = Arc::new(HandlerStats::default());
+    let task_stats = Arc::clone(&stats);
+    let task = tokio::spawn(drive(handler, task_stats));
+    (task, stats)
+}
+
+/// Drain a handler stream until it ends
+///
+/// Ready events are consumed immediately so listeners never wait on a
+/// backlog; every [`YIELD_EVERY`] consecutive events the loop yields to keep
+/// a chatty page from monopolizing the executor. Errors are counted and
+/// rate-limit logged but never stop the loop: aborting here would sever the
+/// CDP connection for every open page.
+pub(crate) async fn drive<S, E>(mut handler: S, stats: Arc<HandlerStats>)
+where
+    S: Stream<Item = std::result::Result<(), E>> + Unpin,
+    E: std::fmt::Debug,
+{
+    let mut burst = 0usize;
+    loop {
+        let item = match handler.next().now_or_never() {
+            Some(Some(item)) => {
+                burst += 1;
+                if burst == HANDLER_BURST_WARN_THRESHOLD {
+                    stats.burst_warnings.fetch_add(1, Ordering::Relaxed);
+                    warn!(
+                        "CDP handler draining a burst of {}+ events; a page may be flooding",
+                        HANDLER_BURST_WARN_THRESHOLD
+                    );
+                }
+                if burst % YIELD_EVERY == 0 {
+                    tokio::task::yield_now().await;
+                }
+                item
+            }
+            Some(None) => break,
+            None => {
+                burst = 0;
+                match handler.next().await {
+                    Some(item) => item,
+                    None => break,
+                }
+            }
+        };
+
+        stats.events.fetch_add(1, Ordering::Relaxed);
+        if let Err(e) = item {
+            let errors = stats.errors.fetch_add(1, Ordering::Relaxed) + 1;
+            if errors == 1 || errors % ERROR_LOG_INTERVAL == 0 {
+                warn!("Browser handler event error ({} so far): {:?}", errors, e);
+            }
+        }
+    }
+    debug!("Browser handler finished");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drive_counts_events_and_survives_errors() {
+        let items: Vec<std::result::Result<(), String>> =
+            vec![Ok(()), Err("boom".to_string()), Ok(()), Ok(())];
+        let stats = Arc::new(HandlerStats::default());
+
+        drive(futures::stream::iter(items), Arc::clone(&stats)).await;
+
+        // The error did not stop the loop: all four items were drained
+        assert_eq!(stats.events(), 4);
+        assert_eq!(stats.errors(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_drive_warns_on_event_burst() {
+        let items: Vec<std::result::Result<(), String>> =
+            vec![Ok(()); HANDLER_BURST_WARN_THRESHOLD + 10];
+        let stats = Arc::new(HandlerStats::default());
+
+        drive(futures::stream::iter(items), Arc::clone(&stats)).await;
+
+        assert_eq!(stats.events() as usize, HANDLER_BURST_WARN_THRESHOLD + 10);
+        assert_eq!(stats.burst_warnings(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_drive_no_warning_below_threshold() {
+        let items: Vec<std::result::Result<(), String>> = vec![Ok(()); 100];
+        let stats = Arc::new(HandlerStats::default());
+
+        drive(futures::stream::iter(items), Arc::clone(&stats)).await;
+
+        assert_eq!(stats.burst_warnings(), 0);
+    }
+}
@@ -0,0 +1,210 @@
+//! Multi-frame script evaluation
+//!
+//! This module runs a script in every frame of a page (including nested
+//! iframes) through per-frame CDP execution contexts. Each frame yields its
+//! own result entry; frames that refuse execution (e.g. cross-origin
+//! restrictions) are reported as error entries rather than silently omitted.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::page::{
+    CreateIsolatedWorldParams, FrameTree, GetFrameTreeParams,
+};
+use chromiumoxide::cdp::js_protocol::runtime::EvaluateParams;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{info, instrument, warn};
+
+/// World name reported in per-frame execution contexts
+const EVAL_WORLD_NAME: &str = "reasonkit_frame_eval";
+
+/// Identity of a frame in the page's frame tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameInfo {
+    /// CDP frame identifier
+    pub id: String,
+    /// Frame document URL without fragment
+    pub url: String,
+}
+
+/// Result of evaluating a script in one frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameEvalResult {
+    /// The frame the script ran in (or failed to run in)
+    pub frame: FrameInfo,
+    /// Evaluation result, when the script ran successfully
+    #[serde(default)]
+    pub value: Option<Value>,
+    /// Why evaluation failed, when it did
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Multi-frame evaluation functionality
+pub struct FrameEvaluator;
+
+impl FrameEvaluator {
+    /// Evaluate a script in every frame of the page
+    ///
+    /// Frames are visited depth-first with the main frame first. Each entry
+    /// carries either the frame's result or the error that prevented
+    /// execution there.
+    #[instrument(skip(page, script))]
+    pub async fn evaluate_all(page: &PageHandle, script: &str) -> Result<Vec<FrameEvalResult>> {
+        info!("Evaluating script across all frames");
+
+        let tree = page
+            .page
+            .execute(GetFrameTreeParams::default())
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let frames = Self::flatten_frame_tree(&tree.frame_tree);
+        let mut results = Vec::with_capacity(frames.len());
+
+        for frame in frames {
+            match Self::evaluate_in_frame(page, &frame, script).await {
+                Ok(value) => results.push(FrameEvalResult {
+                    frame,
+                    value: Some(value),
+                    error: None,
+                }),
+                Err(e) => {
+                    warn!("Frame {} refused evaluation: {}", frame.url, e);
+                    results.push(FrameEvalResult {
+                        frame,
+                        value: None,
+                        error: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Evaluate a script in one frame via an isolated execution context
+    async fn evaluate_in_frame(page: &PageHandle, frame: &FrameInfo, script: &str) -> Result<Value> {
+        let world = page
+            .page
+            .execute(
+                CreateIsolatedWorldParams::builder()
+                    .frame_id(frame.id.clone())
+                    .world_name(EVAL_WORLD_NAME)
+                    .build()
+                    .map_err(Error::cdp)?,
+            )
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let params = EvaluateParams::builder()
+            .expression(script)
+            .context_id(world.execution_context_id)
+            .return_by_value(true)
+            .await_promise(true)
+            .build()
+            .map_err(Error::cdp)?;
+
+        let result = page
+            .page
+            .execute(params)
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        if let Some(details) = &result.exception_details {
+            return Err(Error::cdp(format!("JavaScript exception: {}", details.text)));
+        }
+
+        Ok(result.result.result.value.clone().unwrap_or(Value::Null))
+    }
+
+    /// Flatten the CDP frame tree depth-first, main frame first
+    pub fn flatten_frame_tree(tree: &FrameTree) -> Vec<FrameInfo> {
+        let mut frames = vec![FrameInfo {
+            id: tree.frame.id.inner().clone(),
+            url: tree.frame.url.clone(),
+        }];
+
+        if let Some(children) = &tree.child_frames {
+            for child in children {
+                frames.extend(Self::flatten_frame_tree(child));
+            }
+        }
+
+        frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// A minimal frame in CDP wire format
+    fn frame_json(id: &str, url: &str) -> Value {
+        json!({
+            "id": id,
+            "loaderId": "loader-1",
+            "url": url,
+            "domainAndRegistry": "example.com",
+            "securityOrigin": "https://example.com",
+            "mimeType": "text/html",
+            "secureContextType": "Secure",
+            "crossOriginIsolatedContextType": "NotIsolated",
+            "gatedAPIFeatures": []
+        })
+    }
+
+    #[test]
+    fn test_flatten_frame_tree_nested() {
+        let tree: FrameTree = serde_json::from_value(json!({
+            "frame": frame_json("main", "https://example.com/"),
+            "childFrames": [
+                {
+                    "frame": frame_json("child-a", "https://example.com/a"),
+                    "childFrames": [
+                        { "frame": frame_json("grandchild", "https://example.com/a/inner") }
+                    ]
+                },
+                { "frame": frame_json("child-b", "https://example.com/b") }
+            ]
+        }))
+        .unwrap();
+
+        let frames = FrameEvaluator::flatten_frame_tree(&tree);
+        let ids: Vec<&str> = frames.iter().map(|f| f.id.as_str()).collect();
+
+        // Depth-first with the main frame first
+        assert_eq!(ids, vec!["main", "child-a", "grandchild", "child-b"]);
+        assert_eq!(frames[0].url, "https://example.com/");
+    }
+
+    #[test]
+    fn test_flatten_frame_tree_single_frame() {
+        let tree: FrameTree = serde_json::from_value(json!({
+            "frame": frame_json("main", "https://example.com/")
+        }))
+        .unwrap();
+
+        let frames = FrameEvaluator::flatten_frame_tree(&tree);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].id, "main");
+    }
+
+    #[test]
+    fn test_frame_eval_result_serialization() {
+        let result = FrameEvalResult {
+            frame: FrameInfo {
+                id: "main".to_string(),
+                url: "https://example.com/".to_string(),
+            },
+            value: None,
+            error: Some("cross-origin frame".to_string()),
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["frame"]["id"], "main");
+        assert_eq!(json["error"], "cross-origin frame");
+        assert!(json["value"].is_null());
+    }
+}
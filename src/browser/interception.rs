@@ -0,0 +1,402 @@
+//! Network request interception
+//!
+//! This module lets callers register URL-pattern rules that mock responses
+//! or rewrite requests using the CDP `Fetch` domain. Rules are evaluated in
+//! registration order and the first match wins; unmatched requests pass
+//! through untouched.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    ContinueRequestParams, DisableParams, EnableParams, EventRequestPaused, FulfillRequestParams,
+    HeaderEntry,
+};
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// A mocked HTTP response served in place of the network
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers as (name, value) pairs
+    pub headers: Vec<(String, String)>,
+    /// Response body bytes
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// A 200 response with a JSON body and matching content type
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: body.into().into_bytes(),
+        }
+    }
+
+    /// A 200 response with an HTML body and matching content type
+    pub fn html(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            headers: vec![(
+                "content-type".to_string(),
+                "text/html; charset=utf-8".to_string(),
+            )],
+            body: body.into().into_bytes(),
+        }
+    }
+}
+
+/// Action taken when an [`InterceptRule`] matches a request
+#[derive(Debug, Clone)]
+pub enum InterceptAction {
+    /// Fulfill the request with a mocked response
+    Mock(MockResponse),
+    /// Rewrite the request URL before it is sent (not observable by the page)
+    RewriteUrl(String),
+    /// Continue the request as a POST with the given body and content type
+    ///
+    /// Other request headers are preserved; only `Content-Type` is replaced.
+    ContinueAsPost {
+        /// Request body bytes
+        body: Vec<u8>,
+        /// Value for the `Content-Type` header
+        content_type: String,
+    },
+}
+
+/// A URL-pattern rule with its action
+#[derive(Debug, Clone)]
+pub struct InterceptRule {
+    /// URL pattern; `*` matches any sequence, `?` a single character
+    pub pattern: String,
+    /// What to do with matching requests
+    pub action: InterceptAction,
+}
+
+impl InterceptRule {
+    /// Create a rule mocking matching requests with a fixed response
+    pub fn mock(pattern: impl Into<String>, response: MockResponse) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: InterceptAction::Mock(response),
+        }
+    }
+
+    /// Create a rule rewriting matching request URLs
+    pub fn rewrite(pattern: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: InterceptAction::RewriteUrl(url.into()),
+        }
+    }
+
+    /// Create a rule continuing matching requests as POSTs
+    pub fn as_post(
+        pattern: impl Into<String>,
+        body: impl Into<Vec<u8>>,
+        content_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            pattern: pattern.into(),
+            action: InterceptAction::ContinueAsPost {
+                body: body.into(),
+                content_type: content_type.into(),
+            },
+        }
+    }
+
+    /// Whether this rule matches the given URL
+    pub fn matches(&self, url: &str) -> bool {
+        url_pattern_matches(&self.pattern, url)
+    }
+}
+
+/// Match a URL against a wildcard pattern
+///
+/// Uses the CDP `Fetch` pattern semantics: `*` matches any sequence of
+/// characters, `?` matches a single character, everything else is literal.
+pub fn url_pattern_matches(pattern: &str, url: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let u: Vec<char> = url.chars().collect();
+
+    let (mut pi, mut ui) = (0usize, 0usize);
+    let mut star: Option<usize> = None;
+    let mut mark = 0usize;
+
+    while ui < u.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == u[ui]) {
+            pi += 1;
+            ui += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some(pi);
+            mark = ui;
+            pi += 1;
+        } else if let Some(s) = star {
+            pi = s + 1;
+            mark += 1;
+            ui = mark;
+        } else {
+            return false;
+        }
+    }
+
+    while pi < p.len() && p[pi] == '*' {
+        pi += 1;
+    }
+    pi == p.len()
+}
+
+/// Active request interception on a page
+///
+/// Holds the background task that answers paused requests. Dropping the
+/// interceptor stops handling; in-flight requests after that point will
+/// stall, so keep it alive for the lifetime of the page.
+pub struct RequestInterceptor {
+    task: JoinHandle<()>,
+}
+
+impl RequestInterceptor {
+    /// Enable interception on a page with the given rules
+    ///
+    /// Rules are evaluated in order and the first match wins. Requests
+    /// matching no rule are continued unmodified.
+    pub async fn enable(page: &PageHandle, rules: Vec<InterceptRule>) -> Result<Self> {
+        info!("Enabling request interception with {} rules", rules.len());
+
+        page.page
+            .execute(EnableParams::builder().build())
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let mut events = page
+            .page
+            .event_listener::<EventRequestPaused>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let cdp_page = page.page.clone();
+        let rules = Arc::new(rules);
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let url = event.request.url.clone();
+                let matched = rules.iter().find(|r| r.matches(&url));
+
+                let result = match matched.map(|r| &r.action) {
+                    Some(InterceptAction::Mock(mock)) => {
+                        debug!("Mocking response for {}", url);
+                        let headers = mock
+                            .headers
+                            .iter()
+                            .map(|(name, value)| HeaderEntry::new(name.clone(), value.clone()))
+                            .collect::<Vec<_>>();
+                        let params = FulfillRequestParams {
+                            request_id: event.request_id.clone(),
+                            response_code: i64::from(mock.status),
+                            response_headers: Some(headers),
+                            binary_response_headers: None,
+                            body: Some(BASE64.encode(&mock.body).into()),
+                            response_phrase: None,
+                        };
+                        cdp_page.execute(params).await.map(|_| ())
+                    }
+                    Some(InterceptAction::RewriteUrl(new_url)) => {
+                        debug!("Rewriting {} -> {}", url, new_url);
+                        let mut params = ContinueRequestParams::new(event.request_id.clone());
+                        params.url = Some(new_url.clone());
+                        cdp_page.execute(params).await.map(|_| ())
+                    }
+                    Some(InterceptAction::ContinueAsPost { body, content_type }) => {
+                        debug!("Continuing {} as POST ({} bytes)", url, body.len());
+                        // Keep the original headers, swapping only the
+                        // content type to match the new body
+                        let mut headers: Vec<HeaderEntry> = event
+                            .request
+                            .headers
+                            .inner()
+                            .as_object()
+                            .map(|map| {
+                                map.iter()
+                                    .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+                                    .filter_map(|(name, value)| {
+                                        value.as_str().map(|v| {
+                                            HeaderEntry::new(name.clone(), v.to_string())
+                                        })
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        headers.push(HeaderEntry::new(
+                            "content-type".to_string(),
+                            content_type.clone(),
+                        ));
+
+                        let mut params = ContinueRequestParams::new(event.request_id.clone());
+                        params.method = Some("POST".to_string());
+                        params.post_data = Some(BASE64.encode(body).into());
+                        params.headers = Some(headers);
+                        cdp_page.execute(params).await.map(|_| ())
+                    }
+                    None => {
+                        let params = ContinueRequestParams::new(event.request_id.clone());
+                        cdp_page.execute(params).await.map(|_| ())
+                    }
+                };
+
+                if let Err(e) = result {
+                    warn!("Failed to handle intercepted request {}: {}", url, e);
+                }
+            }
+        });
+
+        Ok(Self { task })
+    }
+
+    /// Stop handling requests and disable the CDP `Fetch` domain
+    ///
+    /// Prefer this over dropping when the page lives on: with the domain
+    /// still enabled and nothing answering, later requests would stall.
+    pub async fn disable(self, page: &PageHandle) -> Result<()> {
+        self.task.abort();
+        page.page
+            .execute(DisableParams::default())
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        Ok(())
+    }
+}
+
+impl Drop for RequestInterceptor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // URL Pattern Matching Tests
+    // ========================================================================
+
+    #[test]
+    fn test_pattern_literal_match() {
+        assert!(url_pattern_matches(
+            "https://example.com/api/config",
+            "https://example.com/api/config"
+        ));
+        assert!(!url_pattern_matches(
+            "https://example.com/api/config",
+            "https://example.com/api/other"
+        ));
+    }
+
+    #[test]
+    fn test_pattern_star_wildcard() {
+        assert!(url_pattern_matches(
+            "*/api/config",
+            "https://example.com/api/config"
+        ));
+        assert!(url_pattern_matches(
+            "https://example.com/*",
+            "https://example.com/anything/nested?q=1"
+        ));
+        assert!(url_pattern_matches("*", "https://anywhere.example"));
+    }
+
+    #[test]
+    fn test_pattern_star_in_middle() {
+        assert!(url_pattern_matches(
+            "https://*.example.com/api/*",
+            "https://cdn.example.com/api/v2/config"
+        ));
+        assert!(!url_pattern_matches(
+            "https://*.example.com/api/*",
+            "https://example.org/api/v2/config"
+        ));
+    }
+
+    #[test]
+    fn test_pattern_question_mark() {
+        assert!(url_pattern_matches("/v?/config", "/v1/config"));
+        assert!(!url_pattern_matches("/v?/config", "/v12/config"));
+    }
+
+    #[test]
+    fn test_pattern_trailing_star_matches_empty() {
+        assert!(url_pattern_matches("/api/*", "/api/"));
+    }
+
+    // ========================================================================
+    // Rule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_rule_mock_constructor() {
+        let rule = InterceptRule::mock("*/api/config", MockResponse::json(r#"{"flag":true}"#));
+        assert!(rule.matches("https://example.com/api/config"));
+
+        match rule.action {
+            InterceptAction::Mock(mock) => {
+                assert_eq!(mock.status, 200);
+                assert_eq!(
+                    mock.headers[0],
+                    ("content-type".to_string(), "application/json".to_string())
+                );
+                assert_eq!(mock.body, br#"{"flag":true}"#);
+            }
+            _ => panic!("expected mock action"),
+        }
+    }
+
+    #[test]
+    fn test_rule_rewrite_constructor() {
+        let rule = InterceptRule::rewrite("*/tracking.js", "https://localhost/empty.js");
+        assert!(rule.matches("https://cdn.example.com/tracking.js"));
+        assert!(matches!(rule.action, InterceptAction::RewriteUrl(_)));
+    }
+
+    #[test]
+    fn test_rule_as_post_constructor() {
+        let rule = InterceptRule::as_post(
+            "https://example.com/search",
+            "q=rust",
+            "application/x-www-form-urlencoded",
+        );
+        assert!(rule.matches("https://example.com/search"));
+
+        match rule.action {
+            InterceptAction::ContinueAsPost { body, content_type } => {
+                assert_eq!(body, b"q=rust");
+                assert_eq!(content_type, "application/x-www-form-urlencoded");
+            }
+            _ => panic!("expected post action"),
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let rules = [
+            InterceptRule::mock("*/api/*", MockResponse::json("{}")),
+            InterceptRule::mock("*/api/config", MockResponse::json(r#"{"x":1}"#)),
+        ];
+
+        let url = "https://example.com/api/config";
+        let matched = rules.iter().find(|r| r.matches(url)).unwrap();
+        // First rule matches even though the second is more specific
+        assert_eq!(matched.pattern, "*/api/*");
+    }
+
+    #[test]
+    fn test_mock_response_html() {
+        let mock = MockResponse::html("<h1>stub</h1>");
+        assert_eq!(mock.status, 200);
+        assert!(mock.headers[0].1.starts_with("text/html"));
+    }
+}
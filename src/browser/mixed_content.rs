@@ -0,0 +1,152 @@
+//! Mixed-content handling
+//!
+//! Https pages referencing http subresources ("mixed content") behave
+//! unevenly in headless Chrome: blockable resources such as scripts are
+//! silently dropped. This module reports what Chrome blocked so callers
+//! know the page may be incomplete, and documents how to allow insecure
+//! subresources when that is actually wanted.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::network::{
+    BlockedReason, EventLoadingFailed, EventRequestWillBeSent, RequestId,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// How mixed content should be treated during a navigation
+///
+/// Chrome exposes no per-page CDP switch for allowing mixed content, so
+/// `Allow` relies on the browser having been launched with
+/// [`crate::browser::BrowserConfig::allow_insecure_content`]. Either mode
+/// installs the monitor, so anything Chrome still blocks is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MixedContentMode {
+    /// Let Chrome block insecure subresources (its default) and report them
+    Block,
+    /// Expect insecure subresources to load; report any still blocked
+    Allow,
+}
+
+/// An insecure subresource Chrome refused to load
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockedResource {
+    /// URL of the blocked subresource
+    pub url: String,
+    /// Resource type as reported by Chrome (e.g. `Script`, `Image`)
+    pub resource_type: String,
+}
+
+/// Active mixed-content monitoring on a page
+///
+/// Correlates `Network.requestWillBeSent` with `Network.loadingFailed`
+/// events to recover the URLs of subresources blocked as mixed content.
+/// Dropping the monitor stops collection.
+pub struct MixedContentMonitor {
+    task: JoinHandle<()>,
+    blocked: Arc<RwLock<Vec<BlockedResource>>>,
+}
+
+impl MixedContentMonitor {
+    /// Install mixed-content monitoring on a page
+    pub async fn install(page: &PageHandle, mode: MixedContentMode) -> Result<Self> {
+        info!("Installing mixed-content monitor (mode {:?})", mode);
+
+        let mut requests = page
+            .page
+            .event_listener::<EventRequestWillBeSent>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let mut failures = page
+            .page
+            .event_listener::<EventLoadingFailed>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let blocked = Arc::new(RwLock::new(Vec::new()));
+        let task_blocked = Arc::clone(&blocked);
+
+        let task = tokio::spawn(async move {
+            let mut urls: HashMap<RequestId, String> = HashMap::new();
+            loop {
+                tokio::select! {
+                    Some(event) = requests.next() => {
+                        urls.insert(event.request_id.clone(), event.request.url.clone());
+                    }
+                    Some(event) = failures.next() => {
+                        if event.blocked_reason != Some(BlockedReason::MixedContent) {
+                            continue;
+                        }
+                        let url = urls
+                            .get(&event.request_id)
+                            .cloned()
+                            .unwrap_or_default();
+                        if mode == MixedContentMode::Allow {
+                            warn!(
+                                "Mixed content blocked despite Allow mode (launch \
+                                 with allow_insecure_content to permit it): {}",
+                                url
+                            );
+                        }
+                        task_blocked.write().await.push(BlockedResource {
+                            url,
+                            resource_type: event.r#type.as_ref().to_string(),
+                        });
+                    }
+                    else => break,
+                }
+            }
+        });
+
+        Ok(Self { task, blocked })
+    }
+
+    /// Insecure subresources blocked so far, in order of failure
+    pub async fn blocked(&self) -> Vec<BlockedResource> {
+        self.blocked.read().await.clone()
+    }
+}
+
+impl Drop for MixedContentMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_serializes_lowercase() {
+        assert_eq!(
+            serde_json::to_string(&MixedContentMode::Block).unwrap(),
+            "\"block\""
+        );
+        assert_eq!(
+            serde_json::to_string(&MixedContentMode::Allow).unwrap(),
+            "\"allow\""
+        );
+    }
+
+    #[test]
+    fn test_blocked_resource_serialization() {
+        let resource = BlockedResource {
+            url: "http://cdn.example.com/app.js".to_string(),
+            resource_type: "Script".to_string(),
+        };
+
+        let json = serde_json::to_value(&resource).unwrap();
+        assert_eq!(json["url"], "http://cdn.example.com/app.js");
+        assert_eq!(json["resource_type"], "Script");
+
+        let back: BlockedResource = serde_json::from_value(json).unwrap();
+        assert_eq!(back, resource);
+    }
+}
@@ -4,11 +4,42 @@
 //! including lifecycle management, navigation, and capture functionality.
 
 pub mod capture;
+pub mod content_type;
 pub mod controller;
+pub mod critical_resources;
+pub mod diagnostics;
+pub mod dialogs;
+pub mod downloads;
+pub mod events;
+pub mod frames;
+pub mod interception;
+pub mod mixed_content;
 pub mod navigation;
+pub mod redirect_guard;
+pub mod responses;
 pub mod stealth;
+pub mod storage_state;
 
-pub use capture::{CaptureFormat, CaptureOptions, CaptureResult, PageCapture};
-pub use controller::{BrowserConfig, BrowserController, PageHandle};
-pub use navigation::{NavigationOptions, NavigationResult, PageNavigator, WaitUntil};
+pub use capture::{
+    CaptureFormat, CaptureOptions, CaptureResult, HtmlInlineOptions, PageCapture,
+    TRACKER_SCRIPT_PATTERNS,
+};
+pub use content_type::ContentTypeMonitor;
+pub use critical_resources::{CriticalResourceMonitor, FailedResource};
+pub use diagnostics::{DiagnosticArtifacts, DiagnosticsRecorder};
+pub use dialogs::{DialogAction, DialogHandler, DialogPolicy, DialogRecord};
+pub use downloads::{DownloadCapturer, DownloadOptions, DownloadedFile};
+pub use events::{HandlerStats, HANDLER_BURST_WARN_THRESHOLD};
+pub use frames::{FrameEvalResult, FrameEvaluator, FrameInfo};
+pub use interception::{InterceptAction, InterceptRule, MockResponse, RequestInterceptor};
+pub use mixed_content::{BlockedResource, MixedContentMode, MixedContentMonitor};
+pub use controller::{BrowserConfig, BrowserController, PageHandle, PageInjection};
+pub use navigation::{
+    AuthWallDetection, AuthWallDetector, AuthWallSignals, LoadState, NavigationOptions,
+    NavigationResult, NavigationTiming, NetworkConditions, PageNavigator, PostData, RefererPolicy,
+    SpinnerWait, ViewportOverride, WaitUntil, DEFAULT_SPINNER_SELECTORS,
+};
+pub use redirect_guard::{RedirectAttempt, RedirectGuard, RedirectGuardMode};
+pub use responses::{CapturedResponse, ResponseCapturer};
 pub use stealth::StealthMode;
+pub use storage_state::{OriginStorage, StorageCookie, StorageState};
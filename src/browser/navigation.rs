@@ -5,6 +5,9 @@
 
 use crate::browser::PageHandle;
 use crate::error::{Error, NavigationError, Result};
+use chromiumoxide::cdp::browser_protocol::network::SetBypassServiceWorkerParams;
+use chromiumoxide::cdp::browser_protocol::page::NavigateParams;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tracing::{debug, info, instrument, warn};
 
@@ -21,6 +24,300 @@ pub struct NavigationOptions {
     pub retry_delay_ms: u64,
     /// Simulate human-like behavior (default: true)
     pub human_like: bool,
+    /// Collect a [`NavigationTiming`] breakdown from the Navigation Timing
+    /// API after a successful navigation (default: false)
+    pub collect_timing: bool,
+    /// Referer header for the top-level navigation (default: none)
+    ///
+    /// Some sites gate content on the Referer header; this sets it for the
+    /// navigation itself via CDP. Must be an http(s) URL when set.
+    pub referrer: Option<String>,
+    /// Policy reducing the configured referrer before it is sent (default:
+    /// none, the referrer is sent as given)
+    ///
+    /// Following discovered links with the full referrer leaks the current
+    /// URL's path and query string to the next site. A policy trims the
+    /// referrer down per [`RefererPolicy`] before navigation.
+    pub referrer_policy: Option<RefererPolicy>,
+    /// Strip `utm_*` tracking parameters from the URL before navigating
+    /// (default: false)
+    ///
+    /// Campaign parameters on discovered links change nothing about the
+    /// content served but fingerprint the crawl; stripping them also
+    /// deduplicates otherwise-identical URLs.
+    pub strip_utm: bool,
+    /// Minimum rendered body length, in characters, for a navigation to
+    /// count as successful (default: 0, any body accepted)
+    ///
+    /// A navigation can complete just before the page swaps real content
+    /// in, leaving an empty body at read time. When set, an empty or
+    /// near-empty body is re-read after `retry_delay_ms` (up to `retries`
+    /// times) before the attempt fails as retryable.
+    pub min_body_chars: usize,
+    /// Bypass service workers so requests hit the network (default: true)
+    ///
+    /// An aggressive service worker can serve stale cached content or
+    /// intercept navigation entirely, making captures unrepresentative of
+    /// the live origin. Set to `false` to observe the page as returning
+    /// visitors would, service worker included.
+    pub bypass_service_worker: bool,
+    /// Per-type policy for JavaScript dialogs fired during navigation
+    /// (default: none, dialogs are left unanswered)
+    ///
+    /// When set, dialogs are answered per the policy and recorded into
+    /// [`NavigationResult::dialogs`].
+    pub dialog_policy: Option<super::DialogPolicy>,
+    /// Mixed-content handling for this navigation (default: none, Chrome's
+    /// default behavior with no reporting)
+    ///
+    /// When set, insecure subresources Chrome blocks are recorded into
+    /// [`NavigationResult::blocked_mixed_content`].
+    pub mixed_content: Option<super::MixedContentMode>,
+    /// Allowlist for the main document's `Content-Type` (default: none,
+    /// any type passes)
+    ///
+    /// When set, the navigation fails with
+    /// [`NavigationError::DisallowedContentType`] if the main document's
+    /// MIME type matches none of the entries (compared without parameters
+    /// such as `charset`), before any extraction sees the body. See
+    /// [`NavigationOptions::html_content_types`] for the usual allowlist.
+    pub allowed_content_types: Option<Vec<String>>,
+    /// Directory for failure diagnostics (default: none, disabled)
+    ///
+    /// When set and all navigation attempts fail, a screenshot, the current
+    /// HTML, and collected console output are written there and the error
+    /// message references the files.
+    pub diagnostics_dir: Option<std::path::PathBuf>,
+    /// Viewport override for this navigation (default: none, launch
+    /// viewport applies)
+    ///
+    /// Applied via device metrics override *before* the navigation, so
+    /// media queries and load-time `window.innerWidth` checks see the
+    /// emulated size.
+    pub viewport: Option<ViewportOverride>,
+    /// Emulated network conditions for this navigation (default: none,
+    /// full speed)
+    ///
+    /// Applied via `Network.emulateNetworkConditions` before navigating,
+    /// for testing loading states or deliberately slow, polite scraping.
+    /// See [`NetworkConditions::fast_3g`] and [`NetworkConditions::slow_3g`]
+    /// for the DevTools-style presets.
+    pub network_conditions: Option<NetworkConditions>,
+    /// Guard against post-load cross-origin redirects (default: none,
+    /// redirects proceed unobserved)
+    ///
+    /// Some pages JS-redirect the main frame right after loading. When set,
+    /// cross-origin main-frame navigations are recorded into
+    /// [`NavigationResult::redirect_attempts`] and, under
+    /// [`RedirectGuardMode::Block`](super::RedirectGuardMode::Block),
+    /// aborted so the original content stays.
+    pub redirect_guard: Option<super::RedirectGuardMode>,
+    /// Regex patterns for resources that must load successfully (default:
+    /// empty, only the main document matters)
+    ///
+    /// Failed subresources normally do not fail a navigation — a 404ing
+    /// tracking pixel is routine. When a failing resource matches one of
+    /// these patterns, the navigation fails with the resource and reason.
+    pub critical_resource_patterns: Vec<String>,
+    /// Body for performing the top-level navigation as a POST (default:
+    /// none, a regular GET)
+    ///
+    /// Some content — search results, form targets — is only reachable via
+    /// POST. The navigation request is paused via CDP `Fetch` and continued
+    /// with the method and body swapped, so the rendered page is the POST
+    /// response.
+    pub post: Option<PostData>,
+    /// Additionally wait until no loading spinners remain visible
+    /// (default: none, disabled)
+    ///
+    /// SPAs show spinners that network- and DOM-idle heuristics miss.
+    /// After the main readiness condition, this waits until every element
+    /// matching the spinner selectors is gone or hidden, bounded by its
+    /// own timeout; a spinner that never settles is logged, not fatal.
+    pub spinner_wait: Option<SpinnerWait>,
+}
+
+impl NavigationOptions {
+    /// The usual allowlist for HTML extraction:
+    /// `text/html` and `application/xhtml+xml`
+    pub fn html_content_types() -> Vec<String> {
+        vec![
+            "text/html".to_string(),
+            "application/xhtml+xml".to_string(),
+        ]
+    }
+}
+
+/// Per-navigation viewport/device emulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewportOverride {
+    /// Viewport width in pixels
+    pub width: u32,
+    /// Viewport height in pixels
+    pub height: u32,
+    /// Device scale factor (default: 1.0)
+    pub device_scale_factor: f64,
+    /// Emulate a mobile device (viewport meta tag, overlay scrollbars, …)
+    pub mobile: bool,
+    /// Re-apply the override after the load completes, for pages that
+    /// reset metrics during load (default: false)
+    pub reapply_after_load: bool,
+}
+
+impl ViewportOverride {
+    /// Override with the given dimensions and desktop defaults
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            device_scale_factor: 1.0,
+            mobile: false,
+            reapply_after_load: false,
+        }
+    }
+
+    /// The CDP device metrics parameters for this override
+    fn to_params(
+        &self,
+    ) -> chromiumoxide::cdp::browser_protocol::emulation::SetDeviceMetricsOverrideParams {
+        chromiumoxide::cdp::browser_protocol::emulation::SetDeviceMetricsOverrideParams::builder()
+            .width(self.width as i64)
+            .height(self.height as i64)
+            .device_scale_factor(self.device_scale_factor)
+            .mobile(self.mobile)
+            .build()
+            .expect("all required device metrics fields are set")
+    }
+
+    /// Apply the override to a page
+    pub(crate) async fn apply(&self, page: &chromiumoxide::Page) -> Result<()> {
+        page.execute(self.to_params())
+            .await
+            .map_err(|e| crate::error::Error::cdp(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// Emulated network conditions for a navigation
+///
+/// Maps to CDP `Network.emulateNetworkConditions`. Throughputs are in
+/// bytes per second; a negative value leaves that direction unthrottled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkConditions {
+    /// Simulate being completely offline
+    pub offline: bool,
+    /// Minimum latency added to every request, in milliseconds
+    pub latency_ms: f64,
+    /// Maximum download throughput in bytes per second (negative: unlimited)
+    pub download_throughput: f64,
+    /// Maximum upload throughput in bytes per second (negative: unlimited)
+    pub upload_throughput: f64,
+}
+
+impl NetworkConditions {
+    /// The DevTools "Fast 3G" preset
+    pub fn fast_3g() -> Self {
+        Self {
+            offline: false,
+            latency_ms: 562.5,
+            download_throughput: 180_000.0,
+            upload_throughput: 84_375.0,
+        }
+    }
+
+    /// The DevTools "Slow 3G" preset
+    pub fn slow_3g() -> Self {
+        Self {
+            offline: false,
+            latency_ms: 2000.0,
+            download_throughput: 50_000.0,
+            upload_throughput: 50_000.0,
+        }
+    }
+
+    /// Simulated loss of connectivity
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            latency_ms: 0.0,
+            download_throughput: -1.0,
+            upload_throughput: -1.0,
+        }
+    }
+
+    /// The CDP parameters for these conditions
+    ///
+    /// The protocol flags the command as deprecated, but it remains the
+    /// only way to throttle and Chrome DevTools still drives its own
+    /// throttling presets through it.
+    #[allow(deprecated)]
+    fn to_params(
+        &self,
+    ) -> chromiumoxide::cdp::browser_protocol::network::EmulateNetworkConditionsParams {
+        chromiumoxide::cdp::browser_protocol::network::EmulateNetworkConditionsParams::builder()
+            .offline(self.offline)
+            .latency(self.latency_ms)
+            .download_throughput(self.download_throughput)
+            .upload_throughput(self.upload_throughput)
+            .build()
+            .expect("all required network condition fields are set")
+    }
+
+    /// Apply the conditions to a page
+    async fn apply(&self, page: &chromiumoxide::Page) -> Result<()> {
+        page.execute(self.to_params())
+            .await
+            .map_err(|e| crate::error::Error::cdp(e.to_string()))?;
+        Ok(())
+    }
+}
+
+/// How much of the referrer to reveal when navigating
+///
+/// Mirrors the subset of the web's `Referrer-Policy` values that make sense
+/// for crawler-initiated navigations. Applied to
+/// [`NavigationOptions::referrer`] before the Referer header is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RefererPolicy {
+    /// Send no Referer header at all
+    NoReferrer,
+    /// Send only the referrer's origin, never its path or query
+    OriginOnly,
+    /// Send the referrer's origin, except on https-to-http downgrades
+    /// where nothing is sent
+    StrictOrigin,
+}
+
+impl RefererPolicy {
+    /// The Referer value to send for navigating from `referrer` to `target`
+    ///
+    /// Returns `None` when no header should be sent: always for
+    /// [`Self::NoReferrer`], and for [`Self::StrictOrigin`] on downgrades
+    /// from https to http. Referrers whose origin cannot be determined send
+    /// nothing rather than leak the raw value.
+    pub fn apply(&self, referrer: &str, target: &str) -> Option<String> {
+        match self {
+            Self::NoReferrer => None,
+            Self::OriginOnly => Self::origin(referrer),
+            Self::StrictOrigin => {
+                if referrer.starts_with("https://") && target.starts_with("http://") {
+                    None
+                } else {
+                    Self::origin(referrer)
+                }
+            }
+        }
+    }
+
+    /// The origin of a URL with a trailing slash, as Referer headers spell it
+    fn origin(url: &str) -> Option<String> {
+        let parsed = url::Url::parse(url).ok()?;
+        match parsed.origin() {
+            origin @ url::Origin::Tuple(..) => Some(format!("{}/", origin.ascii_serialization())),
+            url::Origin::Opaque(_) => None,
+        }
+    }
 }
 
 impl Default for NavigationOptions {
@@ -31,6 +328,85 @@ impl Default for NavigationOptions {
             retries: 3,
             retry_delay_ms: 1000,
             human_like: true,
+            collect_timing: false,
+            referrer: None,
+            referrer_policy: None,
+            strip_utm: false,
+            min_body_chars: 0,
+            bypass_service_worker: true,
+            dialog_policy: None,
+            mixed_content: None,
+            allowed_content_types: None,
+            diagnostics_dir: None,
+            viewport: None,
+            network_conditions: None,
+            redirect_guard: None,
+            critical_resource_patterns: Vec::new(),
+            post: None,
+            spinner_wait: None,
+        }
+    }
+}
+
+/// Selectors treated as loading spinners by default
+pub const DEFAULT_SPINNER_SELECTORS: &[&str] = &[
+    ".spinner",
+    ".loader",
+    ".loading",
+    ".spinner-border",
+    "[role=\"progressbar\"]",
+    "[aria-busy=\"true\"]",
+];
+
+/// Wait for loading spinners to disappear
+///
+/// See [`NavigationOptions::spinner_wait`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpinnerWait {
+    /// Selectors treated as spinners (default:
+    /// [`DEFAULT_SPINNER_SELECTORS`])
+    pub selectors: Vec<String>,
+    /// Maximum wait in milliseconds (default: 10000)
+    pub timeout_ms: u64,
+}
+
+impl Default for SpinnerWait {
+    fn default() -> Self {
+        Self {
+            selectors: DEFAULT_SPINNER_SELECTORS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            timeout_ms: 10_000,
+        }
+    }
+}
+
+/// Body and content type for a POST navigation
+///
+/// See [`NavigationOptions::post`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostData {
+    /// Request body bytes
+    pub body: Vec<u8>,
+    /// Value for the `Content-Type` header
+    pub content_type: String,
+}
+
+impl PostData {
+    /// A `application/x-www-form-urlencoded` body, as an HTML form submits
+    pub fn form(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into().into_bytes(),
+            content_type: "application/x-www-form-urlencoded".to_string(),
+        }
+    }
+
+    /// An `application/json` body
+    pub fn json(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into().into_bytes(),
+            content_type: "application/json".to_string(),
         }
     }
 }
@@ -46,6 +422,26 @@ pub enum WaitUntil {
     NetworkIdle0,
     /// Wait until network is idle (max 2 connections for 500ms)
     NetworkIdle2,
+    /// Wait until the DOM has not mutated for the given number of
+    /// milliseconds, observed via a `MutationObserver`
+    ///
+    /// A better readiness signal than network idle for SPAs that render
+    /// from already-loaded data. Bounded by the navigation timeout.
+    DomStable(u64),
+}
+
+/// Document load state to wait for, independent of navigation
+///
+/// Unlike [`WaitUntil`], this targets `document.readyState` directly and can
+/// be awaited at any time via [`PageHandle::wait_for_load_state`].
+///
+/// [`PageHandle::wait_for_load_state`]: super::PageHandle::wait_for_load_state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// `document.readyState` is at least `interactive`
+    DomContentLoaded,
+    /// `document.readyState` is `complete`
+    Complete,
 }
 
 /// Result of a navigation operation
@@ -59,6 +455,66 @@ pub struct NavigationResult {
     pub title: Option<String>,
     /// Navigation duration in milliseconds
     pub duration_ms: u64,
+    /// Per-phase timing breakdown, when
+    /// [`NavigationOptions::collect_timing`] was enabled
+    pub timing: Option<NavigationTiming>,
+    /// Dialogs answered during navigation, when
+    /// [`NavigationOptions::dialog_policy`] was set
+    pub dialogs: Vec<super::DialogRecord>,
+    /// Insecure subresources blocked as mixed content, when
+    /// [`NavigationOptions::mixed_content`] was set
+    pub blocked_mixed_content: Vec<super::BlockedResource>,
+    /// Cross-origin main-frame navigations seen (and possibly aborted), when
+    /// [`NavigationOptions::redirect_guard`] was set
+    pub redirect_attempts: Vec<super::RedirectAttempt>,
+}
+
+/// Per-phase breakdown of navigation time
+///
+/// Durations come from the browser's Navigation Timing API. Phases the page
+/// does not expose (e.g. DNS for cached or `data:` navigations) are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationTiming {
+    /// DNS lookup duration in milliseconds
+    pub dns_ms: Option<u64>,
+    /// TCP/TLS connect duration in milliseconds
+    pub connect_ms: Option<u64>,
+    /// Time to first byte (request start to response start) in milliseconds
+    pub ttfb_ms: Option<u64>,
+    /// Content phase (response start to load event end) in milliseconds
+    pub content_ms: Option<u64>,
+}
+
+impl NavigationTiming {
+    /// Build a timing breakdown from a Navigation Timing API entry
+    ///
+    /// `entry` is the JSON object produced by the collection script: marks
+    /// in milliseconds relative to the navigation start. Missing or negative
+    /// marks null out the phases that depend on them.
+    pub fn from_entry(entry: &serde_json::Value) -> Option<Self> {
+        if !entry.is_object() {
+            return None;
+        }
+
+        let mark = |name: &str| -> Option<f64> { entry[name].as_f64().filter(|v| *v >= 0.0) };
+        let phase = |start: Option<f64>, end: Option<f64>| -> Option<u64> {
+            match (start, end) {
+                (Some(start), Some(end)) if end >= start => Some((end - start) as u64),
+                _ => None,
+            }
+        };
+
+        let response_end = mark("loadEventEnd")
+            .filter(|v| *v > 0.0)
+            .or_else(|| mark("responseEnd"));
+
+        Some(Self {
+            dns_ms: phase(mark("domainLookupStart"), mark("domainLookupEnd")),
+            connect_ms: phase(mark("connectStart"), mark("connectEnd")),
+            ttfb_ms: phase(mark("requestStart"), mark("responseStart")),
+            content_ms: phase(mark("responseStart"), response_end),
+        })
+    }
 }
 
 /// URL validation utilities
@@ -95,6 +551,65 @@ impl UrlValidator {
         Ok(())
     }
 
+    /// Validate a URL for use as a Referer header
+    ///
+    /// Stricter than [`Self::validate`]: referrers must be http(s).
+    pub fn validate_referrer(url: &str) -> std::result::Result<(), String> {
+        if url.is_empty() {
+            return Err("Referrer cannot be empty".to_string());
+        }
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(format!(
+                "Referrer must start with http:// or https://: {}",
+                url
+            ));
+        }
+
+        if url.len() > 2048 {
+            return Err("Referrer exceeds maximum length of 2048 characters".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Remove `utm_*` tracking parameters from a URL
+    ///
+    /// Name matching is case-insensitive. URLs that do not parse, or that
+    /// carry no tracking parameter, are returned unchanged so odd input is
+    /// never reformatted.
+    pub fn strip_utm(url: &str) -> String {
+        let Ok(mut parsed) = url::Url::parse(url) else {
+            return url.to_string();
+        };
+        if parsed.query().is_none() {
+            return url.to_string();
+        }
+
+        let is_tracking = |name: &str| name.to_ascii_lowercase().starts_with("utm_");
+
+        let pairs: Vec<(String, String)> = parsed
+            .query_pairs()
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if !pairs.iter().any(|(name, _)| is_tracking(name)) {
+            return url.to_string();
+        }
+
+        if pairs.iter().all(|(name, _)| is_tracking(name)) {
+            parsed.set_query(None);
+        } else {
+            let mut query = parsed.query_pairs_mut();
+            query.clear();
+            for (name, value) in &pairs {
+                if !is_tracking(name) {
+                    query.append_pair(name, value);
+                }
+            }
+        }
+        parsed.to_string()
+    }
+
     /// Check if URL points to localhost
     pub fn is_localhost(url: &str) -> bool {
         let lower = url.to_lowercase();
@@ -202,7 +717,7 @@ impl PageNavigator {
         url: &str,
         options: Option<NavigationOptions>,
     ) -> Result<NavigationResult> {
-        let opts = options.unwrap_or_default();
+        let mut opts = options.unwrap_or_default();
         let start = std::time::Instant::now();
 
         // Validate URL
@@ -217,43 +732,231 @@ impl PageNavigator {
             .into());
         }
 
-        info!("Navigating to: {}", url);
+        // Drop tracking parameters before navigating, when requested
+        let stripped;
+        let url = if opts.strip_utm {
+            stripped = UrlValidator::strip_utm(url);
+            stripped.as_str()
+        } else {
+            url
+        };
 
-        let mut last_error = None;
-        for attempt in 0..=opts.retries {
-            if attempt > 0 {
-                warn!("Navigation retry attempt {} of {}", attempt, opts.retries);
-                tokio::time::sleep(Duration::from_millis(opts.retry_delay_ms)).await;
+        // Validate referrer, if configured
+        if let Some(referrer) = &opts.referrer {
+            if let Err(msg) = UrlValidator::validate_referrer(referrer) {
+                return Err(NavigationError::InvalidUrl(msg).into());
             }
+        }
+
+        // Reduce the referrer per policy so the navigation does not leak
+        // the current URL's path or query string
+        if let (Some(referrer), Some(policy)) = (&opts.referrer, opts.referrer_policy) {
+            opts.referrer = policy.apply(referrer, url);
+        }
 
-            match Self::navigate_once(&page.page, url, &opts).await {
-                Ok(result) => {
-                    // Update page URL
-                    page.set_url(result.final_url.clone()).await;
+        info!("Navigating to: {}", crate::logging::sanitize_url(url));
+
+        // Answer dialogs per policy for the duration of the navigation
+        let dialog_handler = match &opts.dialog_policy {
+            Some(policy) => Some(super::DialogHandler::install(page, policy.clone()).await?),
+            None => None,
+        };
+
+        // Track insecure subresources Chrome blocks, when requested
+        let mixed_content_monitor = match opts.mixed_content {
+            Some(mode) => Some(super::MixedContentMonitor::install(page, mode).await?),
+            None => None,
+        };
+
+        // Record the main document's MIME type, when an allowlist is set
+        let content_type_monitor = match &opts.allowed_content_types {
+            Some(_) => Some(super::ContentTypeMonitor::install(page).await?),
+            None => None,
+        };
+
+        // Watch for the page redirecting the main frame elsewhere after load
+        let redirect_guard = match opts.redirect_guard {
+            Some(mode) => Some(super::RedirectGuard::install(page, mode, url).await?),
+            None => None,
+        };
+
+        // Watch resources that must load, failing the navigation when one
+        // breaks while tolerating other subresource errors
+        let critical_monitor = if opts.critical_resource_patterns.is_empty() {
+            None
+        } else {
+            let patterns =
+                super::CriticalResourceMonitor::compile_patterns(&opts.critical_resource_patterns)?;
+            Some(super::CriticalResourceMonitor::install(page, patterns).await?)
+        };
+
+        // Record console output so failure diagnostics can include it
+        let diagnostics_recorder = match &opts.diagnostics_dir {
+            Some(_) => Some(super::DiagnosticsRecorder::install(page).await?),
+            None => None,
+        };
+
+        // Emulate the viewport before navigating so load-time media queries
+        // and innerWidth checks see it
+        if let Some(viewport) = &opts.viewport {
+            viewport.apply(&page.page).await?;
+        }
+
+        // Throttle the connection before navigating so the document itself
+        // loads under the emulated conditions
+        if let Some(conditions) = &opts.network_conditions {
+            conditions.apply(&page.page).await?;
+        }
+
+        // Pause the navigation request via CDP Fetch and continue it as a
+        // POST, so the rendered page is the POST response
+        let post_interceptor = match &opts.post {
+            Some(post) => Some(
+                super::RequestInterceptor::enable(
+                    page,
+                    vec![super::InterceptRule::as_post(
+                        url,
+                        post.body.clone(),
+                        post.content_type.clone(),
+                    )],
+                )
+                .await?,
+            ),
+            None => None,
+        };
+
+        // Ignore service workers so the navigation and its subresources
+        // load from the network, not a possibly stale worker cache
+        page.page
+            .execute(SetBypassServiceWorkerParams::new(
+                opts.bypass_service_worker,
+            ))
+            .await
+            .map_err(|e| {
+                let message = e.to_string();
+                Error::protocol_unsupported(
+                    "Network.setBypassServiceWorker",
+                    &message,
+                    page.chrome_version(),
+                )
+                .unwrap_or_else(|| Error::cdp(message))
+            })?;
+
+        // Constant delay between attempts: base == cap disables backoff
+        // growth, preserving the documented retry_delay_ms semantics
+        let policy = crate::retry::RetryPolicy::<Error> {
+            max_attempts: opts.retries + 1,
+            base_delay: Duration::from_millis(opts.retry_delay_ms),
+            max_delay: Duration::from_millis(opts.retry_delay_ms),
+            jitter: false,
+            retryable: |_| true,
+        };
 
-                    // Apply human-like behavior if enabled
-                    if opts.human_like {
-                        Self::simulate_human_behavior(&page.page).await?;
+        let nav_result = crate::retry::execute_with_policy(&policy, || {
+            Self::navigate_once(&page.page, url, &opts)
+        })
+        .await;
+
+        // Stop rewriting requests as soon as the navigation has settled;
+        // anything the page fetches afterwards goes out untouched
+        if let Some(interceptor) = post_interceptor {
+            if let Err(e) = interceptor.disable(page).await {
+                warn!("Failed to disable POST navigation interception: {}", e);
+            }
+        }
+
+        let error = match nav_result {
+            Ok(result) => {
+                // Update page URL
+                page.set_url(result.final_url.clone()).await;
+
+                if let Some(viewport) = &opts.viewport {
+                    if viewport.reapply_after_load {
+                        viewport.apply(&page.page).await?;
+                    }
+                }
+
+                // Apply human-like behavior if enabled
+                if opts.human_like {
+                    Self::simulate_human_behavior(&page.page).await?;
+                }
+
+                let timing = if opts.collect_timing {
+                    Self::collect_timing(&page.page).await
+                } else {
+                    None
+                };
+
+                let dialogs = match &dialog_handler {
+                    Some(handler) => handler.records().await,
+                    None => Vec::new(),
+                };
+
+                let blocked_mixed_content = match &mixed_content_monitor {
+                    Some(monitor) => monitor.blocked().await,
+                    None => Vec::new(),
+                };
+
+                let redirect_attempts = match &redirect_guard {
+                    Some(guard) => guard.attempts().await,
+                    None => Vec::new(),
+                };
+
+                if let Some(monitor) = &critical_monitor {
+                    if let Some(failure) = monitor.failures().await.first() {
+                        return Err(NavigationError::LoadFailed(format!(
+                            "Critical resource failed: {} ({})",
+                            failure.url, failure.reason
+                        ))
+                        .into());
                     }
+                }
 
-                    let duration_ms = start.elapsed().as_millis() as u64;
-                    return Ok(NavigationResult {
-                        final_url: result.final_url,
-                        status: result.status,
-                        title: result.title,
-                        duration_ms,
-                    });
+                if let Some(mime) =
+                    Self::disallowed_content_type(&opts, &content_type_monitor).await
+                {
+                    return Err(NavigationError::DisallowedContentType(mime).into());
                 }
-                Err(e) => {
-                    warn!("Navigation attempt {} failed: {}", attempt + 1, e);
-                    last_error = Some(e);
+
+                let duration_ms = start.elapsed().as_millis() as u64;
+                return Ok(NavigationResult {
+                    final_url: result.final_url,
+                    status: result.status,
+                    title: result.title,
+                    duration_ms,
+                    timing,
+                    dialogs,
+                    blocked_mixed_content,
+                    redirect_attempts,
+                });
+            }
+            Err(error) => error,
+        };
+
+        // A disallowed document (e.g. a zip Chrome turned into a download)
+        // often aborts the navigation outright; the content type is the
+        // clearer error than the transport failure
+        if let Some(mime) = Self::disallowed_content_type(&opts, &content_type_monitor).await {
+            return Err(NavigationError::DisallowedContentType(mime).into());
+        }
+
+        // On final failure, write what the page looked like into the
+        // diagnostics directory and point the error at the artifacts
+        if let (Some(recorder), Some(dir)) = (&diagnostics_recorder, &opts.diagnostics_dir) {
+            match recorder.capture(page, dir).await {
+                Ok(artifacts) => {
+                    return Err(NavigationError::LoadFailed(format!(
+                        "{} (diagnostics: {})",
+                        error,
+                        artifacts.summary()
+                    ))
+                    .into());
                 }
+                Err(e) => warn!("Failed to capture failure diagnostics: {}", e),
             }
         }
 
-        Err(last_error.unwrap_or_else(|| {
-            NavigationError::LoadFailed("Navigation failed after all retries".to_string()).into()
-        }))
+        Err(error)
     }
 
     /// Perform a single navigation attempt
@@ -265,7 +968,10 @@ impl PageNavigator {
         // Navigate with timeout
         let timeout = Duration::from_millis(opts.timeout_ms);
 
-        let nav_future = page.goto(url);
+        let mut params = NavigateParams::new(url);
+        params.referrer = opts.referrer.clone();
+
+        let nav_future = page.goto(params);
         let _response = tokio::time::timeout(timeout, nav_future)
             .await
             .map_err(|_| NavigationError::Timeout(opts.timeout_ms))?
@@ -274,6 +980,20 @@ impl PageNavigator {
         // Wait for page to be ready based on wait_until option
         Self::wait_for_ready(page, opts).await?;
 
+        // A heuristic, so a spinner that never settles degrades to a
+        // warning rather than failing the navigation
+        if let Some(spinner_wait) = &opts.spinner_wait {
+            if let Err(e) = Self::wait_for_spinners_gone_inner(page, spinner_wait).await {
+                warn!("Spinner wait did not settle: {}", e);
+            }
+        }
+
+        // A ready document can still race content hydration; re-wait and
+        // re-read before surfacing an empty body as a retryable failure
+        if opts.min_body_chars > 0 {
+            Self::wait_for_body(page, opts).await?;
+        }
+
         // Get final URL and title
         let final_url = page
             .url()
@@ -290,19 +1010,143 @@ impl PageNavigator {
         // Navigation doesn't return status directly in chromiumoxide
         let status: Option<u16> = None;
 
-        debug!("Navigation complete: {} -> {}", url, final_url);
+        debug!(
+            "Navigation complete: {} -> {}",
+            crate::logging::sanitize_url(url),
+            crate::logging::sanitize_url(&final_url)
+        );
 
         Ok(NavigationResult {
             final_url,
             status,
             title,
             duration_ms: 0, // Will be set by caller
+            timing: None,   // Collected by caller when enabled
+            dialogs: Vec::new(), // Collected by caller when a policy is set
+            blocked_mixed_content: Vec::new(), // Collected by caller when enabled
+            redirect_attempts: Vec::new(), // Collected by caller when guarded
         })
     }
 
+    /// Read the Navigation Timing API entry for the current document
+    ///
+    /// Errors are swallowed: timing is best-effort diagnostics and must not
+    /// fail an otherwise successful navigation.
+    async fn collect_timing(page: &chromiumoxide::Page) -> Option<NavigationTiming> {
+        let script = r#"
+            (() => {
+                const entries = performance.getEntriesByType('navigation');
+                if (entries && entries.length > 0) {
+                    const e = entries[0];
+                    return {
+                        domainLookupStart: e.domainLookupStart,
+                        domainLookupEnd: e.domainLookupEnd,
+                        connectStart: e.connectStart,
+                        connectEnd: e.connectEnd,
+                        requestStart: e.requestStart,
+                        responseStart: e.responseStart,
+                        responseEnd: e.responseEnd,
+                        loadEventEnd: e.loadEventEnd
+                    };
+                }
+                return null;
+            })()
+        "#;
+
+        let entry: serde_json::Value = page.evaluate(script).await.ok()?.into_value().ok()?;
+        NavigationTiming::from_entry(&entry)
+    }
+
+    /// Re-read the rendered body until it reaches `min_body_chars`
+    ///
+    /// Polls up to `retries` extra times, sleeping `retry_delay_ms` between
+    /// reads. A body that never fills up fails the attempt with a
+    /// [`NavigationError::LoadFailed`], which the outer retry loop treats as
+    /// retryable like any other load failure.
+    async fn wait_for_body(page: &chromiumoxide::Page, opts: &NavigationOptions) -> Result<()> {
+        let script = "document.body ? document.body.innerText.trim().length : 0";
+
+        for attempt in 0..=opts.retries {
+            let length = page
+                .evaluate(script)
+                .await
+                .map_err(|e| Error::cdp(e.to_string()))?
+                .into_value::<u64>()
+                .unwrap_or(0) as usize;
+
+            if length >= opts.min_body_chars {
+                if attempt > 0 {
+                    debug!("Body reached {} chars after {} re-read(s)", length, attempt);
+                }
+                return Ok(());
+            }
+
+            if attempt < opts.retries {
+                debug!(
+                    "Body has {} of {} required chars, re-reading in {}ms",
+                    length, opts.min_body_chars, opts.retry_delay_ms
+                );
+                tokio::time::sleep(Duration::from_millis(opts.retry_delay_ms)).await;
+            }
+        }
+
+        Err(NavigationError::LoadFailed(format!(
+            "Body still below {} characters after re-reading",
+            opts.min_body_chars
+        ))
+        .into())
+    }
+
+    /// The document's MIME type when it falls outside the allowlist
+    ///
+    /// `None` when no allowlist is configured, no document response was
+    /// observed, or the type is allowed.
+    async fn disallowed_content_type(
+        opts: &NavigationOptions,
+        monitor: &Option<super::ContentTypeMonitor>,
+    ) -> Option<String> {
+        let allowlist = opts.allowed_content_types.as_ref()?;
+        let mime = monitor.as_ref()?.document_mime_type().await?;
+        if super::ContentTypeMonitor::is_allowed(&mime, allowlist) {
+            None
+        } else {
+            Some(mime)
+        }
+    }
+
+    /// Script resolving once the DOM has been quiet for `quiet_ms`
+    ///
+    /// Every mutation resets the countdown, so the promise settles only
+    /// after a full quiet window; the navigation timeout bounds the wait.
+    fn dom_stable_script(quiet_ms: u64) -> String {
+        format!(
+            r#"
+                new Promise(resolve => {{
+                    const quiet = {quiet_ms};
+                    const done = () => {{
+                        observer.disconnect();
+                        resolve(true);
+                    }};
+                    let timer = setTimeout(done, quiet);
+                    const observer = new MutationObserver(() => {{
+                        clearTimeout(timer);
+                        timer = setTimeout(done, quiet);
+                    }});
+                    observer.observe(document.documentElement, {{
+                        childList: true,
+                        subtree: true,
+                        attributes: true,
+                        characterData: true
+                    }});
+                }})
+            "#
+        )
+    }
+
     /// Wait for page to be ready based on wait_until condition
     async fn wait_for_ready(page: &chromiumoxide::Page, opts: &NavigationOptions) -> Result<()> {
         let script = match opts.wait_until {
+            WaitUntil::DomStable(quiet_ms) => Self::dom_stable_script(quiet_ms),
             WaitUntil::Load => {
                 r#"
                     new Promise(resolve => {
@@ -313,6 +1157,7 @@ impl PageNavigator {
                         }
                     })
                 "#
+                .to_string()
             }
             WaitUntil::DomContentLoaded => {
                 r#"
@@ -324,6 +1169,7 @@ impl PageNavigator {
                         }
                     })
                 "#
+                .to_string()
             }
             WaitUntil::NetworkIdle0 | WaitUntil::NetworkIdle2 => {
                 // For network idle, we'll just wait a short time after load
@@ -339,6 +1185,7 @@ impl PageNavigator {
                         }
                     })
                 "#
+                .to_string()
             }
         };
 
@@ -446,6 +1293,309 @@ impl PageNavigator {
 
         Ok(())
     }
+
+    /// Wait for an element to be clickable
+    ///
+    /// Clickable means present, visible, enabled, non-zero size, and not
+    /// obscured: the element's center point is hit-tested with
+    /// `document.elementFromPoint`, so an overlay or spinner covering it
+    /// keeps the wait going until it is removed. Elements outside the
+    /// viewport are scrolled into view first. The rejection message names
+    /// the last failing condition.
+    #[instrument(skip(page))]
+    pub async fn wait_for_clickable(
+        page: &PageHandle,
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<()> {
+        let script = format!(
+            r#"
+                new Promise((resolve, reject) => {{
+                    const timeout = {};
+                    const start = Date.now();
+                    let reason = 'not found';
+
+                    function clickable() {{
+                        const el = document.querySelector('{}');
+                        if (!el) {{ reason = 'not found'; return false; }}
+                        if (el.disabled) {{ reason = 'disabled'; return false; }}
+                        const style = window.getComputedStyle(el);
+                        if (style.display === 'none' || style.visibility === 'hidden' ||
+                            style.pointerEvents === 'none') {{
+                            reason = 'not visible';
+                            return false;
+                        }}
+                        const rect = el.getBoundingClientRect();
+                        if (rect.width <= 0 || rect.height <= 0) {{
+                            reason = 'zero size';
+                            return false;
+                        }}
+                        const cx = rect.left + rect.width / 2;
+                        const cy = rect.top + rect.height / 2;
+                        if (cx < 0 || cy < 0 || cx >= window.innerWidth || cy >= window.innerHeight) {{
+                            el.scrollIntoView({{ block: 'center', inline: 'center' }});
+                            reason = 'outside viewport';
+                            return false;
+                        }}
+                        const hit = document.elementFromPoint(cx, cy);
+                        if (!hit || !(el === hit || el.contains(hit) || hit.contains(el))) {{
+                            reason = 'obscured by ' + (hit ? hit.tagName.toLowerCase() : 'nothing');
+                            return false;
+                        }}
+                        return true;
+                    }}
+
+                    function check() {{
+                        if (clickable()) {{
+                            resolve(true);
+                        }} else if (Date.now() - start > timeout) {{
+                            reject(new Error('Timeout waiting for clickable element (' + reason + ')'));
+                        }} else {{
+                            requestAnimationFrame(check);
+                        }}
+                    }}
+                    check();
+                }})
+            "#,
+            timeout_ms,
+            selector.replace('\'', "\\'")
+        );
+
+        let timeout = Duration::from_millis(timeout_ms + 1000);
+        tokio::time::timeout(timeout, page.page.evaluate(script.as_str()))
+            .await
+            .map_err(|_| NavigationError::Timeout(timeout_ms))?
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Wait until no loading spinners remain visible
+    ///
+    /// Resolves once every element matching `options.selectors` is gone
+    /// from the DOM or hidden (zero-size, `display: none`, or
+    /// `visibility: hidden`), rechecked each animation frame up to
+    /// `options.timeout_ms`.
+    #[instrument(skip(page, options))]
+    pub async fn wait_for_spinners_gone(page: &PageHandle, options: &SpinnerWait) -> Result<()> {
+        Self::wait_for_spinners_gone_inner(&page.page, options).await
+    }
+
+    /// [`Self::wait_for_spinners_gone`] against a raw page, for use inside
+    /// the navigation flow
+    async fn wait_for_spinners_gone_inner(
+        page: &chromiumoxide::Page,
+        options: &SpinnerWait,
+    ) -> Result<()> {
+        let selectors = options.selectors.join(", ");
+        let selectors =
+            serde_json::to_string(&selectors).unwrap_or_else(|_| "\".spinner\"".to_string());
+        let script = format!(
+            r#"
+                new Promise((resolve, reject) => {{
+                    const timeout = {};
+                    const start = Date.now();
+
+                    const visible = (el) => {{
+                        if (el.offsetWidth <= 0 && el.offsetHeight <= 0) return false;
+                        const style = window.getComputedStyle(el);
+                        return style.display !== 'none' && style.visibility !== 'hidden';
+                    }};
+
+                    function check() {{
+                        const spinners = document.querySelectorAll({selectors});
+                        if (![...spinners].some(visible)) {{
+                            resolve(true);
+                        }} else if (Date.now() - start > timeout) {{
+                            reject(new Error('Timeout waiting for spinners to disappear'));
+                        }} else {{
+                            requestAnimationFrame(check);
+                        }}
+                    }}
+                    check();
+                }})
+            "#,
+            options.timeout_ms
+        );
+
+        let timeout = Duration::from_millis(options.timeout_ms + 1000);
+        tokio::time::timeout(timeout, page.evaluate(script.as_str()))
+            .await
+            .map_err(|_| NavigationError::Timeout(options.timeout_ms))?
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Check whether the current page is an authentication wall
+    ///
+    /// Convenience wrapper around [`AuthWallDetector::detect`]. Callers that
+    /// want the reasons behind the verdict should use the detector directly.
+    #[instrument(skip(page))]
+    pub async fn detect_auth_wall(page: &PageHandle) -> Result<bool> {
+        Ok(AuthWallDetector::detect(page).await?.is_auth_wall)
+    }
+}
+
+/// Signals gathered from a page when checking for an authentication wall
+#[derive(Debug, Clone, Default)]
+pub struct AuthWallSignals {
+    /// Final URL of the page
+    pub url: String,
+    /// HTTP status code, if known
+    pub status: Option<u16>,
+    /// Number of password input fields
+    pub password_fields: u32,
+    /// Number of visible (non-hidden) input fields
+    pub visible_inputs: u32,
+    /// Number of forms on the page
+    pub form_count: u32,
+    /// Length of the visible body text
+    pub body_text_length: u64,
+    /// Page title
+    pub title: String,
+}
+
+/// Result of auth wall detection
+#[derive(Debug, Clone)]
+pub struct AuthWallDetection {
+    /// Whether the page is judged to be an authentication wall
+    pub is_auth_wall: bool,
+    /// Human-readable reasons supporting the verdict
+    pub reasons: Vec<String>,
+}
+
+/// Heuristic detection of pages that require login
+///
+/// Combines URL patterns (SSO/login paths), HTTP status, and DOM signals
+/// (password fields dominating an otherwise empty page) so extraction agents
+/// can bail out instead of returning a login form as content.
+pub struct AuthWallDetector;
+
+impl AuthWallDetector {
+    /// Detect whether the current page is an authentication wall
+    #[instrument(skip(page))]
+    pub async fn detect(page: &PageHandle) -> Result<AuthWallDetection> {
+        Self::detect_with_status(page, None).await
+    }
+
+    /// Detect an auth wall, factoring in a known HTTP status code
+    #[instrument(skip(page))]
+    pub async fn detect_with_status(
+        page: &PageHandle,
+        status: Option<u16>,
+    ) -> Result<AuthWallDetection> {
+        let script = r#"
+            (() => {
+                const inputs = Array.from(document.querySelectorAll('input'));
+                const visible = inputs.filter(i =>
+                    i.type !== 'hidden' && i.offsetParent !== null
+                );
+                return {
+                    passwordFields: inputs.filter(i => i.type === 'password').length,
+                    visibleInputs: visible.length,
+                    formCount: document.forms.length,
+                    bodyTextLength: document.body
+                        ? document.body.innerText.trim().length
+                        : 0,
+                    title: document.title || ''
+                };
+            })()
+        "#;
+
+        let value: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?
+            .into_value()
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let signals = AuthWallSignals {
+            url: page.url().await,
+            status,
+            password_fields: value["passwordFields"].as_u64().unwrap_or(0) as u32,
+            visible_inputs: value["visibleInputs"].as_u64().unwrap_or(0) as u32,
+            form_count: value["formCount"].as_u64().unwrap_or(0) as u32,
+            body_text_length: value["bodyTextLength"].as_u64().unwrap_or(0),
+            title: value["title"].as_str().unwrap_or("").to_string(),
+        };
+
+        let detection = Self::classify(&signals);
+        if detection.is_auth_wall {
+            debug!("Auth wall detected: {:?}", detection.reasons);
+        }
+        Ok(detection)
+    }
+
+    /// Check whether a URL points at a known login/SSO endpoint
+    pub fn is_sso_url(url: &str) -> bool {
+        const SSO_HOSTS: &[&str] = &[
+            "accounts.google.com",
+            "login.microsoftonline.com",
+            "login.live.com",
+            "auth0.com",
+            "okta.com",
+        ];
+        const LOGIN_PATHS: &[&str] = &[
+            "/login", "/log-in", "/signin", "/sign-in", "/sso", "/oauth", "/auth/",
+        ];
+
+        let lower = url.to_lowercase();
+        let host = UrlValidator::extract_host(&lower).unwrap_or_default();
+
+        if SSO_HOSTS.iter().any(|h| host == *h || host.ends_with(&format!(".{}", h))) {
+            return true;
+        }
+
+        // Only match path segments, not the host or query string
+        let path = lower
+            .split_once("://")
+            .map(|(_, rest)| rest)
+            .and_then(|rest| rest.find('/').map(|i| &rest[i..]))
+            .unwrap_or("");
+        let path = path.split(['?', '#']).next().unwrap_or("");
+
+        LOGIN_PATHS.iter().any(|p| path.starts_with(p))
+    }
+
+    /// Classify gathered signals into an auth wall verdict
+    pub fn classify(signals: &AuthWallSignals) -> AuthWallDetection {
+        let mut reasons = Vec::new();
+
+        if let Some(status) = signals.status {
+            if status == 401 || status == 403 {
+                reasons.push(format!("HTTP status {} requires authentication", status));
+            }
+        }
+
+        if Self::is_sso_url(&signals.url) {
+            reasons.push(format!("URL matches a login/SSO pattern: {}", signals.url));
+        }
+
+        // A password field on an otherwise sparse page means the login form
+        // *is* the content, not just a widget in the header
+        if signals.password_fields > 0
+            && signals.visible_inputs <= 5
+            && signals.body_text_length < 2000
+        {
+            reasons.push("Page is dominated by a login form".to_string());
+        }
+
+        let title = signals.title.to_lowercase();
+        if signals.password_fields > 0
+            && (title.contains("log in")
+                || title.contains("login")
+                || title.contains("sign in"))
+        {
+            reasons.push(format!("Title indicates a login page: {}", signals.title));
+        }
+
+        AuthWallDetection {
+            is_auth_wall: !reasons.is_empty(),
+            reasons,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -463,12 +1613,160 @@ mod tests {
         assert_eq!(opts.retries, 3);
         assert!(opts.human_like);
         assert_eq!(opts.retry_delay_ms, 1000);
+        assert_eq!(opts.min_body_chars, 0);
+        assert!(opts.bypass_service_worker);
+        assert_eq!(opts.allowed_content_types, None);
+    }
+
+    #[test]
+    fn test_html_content_types() {
+        let types = NavigationOptions::html_content_types();
+        assert!(types.contains(&"text/html".to_string()));
+        assert!(types.contains(&"application/xhtml+xml".to_string()));
+    }
+
+    #[test]
+    fn test_network_conditions_presets() {
+        let fast = NetworkConditions::fast_3g();
+        let slow = NetworkConditions::slow_3g();
+        assert!(!fast.offline);
+        assert!(!slow.offline);
+        assert!(slow.latency_ms > fast.latency_ms);
+        assert!(slow.download_throughput < fast.download_throughput);
+
+        let offline = NetworkConditions::offline();
+        assert!(offline.offline);
+        assert!(offline.download_throughput < 0.0);
+    }
+
+    #[test]
+    fn test_viewport_override_new_defaults() {
+        let viewport = ViewportOverride::new(400, 300);
+        assert_eq!(viewport.width, 400);
+        assert_eq!(viewport.height, 300);
+        assert_eq!(viewport.device_scale_factor, 1.0);
+        assert!(!viewport.mobile);
+        assert!(!viewport.reapply_after_load);
+    }
+
+    #[test]
+    fn test_referer_policy_no_referrer() {
+        assert_eq!(
+            RefererPolicy::NoReferrer.apply("https://a.example/page?q=1", "https://b.example/"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_referer_policy_origin_only_drops_path_and_query() {
+        assert_eq!(
+            RefererPolicy::OriginOnly
+                .apply("https://a.example/deep/page?token=secret", "http://b.example/"),
+            Some("https://a.example/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_referer_policy_strict_origin_downgrade_sends_nothing() {
+        let policy = RefererPolicy::StrictOrigin;
+        assert_eq!(policy.apply("https://a.example/page", "http://b.example/"), None);
+        assert_eq!(
+            policy.apply("https://a.example/page", "https://b.example/"),
+            Some("https://a.example/".to_string())
+        );
+        assert_eq!(
+            policy.apply("http://a.example/page", "http://b.example/"),
+            Some("http://a.example/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_referer_policy_keeps_non_default_port() {
+        assert_eq!(
+            RefererPolicy::OriginOnly.apply("https://a.example:8443/page", "https://b.example/"),
+            Some("https://a.example:8443/".to_string())
+        );
+    }
+
+    #[test]
+    fn test_referer_policy_serde_kebab_case() {
+        assert_eq!(
+            serde_json::from_str::<RefererPolicy>("\"origin-only\"").unwrap(),
+            RefererPolicy::OriginOnly
+        );
+        assert_eq!(
+            serde_json::to_string(&RefererPolicy::NoReferrer).unwrap(),
+            "\"no-referrer\""
+        );
+    }
+
+    #[test]
+    fn test_strip_utm_removes_tracking_params_only() {
+        assert_eq!(
+            UrlValidator::strip_utm(
+                "https://example.com/page?utm_source=news&page=2&utm_campaign=x"
+            ),
+            "https://example.com/page?page=2"
+        );
+    }
+
+    #[test]
+    fn test_strip_utm_clears_query_when_all_tracking() {
+        assert_eq!(
+            UrlValidator::strip_utm("https://example.com/page?utm_source=news&UTM_Medium=email"),
+            "https://example.com/page"
+        );
+    }
+
+    #[test]
+    fn test_strip_utm_leaves_clean_urls_untouched() {
+        assert_eq!(
+            UrlValidator::strip_utm("https://example.com/page?q=rust&page=2"),
+            "https://example.com/page?q=rust&page=2"
+        );
+        assert_eq!(UrlValidator::strip_utm("not a url"), "not a url");
+        assert_eq!(
+            UrlValidator::strip_utm("https://example.com/utm_source"),
+            "https://example.com/utm_source"
+        );
     }
 
     #[test]
     fn test_wait_until_variants() {
         assert_ne!(WaitUntil::Load, WaitUntil::DomContentLoaded);
         assert_eq!(WaitUntil::NetworkIdle0, WaitUntil::NetworkIdle0);
+        assert_ne!(WaitUntil::DomStable(250), WaitUntil::DomStable(500));
+    }
+
+    #[test]
+    fn test_dom_stable_script_embeds_quiet_period() {
+        let script = PageNavigator::dom_stable_script(250);
+        assert!(script.contains("const quiet = 250"));
+        assert!(script.contains("MutationObserver"));
+    }
+
+    #[test]
+    fn test_spinner_wait_defaults() {
+        let wait = SpinnerWait::default();
+        assert!(wait.selectors.contains(&".spinner".to_string()));
+        assert!(wait.selectors.contains(&"[aria-busy=\"true\"]".to_string()));
+        assert_eq!(wait.timeout_ms, 10_000);
+    }
+
+    #[test]
+    fn test_post_data_helpers() {
+        let form = PostData::form("q=rust&page=2");
+        assert_eq!(form.body, b"q=rust&page=2");
+        assert_eq!(form.content_type, "application/x-www-form-urlencoded");
+
+        let json = PostData::json(r#"{"q":"rust"}"#);
+        assert_eq!(json.content_type, "application/json");
+    }
+
+    #[test]
+    fn test_load_state_variants() {
+        assert_ne!(LoadState::DomContentLoaded, LoadState::Complete);
+        assert_eq!(LoadState::Complete, LoadState::Complete);
     }
 
     // ========================================================================
@@ -674,6 +1972,10 @@ mod tests {
             status: Some(200),
             title: Some("Example".to_string()),
             duration_ms: 150,
+            timing: None,
+            dialogs: Vec::new(),
+            blocked_mixed_content: Vec::new(),
+            redirect_attempts: Vec::new(),
         };
 
         assert_eq!(result.final_url, "https://example.com");
@@ -689,12 +1991,104 @@ mod tests {
             status: None,
             title: None,
             duration_ms: 100,
+            timing: None,
+            dialogs: Vec::new(),
+            blocked_mixed_content: Vec::new(),
+            redirect_attempts: Vec::new(),
         };
 
         assert!(result.status.is_none());
         assert!(result.title.is_none());
     }
 
+    // ========================================================================
+    // Navigation Timing Tests
+    // ========================================================================
+
+    #[test]
+    fn test_navigation_timing_from_entry() {
+        let entry = serde_json::json!({
+            "domainLookupStart": 5.0,
+            "domainLookupEnd": 25.0,
+            "connectStart": 25.0,
+            "connectEnd": 75.0,
+            "requestStart": 75.0,
+            "responseStart": 175.0,
+            "responseEnd": 300.0,
+            "loadEventEnd": 405.0
+        });
+
+        let timing = NavigationTiming::from_entry(&entry).unwrap();
+        assert_eq!(timing.dns_ms, Some(20));
+        assert_eq!(timing.connect_ms, Some(50));
+        assert_eq!(timing.ttfb_ms, Some(100));
+        assert_eq!(timing.content_ms, Some(230));
+
+        // Phases sum to approximately the full navigation span
+        let sum = timing.dns_ms.unwrap()
+            + timing.connect_ms.unwrap()
+            + timing.ttfb_ms.unwrap()
+            + timing.content_ms.unwrap();
+        assert_eq!(sum, 400);
+    }
+
+    #[test]
+    fn test_navigation_timing_missing_phases_are_none() {
+        // A page that exposes no DNS/connect marks (e.g. served from cache)
+        let entry = serde_json::json!({
+            "requestStart": 10.0,
+            "responseStart": 30.0,
+            "responseEnd": 50.0,
+            "loadEventEnd": 0.0
+        });
+
+        let timing = NavigationTiming::from_entry(&entry).unwrap();
+        assert_eq!(timing.dns_ms, None);
+        assert_eq!(timing.connect_ms, None);
+        assert_eq!(timing.ttfb_ms, Some(20));
+        // loadEventEnd of 0 falls back to responseEnd
+        assert_eq!(timing.content_ms, Some(20));
+    }
+
+    #[test]
+    fn test_navigation_timing_rejects_non_object() {
+        assert!(NavigationTiming::from_entry(&serde_json::Value::Null).is_none());
+    }
+
+    #[test]
+    fn test_navigation_options_timing_disabled_by_default() {
+        assert!(!NavigationOptions::default().collect_timing);
+    }
+
+    // ========================================================================
+    // Referrer Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn test_navigation_options_no_referrer_by_default() {
+        assert!(NavigationOptions::default().referrer.is_none());
+    }
+
+    #[test]
+    fn test_validate_referrer_accepts_http_and_https() {
+        assert!(UrlValidator::validate_referrer("https://www.google.com/search?q=x").is_ok());
+        assert!(UrlValidator::validate_referrer("http://example.com").is_ok());
+    }
+
+    #[test]
+    fn test_validate_referrer_rejects_invalid() {
+        assert!(UrlValidator::validate_referrer("").is_err());
+        assert!(UrlValidator::validate_referrer("not-a-url").is_err());
+        assert!(UrlValidator::validate_referrer("file:///etc/passwd").is_err());
+        assert!(UrlValidator::validate_referrer("javascript:alert(1)").is_err());
+    }
+
+    #[test]
+    fn test_validate_referrer_rejects_overlong() {
+        let long = format!("https://example.com/{}", "a".repeat(2048));
+        assert!(UrlValidator::validate_referrer(&long).is_err());
+    }
+
     // ========================================================================
     // Edge Cases Tests
     // ========================================================================
@@ -727,4 +2121,96 @@ mod tests {
             "https://example.com/localhost/api"
         ));
     }
+
+    // ========================================================================
+    // Auth Wall Detection Tests
+    // ========================================================================
+
+    #[test]
+    fn test_sso_url_known_hosts() {
+        assert!(AuthWallDetector::is_sso_url(
+            "https://accounts.google.com/o/oauth2/v2/auth"
+        ));
+        assert!(AuthWallDetector::is_sso_url(
+            "https://login.microsoftonline.com/common/oauth2"
+        ));
+        assert!(AuthWallDetector::is_sso_url(
+            "https://mycompany.okta.com/app/dashboard"
+        ));
+    }
+
+    #[test]
+    fn test_sso_url_login_paths() {
+        assert!(AuthWallDetector::is_sso_url("https://example.com/login"));
+        assert!(AuthWallDetector::is_sso_url(
+            "https://example.com/signin?next=/dashboard"
+        ));
+        assert!(AuthWallDetector::is_sso_url("https://example.com/sso/start"));
+    }
+
+    #[test]
+    fn test_sso_url_not_matched_for_content() {
+        assert!(!AuthWallDetector::is_sso_url("https://example.com/articles"));
+        // "login" in the host or query alone should not match
+        assert!(!AuthWallDetector::is_sso_url("https://loginexample.com/docs"));
+        assert!(!AuthWallDetector::is_sso_url(
+            "https://example.com/docs?ref=login"
+        ));
+    }
+
+    #[test]
+    fn test_classify_login_form_fixture() {
+        // A page that is just a login form: one password field, a couple of
+        // visible inputs, and very little text
+        let signals = AuthWallSignals {
+            url: "https://example.com/account".to_string(),
+            status: None,
+            password_fields: 1,
+            visible_inputs: 3,
+            form_count: 1,
+            body_text_length: 250,
+            title: "Sign in to Example".to_string(),
+        };
+
+        let detection = AuthWallDetector::classify(&signals);
+        assert!(detection.is_auth_wall);
+        assert!(!detection.reasons.is_empty());
+    }
+
+    #[test]
+    fn test_classify_content_page_with_login_widget() {
+        // An article page with a login form in the header should not be
+        // flagged: plenty of text and inputs beyond the form
+        let signals = AuthWallSignals {
+            url: "https://example.com/articles/rust-tips".to_string(),
+            status: Some(200),
+            password_fields: 1,
+            visible_inputs: 8,
+            form_count: 2,
+            body_text_length: 15_000,
+            title: "Ten Rust Tips".to_string(),
+        };
+
+        let detection = AuthWallDetector::classify(&signals);
+        assert!(!detection.is_auth_wall);
+    }
+
+    #[test]
+    fn test_classify_forbidden_status() {
+        let signals = AuthWallSignals {
+            url: "https://example.com/private".to_string(),
+            status: Some(403),
+            ..AuthWallSignals::default()
+        };
+
+        let detection = AuthWallDetector::classify(&signals);
+        assert!(detection.is_auth_wall);
+        assert!(detection.reasons[0].contains("403"));
+    }
+
+    #[test]
+    fn test_authentication_required_error() {
+        let err = NavigationError::AuthenticationRequired("login wall at /login".to_string());
+        assert!(err.to_string().contains("Authentication required"));
+    }
 }
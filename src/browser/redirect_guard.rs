@@ -0,0 +1,211 @@
+//! Post-load redirect guarding
+//!
+//! Some pages load fine and then immediately JS-redirect the main frame to
+//! an unexpected destination — a paywall, an app-store page, an ad
+//! interstitial — so the agent ends up capturing the wrong page. This module
+//! watches main-frame document requests via the CDP `Fetch` domain and
+//! reports, or aborts, those targeting a different origin than the one
+//! navigated to.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::fetch::{
+    ContinueRequestParams, EnableParams, EventRequestPaused, FailRequestParams, RequestPattern,
+    RequestStage,
+};
+use chromiumoxide::cdp::browser_protocol::network::{ErrorReason, ResourceType};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// What to do with a cross-origin main-frame navigation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedirectGuardMode {
+    /// Let the navigation proceed but record the destination
+    Report,
+    /// Abort the navigation, keeping the current document, and record the
+    /// destination
+    Block,
+}
+
+/// A cross-origin main-frame navigation the guard saw
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectAttempt {
+    /// URL the page tried to navigate to
+    pub url: String,
+    /// Whether the guard aborted the navigation
+    pub blocked: bool,
+}
+
+/// Active redirect guarding on a page
+///
+/// Pauses main-frame document requests and compares their origin against the
+/// navigated origin; same-origin loads (including the initial navigation)
+/// pass through untouched. Uses the `Fetch` domain, so do not combine with
+/// [`RequestInterceptor`] on the same page — both would answer each paused
+/// request. Dropping the guard stops handling.
+///
+/// [`RequestInterceptor`]: super::RequestInterceptor
+pub struct RedirectGuard {
+    task: JoinHandle<()>,
+    attempts: Arc<RwLock<Vec<RedirectAttempt>>>,
+}
+
+impl RedirectGuard {
+    /// Install redirect guarding on a page
+    ///
+    /// `expected_url` is the navigation target whose origin counts as "this
+    /// site". Install before navigating: the initial document request is
+    /// same-origin and passes through.
+    pub async fn install(
+        page: &PageHandle,
+        mode: RedirectGuardMode,
+        expected_url: &str,
+    ) -> Result<Self> {
+        info!("Installing redirect guard (mode {:?})", mode);
+
+        // Only document requests are paused; subresources stay untouched
+        let pattern = RequestPattern::builder()
+            .url_pattern("*")
+            .resource_type(ResourceType::Document)
+            .request_stage(RequestStage::Request)
+            .build();
+        page.page
+            .execute(EnableParams::builder().pattern(pattern).build())
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let mut events = page
+            .page
+            .event_listener::<EventRequestPaused>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        // Iframe documents navigate cross-origin routinely (ads, embeds);
+        // only the main frame matters. Unknown before the first navigation
+        // commits, in which case every document request is treated as
+        // main-frame.
+        let main_frame = page.page.mainframe().await.ok().flatten();
+
+        let attempts = Arc::new(RwLock::new(Vec::new()));
+        let attempts_writer = Arc::clone(&attempts);
+        let cdp_page = page.page.clone();
+        let expected = expected_url.to_string();
+
+        let task = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let url = event.request.url.clone();
+                let main_frame_request = match &main_frame {
+                    Some(id) => event.frame_id == *id,
+                    None => true,
+                };
+
+                let result = if main_frame_request && !same_origin(&url, &expected) {
+                    let blocked = mode == RedirectGuardMode::Block;
+                    warn!(
+                        "Cross-origin main-frame navigation to {} ({})",
+                        crate::logging::sanitize_url(&url),
+                        if blocked { "blocked" } else { "reported" }
+                    );
+                    attempts_writer
+                        .write()
+                        .await
+                        .push(RedirectAttempt { url, blocked });
+
+                    if blocked {
+                        cdp_page
+                            .execute(FailRequestParams::new(
+                                event.request_id.clone(),
+                                ErrorReason::Aborted,
+                            ))
+                            .await
+                            .map(|_| ())
+                    } else {
+                        cdp_page
+                            .execute(ContinueRequestParams::new(event.request_id.clone()))
+                            .await
+                            .map(|_| ())
+                    }
+                } else {
+                    cdp_page
+                        .execute(ContinueRequestParams::new(event.request_id.clone()))
+                        .await
+                        .map(|_| ())
+                };
+
+                if let Err(e) = result {
+                    warn!("Failed to answer paused document request: {}", e);
+                }
+            }
+        });
+
+        Ok(Self { task, attempts })
+    }
+
+    /// The cross-origin navigations seen so far
+    pub async fn attempts(&self) -> Vec<RedirectAttempt> {
+        self.attempts.read().await.clone()
+    }
+}
+
+impl Drop for RedirectGuard {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Whether two URLs share an origin (scheme, host, port)
+///
+/// Unparseable URLs never match: an attempt whose URL cannot be understood
+/// is treated as cross-origin rather than waved through.
+fn same_origin(a: &str, b: &str) -> bool {
+    match (url::Url::parse(a), url::Url::parse(b)) {
+        (Ok(a), Ok(b)) => a.origin() == b.origin(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_origin_ignores_path_and_query() {
+        assert!(same_origin(
+            "https://example.com/a?x=1",
+            "https://example.com/b"
+        ));
+    }
+
+    #[test]
+    fn test_same_origin_distinguishes_scheme_host_port() {
+        assert!(!same_origin("http://example.com/", "https://example.com/"));
+        assert!(!same_origin("https://example.com/", "https://other.com/"));
+        assert!(!same_origin(
+            "https://example.com/",
+            "https://example.com:8443/"
+        ));
+    }
+
+    #[test]
+    fn test_same_origin_unparseable_never_matches() {
+        assert!(!same_origin("not a url", "https://example.com/"));
+        assert!(!same_origin("https://example.com/", "not a url"));
+    }
+
+    #[test]
+    fn test_redirect_guard_mode_serde_lowercase() {
+        assert_eq!(
+            serde_json::from_str::<RedirectGuardMode>("\"block\"").unwrap(),
+            RedirectGuardMode::Block
+        );
+        assert_eq!(
+            serde_json::to_string(&RedirectGuardMode::Report).unwrap(),
+            "\"report\""
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Network response capture
+//!
+//! Single-page applications often render data the DOM never shows cleanly;
+//! the underlying API response is the better source. This module watches
+//! network traffic during a navigation and returns the body of the first
+//! response whose URL matches a pattern, via `Network.getResponseBody`.
+
+use crate::browser::PageHandle;
+use crate::error::{BrowserError, Error, Result};
+use chromiumoxide::cdp::browser_protocol::network::{
+    EventLoadingFinished, EventResponseReceived, GetResponseBodyParams, RequestId,
+};
+use futures::StreamExt;
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+/// How often the waiter re-checks collected responses
+const POLL_INTERVAL_MS: u64 = 50;
+
+/// A network response matched during navigation
+#[derive(Debug, Clone)]
+pub struct CapturedResponse {
+    /// URL of the matched response
+    pub url: String,
+    /// HTTP status code
+    pub status: i64,
+    /// MIME type reported by the browser (e.g. `application/json`)
+    pub content_type: String,
+    /// Response body, decoded if the browser returned it base64-encoded
+    pub body: Vec<u8>,
+}
+
+impl CapturedResponse {
+    /// Body as text, with invalid UTF-8 replaced
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// A matched response whose body has not been fetched yet
+#[derive(Debug, Clone)]
+struct PendingResponse {
+    request_id: RequestId,
+    url: String,
+    status: i64,
+    content_type: String,
+}
+
+/// Active response capture on a page
+///
+/// Install before navigating so responses fired during the page load are
+/// seen. Bodies are only fetched on request: `Network.getResponseBody`
+/// requires the response to have finished loading, so the capturer tracks
+/// `Network.loadingFinished` alongside the matches. Dropping the capturer
+/// stops collection.
+pub struct ResponseCapturer {
+    task: JoinHandle<()>,
+    matched: Arc<RwLock<Vec<PendingResponse>>>,
+    finished: Arc<RwLock<HashSet<RequestId>>>,
+}
+
+impl ResponseCapturer {
+    /// Install response capture for URLs matching `pattern` on a page
+    pub async fn install(page: &PageHandle, pattern: Regex) -> Result<Self> {
+        info!("Installing response capture (pattern {})", pattern);
+
+        let mut responses = page
+            .page
+            .event_listener::<EventResponseReceived>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let mut finishes = page
+            .page
+            .event_listener::<EventLoadingFinished>()
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        let matched = Arc::new(RwLock::new(Vec::new()));
+        let finished = Arc::new(RwLock::new(HashSet::new()));
+        let task_matched = Arc::clone(&matched);
+        let task_finished = Arc::clone(&finished);
+
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    Some(event) = responses.next() => {
+                        if !pattern.is_match(&event.response.url) {
+                            continue;
+                        }
+                        task_matched.write().await.push(PendingResponse {
+                            request_id: event.request_id.clone(),
+                            url: event.response.url.clone(),
+                            status: event.response.status,
+                            content_type: event.response.mime_type.clone(),
+                        });
+                    }
+                    Some(event) = finishes.next() => {
+                        task_finished.write().await.insert(event.request_id.clone());
+                    }
+                    else => break,
+                }
+            }
+        });
+
+        Ok(Self {
+            task,
+            matched,
+            finished,
+        })
+    }
+
+    /// Wait for the first matched response to finish loading and return it
+    ///
+    /// Fails with [`BrowserError::Timeout`] if no matching response finishes
+    /// within `timeout_ms`.
+    pub async fn wait_for_body(
+        &self,
+        page: &PageHandle,
+        timeout_ms: u64,
+    ) -> Result<CapturedResponse> {
+        let pending = tokio::time::timeout(Duration::from_millis(timeout_ms), async {
+            loop {
+                {
+                    let matched = self.matched.read().await;
+                    let finished = self.finished.read().await;
+                    if let Some(pending) = matched
+                        .iter()
+                        .find(|p| finished.contains(&p.request_id))
+                    {
+                        break pending.clone();
+                    }
+                }
+                tokio::time::sleep(Duration::from_millis(POLL_INTERVAL_MS)).await;
+            }
+        })
+        .await
+        .map_err(|_| Error::from(BrowserError::Timeout(timeout_ms)))?;
+
+        let returns = page
+            .page
+            .execute(GetResponseBodyParams::new(pending.request_id.clone()))
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+        let body = decode_body(&returns.body, returns.base64_encoded)?;
+
+        Ok(CapturedResponse {
+            url: pending.url,
+            status: pending.status,
+            content_type: pending.content_type,
+            body,
+        })
+    }
+}
+
+impl Drop for ResponseCapturer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Decode a `Network.getResponseBody` body into raw bytes
+fn decode_body(body: &str, base64_encoded: bool) -> Result<Vec<u8>> {
+    if !base64_encoded {
+        return Ok(body.as_bytes().to_vec());
+    }
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+    BASE64
+        .decode(body)
+        .map_err(|e| Error::cdp(format!("Invalid base64 response body: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_body_plain_text() {
+        let body = decode_body("{\"items\":[]}", false).unwrap();
+        assert_eq!(body, b"{\"items\":[]}");
+    }
+
+    #[test]
+    fn test_decode_body_base64() {
+        let body = decode_body("aGVsbG8=", true).unwrap();
+        assert_eq!(body, b"hello");
+    }
+
+    #[test]
+    fn test_decode_body_invalid_base64() {
+        let err = decode_body("not base64!", true).unwrap_err();
+        assert!(err.to_string().contains("base64"));
+    }
+
+    #[test]
+    fn test_captured_response_text() {
+        let response = CapturedResponse {
+            url: "https://example.com/api/items".to_string(),
+            status: 200,
+            content_type: "application/json".to_string(),
+            body: b"[1, 2, 3]".to_vec(),
+        };
+        assert_eq!(response.text(), "[1, 2, 3]");
+    }
+}
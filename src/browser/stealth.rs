@@ -3,32 +3,135 @@
 //! This module provides techniques to make the automated browser appear
 //! more like a regular user browser, bypassing common bot detection.
 
-use crate::error::{Error, Result};
+use crate::error::{BrowserError, Error, Result};
 use chromiumoxide::cdp::browser_protocol::page::AddScriptToEvaluateOnNewDocumentParams;
 use chromiumoxide::Page;
+use std::future::Future;
+use std::time::Duration;
 use tracing::{debug, instrument};
 
-/// Stealth mode configuration and application
-pub struct StealthMode;
+/// Default timeout for applying stealth techniques to a new page
+pub const DEFAULT_CLOAK_TIMEOUT_MS: u64 = 5000;
+
+/// Stealth mode configuration
+///
+/// Individual techniques can be toggled directly, but most users should
+/// pick one of the named presets: [`StealthMode::minimal`],
+/// [`StealthMode::standard`], or [`StealthMode::aggressive`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StealthMode {
+    /// Hide navigator.webdriver
+    pub hide_webdriver: bool,
+    /// Mock the Chrome extension runtime object
+    pub mock_chrome_runtime: bool,
+    /// Spoof WebGL vendor/renderer strings
+    pub override_webgl: bool,
+    /// Mock navigator.plugins
+    pub mock_plugins: bool,
+    /// Mock navigator.languages
+    pub mock_languages: bool,
+    /// Hide miscellaneous automation indicators (permissions, connection, UA hints)
+    pub hide_automation_indicators: bool,
+    /// Inject deterministic noise into canvas readback APIs (aggressive only)
+    pub canvas_noise: bool,
+    /// Seed for fingerprint noise; stable for the lifetime of this mode so the
+    /// fingerprint does not look randomized per call
+    pub fingerprint_seed: u32,
+}
+
+impl Default for StealthMode {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
 
 impl StealthMode {
-    /// Apply all stealth techniques to a page
-    #[instrument(skip(page))]
-    pub async fn apply(page: &Page) -> Result<()> {
-        debug!("Applying stealth mode");
-
-        // Apply all stealth scripts
-        Self::hide_webdriver(page).await?;
-        Self::mock_chrome_runtime(page).await?;
-        Self::override_webgl(page).await?;
-        Self::mock_plugins(page).await?;
-        Self::mock_languages(page).await?;
-        Self::hide_automation_indicators(page).await?;
+    /// Minimal preset: only hides navigator.webdriver
+    pub fn minimal() -> Self {
+        Self {
+            hide_webdriver: true,
+            mock_chrome_runtime: false,
+            override_webgl: false,
+            mock_plugins: false,
+            mock_languages: false,
+            hide_automation_indicators: false,
+            canvas_noise: false,
+            fingerprint_seed: 0,
+        }
+    }
+
+    /// Standard preset: the full set of navigator/runtime masking techniques
+    pub fn standard() -> Self {
+        Self {
+            hide_webdriver: true,
+            mock_chrome_runtime: true,
+            override_webgl: true,
+            mock_plugins: true,
+            mock_languages: true,
+            hide_automation_indicators: true,
+            canvas_noise: false,
+            fingerprint_seed: 0,
+        }
+    }
+
+    /// Aggressive preset: everything in standard plus canvas fingerprint noise
+    /// with a fresh per-session seed
+    pub fn aggressive() -> Self {
+        Self {
+            canvas_noise: true,
+            fingerprint_seed: rand::random(),
+            ..Self::standard()
+        }
+    }
+
+    /// Set the fingerprint noise seed (useful for reproducible sessions)
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.fingerprint_seed = seed;
+        self
+    }
+
+    /// Apply the configured stealth techniques to a page
+    #[instrument(skip(self, page))]
+    pub async fn apply(&self, page: &Page) -> Result<()> {
+        debug!("Applying stealth mode: {:?}", self);
+
+        if self.hide_webdriver {
+            Self::hide_webdriver(page).await?;
+        }
+        if self.mock_chrome_runtime {
+            Self::mock_chrome_runtime(page).await?;
+        }
+        if self.override_webgl {
+            Self::override_webgl(page).await?;
+        }
+        if self.mock_plugins {
+            Self::mock_plugins(page).await?;
+        }
+        if self.mock_languages {
+            Self::mock_languages(page).await?;
+        }
+        if self.hide_automation_indicators {
+            Self::hide_automation_indicators(page).await?;
+        }
+        if self.canvas_noise {
+            Self::spoof_canvas_fingerprint(page, self.fingerprint_seed).await?;
+        }
 
         debug!("Stealth mode applied successfully");
         Ok(())
     }
 
+    /// Apply stealth techniques with a timeout
+    ///
+    /// A hung or failed cloak step (e.g. a slow
+    /// `addScriptToEvaluateOnNewDocument`) surfaces as
+    /// [`BrowserError::StealthSetupFailed`], so callers can tell stealth
+    /// setup apart from navigation failures.
+    #[instrument(skip(self, page))]
+    pub async fn apply_with_timeout(&self, page: &Page, timeout_ms: u64) -> Result<()> {
+        cloak_with_timeout(self.apply(page), timeout_ms).await
+    }
+
     /// Hide navigator.webdriver property
     async fn hide_webdriver(page: &Page) -> Result<()> {
         let script = r#"
@@ -180,6 +283,59 @@ impl StealthMode {
         Self::inject_script(page, script).await
     }
 
+    /// Inject deterministic noise into canvas readback APIs
+    ///
+    /// The noise is derived from the session seed, so repeated reads within a
+    /// session produce the same fingerprint while differing across sessions.
+    async fn spoof_canvas_fingerprint(page: &Page, seed: u32) -> Result<()> {
+        let script = format!(
+            r#"
+            (() => {{
+                const SEED = {seed};
+
+                // mulberry32: tiny deterministic PRNG
+                const prng = (s) => {{
+                    return () => {{
+                        s |= 0; s = s + 0x6D2B79F5 | 0;
+                        let t = Math.imul(s ^ s >>> 15, 1 | s);
+                        t = t + Math.imul(t ^ t >>> 7, 61 | t) ^ t;
+                        return ((t ^ t >>> 14) >>> 0) / 4294967296;
+                    }};
+                }};
+
+                const addNoise = (imageData) => {{
+                    const rand = prng(SEED);
+                    const data = imageData.data;
+                    // Perturb the low bit of a sparse sample of pixels
+                    for (let i = 0; i < data.length; i += 97 * 4) {{
+                        const channel = i + Math.floor(rand() * 3);
+                        data[channel] = data[channel] ^ (rand() < 0.5 ? 0 : 1);
+                    }}
+                    return imageData;
+                }};
+
+                const getImageDataOriginal = CanvasRenderingContext2D.prototype.getImageData;
+                CanvasRenderingContext2D.prototype.getImageData = function(...args) {{
+                    return addNoise(getImageDataOriginal.apply(this, args));
+                }};
+
+                const toDataURLOriginal = HTMLCanvasElement.prototype.toDataURL;
+                HTMLCanvasElement.prototype.toDataURL = function(...args) {{
+                    const ctx = this.getContext('2d');
+                    if (ctx && this.width > 0 && this.height > 0) {{
+                        try {{
+                            const imageData = getImageDataOriginal.call(ctx, 0, 0, this.width, this.height);
+                            ctx.putImageData(addNoise(imageData), 0, 0);
+                        }} catch (e) {{}}
+                    }}
+                    return toDataURLOriginal.apply(this, args);
+                }};
+            }})();
+            "#
+        );
+        Self::inject_script(page, &script).await
+    }
+
     /// Inject a script to run on new document
     async fn inject_script(page: &Page, script: &str) -> Result<()> {
         let params = AddScriptToEvaluateOnNewDocumentParams::builder()
@@ -195,8 +351,116 @@ impl StealthMode {
     }
 }
 
+/// Run a cloak future under a timeout, mapping failures to a typed error
+///
+/// Both a hang (timeout) and an inner failure become
+/// [`BrowserError::StealthSetupFailed`]. Generic over the future so tests
+/// can inject a hanging or failing cloak step without a browser.
+pub async fn cloak_with_timeout<F>(cloak: F, timeout_ms: u64) -> Result<()>
+where
+    F: Future<Output = Result<()>>,
+{
+    match tokio::time::timeout(Duration::from_millis(timeout_ms), cloak).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(BrowserError::StealthSetupFailed(e.to_string()).into()),
+        Err(_) => Err(BrowserError::StealthSetupFailed(format!(
+            "timed out after {}ms",
+            timeout_ms
+        ))
+        .into()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    // Stealth mode tests require a running browser
-    // These are integration tests that will be in tests/browser_tests.rs
+    use super::*;
+
+    // Applying stealth scripts requires a running browser; those paths are
+    // covered by the integration tests in tests/browser_tests.rs.
+
+    #[test]
+    fn test_minimal_preset() {
+        let mode = StealthMode::minimal();
+        assert!(mode.hide_webdriver);
+        assert!(!mode.mock_chrome_runtime);
+        assert!(!mode.override_webgl);
+        assert!(!mode.mock_plugins);
+        assert!(!mode.mock_languages);
+        assert!(!mode.hide_automation_indicators);
+    }
+
+    #[test]
+    fn test_standard_preset() {
+        let mode = StealthMode::standard();
+        assert!(mode.hide_webdriver);
+        assert!(mode.mock_chrome_runtime);
+        assert!(mode.override_webgl);
+        assert!(mode.mock_plugins);
+        assert!(mode.mock_languages);
+        assert!(mode.hide_automation_indicators);
+    }
+
+    #[test]
+    fn test_aggressive_preset_is_superset_of_standard() {
+        let aggressive = StealthMode::aggressive();
+        assert!(aggressive.hide_webdriver);
+        assert!(aggressive.mock_chrome_runtime);
+        assert!(aggressive.override_webgl);
+        assert!(aggressive.mock_plugins);
+        assert!(aggressive.mock_languages);
+        assert!(aggressive.hide_automation_indicators);
+        assert!(aggressive.canvas_noise);
+    }
+
+    #[test]
+    fn test_standard_has_no_canvas_noise() {
+        assert!(!StealthMode::standard().canvas_noise);
+        assert!(!StealthMode::minimal().canvas_noise);
+    }
+
+    #[test]
+    fn test_seed_stable_within_mode() {
+        let mode = StealthMode::aggressive();
+        // Seed does not change once the mode is constructed
+        assert_eq!(mode.fingerprint_seed, mode.fingerprint_seed);
+
+        let seeded = StealthMode::aggressive().with_seed(42);
+        assert_eq!(seeded.fingerprint_seed, 42);
+    }
+
+    #[test]
+    fn test_default_is_standard() {
+        assert_eq!(StealthMode::default(), StealthMode::standard());
+    }
+
+    #[tokio::test]
+    async fn test_cloak_with_timeout_passes_through_success() {
+        let result = cloak_with_timeout(async { Ok(()) }, 100).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_hanging_cloak_surfaces_stealth_error() {
+        let result = cloak_with_timeout(std::future::pending::<Result<()>>(), 25).await;
+
+        match result {
+            Err(Error::Browser(BrowserError::StealthSetupFailed(msg))) => {
+                assert!(msg.contains("timed out after 25ms"));
+            }
+            other => panic!("expected StealthSetupFailed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_cloak_surfaces_stealth_error() {
+        let failing = async { Err(Error::cdp("injection refused")) };
+        let result = cloak_with_timeout(failing, 100).await;
+
+        match result {
+            Err(Error::Browser(BrowserError::StealthSetupFailed(msg))) => {
+                assert!(msg.contains("injection refused"));
+            }
+            other => panic!("expected StealthSetupFailed, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,361 @@
+//! Authenticated session persistence
+//!
+//! Logged-in scraping works much better when an agent can authenticate once
+//! and reuse the session everywhere. This module snapshots the browser's
+//! cookies plus per-origin `localStorage`/`sessionStorage` into a
+//! serializable [`StorageState`] (similar to Playwright's `storageState`)
+//! that can be loaded into a fresh browser before navigation.
+
+use crate::browser::PageHandle;
+use crate::error::{Error, Result};
+use chromiumoxide::cdp::browser_protocol::network::{
+    Cookie, CookieParam, CookieSameSite, TimeSinceEpoch,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// A cookie captured from the browser
+///
+/// Mirrors the scalar fields of a CDP cookie so the state survives a round
+/// trip through JSON without depending on protocol types.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageCookie {
+    /// Cookie name
+    pub name: String,
+    /// Cookie value
+    pub value: String,
+    /// Domain the cookie applies to
+    pub domain: String,
+    /// Path the cookie applies to
+    pub path: String,
+    /// Expiry in seconds since the Unix epoch; `None` for session cookies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<f64>,
+    /// True when the cookie is hidden from JavaScript
+    pub http_only: bool,
+    /// True when the cookie is only sent over HTTPS
+    pub secure: bool,
+    /// `Strict`, `Lax`, or `None` when the cookie declares a SameSite policy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub same_site: Option<String>,
+}
+
+impl StorageCookie {
+    /// Convert a CDP cookie into its serializable form
+    ///
+    /// CDP reports session cookies with an expiry of `-1`; those map to
+    /// `None` so loading the state does not set a bogus expiration.
+    pub fn from_cookie(cookie: &Cookie) -> Self {
+        Self {
+            name: cookie.name.clone(),
+            value: cookie.value.clone(),
+            domain: cookie.domain.clone(),
+            path: cookie.path.clone(),
+            expires: (cookie.expires >= 0.0).then_some(cookie.expires),
+            http_only: cookie.http_only,
+            secure: cookie.secure,
+            same_site: cookie.same_site.as_ref().map(|s| s.as_ref().to_string()),
+        }
+    }
+
+    /// Convert back into the CDP parameter used by `Network.setCookies`
+    pub fn to_cookie_param(&self) -> CookieParam {
+        let mut param = CookieParam::new(self.name.clone(), self.value.clone());
+        param.domain = Some(self.domain.clone());
+        param.path = Some(self.path.clone());
+        param.expires = self.expires.map(TimeSinceEpoch::new);
+        param.http_only = Some(self.http_only);
+        param.secure = Some(self.secure);
+        param.same_site = match self.same_site.as_deref() {
+            Some("Strict") => Some(CookieSameSite::Strict),
+            Some("Lax") => Some(CookieSameSite::Lax),
+            Some("None") => Some(CookieSameSite::None),
+            _ => None,
+        };
+        param
+    }
+}
+
+/// Web storage captured for a single origin
+///
+/// `localStorage` and `sessionStorage` are origin-scoped, so each origin's
+/// values are recorded separately and restored by navigating to that origin.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OriginStorage {
+    /// Origin the values belong to (e.g. `https://example.com`)
+    pub origin: String,
+    /// `localStorage` entries for the origin
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub local_storage: HashMap<String, String>,
+    /// `sessionStorage` entries for the origin
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub session_storage: HashMap<String, String>,
+}
+
+impl OriginStorage {
+    /// True when neither storage area has any entries
+    pub fn is_empty(&self) -> bool {
+        self.local_storage.is_empty() && self.session_storage.is_empty()
+    }
+
+    /// Capture the storage areas of the page's current origin
+    ///
+    /// Returns `None` for pages without a usable origin (`about:blank`
+    /// reports `"null"`) and for pages whose storage cannot be read.
+    pub(crate) async fn capture(page: &PageHandle) -> Option<Self> {
+        let script = r#"
+            (() => {
+                const dump = (area) => {
+                    const out = {};
+                    for (let i = 0; i < area.length; i++) {
+                        const key = area.key(i);
+                        out[key] = area.getItem(key);
+                    }
+                    return out;
+                };
+                try {
+                    return {
+                        origin: window.location.origin,
+                        localStorage: dump(window.localStorage),
+                        sessionStorage: dump(window.sessionStorage),
+                    };
+                } catch (e) {
+                    return null;
+                }
+            })()
+        "#;
+
+        let result = page.page.evaluate(script).await.ok()?;
+        let value: serde_json::Value = result.into_value().ok()?;
+        Self::from_dump(&value)
+    }
+
+    /// Parse the JSON produced by the capture script
+    pub fn from_dump(value: &serde_json::Value) -> Option<Self> {
+        let origin = value.get("origin")?.as_str()?;
+        if origin.is_empty() || origin == "null" {
+            return None;
+        }
+
+        let parse_area = |key: &str| -> HashMap<String, String> {
+            value
+                .get(key)
+                .and_then(|v| v.as_object())
+                .map(|map| {
+                    map.iter()
+                        .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Some(Self {
+            origin: origin.to_string(),
+            local_storage: parse_area("localStorage"),
+            session_storage: parse_area("sessionStorage"),
+        })
+    }
+
+    /// Write the captured values into the page's storage areas
+    ///
+    /// The page must already be on this origin; web storage writes always
+    /// land in the scope of the current document.
+    pub(crate) async fn apply(&self, page: &PageHandle) -> Result<()> {
+        let local = serde_json::to_string(&self.local_storage)
+            .map_err(|e| Error::cdp(format!("Failed to encode localStorage: {}", e)))?;
+        let session = serde_json::to_string(&self.session_storage)
+            .map_err(|e| Error::cdp(format!("Failed to encode sessionStorage: {}", e)))?;
+
+        let script = format!(
+            r#"
+            (() => {{
+                const local = {local};
+                const session = {session};
+                for (const [key, value] of Object.entries(local)) {{
+                    window.localStorage.setItem(key, value);
+                }}
+                for (const [key, value] of Object.entries(session)) {{
+                    window.sessionStorage.setItem(key, value);
+                }}
+            }})()
+            "#
+        );
+
+        page.page
+            .evaluate(script)
+            .await
+            .map_err(|e| Error::cdp(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+/// Snapshot of the browser's session state
+///
+/// Produced by [`BrowserController::save_storage_state`] and restored with
+/// [`BrowserController::load_storage_state`]. The whole structure is plain
+/// serde data, so it can be written to disk between runs.
+///
+/// [`BrowserController::save_storage_state`]: crate::browser::BrowserController::save_storage_state
+/// [`BrowserController::load_storage_state`]: crate::browser::BrowserController::load_storage_state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StorageState {
+    /// Cookies captured from the whole browser
+    #[serde(default)]
+    pub cookies: Vec<StorageCookie>,
+    /// Per-origin web storage captures
+    #[serde(default)]
+    pub origins: Vec<OriginStorage>,
+}
+
+impl StorageState {
+    /// True when the state holds no cookies and no origin storage
+    pub fn is_empty(&self) -> bool {
+        self.cookies.is_empty() && self.origins.is_empty()
+    }
+
+    /// Merge an origin capture into the state, first capture per origin wins
+    pub(crate) fn push_origin(&mut self, storage: OriginStorage) {
+        if storage.is_empty() {
+            debug!("Skipping origin with empty storage: {}", storage.origin);
+            return;
+        }
+        if self.origins.iter().any(|o| o.origin == storage.origin) {
+            debug!("Skipping duplicate origin capture: {}", storage.origin);
+            return;
+        }
+        self.origins.push(storage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cdp_cookie(expires: f64, same_site: Option<&str>) -> Cookie {
+        let mut value = json!({
+            "name": "sid",
+            "value": "abc123",
+            "domain": "example.com",
+            "path": "/",
+            "expires": expires,
+            "size": 9,
+            "httpOnly": true,
+            "secure": true,
+            "session": expires < 0.0,
+            "priority": "Medium",
+            "sourceScheme": "Secure",
+            "sourcePort": 443,
+        });
+        if let Some(same_site) = same_site {
+            value["sameSite"] = json!(same_site);
+        }
+        serde_json::from_value(value).expect("valid CDP cookie")
+    }
+
+    #[test]
+    fn test_storage_cookie_round_trip() {
+        let cookie = StorageCookie::from_cookie(&cdp_cookie(1_900_000_000.0, Some("Lax")));
+        assert_eq!(cookie.name, "sid");
+        assert_eq!(cookie.value, "abc123");
+        assert_eq!(cookie.expires, Some(1_900_000_000.0));
+        assert_eq!(cookie.same_site.as_deref(), Some("Lax"));
+
+        let param = cookie.to_cookie_param();
+        assert_eq!(param.domain.as_deref(), Some("example.com"));
+        assert_eq!(param.path.as_deref(), Some("/"));
+        assert!(param.expires.is_some());
+        assert_eq!(param.http_only, Some(true));
+        assert_eq!(param.secure, Some(true));
+        assert_eq!(param.same_site, Some(CookieSameSite::Lax));
+    }
+
+    #[test]
+    fn test_session_cookie_has_no_expiry() {
+        let cookie = StorageCookie::from_cookie(&cdp_cookie(-1.0, None));
+        assert_eq!(cookie.expires, None);
+        assert_eq!(cookie.same_site, None);
+
+        let param = cookie.to_cookie_param();
+        assert!(param.expires.is_none());
+        assert!(param.same_site.is_none());
+    }
+
+    #[test]
+    fn test_origin_storage_from_dump() {
+        let storage = OriginStorage::from_dump(&json!({
+            "origin": "https://example.com",
+            "localStorage": {"token": "t0k3n"},
+            "sessionStorage": {"tab": "1"},
+        }))
+        .expect("valid dump");
+
+        assert_eq!(storage.origin, "https://example.com");
+        assert_eq!(storage.local_storage.get("token").map(String::as_str), Some("t0k3n"));
+        assert_eq!(storage.session_storage.get("tab").map(String::as_str), Some("1"));
+        assert!(!storage.is_empty());
+    }
+
+    #[test]
+    fn test_origin_storage_rejects_null_origin() {
+        // about:blank and sandboxed frames report an opaque origin
+        assert!(OriginStorage::from_dump(&json!({
+            "origin": "null",
+            "localStorage": {},
+            "sessionStorage": {},
+        }))
+        .is_none());
+        assert!(OriginStorage::from_dump(&serde_json::Value::Null).is_none());
+    }
+
+    #[test]
+    fn test_storage_state_deduplicates_origins() {
+        let mut state = StorageState::default();
+        state.push_origin(OriginStorage {
+            origin: "https://example.com".to_string(),
+            local_storage: HashMap::from([("a".to_string(), "1".to_string())]),
+            ..Default::default()
+        });
+        state.push_origin(OriginStorage {
+            origin: "https://example.com".to_string(),
+            local_storage: HashMap::from([("b".to_string(), "2".to_string())]),
+            ..Default::default()
+        });
+        state.push_origin(OriginStorage {
+            origin: "https://other.example".to_string(),
+            ..Default::default()
+        });
+
+        // Second capture of the same origin and the empty origin are dropped
+        assert_eq!(state.origins.len(), 1);
+        assert!(state.origins[0].local_storage.contains_key("a"));
+    }
+
+    #[test]
+    fn test_storage_state_serde_round_trip() {
+        let state = StorageState {
+            cookies: vec![StorageCookie {
+                name: "sid".to_string(),
+                value: "abc".to_string(),
+                domain: "example.com".to_string(),
+                path: "/".to_string(),
+                expires: None,
+                http_only: true,
+                secure: false,
+                same_site: None,
+            }],
+            origins: vec![OriginStorage {
+                origin: "https://example.com".to_string(),
+                local_storage: HashMap::from([("token".to_string(), "t".to_string())]),
+                ..Default::default()
+            }],
+        };
+
+        let json = serde_json::to_string(&state).expect("serializes");
+        let restored: StorageState = serde_json::from_str(&json).expect("deserializes");
+        assert_eq!(restored.cookies, state.cookies);
+        assert_eq!(restored.origins, state.origins);
+    }
+}
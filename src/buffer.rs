@@ -417,7 +417,7 @@ impl CaptureBuffer {
         }
 
         // Sort by timestamp descending
-        result.sort_by(|a, b| b.captured_at.cmp(&a.captured_at));
+        result.sort_by_key(|r| std::cmp::Reverse(r.captured_at));
 
         debug!("Retrieved {} captures since {}", result.len(), timestamp);
         result
@@ -437,7 +437,7 @@ impl CaptureBuffer {
         }
 
         // Sort by timestamp descending
-        result.sort_by(|a, b| b.captured_at.cmp(&a.captured_at));
+        result.sort_by_key(|r| std::cmp::Reverse(r.captured_at));
 
         debug!("Retrieved {} captures for URL {}", result.len(), url);
         result
@@ -478,11 +478,9 @@ impl CaptureBuffer {
             evictions_age: self.stats.evictions_age.load(Ordering::Relaxed),
             gets_by_id: self.stats.gets_by_id.load(Ordering::Relaxed),
             gets_by_id_hits: self.stats.gets_by_id_hits.load(Ordering::Relaxed),
-            avg_processing_time_us: if total_pushed > 0 {
-                total_processing_time / total_pushed
-            } else {
-                0
-            },
+            avg_processing_time_us: total_processing_time
+                .checked_div(total_pushed)
+                .unwrap_or(0),
         }
     }
 
@@ -503,9 +503,40 @@ pub enum BrowserError {
     #[error("Browser already closed")]
     AlreadyClosed,
 
+    /// Stealth setup (cloak step) failed or timed out
+    #[error("Stealth setup failed: {0}")]
+    StealthSetupFailed(String),
+
+    /// Download capture failed
+    #[error("Download failed: {0}")]
+    DownloadFailed(String),
+
+    /// Download exceeded the configured size cap
+    #[error("Download of {actual} bytes exceeds the {max} byte limit")]
+    DownloadTooLarge {
+        /// Reported size of the download in bytes
+        actual: u64,
+        /// Configured maximum in bytes
+        max: u64,
+    },
+
     /// Timeout waiting for browser
     #[error("Browser operation timed out after {0}ms")]
     Timeout(u64),
+
+    /// The connected Chrome rejected a CDP method or its parameters
+    ///
+    /// Indicates a protocol mismatch between chromiumoxide and the Chrome
+    /// build, e.g. a method or parameter this crate uses that the browser
+    /// does not know. Surfaced instead of a silent no-op or a generic CDP
+    /// error so callers can see which feature the browser lacks.
+    #[error("CDP method '{method}' not supported by Chrome {chrome_version}")]
+    ProtocolUnsupported {
+        /// Fully qualified CDP method, e.g. `Page.captureSnapshot`
+        method: String,
+        /// Chrome product string, or `unknown` when not yet known
+        chrome_version: String,
+    },
 }
 
 /// MCP protocol errors
@@ -564,6 +595,10 @@ pub enum ExtractionError {
     #[error("Content parsing failed: {0}")]
     ParsingFailed(String),
 
+    /// Invalid search pattern
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(String),
+
     /// JavaScript execution failed
     #[error("JavaScript execution failed: {0}")]
     JsExecutionFailed(String),
@@ -600,6 +635,14 @@ pub enum NavigationError {
         /// Error message
         message: String,
     },
+
+    /// Page requires authentication (login wall detected)
+    #[error("Authentication required: {0}")]
+    AuthenticationRequired(String),
+
+    /// Main document served a content type outside the configured allowlist
+    #[error("Disallowed content type: {0}")]
+    DisallowedContentType(String),
 }
 
 /// Capture errors (screenshots, PDFs, etc.)
@@ -647,6 +690,38 @@ impl Error {
         Error::Cdp(msg.into())
     }
 
+    /// Detect Chrome rejecting a CDP method or its parameters
+    ///
+    /// Returns the typed [`BrowserError::ProtocolUnsupported`] when
+    /// `message` is a protocol rejection (unknown method, invalid
+    /// parameters); `None` means the failure is ordinary and the caller's
+    /// usual error mapping applies.
+    pub fn protocol_unsupported(
+        method: &str,
+        message: &str,
+        chrome_version: Option<&str>,
+    ) -> Option<Self> {
+        if !Self::is_protocol_rejection(message) {
+            return None;
+        }
+        Some(
+            BrowserError::ProtocolUnsupported {
+                method: method.to_string(),
+                chrome_version: chrome_version.unwrap_or("unknown").to_string(),
+            }
+            .into(),
+        )
+    }
+
+    /// Whether a CDP error message means the browser rejected the method
+    /// or its parameters rather than the operation itself failing
+    pub fn is_protocol_rejection(message: &str) -> bool {
+        message.contains("wasn't found")
+            || message.contains("Method not found")
+            || message.contains("Invalid parameters")
+            || message.contains("unknown command")
+    }
+
     /// Convert internal error to WebError for HTTP responses
     pub fn into_web_error(self) -> WebError {
         match self {
@@ -687,6 +762,7 @@ impl Error {
                         WebError::internal(format!("HTTP {}: {}", status, message))
                     }
                 }
+                NavigationError::AuthenticationRequired(msg) => WebError::unauthorized(msg),
                 _ => WebError::internal(e.to_string()),
             },
             Error::Extraction(e) => match e {
@@ -921,6 +997,44 @@ mod tests {
         assert_eq!(err.to_string(), "something went wrong");
     }
 
+    #[test]
+    fn test_protocol_unsupported_detects_method_rejection() {
+        let err = Error::protocol_unsupported(
+            "Page.captureSnapshot",
+            "'Page.captureSnapshot' wasn't found",
+            Some("Chrome/128.0.6613.84"),
+        )
+        .expect("rejection should be detected");
+
+        let message = err.to_string();
+        assert!(message.contains("Page.captureSnapshot"), "got: {}", message);
+        assert!(message.contains("Chrome/128.0.6613.84"), "got: {}", message);
+        assert!(matches!(
+            err,
+            Error::Browser(BrowserError::ProtocolUnsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_protocol_unsupported_detects_param_rejection() {
+        let err = Error::protocol_unsupported(
+            "Network.setBypassServiceWorker",
+            "Invalid parameters: bypass",
+            None,
+        )
+        .expect("rejection should be detected");
+        assert!(err.to_string().contains("unknown"));
+    }
+
+    #[test]
+    fn test_protocol_unsupported_ignores_ordinary_failures() {
+        assert!(
+            Error::protocol_unsupported("Page.navigate", "net::ERR_CONNECTION_REFUSED", None)
+                .is_none()
+        );
+        assert!(Error::protocol_unsupported("Page.navigate", "Timeout", None).is_none());
+    }
+
     #[test]
     fn test_error_into_web_error() {
         let err = Error::Navigation(NavigationError::InvalidUrl("bad-url".to_string()));
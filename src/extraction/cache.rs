@@ -0,0 +1,186 @@
+//! Extraction output caching
+//!
+//! Mirrors and CDN frontends serve byte-identical HTML under different
+//! URLs; re-running extraction over the same markup is pure waste. This
+//! module hashes normalized HTML and caches extraction output by that hash
+//! plus an operation discriminator, so identical content is processed once
+//! regardless of the URL it came from.
+
+use parking_lot::Mutex;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Default number of cached extraction results
+pub const DEFAULT_EXTRACTION_CACHE_CAPACITY: usize = 64;
+
+/// Hash HTML content for cache keying
+///
+/// Whitespace runs are collapsed first so formatting-only differences
+/// (trailing newlines, indentation re-flows) still hit the cache; anything
+/// beyond that counts as different content.
+pub fn content_hash(html: &str) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for token in html.split_whitespace() {
+        token.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Key for one cached extraction result
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    content_hash: u64,
+    operation: String,
+}
+
+/// Bounded cache of extraction output keyed by content hash
+///
+/// The `operation` discriminator keeps results for different extraction
+/// parameters (selector, format) apart even over identical HTML. Eviction
+/// is insertion-ordered: at capacity, the oldest entry goes first.
+#[derive(Debug)]
+pub struct ExtractionCache {
+    capacity: usize,
+    entries: Mutex<CacheEntries>,
+    hits: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    values: HashMap<CacheKey, Value>,
+    order: VecDeque<CacheKey>,
+}
+
+impl ExtractionCache {
+    /// Create a cache with the default capacity
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_EXTRACTION_CACHE_CAPACITY)
+    }
+
+    /// Create a cache holding at most `capacity` results
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(CacheEntries::default()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up a cached result for a content hash and operation
+    pub fn get(&self, content_hash: u64, operation: &str) -> Option<Value> {
+        let key = CacheKey {
+            content_hash,
+            operation: operation.to_string(),
+        };
+        let value = self.entries.lock().values.get(&key).cloned();
+        if value.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+        value
+    }
+
+    /// Store a result, evicting the oldest entry when at capacity
+    pub fn insert(&self, content_hash: u64, operation: &str, value: Value) {
+        let key = CacheKey {
+            content_hash,
+            operation: operation.to_string(),
+        };
+        let mut entries = self.entries.lock();
+        if entries.values.insert(key.clone(), value).is_none() {
+            entries.order.push_back(key);
+        }
+        while entries.values.len() > self.capacity {
+            let Some(oldest) = entries.order.pop_front() else {
+                break;
+            };
+            entries.values.remove(&oldest);
+        }
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.entries.lock().values.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of lookups answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for ExtractionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_content_hash_identical_input() {
+        assert_eq!(
+            content_hash("<html><body>hi</body></html>"),
+            content_hash("<html><body>hi</body></html>")
+        );
+    }
+
+    #[test]
+    fn test_content_hash_ignores_whitespace_runs() {
+        assert_eq!(
+            content_hash("<p>a</p>\n  <p>b</p>\n"),
+            content_hash("<p>a</p> <p>b</p>")
+        );
+        assert_ne!(content_hash("<p>a</p>"), content_hash("<p>b</p>"));
+    }
+
+    #[test]
+    fn test_cache_runs_extraction_once_for_identical_html() {
+        let cache = ExtractionCache::new();
+        let html = "<html><body>mirrored</body></html>";
+        let mut extraction_runs = 0;
+
+        // Two "URLs" serving byte-identical HTML
+        for _ in 0..2 {
+            let hash = content_hash(html);
+            if cache.get(hash, "content").is_none() {
+                extraction_runs += 1;
+                cache.insert(hash, "content", json!("extracted"));
+            }
+        }
+
+        assert_eq!(extraction_runs, 1);
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[test]
+    fn test_cache_separates_operations() {
+        let cache = ExtractionCache::new();
+        let hash = content_hash("<p>hi</p>");
+
+        cache.insert(hash, "content:markdown", json!("md"));
+        assert!(cache.get(hash, "content:text").is_none());
+        assert_eq!(cache.get(hash, "content:markdown"), Some(json!("md")));
+    }
+
+    #[test]
+    fn test_cache_evicts_oldest_at_capacity() {
+        let cache = ExtractionCache::with_capacity(2);
+        cache.insert(1, "content", json!(1));
+        cache.insert(2, "content", json!(2));
+        cache.insert(3, "content", json!(3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(1, "content").is_none());
+        assert_eq!(cache.get(3, "content"), Some(json!(3)));
+    }
+}
@@ -0,0 +1,308 @@
+//! Page type classification
+//!
+//! This module tags pages with a coarse type (article, product, listing,
+//! login) from signals that are already cheap to collect: JSON-LD `@type`
+//! declarations, semantic elements, microdata, login forms, and pagination.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// Coarse page type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PageClass {
+    /// Long-form content: news article, blog post, documentation page
+    Article,
+    /// A single product or offer page
+    Product,
+    /// A listing of many items: search results, category page, index
+    Listing,
+    /// A login or sign-in page
+    Login,
+    /// No signal matched
+    Unknown,
+}
+
+/// Raw signals collected from the page for classification
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassSignals {
+    /// JSON-LD `@type` values found on the page
+    #[serde(default)]
+    pub json_ld_types: Vec<String>,
+    /// Whether the page contains an `<article>` element
+    #[serde(default)]
+    pub has_article_element: bool,
+    /// Whether product microdata (`itemtype` schema.org/Product) is present
+    #[serde(default)]
+    pub has_product_microdata: bool,
+    /// Number of password inputs on the page
+    #[serde(default)]
+    pub password_field_count: usize,
+    /// Number of `<form>` elements on the page
+    #[serde(default)]
+    pub form_count: usize,
+    /// Whether pagination controls are present
+    #[serde(default)]
+    pub has_pagination: bool,
+    /// Largest run of same-shaped sibling elements in a linked container
+    #[serde(default)]
+    pub repeated_item_count: usize,
+    /// Number of `<p>` elements on the page
+    #[serde(default)]
+    pub paragraph_count: usize,
+}
+
+/// A candidate class with its share of the total score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassCandidate {
+    /// The candidate page class
+    pub class: PageClass,
+    /// Confidence in [0, 1]: this class's score over the total score
+    pub confidence: f64,
+}
+
+/// Classification result: primary class plus ranked candidates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageClassification {
+    /// Highest-scoring class
+    pub primary: PageClass,
+    /// Confidence of the primary class in [0, 1]
+    pub confidence: f64,
+    /// All classes that scored, strongest first; ambiguous pages
+    /// show several entries with comparable confidence
+    pub candidates: Vec<ClassCandidate>,
+}
+
+/// Page type classification functionality
+pub struct PageClassifier;
+
+impl PageClassifier {
+    /// Classify the current page
+    #[instrument(skip(page))]
+    pub async fn classify_page(page: &PageHandle) -> Result<PageClassification> {
+        info!("Classifying page");
+
+        let signals = Self::collect_signals(page).await?;
+        let classification = Self::classify_signals(&signals);
+        debug!(
+            "Page classified as {:?} (confidence {:.2})",
+            classification.primary, classification.confidence
+        );
+        Ok(classification)
+    }
+
+    /// Collect classification signals from the page
+    async fn collect_signals(page: &PageHandle) -> Result<ClassSignals> {
+        let script = r#"
+            (() => {
+                const jsonLdTypes = [];
+                document.querySelectorAll('script[type="application/ld+json"]').forEach(s => {
+                    try {
+                        const data = JSON.parse(s.textContent);
+                        const items = Array.isArray(data) ? data : [data];
+                        items.forEach(d => {
+                            const t = d && d['@type'];
+                            if (typeof t === 'string') {
+                                jsonLdTypes.push(t);
+                            } else if (Array.isArray(t)) {
+                                t.forEach(x => { if (typeof x === 'string') jsonLdTypes.push(x); });
+                            }
+                        });
+                    } catch (e) {}
+                });
+
+                // Largest run of same-shaped siblings inside a linked container,
+                // a proxy for result cards or category entries
+                let repeated = 0;
+                document.querySelectorAll('ul, ol, div, section').forEach(c => {
+                    if (!c.querySelector('a[href]')) return;
+                    const groups = {};
+                    for (const child of c.children) {
+                        const key = child.tagName + '.' + child.className;
+                        groups[key] = (groups[key] || 0) + 1;
+                        if (groups[key] > repeated) repeated = groups[key];
+                    }
+                });
+
+                return {
+                    jsonLdTypes: jsonLdTypes,
+                    hasArticleElement: document.querySelector('article') !== null,
+                    hasProductMicrodata:
+                        document.querySelector('[itemtype*="schema.org/Product"]') !== null,
+                    passwordFieldCount:
+                        document.querySelectorAll('input[type="password"]').length,
+                    formCount: document.querySelectorAll('form').length,
+                    hasPagination: document.querySelector(
+                        'nav[aria-label*="pag" i], .pagination, .pager, a[rel="next"]'
+                    ) !== null,
+                    repeatedItemCount: repeated,
+                    paragraphCount: document.querySelectorAll('p').length
+                };
+            })()
+        "#;
+
+        page.page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()).into())
+    }
+
+    /// Score the collected signals into a ranked classification
+    ///
+    /// Each signal votes for one class; confidence is a class's share of
+    /// the total score, so a page matching only article signals scores
+    /// close to 1.0 while mixed pages surface several candidates.
+    pub fn classify_signals(signals: &ClassSignals) -> PageClassification {
+        let mut article = 0.0;
+        let mut product = 0.0;
+        let mut listing = 0.0;
+        let mut login = 0.0;
+
+        for ld_type in &signals.json_ld_types {
+            let lower = ld_type.to_lowercase();
+            if lower.contains("article") || lower == "blogposting" {
+                article += 3.0;
+            } else if lower == "product" {
+                product += 3.0;
+            } else if lower == "itemlist" || lower == "searchresultspage" {
+                listing += 2.5;
+            }
+        }
+
+        if signals.has_article_element {
+            article += 1.5;
+        }
+        if signals.paragraph_count >= 5 {
+            article += 1.0;
+        }
+
+        if signals.has_product_microdata {
+            product += 2.5;
+        }
+
+        if signals.has_pagination {
+            listing += 1.5;
+        }
+        if signals.repeated_item_count >= 8 {
+            listing += 2.0;
+        } else if signals.repeated_item_count >= 4 {
+            listing += 1.0;
+        }
+
+        if signals.password_field_count > 0 {
+            login += 3.0;
+            // A password form on an otherwise empty page is almost
+            // certainly a dedicated login page
+            if signals.form_count <= 2 && signals.paragraph_count < 3 {
+                login += 1.0;
+            }
+        }
+
+        let scored = [
+            (PageClass::Article, article),
+            (PageClass::Product, product),
+            (PageClass::Listing, listing),
+            (PageClass::Login, login),
+        ];
+        let total: f64 = scored.iter().map(|(_, s)| s).sum();
+
+        if total == 0.0 {
+            return PageClassification {
+                primary: PageClass::Unknown,
+                confidence: 0.0,
+                candidates: Vec::new(),
+            };
+        }
+
+        let mut candidates: Vec<ClassCandidate> = scored
+            .iter()
+            .filter(|(_, score)| *score > 0.0)
+            .map(|(class, score)| ClassCandidate {
+                class: *class,
+                confidence: score / total,
+            })
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        PageClassification {
+            primary: candidates[0].class,
+            confidence: candidates[0].confidence,
+            candidates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_article_jsonld_classifies_as_article() {
+        let signals = ClassSignals {
+            json_ld_types: vec!["Article".to_string()],
+            has_article_element: true,
+            paragraph_count: 12,
+            ..Default::default()
+        };
+
+        let result = PageClassifier::classify_signals(&signals);
+        assert_eq!(result.primary, PageClass::Article);
+        assert!(result.confidence > 0.9, "confidence {}", result.confidence);
+    }
+
+    #[test]
+    fn test_login_form_classifies_as_login() {
+        let signals = ClassSignals {
+            password_field_count: 1,
+            form_count: 1,
+            ..Default::default()
+        };
+
+        let result = PageClassifier::classify_signals(&signals);
+        assert_eq!(result.primary, PageClass::Login);
+    }
+
+    #[test]
+    fn test_ambiguous_page_returns_multiple_candidates() {
+        // Product page with pagination and repeated cards: product should
+        // win, but listing must show up as a candidate
+        let signals = ClassSignals {
+            json_ld_types: vec!["Product".to_string()],
+            has_product_microdata: true,
+            has_pagination: true,
+            repeated_item_count: 6,
+            ..Default::default()
+        };
+
+        let result = PageClassifier::classify_signals(&signals);
+        assert_eq!(result.primary, PageClass::Product);
+        assert!(result.candidates.len() >= 2);
+        assert!(result
+            .candidates
+            .iter()
+            .any(|c| c.class == PageClass::Listing));
+    }
+
+    #[test]
+    fn test_no_signals_is_unknown() {
+        let result = PageClassifier::classify_signals(&ClassSignals::default());
+        assert_eq!(result.primary, PageClass::Unknown);
+        assert_eq!(result.confidence, 0.0);
+        assert!(result.candidates.is_empty());
+    }
+
+    #[test]
+    fn test_page_class_serializes_lowercase() {
+        let json = serde_json::to_string(&PageClass::Listing).unwrap();
+        assert_eq!(json, "\"listing\"");
+    }
+}
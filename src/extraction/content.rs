@@ -8,11 +8,52 @@ use crate::error::{ExtractionError, Result};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, instrument};
 
+/// Default settle delay after scrolling an element into view, in
+/// milliseconds — long enough for typical IntersectionObserver-driven lazy
+/// loading to render
+pub const DEFAULT_SCROLL_SETTLE_MS: u64 = 500;
+
+/// Default reading speed used for the reading-time estimate, in words per
+/// minute — a middle-of-the-road adult silent-reading pace
+pub const DEFAULT_READING_WPM: usize = 225;
+
+/// Selectors identifying comment sections within an article
+///
+/// Covers the common id/class conventions, schema.org markup, and the
+/// mount points of embedded comment widgets (Disqus, giscus, utterances,
+/// Facebook, Isso). Matching subtrees are excluded from `main_text` so
+/// article extraction does not bleed into the thread below it.
+pub const COMMENT_SECTION_SELECTORS: &[&str] = &[
+    "#comments",
+    "#respond",
+    "#disqus_thread",
+    "#isso-thread",
+    ".comments",
+    ".comment-list",
+    ".comments-area",
+    ".comments-section",
+    ".comment-respond",
+    ".fb-comments",
+    ".giscus",
+    ".utterances",
+    "[itemprop=\"comment\"]",
+];
+
 /// Extracted content from a page
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtractedContent {
     /// Plain text content
+    ///
+    /// For [`ContentExtractor::extract_main_content`] this is the full
+    /// visible page text; `main_text` holds the readability-cleaned
+    /// version so one navigation yields both.
     pub text: String,
+    /// Readability-cleaned text of the main content region
+    ///
+    /// Falls back to `text` for extraction paths that are already scoped
+    /// to a single region (selector extraction, provenance extraction).
+    #[serde(default)]
+    pub main_text: String,
     /// Content as markdown (if converted)
     pub markdown: Option<String>,
     /// HTML of the main content
@@ -21,8 +62,108 @@ pub struct ExtractedContent {
     pub word_count: usize,
     /// Character count
     pub char_count: usize,
+    /// Estimated reading time in minutes at [`DEFAULT_READING_WPM`],
+    /// rounded up; recompute via
+    /// [`ContentExtractor::reading_time_minutes`] for a different pace
+    #[serde(default)]
+    pub reading_time_minutes: u64,
     /// Whether content was extracted from article/main element
     pub from_main: bool,
+    /// Provenance of each extracted block (selector paths and offsets),
+    /// present when extraction was run with provenance enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Vec<BlockProvenance>>,
+    /// Anchors within the main content region, present when extraction was
+    /// run with link mapping enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links_in_content: Option<Vec<ContentLink>>,
+    /// Text of detected comment sections, present when the page had any
+    ///
+    /// Always excluded from `main_text`; see [`COMMENT_SECTION_SELECTORS`]
+    /// for what counts as a comment section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+/// Provenance record mapping an extracted text block back to its DOM origin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockProvenance {
+    /// CSS selector path to the originating element
+    pub selector_path: String,
+    /// Start character offset of the block in the extracted text
+    pub start_offset: usize,
+    /// End character offset (exclusive) of the block in the extracted text
+    pub end_offset: usize,
+}
+
+/// One heading in a document outline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutlineEntry {
+    /// Heading level, 1 (`<h1>`) through 6 (`<h6>`)
+    pub level: u8,
+    /// Heading text, trimmed
+    pub text: String,
+    /// Anchor for linking to the heading: the element's existing `id`,
+    /// or a slug derived from the text when it has none
+    pub anchor_id: String,
+}
+
+/// An anchor found within the main content region
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentLink {
+    /// The anchor's visible text
+    pub anchor_text: String,
+    /// The href, resolved to an absolute URL
+    pub url: String,
+    /// Snippet of surrounding text, when the anchor sits in running prose
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Options controlling visible-text extraction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VisibleTextOptions {
+    /// Only include text that is actually rendered (default: true)
+    ///
+    /// Excludes `display:none`, `visibility:hidden`, `aria-hidden="true"`
+    /// subtrees and elements without a layout box (`offsetParent === null`).
+    #[serde(default = "default_true")]
+    pub visible_only: bool,
+    /// Include screen-reader-only text hidden via clip techniques
+    /// (`.sr-only`-style 1x1 clipped elements), default: false
+    #[serde(default)]
+    pub include_sr_only: bool,
+    /// Only include text within the initial viewport height (default: false)
+    ///
+    /// Keeps what is visible on load — the above-the-fold preview — by
+    /// comparing each element's bounding rect against `window.innerHeight`,
+    /// which reflects the emulated viewport when device metrics are
+    /// overridden.
+    #[serde(default)]
+    pub above_fold_only: bool,
+    /// Include `<noscript>` fallback content (default: false)
+    ///
+    /// With scripting enabled the browser never renders `<noscript>`
+    /// children — they sit in the DOM as raw text — so the walker cannot
+    /// see them. This parses each block's inner HTML and appends its text,
+    /// capturing fallback copy and links meant for JS-disabled visitors.
+    #[serde(default)]
+    pub include_noscript: bool,
+}
+
+impl Default for VisibleTextOptions {
+    fn default() -> Self {
+        Self {
+            visible_only: true,
+            include_sr_only: false,
+            above_fold_only: false,
+            include_noscript: false,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
 }
 
 /// Content extraction functionality
@@ -30,17 +171,30 @@ pub struct ContentExtractor;
 
 impl ContentExtractor {
     /// Extract main content from the page
+    ///
+    /// One pass yields both the full visible page text (`text`) and the
+    /// readability-cleaned main region (`main_text`), so callers needing
+    /// raw completeness alongside cleaned content don't re-extract. The
+    /// word and character counts describe `main_text`.
     #[instrument(skip(page))]
     pub async fn extract_main_content(page: &PageHandle) -> Result<ExtractedContent> {
         info!("Extracting main content");
 
         // Try to find the main content using various strategies
-        let (html, from_main) = Self::find_main_content(&page.page).await?;
-        let text = Self::html_to_text(&html);
+        let (html, from_main, full_text, comments) = Self::find_main_content(&page.page).await?;
+        let main_text = Self::html_to_text(&html);
         let markdown = Self::html_to_markdown(&html);
 
-        let word_count = text.split_whitespace().count();
-        let char_count = text.chars().count();
+        // Pages without a body text fall back to the cleaned region so
+        // `text` is never emptier than `main_text`
+        let text = if full_text.trim().is_empty() {
+            main_text.clone()
+        } else {
+            full_text
+        };
+
+        let word_count = main_text.split_whitespace().count();
+        let char_count = main_text.chars().count();
 
         debug!(
             "Extracted {} words, {} chars, from_main={}",
@@ -48,15 +202,179 @@ impl ContentExtractor {
         );
 
         Ok(ExtractedContent {
+            text,
+            main_text,
+            markdown: Some(markdown),
+            html,
+            word_count,
+            char_count,
+            reading_time_minutes: Self::reading_time_minutes(word_count, DEFAULT_READING_WPM),
+            from_main,
+            provenance: None,
+            links_in_content: None,
+            comments: if comments.is_empty() {
+                None
+            } else {
+                Some(comments)
+            },
+        })
+    }
+
+    /// Extract main content with provenance for each text block
+    ///
+    /// In addition to the usual content, returns a [`BlockProvenance`] record
+    /// per block mapping it back to the CSS selector path of its originating
+    /// element and its character offset range in the extracted text. The text
+    /// is built by joining block texts with blank lines so the offsets are
+    /// exact.
+    #[instrument(skip(page))]
+    pub async fn extract_main_content_with_provenance(
+        page: &PageHandle,
+    ) -> Result<ExtractedContent> {
+        info!("Extracting main content with provenance");
+
+        let script = r#"
+            (() => {
+                const cssPath = (el) => {
+                    const parts = [];
+                    while (el && el.nodeType === Node.ELEMENT_NODE) {
+                        if (el.id) {
+                            parts.unshift('#' + el.id);
+                            break;
+                        }
+                        const tag = el.tagName.toLowerCase();
+                        let index = 1;
+                        let sibling = el.previousElementSibling;
+                        while (sibling) {
+                            if (sibling.tagName === el.tagName) index++;
+                            sibling = sibling.previousElementSibling;
+                        }
+                        parts.unshift(tag + ':nth-of-type(' + index + ')');
+                        el = el.parentElement;
+                    }
+                    return parts.join(' > ');
+                };
+
+                const mainSelectors = [
+                    'article', 'main', '[role="main"]', '[role="article"]',
+                    '.article', '.post', '.content', '.entry-content',
+                    '.post-content', '#content', '#main-content', '.main-content'
+                ];
+
+                let root = null;
+                let fromMain = false;
+                for (const selector of mainSelectors) {
+                    const el = document.querySelector(selector);
+                    if (el && el.innerText.length > 200) {
+                        root = el;
+                        fromMain = true;
+                        break;
+                    }
+                }
+                if (!root) root = document.body;
+
+                const blockTags = 'p, h1, h2, h3, h4, h5, h6, li, blockquote, pre, figcaption';
+                const blocks = [];
+                root.querySelectorAll(blockTags).forEach(el => {
+                    // Skip elements nested in another block (e.g. p inside li)
+                    if (el.parentElement && el.parentElement.closest(blockTags)) return;
+                    const text = el.innerText.trim();
+                    if (text) {
+                        blocks.push({ path: cssPath(el), text });
+                    }
+                });
+
+                return { html: root.innerHTML, fromMain, blocks };
+            })()
+        "#;
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let html = result["html"].as_str().unwrap_or("").to_string();
+        let from_main = result["fromMain"].as_bool().unwrap_or(false);
+
+        let blocks: Vec<(String, String)> = result["blocks"]
+            .as_array()
+            .map(|arr| {
+                arr.iter()
+                    .map(|b| {
+                        (
+                            b["path"].as_str().unwrap_or("").to_string(),
+                            b["text"].as_str().unwrap_or("").to_string(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let (text, provenance) = Self::assign_block_offsets(&blocks);
+        let markdown = Self::html_to_markdown(&html);
+        let word_count = text.split_whitespace().count();
+        let char_count = text.chars().count();
+
+        debug!(
+            "Extracted {} blocks with provenance ({} chars)",
+            provenance.len(),
+            char_count
+        );
+
+        Ok(ExtractedContent {
+            main_text: text.clone(),
             text,
             markdown: Some(markdown),
             html,
             word_count,
             char_count,
+            reading_time_minutes: Self::reading_time_minutes(word_count, DEFAULT_READING_WPM),
             from_main,
+            provenance: Some(provenance),
+            links_in_content: None,
+            comments: None,
         })
     }
 
+    /// Join block texts with blank lines and compute per-block offsets
+    ///
+    /// Offsets are character (not byte) positions into the returned text.
+    pub fn assign_block_offsets(blocks: &[(String, String)]) -> (String, Vec<BlockProvenance>) {
+        let mut text = String::new();
+        let mut provenance = Vec::with_capacity(blocks.len());
+        let mut offset = 0usize;
+
+        for (path, block_text) in blocks {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+                offset += 2;
+            }
+            let len = block_text.chars().count();
+            provenance.push(BlockProvenance {
+                selector_path: path.clone(),
+                start_offset: offset,
+                end_offset: offset + len,
+            });
+            text.push_str(block_text);
+            offset += len;
+        }
+
+        (text, provenance)
+    }
+
+    /// Find the provenance record covering a character offset
+    pub fn provenance_at(
+        provenance: &[BlockProvenance],
+        offset: usize,
+    ) -> Option<&BlockProvenance> {
+        provenance
+            .iter()
+            .find(|p| offset >= p.start_offset && offset < p.end_offset)
+    }
+
     /// Extract content from a specific selector
     #[instrument(skip(page))]
     pub async fn extract_from_selector(
@@ -98,23 +416,92 @@ impl ContentExtractor {
         let char_count = text.chars().count();
 
         Ok(ExtractedContent {
+            main_text: text.clone(),
             text,
             markdown: Some(markdown),
             html,
             word_count,
             char_count,
+            reading_time_minutes: Self::reading_time_minutes(word_count, DEFAULT_READING_WPM),
             from_main: false,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
         })
     }
 
-    /// Extract all text from the page body
+    /// Scroll a specific element into view and let lazy content settle
+    ///
+    /// Some sections only render their children once scrolled into view.
+    /// Unlike scrolling the whole page, this brings just the named section
+    /// into the viewport, then waits `settle_ms` for lazy loading to run.
+    /// Errors with [`ExtractionError::ElementNotFound`] when the selector
+    /// matches nothing.
     #[instrument(skip(page))]
-    pub async fn extract_all_text(page: &PageHandle) -> Result<String> {
+    pub async fn scroll_to_selector(
+        page: &PageHandle,
+        selector: &str,
+        settle_ms: u64,
+    ) -> Result<()> {
+        info!("Scrolling to selector: {}", selector);
+
+        let script = format!(
+            r#"
+            (() => {{
+                const el = document.querySelector('{}');
+                if (!el) return false;
+                el.scrollIntoView({{ block: 'center' }});
+                return true;
+            }})()
+            "#,
+            selector.replace('\'', "\\'")
+        );
+
+        let found: bool = page
+            .page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        if !found {
+            return Err(ExtractionError::ElementNotFound(selector.to_string()).into());
+        }
+
+        if settle_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(settle_ms)).await;
+        }
+
+        Ok(())
+    }
+
+    /// Extract the heading outline of the page
+    ///
+    /// Walks `h1`–`h6` in document order. Anchors reuse each heading's
+    /// existing `id`; headings without one get a slug derived from their
+    /// text, deduplicated with numeric suffixes.
+    #[instrument(skip(page))]
+    pub async fn extract_outline(page: &PageHandle) -> Result<Vec<OutlineEntry>> {
+        info!("Extracting heading outline");
+
         let script = r#"
-            document.body.innerText
+            (() => {
+                const headings = [];
+                document.querySelectorAll('h1, h2, h3, h4, h5, h6').forEach(h => {
+                    const text = h.innerText.trim();
+                    if (!text) return;
+                    headings.push({
+                        level: parseInt(h.tagName.substring(1), 10),
+                        text: text,
+                        id: h.getAttribute('id')
+                    });
+                });
+                return headings;
+            })()
         "#;
 
-        let text: String = page
+        let headings: serde_json::Value = page
             .page
             .evaluate(script)
             .await
@@ -122,76 +509,365 @@ impl ContentExtractor {
             .into_value()
             .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
 
-        Ok(text)
+        Ok(Self::outline_from_headings(&headings))
     }
 
-    /// Find the main content element using various strategies
-    async fn find_main_content(page: &chromiumoxide::Page) -> Result<(String, bool)> {
+    /// Build an outline from raw heading entries (`level`, `text`, `id`)
+    pub fn outline_from_headings(headings: &serde_json::Value) -> Vec<OutlineEntry> {
+        let mut outline: Vec<OutlineEntry> = Vec::new();
+        let Some(headings) = headings.as_array() else {
+            return outline;
+        };
+
+        let mut taken: std::collections::HashSet<String> = std::collections::HashSet::new();
+        for heading in headings {
+            let level = match heading["level"].as_u64() {
+                Some(level @ 1..=6) => level as u8,
+                _ => continue,
+            };
+            let Some(text) = heading["text"].as_str().filter(|t| !t.is_empty()) else {
+                continue;
+            };
+
+            // Declared ids are kept verbatim; generated slugs are made
+            // unique so invented anchors stay unambiguous
+            let anchor_id = match heading["id"].as_str().filter(|id| !id.is_empty()) {
+                Some(id) => id.to_string(),
+                None => {
+                    let slug = Self::slugify(text);
+                    let mut candidate = slug.clone();
+                    let mut suffix = 2;
+                    while taken.contains(&candidate) {
+                        candidate = format!("{}-{}", slug, suffix);
+                        suffix += 1;
+                    }
+                    candidate
+                }
+            };
+            taken.insert(anchor_id.clone());
+
+            outline.push(OutlineEntry {
+                level,
+                text: text.to_string(),
+                anchor_id,
+            });
+        }
+
+        outline
+    }
+
+    /// Derive a URL-safe anchor slug from heading text
+    pub fn slugify(text: &str) -> String {
+        let mut slug = String::new();
+        let mut pending_dash = false;
+        for c in text.chars() {
+            if c.is_ascii_alphanumeric() {
+                if pending_dash && !slug.is_empty() {
+                    slug.push('-');
+                }
+                slug.push(c.to_ascii_lowercase());
+                pending_dash = false;
+            } else {
+                pending_dash = true;
+            }
+        }
+        if slug.is_empty() {
+            "section".to_string()
+        } else {
+            slug
+        }
+    }
+
+    /// Estimated reading time in minutes for a word count at a given pace
+    ///
+    /// Rounded up, so any non-empty content takes at least a minute. The
+    /// counts on [`ExtractedContent`] use [`DEFAULT_READING_WPM`]; callers
+    /// with a different audience can recompute from `word_count`.
+    pub fn reading_time_minutes(word_count: usize, wpm: usize) -> u64 {
+        if word_count == 0 || wpm == 0 {
+            return 0;
+        }
+        word_count.div_ceil(wpm) as u64
+    }
+
+    /// Extract anchors within the main content region
+    ///
+    /// Uses the same root detection as main-content extraction, then skips
+    /// anchors inside `nav`/`header`/`footer`/`aside` so chrome links don't
+    /// pollute the mapping. Each anchor comes back with its resolved URL and
+    /// a snippet of the surrounding prose.
+    #[instrument(skip(page))]
+    pub async fn extract_content_links(page: &PageHandle) -> Result<Vec<ContentLink>> {
+        info!("Extracting content links");
+
         let script = r#"
             (() => {
-                // Strategy 1: Look for article or main elements
                 const mainSelectors = [
-                    'article',
-                    'main',
-                    '[role="main"]',
-                    '[role="article"]',
-                    '.article',
-                    '.post',
-                    '.content',
-                    '.entry-content',
-                    '.post-content',
-                    '#content',
-                    '#main-content',
-                    '.main-content'
+                    'article', 'main', '[role="main"]', '[role="article"]',
+                    '.article', '.post', '.content', '.entry-content',
+                    '.post-content', '#content', '#main-content', '.main-content'
                 ];
 
+                let root = null;
                 for (const selector of mainSelectors) {
                     const el = document.querySelector(selector);
                     if (el && el.innerText.length > 200) {
-                        return { html: el.innerHTML, fromMain: true };
+                        root = el;
+                        break;
                     }
                 }
+                if (!root) root = document.body;
+
+                const links = [];
+                root.querySelectorAll('a[href]').forEach(el => {
+                    if (el.closest('nav, header, footer, aside')) return;
+                    const text = el.innerText.trim();
+                    if (!text) return;
+                    const href = el.getAttribute('href') || '';
+                    if (href.startsWith('#') || href.startsWith('mailto:') ||
+                        href.startsWith('tel:') || href.startsWith('javascript:')) return;
+                    let url;
+                    try {
+                        url = new URL(href, window.location.href).href;
+                    } catch (e) {
+                        return;
+                    }
+                    const parent = el.closest('p, li, blockquote, figcaption, td, dd') ||
+                        el.parentElement;
+                    links.push({
+                        anchorText: text,
+                        url,
+                        parentText: parent ? parent.innerText : '',
+                    });
+                });
+                return links;
+            })()
+        "#;
 
-                // Strategy 2: Find the largest text block
-                const textBlocks = [];
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let links = Self::content_links_from_value(&result);
+        debug!("Extracted {} content links", links.len());
+        Ok(links)
+    }
+
+    /// Build content links from raw anchor entries
+    /// (`anchorText`, `url`, `parentText`)
+    pub fn content_links_from_value(value: &serde_json::Value) -> Vec<ContentLink> {
+        let Some(entries) = value.as_array() else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let anchor_text = entry["anchorText"].as_str().filter(|t| !t.is_empty())?;
+                let url = entry["url"].as_str().filter(|u| !u.is_empty())?;
+                let parent_text = entry["parentText"].as_str().unwrap_or("");
+                Some(ContentLink {
+                    anchor_text: anchor_text.to_string(),
+                    url: url.to_string(),
+                    context: Self::link_context(parent_text, anchor_text),
+                })
+            })
+            .collect()
+    }
+
+    /// Snippet of surrounding prose for an anchor, or None when the anchor
+    /// is the whole block
+    ///
+    /// Finds the anchor text inside its block's text and keeps up to 60
+    /// characters on each side, whitespace-normalized, with ellipses where
+    /// the block continues.
+    pub fn link_context(parent_text: &str, anchor_text: &str) -> Option<String> {
+        const WINDOW: usize = 60;
+
+        let parent = Self::normalize_whitespace(parent_text);
+        let anchor = Self::normalize_whitespace(anchor_text);
+        if parent.is_empty() || parent == anchor {
+            return None;
+        }
+        let start = parent.find(&anchor)?;
+        let end = start + anchor.len();
+
+        // Walk char boundaries so the window never splits a code point
+        let from = parent[..start]
+            .char_indices()
+            .rev()
+            .nth(WINDOW.saturating_sub(1))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        let to = parent[end..]
+            .char_indices()
+            .nth(WINDOW)
+            .map(|(i, _)| end + i)
+            .unwrap_or(parent.len());
+
+        let mut snippet = String::new();
+        if from > 0 {
+            snippet.push_str("...");
+        }
+        snippet.push_str(parent[from..to].trim());
+        if to < parent.len() {
+            snippet.push_str("...");
+        }
+        Some(snippet)
+    }
+
+    /// Extract all text from the page body
+    #[instrument(skip(page))]
+    pub async fn extract_all_text(page: &PageHandle) -> Result<String> {
+        let script = r#"
+            document.body.innerText
+        "#;
+
+        let text: String = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        Ok(text)
+    }
+
+    /// Extract page text honoring visibility
+    ///
+    /// With `visible_only` (the default) this walks the DOM with a JS pass
+    /// checking computed style and `offsetParent`, so `display:none` /
+    /// `visibility:hidden` / `aria-hidden` content and off-screen menus are
+    /// excluded. With `visible_only: false` this behaves like
+    /// [`Self::extract_all_text`].
+    #[instrument(skip(page))]
+    pub async fn extract_visible_text(
+        page: &PageHandle,
+        options: &VisibleTextOptions,
+    ) -> Result<String> {
+        if !options.visible_only && !options.above_fold_only && !options.include_noscript {
+            return Self::extract_all_text(page).await;
+        }
+
+        let script = Self::visible_text_script(options);
+
+        let text: String = page
+            .page
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        Ok(text)
+    }
+
+    /// Build the JS used by [`Self::extract_visible_text`]
+    ///
+    /// Exposed so the generated script can be unit tested without a browser.
+    pub fn visible_text_script(options: &VisibleTextOptions) -> String {
+        format!(
+            r#"
+            (() => {{
+                const includeSrOnly = {include_sr_only};
+                const aboveFoldOnly = {above_fold_only};
+                const includeNoscript = {include_noscript};
+                // innerHeight honors device metrics emulation
+                const foldHeight = window.innerHeight;
+
+                const isAboveFold = (el) => {{
+                    if (!aboveFoldOnly) return true;
+                    const rect = el.getBoundingClientRect();
+                    return rect.top < foldHeight && rect.bottom > 0;
+                }};
+
+                const isSrOnly = (el, style) => {{
+                    // Classic .sr-only clip technique: a 1x1 (or smaller)
+                    // box clipped or overflow-hidden off screen
+                    const rect = el.getBoundingClientRect();
+                    if (rect.width > 1 || rect.height > 1) return false;
+                    return style.clip !== 'auto'
+                        || (style.clipPath && style.clipPath !== 'none')
+                        || style.overflow === 'hidden';
+                }};
+
+                const isVisible = (el) => {{
+                    for (let cur = el; cur && cur !== document.body; cur = cur.parentElement) {{
+                        if (cur.getAttribute('aria-hidden') === 'true') return false;
+                        const style = window.getComputedStyle(cur);
+                        if (style.display === 'none') return false;
+                        if (style.visibility === 'hidden' || style.visibility === 'collapse') return false;
+                        if (!includeSrOnly && isSrOnly(cur, style)) return false;
+                    }}
+                    // Elements without a layout box are not rendered
+                    // (position:fixed legitimately has no offsetParent)
+                    const style = window.getComputedStyle(el);
+                    if (el.offsetParent === null && style.position !== 'fixed') return false;
+                    return true;
+                }};
+
+                const parts = [];
                 const walker = document.createTreeWalker(
                     document.body,
-                    NodeFilter.SHOW_ELEMENT,
-                    {
-                        acceptNode: (node) => {
-                            const tag = node.tagName.toLowerCase();
-                            if (['script', 'style', 'nav', 'header', 'footer', 'aside', 'noscript'].includes(tag)) {
+                    NodeFilter.SHOW_TEXT,
+                    {{
+                        acceptNode: (node) => {{
+                            const parent = node.parentElement;
+                            if (!parent) return NodeFilter.FILTER_REJECT;
+                            const tag = parent.tagName.toLowerCase();
+                            if (['script', 'style', 'noscript', 'template'].includes(tag)) {{
                                 return NodeFilter.FILTER_REJECT;
-                            }
-                            return NodeFilter.FILTER_ACCEPT;
-                        }
-                    }
+                            }}
+                            if (!node.textContent.trim()) return NodeFilter.FILTER_REJECT;
+                            return isVisible(parent) && isAboveFold(parent)
+                                ? NodeFilter.FILTER_ACCEPT
+                                : NodeFilter.FILTER_REJECT;
+                        }}
+                    }}
                 );
 
                 let node;
-                while (node = walker.nextNode()) {
-                    const text = node.innerText || '';
-                    if (text.length > 200) {
-                        textBlocks.push({
-                            el: node,
-                            length: text.length
-                        });
-                    }
-                }
-
-                if (textBlocks.length > 0) {
-                    // Sort by length and get the longest
-                    textBlocks.sort((a, b) => b.length - a.length);
-                    return { html: textBlocks[0].el.innerHTML, fromMain: false };
-                }
+                while (node = walker.nextNode()) {{
+                    parts.push(node.textContent.trim());
+                }}
+
+                if (includeNoscript) {{
+                    document.querySelectorAll('noscript').forEach(nos => {{
+                        // With scripting on, noscript children are raw
+                        // text; parse them so fallback markup contributes
+                        // its text rather than literal tags
+                        const doc = new DOMParser().parseFromString(nos.textContent, 'text/html');
+                        const text = doc.body ? doc.body.textContent.trim() : '';
+                        if (text) parts.push(text);
+                    }});
+                }}
+
+                return parts.join('\n');
+            }})()
+            "#,
+            include_sr_only = options.include_sr_only,
+            above_fold_only = options.above_fold_only,
+            include_noscript = options.include_noscript
+        )
+    }
 
-                // Fallback: return body
-                return { html: document.body.innerHTML, fromMain: false };
-            })()
-        "#;
+    /// Find the main content element using various strategies
+    ///
+    /// Also returns the full visible body text so a single evaluation
+    /// serves both the raw and cleaned views, plus the text of any comment
+    /// sections, which are stripped from the returned HTML.
+    async fn find_main_content(
+        page: &chromiumoxide::Page,
+    ) -> Result<(String, bool, String, String)> {
+        let script = Self::find_main_content_script();
 
         let result: serde_json::Value = page
-            .evaluate(script)
+            .evaluate(script.as_str())
             .await
             .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
             .into_value()
@@ -199,8 +875,111 @@ impl ContentExtractor {
 
         let html = result["html"].as_str().unwrap_or("").to_string();
         let from_main = result["fromMain"].as_bool().unwrap_or(false);
+        let full_text = result["fullText"].as_str().unwrap_or("").to_string();
+        let comments = result["commentsText"].as_str().unwrap_or("").to_string();
+
+        Ok((html, from_main, full_text, comments))
+    }
+
+    /// Build the JS used by [`Self::find_main_content`]
+    ///
+    /// Exposed so the generated script can be unit tested without a browser.
+    pub fn find_main_content_script() -> String {
+        let comment_selectors =
+            serde_json::to_string(COMMENT_SECTION_SELECTORS).unwrap_or_else(|_| "[]".to_string());
+        format!(
+            r#"
+            (() => {{
+                const fullText = document.body ? document.body.innerText : '';
 
-        Ok((html, from_main))
+                // Strategy 1: Look for article or main elements
+                const mainSelectors = [
+                    'article',
+                    'main',
+                    '[role="main"]',
+                    '[role="article"]',
+                    '.article',
+                    '.post',
+                    '.content',
+                    '.entry-content',
+                    '.post-content',
+                    '#content',
+                    '#main-content',
+                    '.main-content'
+                ];
+
+                let root = null;
+                let fromMain = false;
+                for (const selector of mainSelectors) {{
+                    const el = document.querySelector(selector);
+                    if (el && el.innerText.length > 200) {{
+                        root = el;
+                        fromMain = true;
+                        break;
+                    }}
+                }}
+
+                // Strategy 2: Find the largest text block
+                if (!root) {{
+                    const textBlocks = [];
+                    const walker = document.createTreeWalker(
+                        document.body,
+                        NodeFilter.SHOW_ELEMENT,
+                        {{
+                            acceptNode: (node) => {{
+                                const tag = node.tagName.toLowerCase();
+                                if (['script', 'style', 'nav', 'header', 'footer', 'aside', 'noscript'].includes(tag)) {{
+                                    return NodeFilter.FILTER_REJECT;
+                                }}
+                                return NodeFilter.FILTER_ACCEPT;
+                            }}
+                        }}
+                    );
+
+                    let node;
+                    while (node = walker.nextNode()) {{
+                        const text = node.innerText || '';
+                        if (text.length > 200) {{
+                            textBlocks.push({{
+                                el: node,
+                                length: text.length
+                            }});
+                        }}
+                    }}
+
+                    if (textBlocks.length > 0) {{
+                        // Sort by length and get the longest
+                        textBlocks.sort((a, b) => b.length - a.length);
+                        root = textBlocks[0].el;
+                    }}
+                }}
+
+                // Fallback: use body
+                if (!root) root = document.body;
+
+                // Collect comment-section text from the live (rendered)
+                // root, then strip those subtrees from a clone so the
+                // returned HTML stays clean
+                const commentSel = {comment_selectors}.join(', ');
+                const commentParts = [];
+                root.querySelectorAll(commentSel).forEach(el => {{
+                    // Skip sections nested in another comment section
+                    if (el.parentElement && el.parentElement.closest(commentSel)) return;
+                    const text = el.innerText.trim();
+                    if (text) commentParts.push(text);
+                }});
+                const clone = root.cloneNode(true);
+                clone.querySelectorAll(commentSel).forEach(el => el.remove());
+
+                return {{
+                    html: clone.innerHTML,
+                    fromMain,
+                    fullText,
+                    commentsText: commentParts.join('\n\n')
+                }};
+            }})()
+            "#
+        )
     }
 
     /// Convert HTML to plain text
@@ -504,6 +1283,136 @@ mod tests {
         assert!(text.contains("Middle"));
     }
 
+    // ========================================================================
+    // Outline Tests
+    // ========================================================================
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(ContentExtractor::slugify("Getting Started"), "getting-started");
+        assert_eq!(
+            ContentExtractor::slugify("  FAQ: What's New?  "),
+            "faq-what-s-new"
+        );
+        assert_eq!(ContentExtractor::slugify("1.2 Install"), "1-2-install");
+        assert_eq!(ContentExtractor::slugify("!!!"), "section");
+    }
+
+    #[test]
+    fn test_outline_from_headings_levels_and_anchors() {
+        let headings = serde_json::json!([
+            { "level": 1, "text": "Guide", "id": null },
+            { "level": 2, "text": "Getting Started", "id": "start" },
+            { "level": 3, "text": "Install", "id": null },
+        ]);
+
+        let outline = ContentExtractor::outline_from_headings(&headings);
+        assert_eq!(outline.len(), 3);
+        assert_eq!(outline[0].level, 1);
+        assert_eq!(outline[0].anchor_id, "guide");
+        // Declared ids are kept as-is
+        assert_eq!(outline[1].anchor_id, "start");
+        assert_eq!(outline[2].level, 3);
+        assert_eq!(outline[2].anchor_id, "install");
+    }
+
+    #[test]
+    fn test_outline_from_headings_deduplicates_generated_slugs() {
+        let headings = serde_json::json!([
+            { "level": 2, "text": "Example", "id": null },
+            { "level": 2, "text": "Example", "id": null },
+            { "level": 2, "text": "Example", "id": null },
+        ]);
+
+        let outline = ContentExtractor::outline_from_headings(&headings);
+        assert_eq!(outline[0].anchor_id, "example");
+        assert_eq!(outline[1].anchor_id, "example-2");
+        assert_eq!(outline[2].anchor_id, "example-3");
+    }
+
+    #[test]
+    fn test_outline_from_headings_skips_invalid_entries() {
+        let headings = serde_json::json!([
+            { "level": 0, "text": "Bad level", "id": null },
+            { "level": 7, "text": "Bad level", "id": null },
+            { "level": 2, "text": "", "id": null },
+            { "level": 2, "text": "Kept", "id": null },
+        ]);
+
+        let outline = ContentExtractor::outline_from_headings(&headings);
+        assert_eq!(outline.len(), 1);
+        assert_eq!(outline[0].text, "Kept");
+    }
+
+    // ========================================================================
+    // Reading Time Tests
+    // ========================================================================
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        assert_eq!(ContentExtractor::reading_time_minutes(225, 225), 1);
+        assert_eq!(ContentExtractor::reading_time_minutes(226, 225), 2);
+        assert_eq!(ContentExtractor::reading_time_minutes(450, 225), 2);
+        // Short content still takes at least a minute
+        assert_eq!(ContentExtractor::reading_time_minutes(10, 225), 1);
+    }
+
+    #[test]
+    fn test_reading_time_degenerate_inputs() {
+        assert_eq!(ContentExtractor::reading_time_minutes(0, 225), 0);
+        assert_eq!(ContentExtractor::reading_time_minutes(100, 0), 0);
+    }
+
+    // ========================================================================
+    // Content Link Tests
+    // ========================================================================
+
+    #[test]
+    fn test_content_links_from_value() {
+        let entries = serde_json::json!([
+            {
+                "anchorText": "the spec",
+                "url": "https://example.com/spec",
+                "parentText": "Details are in the spec for reference.",
+            },
+            { "anchorText": "", "url": "https://example.com/skip", "parentText": "" },
+        ]);
+
+        let links = ContentExtractor::content_links_from_value(&entries);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].anchor_text, "the spec");
+        assert_eq!(links[0].url, "https://example.com/spec");
+        assert_eq!(
+            links[0].context.as_deref(),
+            Some("Details are in the spec for reference.")
+        );
+    }
+
+    #[test]
+    fn test_link_context_none_when_anchor_is_whole_block() {
+        assert_eq!(ContentExtractor::link_context("Read more", "Read more"), None);
+        assert_eq!(ContentExtractor::link_context("", "Read more"), None);
+    }
+
+    #[test]
+    fn test_link_context_truncates_long_blocks() {
+        let padding = "word ".repeat(40);
+        let parent = format!("{}see the docs {}", padding, padding);
+        let context = ContentExtractor::link_context(&parent, "the docs").unwrap();
+
+        assert!(context.starts_with("..."));
+        assert!(context.ends_with("..."));
+        assert!(context.contains("see the docs"));
+        assert!(context.chars().count() < parent.chars().count());
+    }
+
+    #[test]
+    fn test_link_context_normalizes_whitespace() {
+        let context =
+            ContentExtractor::link_context("See\n  the   guide\nfor setup.", "the guide").unwrap();
+        assert_eq!(context, "See the guide for setup.");
+    }
+
     // ========================================================================
     // Whitespace Normalization Tests
     // ========================================================================
@@ -689,11 +1598,16 @@ mod tests {
     fn test_extracted_content_structure() {
         let content = ExtractedContent {
             text: "Hello world".to_string(),
+            main_text: "Hello world".to_string(),
             markdown: Some("Hello world".to_string()),
             html: "<p>Hello world</p>".to_string(),
             word_count: 2,
             char_count: 11,
+            reading_time_minutes: 1,
             from_main: true,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
         };
         assert_eq!(content.word_count, 2);
         assert!(content.from_main);
@@ -703,11 +1617,16 @@ mod tests {
     fn test_extracted_content_serialization() {
         let content = ExtractedContent {
             text: "Hello".to_string(),
+            main_text: "Hello".to_string(),
             markdown: Some("Hello".to_string()),
             html: "<p>Hello</p>".to_string(),
             word_count: 1,
             char_count: 5,
+            reading_time_minutes: 1,
             from_main: false,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
         };
 
         let json = serde_json::to_string(&content).unwrap();
@@ -720,21 +1639,243 @@ mod tests {
         assert_eq!(deserialized.word_count, 1);
     }
 
+    #[test]
+    fn test_find_main_content_script_embeds_comment_selectors() {
+        let script = ContentExtractor::find_main_content_script();
+        for selector in COMMENT_SECTION_SELECTORS {
+            assert!(
+                script.contains(&format!("{:?}", selector)),
+                "missing {}",
+                selector
+            );
+        }
+        // Comment subtrees are removed from a clone, not the live DOM
+        assert!(script.contains("cloneNode(true)"));
+    }
+
+    #[test]
+    fn test_comments_omitted_from_serialization_when_none() {
+        let content = ExtractedContent {
+            text: "Hello".to_string(),
+            main_text: "Hello".to_string(),
+            markdown: None,
+            html: String::new(),
+            word_count: 1,
+            char_count: 5,
+            reading_time_minutes: 1,
+            from_main: false,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
+        };
+
+        let json = serde_json::to_string(&content).unwrap();
+        assert!(!json.contains("comments"));
+    }
+
+    #[test]
+    fn test_extracted_content_main_text_defaults_when_absent() {
+        // Payloads from before main_text existed still deserialize
+        let json = r#"{
+            "text": "Hello",
+            "markdown": null,
+            "html": "<p>Hello</p>",
+            "word_count": 1,
+            "char_count": 5,
+            "from_main": false
+        }"#;
+        let content: ExtractedContent = serde_json::from_str(json).unwrap();
+        assert_eq!(content.main_text, "");
+    }
+
     #[test]
     fn test_extracted_content_empty() {
         let content = ExtractedContent {
             text: String::new(),
+            main_text: String::new(),
             markdown: None,
             html: String::new(),
             word_count: 0,
             char_count: 0,
+            reading_time_minutes: 1,
             from_main: false,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
         };
         assert_eq!(content.word_count, 0);
         assert_eq!(content.char_count, 0);
         assert!(content.markdown.is_none());
     }
 
+    // ========================================================================
+    // Provenance Tests
+    // ========================================================================
+
+    #[test]
+    fn test_assign_block_offsets() {
+        let blocks = vec![
+            ("#main > h1:nth-of-type(1)".to_string(), "Title".to_string()),
+            ("#main > p:nth-of-type(1)".to_string(), "First paragraph.".to_string()),
+            ("#main > p:nth-of-type(2)".to_string(), "Second paragraph.".to_string()),
+        ];
+
+        let (text, provenance) = ContentExtractor::assign_block_offsets(&blocks);
+
+        assert_eq!(text, "Title\n\nFirst paragraph.\n\nSecond paragraph.");
+        assert_eq!(provenance.len(), 3);
+        assert_eq!(provenance[0].start_offset, 0);
+        assert_eq!(provenance[0].end_offset, 5);
+        assert_eq!(provenance[1].start_offset, 7);
+        assert_eq!(provenance[1].end_offset, 23);
+        assert_eq!(provenance[2].start_offset, 25);
+        assert_eq!(provenance[2].end_offset, 42);
+    }
+
+    #[test]
+    fn test_provenance_maps_sentence_to_selector() {
+        // Fixture: a structured document where a known sentence lives in the
+        // second paragraph
+        let blocks = vec![
+            ("#main > h1:nth-of-type(1)".to_string(), "Report".to_string()),
+            (
+                "#main > p:nth-of-type(1)".to_string(),
+                "Introductory remarks.".to_string(),
+            ),
+            (
+                "#main > p:nth-of-type(2)".to_string(),
+                "The known sentence appears here.".to_string(),
+            ),
+        ];
+
+        let (text, provenance) = ContentExtractor::assign_block_offsets(&blocks);
+
+        let needle = "The known sentence";
+        let offset = text.find(needle).unwrap();
+        let record = ContentExtractor::provenance_at(&provenance, offset).unwrap();
+        assert_eq!(record.selector_path, "#main > p:nth-of-type(2)");
+    }
+
+    #[test]
+    fn test_assign_block_offsets_unicode() {
+        let blocks = vec![
+            ("p:nth-of-type(1)".to_string(), "héllo".to_string()),
+            ("p:nth-of-type(2)".to_string(), "wörld".to_string()),
+        ];
+
+        let (text, provenance) = ContentExtractor::assign_block_offsets(&blocks);
+
+        // Offsets are character positions, not bytes
+        assert_eq!(provenance[0].end_offset, 5);
+        assert_eq!(provenance[1].start_offset, 7);
+        assert_eq!(text.chars().count(), 12);
+    }
+
+    #[test]
+    fn test_provenance_at_out_of_range() {
+        let (_, provenance) = ContentExtractor::assign_block_offsets(&[(
+            "p:nth-of-type(1)".to_string(),
+            "short".to_string(),
+        )]);
+        assert!(ContentExtractor::provenance_at(&provenance, 100).is_none());
+    }
+
+    #[test]
+    fn test_provenance_omitted_from_serialization_when_none() {
+        let content = ExtractedContent {
+            text: "Hello".to_string(),
+            main_text: "Hello".to_string(),
+            markdown: None,
+            html: String::new(),
+            word_count: 1,
+            char_count: 5,
+            reading_time_minutes: 1,
+            from_main: false,
+            provenance: None,
+            links_in_content: None,
+            comments: None,
+        };
+
+        let json = serde_json::to_string(&content).unwrap();
+        assert!(!json.contains("provenance"));
+    }
+
+    // ========================================================================
+    // Visible Text Options Tests
+    // ========================================================================
+
+    #[test]
+    fn test_visible_text_options_default() {
+        let opts = VisibleTextOptions::default();
+        assert!(opts.visible_only);
+        assert!(!opts.include_sr_only);
+        assert!(!opts.above_fold_only);
+        assert!(!opts.include_noscript);
+    }
+
+    #[test]
+    fn test_visible_text_options_deserialize_defaults() {
+        let opts: VisibleTextOptions = serde_json::from_str("{}").unwrap();
+        assert!(opts.visible_only);
+        assert!(!opts.include_sr_only);
+
+        let opts: VisibleTextOptions =
+            serde_json::from_str(r#"{"visible_only": false}"#).unwrap();
+        assert!(!opts.visible_only);
+    }
+
+    #[test]
+    fn test_visible_text_script_checks_hidden_styles() {
+        let script = ContentExtractor::visible_text_script(&VisibleTextOptions::default());
+        assert!(script.contains("style.display === 'none'"));
+        assert!(script.contains("style.visibility === 'hidden'"));
+        assert!(script.contains("aria-hidden"));
+        assert!(script.contains("offsetParent"));
+    }
+
+    #[test]
+    fn test_visible_text_script_sr_only_flag() {
+        let default_script =
+            ContentExtractor::visible_text_script(&VisibleTextOptions::default());
+        assert!(default_script.contains("const includeSrOnly = false;"));
+
+        let with_sr = ContentExtractor::visible_text_script(&VisibleTextOptions {
+            include_sr_only: true,
+            ..Default::default()
+        });
+        assert!(with_sr.contains("const includeSrOnly = true;"));
+    }
+
+    #[test]
+    fn test_visible_text_script_above_fold_flag() {
+        let default_script =
+            ContentExtractor::visible_text_script(&VisibleTextOptions::default());
+        assert!(default_script.contains("const aboveFoldOnly = false;"));
+
+        let above_fold = ContentExtractor::visible_text_script(&VisibleTextOptions {
+            above_fold_only: true,
+            ..Default::default()
+        });
+        assert!(above_fold.contains("const aboveFoldOnly = true;"));
+        assert!(above_fold.contains("window.innerHeight"));
+        assert!(above_fold.contains("getBoundingClientRect"));
+    }
+
+    #[test]
+    fn test_visible_text_script_noscript_flag() {
+        let default_script =
+            ContentExtractor::visible_text_script(&VisibleTextOptions::default());
+        assert!(default_script.contains("const includeNoscript = false;"));
+
+        let with_noscript = ContentExtractor::visible_text_script(&VisibleTextOptions {
+            include_noscript: true,
+            ..Default::default()
+        });
+        assert!(with_noscript.contains("const includeNoscript = true;"));
+        // Fallback markup is parsed, not dumped as literal tags
+        assert!(with_noscript.contains("DOMParser"));
+    }
+
     // ========================================================================
     // Edge Cases Tests
     // ========================================================================
@@ -0,0 +1,351 @@
+//! Date and time extraction
+//!
+//! Pages express dates in many human formats; agents usually want machine
+//! timestamps. This module collects `<time datetime=...>` elements and,
+//! optionally, visible date strings matched heuristically, normalizing each
+//! into ISO 8601. Values that are ambiguous (like `01/02/2020`) or
+//! unparseable keep a `None` iso rather than guessing.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// A date or time found on the page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractedDate {
+    /// Text as it appears on the page
+    pub text: String,
+    /// ISO 8601 normalization, or `None` when the value is ambiguous or
+    /// could not be parsed
+    pub iso: Option<String>,
+    /// CSS selector path of the element containing the date
+    pub selector: String,
+}
+
+/// Options for date extraction
+#[derive(Debug, Clone, Default)]
+pub struct DateOptions {
+    /// Also scan visible text for date-like strings (`Jan 2, 2020`,
+    /// `2020-01-02`, ...); off by default since `<time>` elements are the
+    /// reliable source
+    pub include_text_dates: bool,
+}
+
+/// Date extraction functionality
+pub struct DateExtractor;
+
+impl DateExtractor {
+    /// Extract dates from the page
+    ///
+    /// `<time>` elements are always collected, preferring their `datetime`
+    /// attribute over the visible text for normalization. With
+    /// [`DateOptions::include_text_dates`] set, text nodes are additionally
+    /// scanned for common date patterns.
+    #[instrument(skip(page, options))]
+    pub async fn extract(page: &PageHandle, options: &DateOptions) -> Result<Vec<ExtractedDate>> {
+        info!(
+            "Extracting dates (include_text_dates={})",
+            options.include_text_dates
+        );
+
+        let script = format!(
+            r#"
+            (() => {{
+                const includeText = {include_text};
+                const cssPath = (el) => {{
+                    const parts = [];
+                    while (el && el.nodeType === Node.ELEMENT_NODE) {{
+                        if (el.id) {{
+                            parts.unshift('#' + el.id);
+                            break;
+                        }}
+                        const tag = el.tagName.toLowerCase();
+                        let index = 1;
+                        let sibling = el.previousElementSibling;
+                        while (sibling) {{
+                            if (sibling.tagName === el.tagName) index++;
+                            sibling = sibling.previousElementSibling;
+                        }}
+                        parts.unshift(tag + ':nth-of-type(' + index + ')');
+                        el = el.parentElement;
+                    }}
+                    return parts.join(' > ');
+                }};
+
+                const entries = [];
+                document.querySelectorAll('time').forEach(el => {{
+                    entries.push({{
+                        text: el.innerText.trim(),
+                        datetime: el.getAttribute('datetime'),
+                        selector: cssPath(el),
+                    }});
+                }});
+
+                if (includeText) {{
+                    const patterns = [
+                        /\b\d{{4}}-\d{{2}}-\d{{2}}(?:[T ]\d{{2}}:\d{{2}}(?::\d{{2}})?(?:Z|[+-]\d{{2}}:?\d{{2}})?)?\b/g,
+                        /\b(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]* \d{{1,2}},? \d{{4}}\b/gi,
+                        /\b\d{{1,2}} (?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]* \d{{1,2}},? ?\d{{4}}\b/gi,
+                        /\b\d{{1,2}} (?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]* \d{{4}}\b/gi,
+                        /\b\d{{1,2}}\/\d{{1,2}}\/\d{{4}}\b/g,
+                    ];
+                    const walker = document.createTreeWalker(
+                        document.body, NodeFilter.SHOW_TEXT
+                    );
+                    let node;
+                    while ((node = walker.nextNode())) {{
+                        const parent = node.parentElement;
+                        if (!parent) continue;
+                        // <time> contents were already collected above
+                        if (parent.closest('time')) continue;
+                        const tag = parent.tagName;
+                        if (tag === 'SCRIPT' || tag === 'STYLE') continue;
+                        for (const pattern of patterns) {{
+                            for (const match of node.textContent.matchAll(pattern)) {{
+                                entries.push({{
+                                    text: match[0],
+                                    datetime: null,
+                                    selector: cssPath(parent),
+                                }});
+                            }}
+                        }}
+                    }}
+                }}
+
+                return entries;
+            }})()
+            "#,
+            include_text = options.include_text_dates
+        );
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let dates = Self::dates_from_entries(&result);
+        debug!("Extracted {} dates", dates.len());
+        Ok(dates)
+    }
+
+    /// Build extracted dates from the JSON produced by the page script
+    ///
+    /// The `datetime` attribute is the preferred source; when it does not
+    /// normalize (or is absent), the visible text is tried instead.
+    pub fn dates_from_entries(entries: &serde_json::Value) -> Vec<ExtractedDate> {
+        let Some(entries) = entries.as_array() else {
+            return Vec::new();
+        };
+
+        let mut dates = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        for entry in entries {
+            let text = entry
+                .get("text")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let selector = entry
+                .get("selector")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let datetime = entry.get("datetime").and_then(|v| v.as_str());
+
+            if text.is_empty() && datetime.is_none() {
+                continue;
+            }
+            if !seen.insert((text.clone(), selector.clone())) {
+                continue;
+            }
+
+            let iso = datetime
+                .and_then(Self::normalize)
+                .or_else(|| Self::normalize(&text));
+            dates.push(ExtractedDate {
+                text,
+                iso,
+                selector,
+            });
+        }
+        dates
+    }
+
+    /// Normalize a date string into ISO 8601
+    ///
+    /// Handles RFC 3339 timestamps, naive date-times, bare dates, and common
+    /// English forms like `Jan 2, 2020` or `2 January 2020`. Slash dates are
+    /// only accepted when one component exceeds 12 and thereby settles the
+    /// day/month order; ambiguous or unrecognized input yields `None`.
+    pub fn normalize(raw: &str) -> Option<String> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return None;
+        }
+
+        if let Ok(dt) = DateTime::parse_from_rfc3339(raw) {
+            return Some(dt.to_rfc3339());
+        }
+
+        for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M"] {
+            if let Ok(dt) = NaiveDateTime::parse_from_str(raw, format) {
+                return Some(dt.format("%Y-%m-%dT%H:%M:%S").to_string());
+            }
+        }
+
+        for format in [
+            "%Y-%m-%d",
+            "%b %d, %Y",
+            "%b %d %Y",
+            "%d %b %Y",
+            "%B %d, %Y",
+            "%B %d %Y",
+            "%d %B %Y",
+        ] {
+            if let Ok(date) = NaiveDate::parse_from_str(raw, format) {
+                return Some(date.format("%Y-%m-%d").to_string());
+            }
+        }
+
+        Self::normalize_slash_date(raw)
+    }
+
+    /// Normalize `a/b/yyyy` only when the day/month order is unambiguous
+    fn normalize_slash_date(raw: &str) -> Option<String> {
+        let parts: Vec<&str> = raw.split('/').collect();
+        if parts.len() != 3 || parts[2].len() != 4 {
+            return None;
+        }
+        let first: u32 = parts[0].parse().ok()?;
+        let second: u32 = parts[1].parse().ok()?;
+        let year: i32 = parts[2].parse().ok()?;
+
+        // Both components could be a month: the order is a locale guess,
+        // which is exactly what we refuse to make
+        let (month, day) = if first > 12 && second <= 12 {
+            (second, first)
+        } else if second > 12 && first <= 12 {
+            (first, second)
+        } else {
+            return None;
+        };
+
+        NaiveDate::from_ymd_opt(year, month, day).map(|d| d.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_rfc3339_passthrough() {
+        assert_eq!(
+            DateExtractor::normalize("2020-01-02T15:04:05Z"),
+            Some("2020-01-02T15:04:05+00:00".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("2020-01-02T15:04:05+02:00"),
+            Some("2020-01-02T15:04:05+02:00".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_naive_forms() {
+        assert_eq!(
+            DateExtractor::normalize("2020-01-02"),
+            Some("2020-01-02".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("2020-01-02T15:04"),
+            Some("2020-01-02T15:04:00".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("2020-01-02 15:04:05"),
+            Some("2020-01-02T15:04:05".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_english_dates() {
+        assert_eq!(
+            DateExtractor::normalize("Jan 2, 2020"),
+            Some("2020-01-02".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("January 2, 2020"),
+            Some("2020-01-02".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("2 January 2020"),
+            Some("2020-01-02".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_refuses_ambiguous_slash_date() {
+        // Could be Jan 2 or Feb 1 depending on locale
+        assert_eq!(DateExtractor::normalize("01/02/2020"), None);
+        // One component over 12 settles the order
+        assert_eq!(
+            DateExtractor::normalize("13/02/2020"),
+            Some("2020-02-13".to_string())
+        );
+        assert_eq!(
+            DateExtractor::normalize("02/13/2020"),
+            Some("2020-02-13".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_rejects_garbage() {
+        assert_eq!(DateExtractor::normalize(""), None);
+        assert_eq!(DateExtractor::normalize("not a date"), None);
+        assert_eq!(DateExtractor::normalize("99/99/2020"), None);
+        assert_eq!(DateExtractor::normalize("PT2H30M"), None);
+    }
+
+    #[test]
+    fn test_dates_from_entries_prefers_datetime_attribute() {
+        let dates = DateExtractor::dates_from_entries(&json!([
+            {
+                "text": "yesterday",
+                "datetime": "2020-01-02T15:00:00Z",
+                "selector": "time:nth-of-type(1)",
+            },
+            {
+                "text": "Jan 2, 2020",
+                "datetime": null,
+                "selector": "p:nth-of-type(1)",
+            },
+            {
+                "text": "sometime soon",
+                "datetime": "invalid",
+                "selector": "time:nth-of-type(2)",
+            },
+        ]));
+
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates[0].iso.as_deref(), Some("2020-01-02T15:00:00+00:00"));
+        assert_eq!(dates[1].iso.as_deref(), Some("2020-01-02"));
+        // Neither the attribute nor the text parses: iso stays unset
+        assert_eq!(dates[2].iso, None);
+        assert_eq!(dates[2].text, "sometime soon");
+    }
+
+    #[test]
+    fn test_dates_from_entries_deduplicates() {
+        let dates = DateExtractor::dates_from_entries(&json!([
+            {"text": "Jan 2, 2020", "datetime": null, "selector": "p:nth-of-type(1)"},
+            {"text": "Jan 2, 2020", "datetime": null, "selector": "p:nth-of-type(1)"},
+            {"text": "Jan 2, 2020", "datetime": null, "selector": "p:nth-of-type(2)"},
+        ]));
+        assert_eq!(dates.len(), 2);
+    }
+}
@@ -0,0 +1,246 @@
+//! DOM tree extraction
+//!
+//! Some agents want the DOM as a structured tree for programmatic traversal
+//! rather than an HTML string to re-parse. This module serializes elements
+//! into nested `{ tag, attrs, text, children }` nodes, excluding script and
+//! style elements, bounded by depth and node count so huge documents cannot
+//! blow up the response.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info, instrument};
+
+/// A single element in the extracted DOM tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomNode {
+    /// Lowercased tag name
+    pub tag: String,
+    /// Attribute name/value pairs
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub attrs: HashMap<String, String>,
+    /// Direct text content (child text nodes, trimmed), if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Child elements in document order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<DomNode>,
+}
+
+impl DomNode {
+    /// Total number of nodes in this subtree, including this one
+    pub fn node_count(&self) -> usize {
+        1 + self.children.iter().map(DomNode::node_count).sum::<usize>()
+    }
+}
+
+/// An extracted DOM tree with its truncation status
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DomTree {
+    /// Root of the tree
+    pub tree: DomNode,
+    /// True when depth or node-count bounds cut the tree short
+    pub truncated: bool,
+    /// Number of nodes actually serialized
+    pub node_count: usize,
+}
+
+/// Bounds for DOM tree extraction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomTreeOptions {
+    /// Maximum nesting depth below the root (deeper children are dropped
+    /// and the tree flagged truncated)
+    pub max_depth: usize,
+    /// Maximum total number of nodes to serialize
+    pub max_nodes: usize,
+}
+
+impl Default for DomTreeOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 25,
+            max_nodes: 5000,
+        }
+    }
+}
+
+/// DOM tree extraction functionality
+pub struct DomTreeExtractor;
+
+impl DomTreeExtractor {
+    /// Extract the DOM subtree rooted at `selector` as a structured tree
+    ///
+    /// Script and style elements are excluded. When the document exceeds
+    /// the configured bounds, the tree is cut off and
+    /// [`DomTree::truncated`] set rather than failing.
+    #[instrument(skip(page, options))]
+    pub async fn extract(
+        page: &PageHandle,
+        selector: &str,
+        options: &DomTreeOptions,
+    ) -> Result<DomTree> {
+        info!("Extracting DOM tree from '{}'", selector);
+
+        // serde_json escaping gives us a valid JS string literal
+        let selector_literal =
+            serde_json::to_string(selector).unwrap_or_else(|_| "\"body\"".to_string());
+
+        let script = format!(
+            r#"
+            (() => {{
+                const maxDepth = {max_depth};
+                const maxNodes = {max_nodes};
+                const state = {{ count: 0, truncated: false }};
+
+                const build = (el, depth) => {{
+                    if (state.count >= maxNodes) {{
+                        state.truncated = true;
+                        return null;
+                    }}
+                    state.count++;
+
+                    const attrs = {{}};
+                    for (const attr of el.attributes) {{
+                        attrs[attr.name] = attr.value;
+                    }}
+
+                    let text = '';
+                    for (const child of el.childNodes) {{
+                        if (child.nodeType === Node.TEXT_NODE) {{
+                            text += child.textContent;
+                        }}
+                    }}
+                    text = text.trim();
+
+                    const children = [];
+                    if (depth >= maxDepth) {{
+                        if (el.children.length > 0) state.truncated = true;
+                    }} else {{
+                        for (const child of el.children) {{
+                            const tag = child.tagName;
+                            if (tag === 'SCRIPT' || tag === 'STYLE') continue;
+                            const node = build(child, depth + 1);
+                            if (node) children.push(node);
+                        }}
+                    }}
+
+                    return {{
+                        tag: el.tagName.toLowerCase(),
+                        attrs,
+                        text: text || null,
+                        children,
+                    }};
+                }};
+
+                const root = document.querySelector({selector_literal});
+                if (!root) return null;
+                return {{
+                    tree: build(root, 0),
+                    truncated: state.truncated,
+                    nodeCount: state.count,
+                }};
+            }})()
+            "#,
+            max_depth = options.max_depth,
+            max_nodes = options.max_nodes,
+        );
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        if result.is_null() {
+            return Err(ExtractionError::ElementNotFound(selector.to_string()).into());
+        }
+
+        let tree = Self::tree_from_value(&result)
+            .ok_or_else(|| ExtractionError::ParsingFailed("Malformed DOM tree".to_string()))?;
+        debug!(
+            "Extracted {} DOM nodes (truncated: {})",
+            tree.node_count, tree.truncated
+        );
+        Ok(tree)
+    }
+
+    /// Build a [`DomTree`] from the JSON produced by the page script
+    pub fn tree_from_value(value: &serde_json::Value) -> Option<DomTree> {
+        let tree: DomNode = serde_json::from_value(value.get("tree")?.clone()).ok()?;
+        Some(DomTree {
+            truncated: value.get("truncated")?.as_bool()?,
+            node_count: value
+                .get("nodeCount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or_else(|| tree.node_count() as u64) as usize,
+            tree,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dom_tree_options_default() {
+        let options = DomTreeOptions::default();
+        assert_eq!(options.max_depth, 25);
+        assert_eq!(options.max_nodes, 5000);
+    }
+
+    #[test]
+    fn test_tree_from_value_preserves_nesting() {
+        let tree = DomTreeExtractor::tree_from_value(&json!({
+            "tree": {
+                "tag": "div",
+                "attrs": {"id": "root"},
+                "text": null,
+                "children": [
+                    {
+                        "tag": "p",
+                        "attrs": {},
+                        "text": "Hello",
+                        "children": [
+                            {"tag": "em", "attrs": {}, "text": "world", "children": []}
+                        ],
+                    },
+                    {"tag": "span", "attrs": {"class": "note"}, "text": null, "children": []}
+                ],
+            },
+            "truncated": false,
+            "nodeCount": 4,
+        }))
+        .expect("valid tree");
+
+        assert_eq!(tree.tree.tag, "div");
+        assert_eq!(tree.tree.attrs.get("id").map(String::as_str), Some("root"));
+        assert_eq!(tree.tree.children.len(), 2);
+        assert_eq!(tree.tree.children[0].text.as_deref(), Some("Hello"));
+        assert_eq!(tree.tree.children[0].children[0].tag, "em");
+        assert_eq!(tree.node_count, 4);
+        assert_eq!(tree.tree.node_count(), 4);
+        assert!(!tree.truncated);
+    }
+
+    #[test]
+    fn test_tree_from_value_truncated_flag() {
+        let tree = DomTreeExtractor::tree_from_value(&json!({
+            "tree": {"tag": "body", "attrs": {}, "text": null, "children": []},
+            "truncated": true,
+            "nodeCount": 1,
+        }))
+        .expect("valid tree");
+        assert!(tree.truncated);
+    }
+
+    #[test]
+    fn test_tree_from_value_rejects_missing_tree() {
+        assert!(DomTreeExtractor::tree_from_value(&json!({"truncated": false})).is_none());
+        assert!(DomTreeExtractor::tree_from_value(&serde_json::Value::Null).is_none());
+    }
+}
@@ -0,0 +1,199 @@
+//! Form structure extraction
+//!
+//! Before filling a form, an agent wants to know what it asks for. This
+//! module extracts each form's action and method plus per-field metadata —
+//! name, type, resolved label, required flag, and the options of selects —
+//! so values can be planned without guessing at the DOM.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// An option inside a `<select>` field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectOption {
+    /// Submitted value
+    pub value: String,
+    /// Visible label
+    pub label: String,
+}
+
+/// A single form field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FormField {
+    /// Name the field submits under
+    pub name: String,
+    /// Control type (`text`, `email`, `select`, `textarea`, ...)
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// Human-readable label, resolved via `<label for>`, a wrapping
+    /// `<label>`, or `aria-label`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Whether the field is marked required
+    pub required: bool,
+    /// Choices, for selects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+}
+
+/// An extracted form with its submission target and fields
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractedForm {
+    /// Absolute submission URL, when the form declares an action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// Submission method, lowercased (`get` when unspecified)
+    pub method: String,
+    /// Fields in document order
+    pub fields: Vec<FormField>,
+}
+
+/// Form extraction functionality
+pub struct FormExtractor;
+
+impl FormExtractor {
+    /// Extract all forms from the page
+    ///
+    /// Hidden inputs and submit/button controls are omitted — they carry no
+    /// decision for the agent. Unnamed fields are skipped too, since they
+    /// never submit a value.
+    #[instrument(skip(page))]
+    pub async fn extract_all(page: &PageHandle) -> Result<Vec<ExtractedForm>> {
+        info!("Extracting forms");
+
+        let script = r#"
+            (() => {
+                const labelFor = (el) => {
+                    if (el.id) {
+                        const label = document.querySelector(
+                            'label[for="' + CSS.escape(el.id) + '"]'
+                        );
+                        if (label) return label.innerText.trim();
+                    }
+                    const wrapping = el.closest('label');
+                    if (wrapping) return wrapping.innerText.trim();
+                    const aria = el.getAttribute('aria-label');
+                    return aria ? aria.trim() : null;
+                };
+
+                const forms = [];
+                document.querySelectorAll('form').forEach(form => {
+                    const fields = [];
+                    form.querySelectorAll('input, select, textarea').forEach(el => {
+                        const name = el.getAttribute('name');
+                        if (!name) return;
+                        const tag = el.tagName.toLowerCase();
+                        const type = tag === 'input' ? (el.type || 'text') : tag;
+                        if (type === 'hidden' || type === 'submit' || type === 'button') return;
+
+                        const options = [];
+                        if (tag === 'select') {
+                            for (const option of el.options) {
+                                options.push({
+                                    value: option.value,
+                                    label: option.innerText.trim(),
+                                });
+                            }
+                        }
+
+                        fields.push({
+                            name,
+                            type,
+                            label: labelFor(el),
+                            required: el.required === true,
+                            options,
+                        });
+                    });
+
+                    forms.push({
+                        action: form.getAttribute('action') ? form.action : null,
+                        method: (form.method || 'get').toLowerCase(),
+                        fields,
+                    });
+                });
+                return forms;
+            })()
+        "#;
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let forms: Vec<ExtractedForm> = serde_json::from_value(result)
+            .map_err(|e| ExtractionError::ParsingFailed(e.to_string()))?;
+        debug!("Extracted {} forms", forms.len());
+        Ok(forms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_form_deserialization() {
+        let forms: Vec<ExtractedForm> = serde_json::from_value(json!([
+            {
+                "action": "https://example.com/signup",
+                "method": "post",
+                "fields": [
+                    {
+                        "name": "email",
+                        "type": "email",
+                        "label": "Email address",
+                        "required": true,
+                        "options": [],
+                    },
+                    {
+                        "name": "country",
+                        "type": "select",
+                        "label": "Country",
+                        "required": false,
+                        "options": [
+                            {"value": "de", "label": "Germany"},
+                            {"value": "fr", "label": "France"},
+                        ],
+                    },
+                ],
+            }
+        ]))
+        .expect("valid forms");
+
+        assert_eq!(forms.len(), 1);
+        let form = &forms[0];
+        assert_eq!(form.action.as_deref(), Some("https://example.com/signup"));
+        assert_eq!(form.method, "post");
+        assert_eq!(form.fields[0].field_type, "email");
+        assert!(form.fields[0].required);
+        assert_eq!(form.fields[1].options.len(), 2);
+        assert_eq!(form.fields[1].options[0].value, "de");
+    }
+
+    #[test]
+    fn test_form_serializes_type_key() {
+        let form = ExtractedForm {
+            action: None,
+            method: "get".to_string(),
+            fields: vec![FormField {
+                name: "q".to_string(),
+                field_type: "text".to_string(),
+                label: None,
+                required: false,
+                options: Vec::new(),
+            }],
+        };
+
+        let json = serde_json::to_value(&form).expect("serializes");
+        assert_eq!(json["fields"][0]["type"], "text");
+        // Empty label and options are omitted, not serialized as noise
+        assert!(json["fields"][0].get("label").is_none());
+        assert!(json["fields"][0].get("options").is_none());
+    }
+}
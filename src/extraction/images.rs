@@ -0,0 +1,392 @@
+//! Image region extraction
+//!
+//! This module locates rendered image regions on a page — for isolating
+//! text-in-images ahead of OCR — and reports their bounding boxes and
+//! selector paths so each region can be cropped from a screenshot. It
+//! also picks the page's primary image for link previews.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// Default selectors considered image regions during auto-detection
+pub const DEFAULT_IMAGE_SELECTORS: &[&str] = &["img", "canvas", "svg", "[role=\"img\"]"];
+
+/// Minimum rendered size, in pixels, for an auto-detected region
+///
+/// Filters out icons, avatars, and tracking pixels; regions carrying
+/// readable text are rarely smaller than this in either dimension.
+pub const DEFAULT_MIN_REGION_SIZE: u32 = 64;
+
+/// A rendered image region on the page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageRegion {
+    /// CSS selector path to the element
+    pub selector_path: String,
+    /// The `src` attribute, when the element has one
+    ///
+    /// Informational only — the crop comes from the rendered region, so
+    /// sprites and `srcset` variants are captured as displayed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    /// Left edge of the rendered box, in CSS pixels from the viewport
+    pub x: f64,
+    /// Top edge of the rendered box, in CSS pixels from the viewport
+    pub y: f64,
+    /// Rendered width in CSS pixels
+    pub width: f64,
+    /// Rendered height in CSS pixels
+    pub height: f64,
+}
+
+/// Options controlling image region detection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageRegionOptions {
+    /// Selectors to inspect instead of [`DEFAULT_IMAGE_SELECTORS`]
+    #[serde(default)]
+    pub selectors: Option<Vec<String>>,
+    /// Minimum rendered width in pixels (default:
+    /// [`DEFAULT_MIN_REGION_SIZE`])
+    pub min_width: u32,
+    /// Minimum rendered height in pixels (default:
+    /// [`DEFAULT_MIN_REGION_SIZE`])
+    pub min_height: u32,
+}
+
+impl Default for ImageRegionOptions {
+    fn default() -> Self {
+        Self {
+            selectors: None,
+            min_width: DEFAULT_MIN_REGION_SIZE,
+            min_height: DEFAULT_MIN_REGION_SIZE,
+        }
+    }
+}
+
+/// The page's primary image, for link previews
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrimaryImage {
+    /// Resolved absolute URL of the image
+    pub url: String,
+    /// Width in pixels: declared for `og:image`, rendered for content
+    /// images
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    /// Height in pixels, like `width`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    /// Where the pick came from: `og:image` or `content`
+    pub source: String,
+}
+
+/// Image region detection functionality
+pub struct ImageRegionExtractor;
+
+impl ImageRegionExtractor {
+    /// Find rendered image regions on the page
+    ///
+    /// Inspects the configured selectors (or the auto-detection defaults),
+    /// keeping elements whose rendered box meets the minimum size. Boxes
+    /// come from `getBoundingClientRect`, so they reflect the displayed
+    /// size and position, not the intrinsic image dimensions.
+    #[instrument(skip(page))]
+    pub async fn extract_regions(
+        page: &PageHandle,
+        options: &ImageRegionOptions,
+    ) -> Result<Vec<ImageRegion>> {
+        info!("Extracting image regions");
+
+        let script = Self::regions_script(options);
+
+        let result: serde_json::Value = page
+            .inner()
+            .evaluate(script.as_str())
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let regions = Self::regions_from_value(&result, options);
+        debug!("Found {} image regions", regions.len());
+        Ok(regions)
+    }
+
+    /// Build the JS used by [`Self::extract_regions`]
+    ///
+    /// Exposed so the generated script can be unit tested without a browser.
+    pub fn regions_script(options: &ImageRegionOptions) -> String {
+        let selectors = match &options.selectors {
+            Some(selectors) => selectors.join(", "),
+            None => DEFAULT_IMAGE_SELECTORS.join(", "),
+        };
+        let selectors =
+            serde_json::to_string(&selectors).unwrap_or_else(|_| "\"img\"".to_string());
+        format!(
+            r#"
+            (() => {{
+                const cssPath = (el) => {{
+                    const parts = [];
+                    while (el && el.nodeType === Node.ELEMENT_NODE) {{
+                        if (el.id) {{
+                            parts.unshift('#' + el.id);
+                            break;
+                        }}
+                        const tag = el.tagName.toLowerCase();
+                        let index = 1;
+                        let sibling = el.previousElementSibling;
+                        while (sibling) {{
+                            if (sibling.tagName === el.tagName) index++;
+                            sibling = sibling.previousElementSibling;
+                        }}
+                        parts.unshift(tag + ':nth-of-type(' + index + ')');
+                        el = el.parentElement;
+                    }}
+                    return parts.join(' > ');
+                }};
+
+                const regions = [];
+                document.querySelectorAll({selectors}).forEach(el => {{
+                    const rect = el.getBoundingClientRect();
+                    if (rect.width <= 0 || rect.height <= 0) return;
+                    const style = window.getComputedStyle(el);
+                    if (style.display === 'none' || style.visibility === 'hidden') return;
+                    regions.push({{
+                        path: cssPath(el),
+                        src: el.getAttribute('src'),
+                        x: rect.left,
+                        y: rect.top,
+                        width: rect.width,
+                        height: rect.height
+                    }});
+                }});
+                return regions;
+            }})()
+            "#
+        )
+    }
+
+    /// Pick the page's primary image
+    ///
+    /// `og:image` wins when present. Otherwise the largest visible content
+    /// image above the fold is chosen, excluding logos, icons, avatars,
+    /// and tracking pixels. `None` when the page has no usable image.
+    #[instrument(skip(page))]
+    pub async fn extract_primary_image(page: &PageHandle) -> Result<Option<PrimaryImage>> {
+        info!("Extracting primary image");
+
+        let result: serde_json::Value = page
+            .inner()
+            .evaluate(Self::primary_image_script())
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        Ok(Self::primary_image_from_value(&result))
+    }
+
+    /// Build the JS used by [`Self::extract_primary_image`]
+    ///
+    /// Exposed so the generated script can be unit tested without a browser.
+    pub fn primary_image_script() -> String {
+        format!(
+            r#"
+            (() => {{
+                const resolve = (u) => {{
+                    try {{ return new URL(u, document.baseURI).href; }} catch (e) {{ return null; }}
+                }};
+
+                const og = document.querySelector('meta[property="og:image"], meta[name="og:image"]');
+                if (og && og.getAttribute('content')) {{
+                    const url = resolve(og.getAttribute('content'));
+                    if (url) {{
+                        const dim = (prop) => {{
+                            const el = document.querySelector('meta[property="og:image:' + prop + '"]');
+                            const v = el ? parseInt(el.getAttribute('content'), 10) : NaN;
+                            return Number.isFinite(v) && v > 0 ? v : null;
+                        }};
+                        return {{ url, width: dim('width'), height: dim('height'), source: 'og:image' }};
+                    }}
+                }}
+
+                const noise = /logo|icon|avatar|sprite|badge|pixel/i;
+                let best = null;
+                let bestArea = 0;
+                document.querySelectorAll('img').forEach(img => {{
+                    const rect = img.getBoundingClientRect();
+                    if (rect.width < {min} || rect.height < {min}) return;
+                    // Above the fold: the preview image is what loads first
+                    if (rect.top >= window.innerHeight || rect.bottom <= 0) return;
+                    const style = window.getComputedStyle(img);
+                    if (style.display === 'none' || style.visibility === 'hidden') return;
+                    const label = [
+                        img.currentSrc || img.src || '', img.alt || '',
+                        img.className || '', img.id || ''
+                    ].join(' ');
+                    if (noise.test(label)) return;
+                    const area = rect.width * rect.height;
+                    if (area > bestArea) {{
+                        bestArea = area;
+                        best = {{
+                            url: resolve(img.currentSrc || img.src),
+                            width: Math.round(rect.width),
+                            height: Math.round(rect.height),
+                            source: 'content'
+                        }};
+                    }}
+                }});
+                return best && best.url ? best : null;
+            }})()
+            "#,
+            min = DEFAULT_MIN_REGION_SIZE
+        )
+    }
+
+    /// Build the primary image from the script's result, `None` for `null`
+    /// or malformed entries
+    pub fn primary_image_from_value(value: &serde_json::Value) -> Option<PrimaryImage> {
+        let url = value["url"].as_str().filter(|u| !u.is_empty())?;
+        let dimension = |key: &str| value[key].as_u64().and_then(|v| u32::try_from(v).ok());
+        Some(PrimaryImage {
+            url: url.to_string(),
+            width: dimension("width"),
+            height: dimension("height"),
+            source: value["source"].as_str().unwrap_or("content").to_string(),
+        })
+    }
+
+    /// Build regions from raw entries (`path`, `src`, `x`, `y`, `width`,
+    /// `height`), dropping those below the configured minimum size
+    pub fn regions_from_value(
+        value: &serde_json::Value,
+        options: &ImageRegionOptions,
+    ) -> Vec<ImageRegion> {
+        let Some(entries) = value.as_array() else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let path = entry["path"].as_str().filter(|p| !p.is_empty())?;
+                let width = entry["width"].as_f64()?;
+                let height = entry["height"].as_f64()?;
+                if width < f64::from(options.min_width) || height < f64::from(options.min_height) {
+                    return None;
+                }
+                Some(ImageRegion {
+                    selector_path: path.to_string(),
+                    src: entry["src"].as_str().map(|s| s.to_string()),
+                    x: entry["x"].as_f64().unwrap_or(0.0),
+                    y: entry["y"].as_f64().unwrap_or(0.0),
+                    width,
+                    height,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, width: f64, height: f64) -> serde_json::Value {
+        serde_json::json!({
+            "path": path,
+            "src": "/logo.png",
+            "x": 10.0,
+            "y": 20.0,
+            "width": width,
+            "height": height,
+        })
+    }
+
+    #[test]
+    fn test_regions_from_value_filters_small_regions() {
+        let value = serde_json::Value::Array(vec![
+            entry("img:nth-of-type(1)", 120.0, 80.0),
+            entry("img:nth-of-type(2)", 16.0, 16.0),
+            entry("img:nth-of-type(3)", 200.0, 32.0),
+        ]);
+
+        let regions =
+            ImageRegionExtractor::regions_from_value(&value, &ImageRegionOptions::default());
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].selector_path, "img:nth-of-type(1)");
+        assert_eq!(regions[0].width, 120.0);
+        assert_eq!(regions[0].src.as_deref(), Some("/logo.png"));
+    }
+
+    #[test]
+    fn test_regions_from_value_honors_custom_minimums() {
+        let value = serde_json::Value::Array(vec![entry("img:nth-of-type(1)", 40.0, 40.0)]);
+        let options = ImageRegionOptions {
+            min_width: 32,
+            min_height: 32,
+            ..Default::default()
+        };
+
+        let regions = ImageRegionExtractor::regions_from_value(&value, &options);
+        assert_eq!(regions.len(), 1);
+    }
+
+    #[test]
+    fn test_regions_from_value_skips_invalid_entries() {
+        let value = serde_json::json!([
+            { "path": "", "width": 100.0, "height": 100.0 },
+            { "path": "img:nth-of-type(1)" },
+            "not an object",
+        ]);
+
+        let regions =
+            ImageRegionExtractor::regions_from_value(&value, &ImageRegionOptions::default());
+        assert!(regions.is_empty());
+    }
+
+    #[test]
+    fn test_primary_image_from_value() {
+        let value = serde_json::json!({
+            "url": "https://example.com/hero.jpg",
+            "width": 1200,
+            "height": 630,
+            "source": "og:image",
+        });
+
+        let image = ImageRegionExtractor::primary_image_from_value(&value).unwrap();
+        assert_eq!(image.url, "https://example.com/hero.jpg");
+        assert_eq!(image.width, Some(1200));
+        assert_eq!(image.height, Some(630));
+        assert_eq!(image.source, "og:image");
+
+        assert!(ImageRegionExtractor::primary_image_from_value(&serde_json::Value::Null).is_none());
+        assert!(
+            ImageRegionExtractor::primary_image_from_value(&serde_json::json!({ "url": "" }))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_primary_image_script_prefers_og_image() {
+        let script = ImageRegionExtractor::primary_image_script();
+        assert!(script.contains("og:image"));
+        assert!(script.contains("window.innerHeight"));
+        // Logos, icons, and tracking pixels never make good previews
+        assert!(script.contains("logo|icon|avatar"));
+    }
+
+    #[test]
+    fn test_regions_script_uses_configured_selectors() {
+        let default_script = ImageRegionExtractor::regions_script(&ImageRegionOptions::default());
+        assert!(default_script.contains("img, canvas, svg"));
+        assert!(default_script.contains("getBoundingClientRect"));
+
+        let custom = ImageRegionOptions {
+            selectors: Some(vec![".chart".to_string(), ".hero img".to_string()]),
+            ..Default::default()
+        };
+        let script = ImageRegionExtractor::regions_script(&custom);
+        assert!(script.contains(".chart, .hero img"));
+    }
+}
@@ -0,0 +1,404 @@
+//! Broken link detection
+//!
+//! This module checks a page's outgoing links for dead targets by issuing
+//! bounded-concurrency HEAD requests (falling back to GET when HEAD is
+//! rejected) and reporting the final status after redirects.
+
+use crate::extraction::{ExtractedLink, LinkType};
+use futures::StreamExt;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tracing::{debug, info, instrument, warn};
+
+/// Default time a checked URL's result stays valid within a session
+pub const DEFAULT_LINK_CHECK_TTL_SECS: u64 = 300;
+
+/// Default session-wide cap on link-check requests in flight
+pub const DEFAULT_SESSION_CONCURRENCY: usize = 16;
+
+/// Result of checking a single link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkCheckResult {
+    /// The URL that was checked
+    pub url: String,
+    /// Final HTTP status after redirects, when a response arrived
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Whether the link resolved to a non-error status
+    pub ok: bool,
+    /// Transport error, when no response arrived at all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Options for link checking
+#[derive(Debug, Clone)]
+pub struct LinkCheckOptions {
+    /// Only check links on the page's own domain (default: false)
+    pub same_domain_only: bool,
+    /// Timeout per link in milliseconds (default: 5000)
+    pub timeout_ms: u64,
+    /// Maximum number of requests in flight at once (default: 8)
+    pub concurrency: usize,
+}
+
+impl Default for LinkCheckOptions {
+    fn default() -> Self {
+        Self {
+            same_domain_only: false,
+            timeout_ms: 5000,
+            concurrency: 8,
+        }
+    }
+}
+
+/// Session-scoped link-check cache and concurrency bound
+///
+/// Checking many pages of one site re-encounters the same external URLs
+/// over and over. A session caches each result for a TTL so repeats are
+/// answered without a request, and bounds total requests in flight across
+/// concurrent calls so a site check cannot fan out without limit.
+#[derive(Debug)]
+pub struct LinkCheckSession {
+    ttl: Duration,
+    semaphore: Arc<Semaphore>,
+    results: Mutex<HashMap<String, CachedCheck>>,
+    hits: AtomicU64,
+}
+
+#[derive(Debug)]
+struct CachedCheck {
+    result: LinkCheckResult,
+    checked_at: Instant,
+}
+
+impl LinkCheckSession {
+    /// Create a session with the default TTL and in-flight cap
+    pub fn new() -> Self {
+        Self::with_limits(
+            Duration::from_secs(DEFAULT_LINK_CHECK_TTL_SECS),
+            DEFAULT_SESSION_CONCURRENCY,
+        )
+    }
+
+    /// Create a session with an explicit TTL and in-flight cap
+    pub fn with_limits(ttl: Duration, max_in_flight: usize) -> Self {
+        Self {
+            ttl,
+            semaphore: Arc::new(Semaphore::new(max_in_flight.max(1))),
+            results: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up a still-fresh cached result for a URL
+    ///
+    /// Expired entries are evicted on lookup rather than by a background
+    /// sweep; stale results never come back.
+    pub fn get(&self, url: &str) -> Option<LinkCheckResult> {
+        let mut results = self.results.lock();
+        match results.get(url) {
+            Some(entry) if entry.checked_at.elapsed() < self.ttl => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(entry.result.clone())
+            }
+            Some(_) => {
+                results.remove(url);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Record a result for later calls within the session
+    pub fn insert(&self, result: &LinkCheckResult) {
+        self.results.lock().insert(
+            result.url.clone(),
+            CachedCheck {
+                result: result.clone(),
+                checked_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Number of lookups answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for LinkCheckSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Link checking functionality
+pub struct LinkChecker;
+
+impl LinkChecker {
+    /// Select the http(s) URLs worth checking from extracted links
+    ///
+    /// Anchors, mailto/tel/javascript links and duplicates are dropped;
+    /// document order of first occurrence is kept. With `same_domain_only`
+    /// set, external links are dropped too.
+    pub fn checkable_urls(links: &[ExtractedLink], same_domain_only: bool) -> Vec<String> {
+        let mut seen = HashSet::new();
+        links
+            .iter()
+            .filter(|link| match link.link_type {
+                LinkType::Internal => true,
+                LinkType::External => !same_domain_only,
+                _ => false,
+            })
+            .filter(|link| seen.insert(link.url.clone()))
+            .map(|link| link.url.clone())
+            .collect()
+    }
+
+    /// Whether a final status counts as a working link
+    ///
+    /// Redirects are followed before this is applied, so anything below
+    /// 400 means the target exists.
+    pub fn is_ok_status(status: u16) -> bool {
+        status < 400
+    }
+
+    /// Check each URL, at most `options.concurrency` requests in flight
+    ///
+    /// Results come back in input order. A HEAD request is tried first;
+    /// servers that reject the method (405/501) are retried with GET.
+    #[instrument(skip(urls, options), fields(count = urls.len()))]
+    pub async fn check_urls(urls: Vec<String>, options: &LinkCheckOptions) -> Vec<LinkCheckResult> {
+        Self::check_urls_bounded(urls, options, None).await
+    }
+
+    /// Check each URL through a session, reusing fresh cached results
+    ///
+    /// URLs already checked within the session's TTL are answered from the
+    /// cache without a request; the rest go out under both the per-call
+    /// concurrency bound and the session-wide one. Results come back in
+    /// input order.
+    #[instrument(skip(urls, options, session), fields(count = urls.len()))]
+    pub async fn check_urls_in_session(
+        urls: Vec<String>,
+        options: &LinkCheckOptions,
+        session: &LinkCheckSession,
+    ) -> Vec<LinkCheckResult> {
+        let mut cached = HashMap::new();
+        let mut pending = Vec::new();
+        let mut seen = HashSet::new();
+        for url in &urls {
+            if cached.contains_key(url) || !seen.insert(url.clone()) {
+                continue;
+            }
+            match session.get(url) {
+                Some(result) => {
+                    cached.insert(url.clone(), result);
+                }
+                None => pending.push(url.clone()),
+            }
+        }
+        debug!(
+            "Link check session answered {} of {} URLs from cache",
+            cached.len(),
+            urls.len()
+        );
+
+        let fresh =
+            Self::check_urls_bounded(pending, options, Some(session.semaphore.clone())).await;
+        for result in fresh {
+            session.insert(&result);
+            cached.insert(result.url.clone(), result);
+        }
+
+        urls.into_iter()
+            .filter_map(|url| cached.get(&url).cloned())
+            .collect()
+    }
+
+    /// Check each URL, optionally under a session-wide in-flight cap
+    async fn check_urls_bounded(
+        urls: Vec<String>,
+        options: &LinkCheckOptions,
+        session_semaphore: Option<Arc<Semaphore>>,
+    ) -> Vec<LinkCheckResult> {
+        info!("Checking {} links", urls.len());
+
+        let client = match reqwest::Client::builder()
+            .timeout(Duration::from_millis(options.timeout_ms))
+            .build()
+        {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("Failed to build HTTP client for link checking: {}", e);
+                return urls
+                    .into_iter()
+                    .map(|url| LinkCheckResult {
+                        url,
+                        status: None,
+                        ok: false,
+                        error: Some(e.to_string()),
+                    })
+                    .collect();
+            }
+        };
+
+        let concurrency = options.concurrency.max(1);
+        futures::stream::iter(urls)
+            .map(|url| {
+                let client = client.clone();
+                let semaphore = session_semaphore.clone();
+                async move {
+                    let _permit = match &semaphore {
+                        Some(s) => Arc::clone(s).acquire_owned().await.ok(),
+                        None => None,
+                    };
+                    let result = Self::check_one(&client, &url).await;
+                    match &result {
+                        Ok(status) => debug!(
+                            "{} -> {}",
+                            crate::logging::sanitize_url(&url),
+                            status
+                        ),
+                        Err(e) => debug!("{} failed: {}", crate::logging::sanitize_url(&url), e),
+                    }
+                    match result {
+                        Ok(status) => LinkCheckResult {
+                            url,
+                            status: Some(status),
+                            ok: Self::is_ok_status(status),
+                            error: None,
+                        },
+                        Err(e) => LinkCheckResult {
+                            url,
+                            status: None,
+                            ok: false,
+                            error: Some(e),
+                        },
+                    }
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
+    }
+
+    /// Check a single URL, falling back to GET when HEAD is rejected
+    async fn check_one(client: &reqwest::Client, url: &str) -> std::result::Result<u16, String> {
+        match client.head(url).send().await {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                // Some servers refuse HEAD outright; GET is authoritative then
+                if status == 405 || status == 501 {
+                    let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+                    Ok(response.status().as_u16())
+                } else {
+                    Ok(status)
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(url: &str, link_type: LinkType) -> ExtractedLink {
+        ExtractedLink {
+            url: url.to_string(),
+            text: String::new(),
+            title: None,
+            link_type,
+            rel: None,
+            new_tab: false,
+            context: None,
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn test_checkable_urls_filters_and_dedupes() {
+        let links = vec![
+            link("https://example.com/a", LinkType::Internal),
+            link("https://other.com/b", LinkType::External),
+            link("https://example.com/a", LinkType::Internal),
+            link("#section", LinkType::Anchor),
+            link("mailto:hi@example.com", LinkType::Email),
+        ];
+
+        let urls = LinkChecker::checkable_urls(&links, false);
+        assert_eq!(urls, vec!["https://example.com/a", "https://other.com/b"]);
+    }
+
+    #[test]
+    fn test_same_domain_only_drops_external() {
+        let links = vec![
+            link("https://example.com/a", LinkType::Internal),
+            link("https://other.com/b", LinkType::External),
+        ];
+
+        let urls = LinkChecker::checkable_urls(&links, true);
+        assert_eq!(urls, vec!["https://example.com/a"]);
+    }
+
+    #[test]
+    fn test_is_ok_status() {
+        assert!(LinkChecker::is_ok_status(200));
+        assert!(LinkChecker::is_ok_status(301));
+        assert!(!LinkChecker::is_ok_status(404));
+        assert!(!LinkChecker::is_ok_status(500));
+    }
+
+    #[test]
+    fn test_session_answers_repeat_lookups_from_cache() {
+        let session = LinkCheckSession::new();
+        let result = LinkCheckResult {
+            url: "https://example.com/a".to_string(),
+            status: Some(200),
+            ok: true,
+            error: None,
+        };
+
+        assert!(session.get("https://example.com/a").is_none());
+        session.insert(&result);
+        let cached = session.get("https://example.com/a").unwrap();
+        assert_eq!(cached.status, Some(200));
+        assert_eq!(session.hits(), 1);
+    }
+
+    #[test]
+    fn test_session_expires_results_after_ttl() {
+        let session = LinkCheckSession::with_limits(Duration::from_millis(10), 4);
+        session.insert(&LinkCheckResult {
+            url: "https://example.com/a".to_string(),
+            status: Some(200),
+            ok: true,
+            error: None,
+        });
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(session.get("https://example.com/a").is_none());
+        assert_eq!(session.hits(), 0);
+    }
+
+    #[test]
+    fn test_result_serialization_omits_empty_fields() {
+        let result = LinkCheckResult {
+            url: "https://example.com/a".to_string(),
+            status: Some(200),
+            ok: true,
+            error: None,
+        };
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"status\":200"));
+        assert!(!json.contains("\"error\""));
+    }
+}
@@ -5,8 +5,9 @@
 
 use crate::browser::PageHandle;
 use crate::error::{ExtractionError, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tracing::{debug, info, instrument};
 
 /// Extracted page metadata
@@ -32,8 +33,94 @@ pub struct PageMetadata {
     pub favicon: Option<String>,
     /// All meta tags
     pub meta_tags: HashMap<String, String>,
+    /// Complete name/property → content map, with duplicate names
+    /// collected into arrays (unlike `meta_tags`, where the last one wins)
+    #[serde(default)]
+    pub meta: HashMap<String, MetaValue>,
     /// JSON-LD structured data
     pub json_ld: Vec<serde_json::Value>,
+    /// Breadcrumb trail, ordered from root to current page
+    #[serde(default)]
+    pub breadcrumbs: Vec<BreadcrumbItem>,
+    /// Canonical, alternate-hreflang, and pagination link relations
+    #[serde(default)]
+    pub link_relations: LinkRelations,
+}
+
+/// Canonical, alternate, and pagination `<link rel>` relations
+///
+/// URLs are resolved against the document, so relative `href`s come back
+/// absolute. Malformed or duplicate declarations are kept out of the
+/// structured fields and reported in `issues` instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkRelations {
+    /// `rel="canonical"` target; first one wins when duplicated
+    pub canonical: Option<String>,
+    /// `rel="alternate" hreflang=...` targets, keyed by hreflang value
+    pub hreflang: HashMap<String, String>,
+    /// `rel="prev"` pagination target
+    pub prev: Option<String>,
+    /// `rel="next"` pagination target
+    pub next: Option<String>,
+    /// Malformed or duplicate declarations, in document order
+    pub issues: Vec<String>,
+}
+
+/// Content of a meta tag name: a single value, or all values when the
+/// page repeats the name (e.g. multiple `article:tag` entries)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetaValue {
+    /// The name appeared once
+    Single(String),
+    /// The name appeared multiple times, in document order
+    Multiple(Vec<String>),
+}
+
+/// A single entry in a breadcrumb trail
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreadcrumbItem {
+    /// Display name of the crumb
+    pub name: String,
+    /// Target URL, if the crumb is a link
+    pub url: Option<String>,
+}
+
+/// A favicon candidate declared by the page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IconCandidate {
+    /// Resolved icon URL
+    pub href: String,
+    /// Raw `sizes` attribute, e.g. `"32x32"` or `"16x16 32x32"`
+    pub sizes: Option<String>,
+}
+
+/// Favicon bytes fetched from the page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaviconData {
+    /// Base64-encoded image bytes
+    pub data: String,
+    /// Detected MIME type, e.g. `image/png`
+    pub mime_type: String,
+    /// Decoded width in pixels, when the image could be decoded
+    pub width: Option<u32>,
+    /// Decoded height in pixels, when the image could be decoded
+    pub height: Option<u32>,
+}
+
+/// Structured `og:video` / `og:audio` metadata with its sub-properties
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenGraphMedia {
+    /// `og:video` / `og:audio` (equivalently the `:url` sub-property)
+    pub url: Option<String>,
+    /// The `:secure_url` sub-property
+    pub secure_url: Option<String>,
+    /// The `:type` sub-property (MIME type)
+    pub media_type: Option<String>,
+    /// The `:width` sub-property; audio has no dimensions
+    pub width: Option<u32>,
+    /// The `:height` sub-property; audio has no dimensions
+    pub height: Option<u32>,
 }
 
 /// Open Graph metadata
@@ -53,6 +140,12 @@ pub struct OpenGraphData {
     pub site_name: Option<String>,
     /// og:locale
     pub locale: Option<String>,
+    /// og:video and its sub-properties, when any are present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<OpenGraphMedia>,
+    /// og:audio and its sub-properties, when any are present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio: Option<OpenGraphMedia>,
 }
 
 /// Twitter Card metadata
@@ -72,6 +165,53 @@ pub struct TwitterCardData {
     pub creator: Option<String>,
 }
 
+/// How a single metadata field differs between two captures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataChangeKind {
+    /// The field was absent before and is present now
+    Added,
+    /// The field was present before and is absent now
+    Removed,
+    /// The field is present in both captures with different values
+    Changed,
+}
+
+/// One field that differs between two metadata captures
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataChange {
+    /// Field name, e.g. `title`, `canonical`, `og:image`, `twitter:card`
+    pub field: String,
+    /// Whether the field was added, removed, or changed
+    pub kind: MetadataChangeKind,
+    /// Value in the older capture, absent for added fields
+    pub old: Option<String>,
+    /// Value in the newer capture, absent for removed fields
+    pub new: Option<String>,
+}
+
+/// Structured diff between two metadata captures of the same page
+///
+/// Covers the scalar SEO-relevant fields (title, description, canonical,
+/// language, author, keywords, favicon) plus every Open Graph and Twitter
+/// Card field. Unchanged fields are omitted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetadataDiff {
+    /// Fields that differ, in a fixed field order
+    pub changes: Vec<MetadataChange>,
+}
+
+impl MetadataDiff {
+    /// Whether the two captures were identical across compared fields
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Maximum depth to which `@id` references are inlined when flattening
+/// JSON-LD graphs
+pub const MAX_JSON_LD_DEPTH: usize = 8;
+
 /// Metadata extraction functionality
 pub struct MetadataExtractor;
 
@@ -94,6 +234,7 @@ impl MetadataExtractor {
                     twitterCard: {},
                     favicon: null,
                     metaTags: {},
+                    meta: {},
                     jsonLd: []
                 };
 
@@ -105,6 +246,11 @@ impl MetadataExtractor {
                     if (!name || !content) return;
 
                     result.metaTags[name] = content;
+                    if (result.meta[name]) {
+                        result.meta[name].push(content);
+                    } else {
+                        result.meta[name] = [content];
+                    }
 
                     // Standard meta
                     if (name === 'description') result.description = content;
@@ -132,6 +278,19 @@ impl MetadataExtractor {
                     result.canonical = canonical.getAttribute('href');
                 }
 
+                // Canonical, alternate, and pagination link relations
+                result.linkRelations = [];
+                document.querySelectorAll(
+                    'link[rel="canonical"], link[rel="alternate"], link[rel="prev"], link[rel="next"]'
+                ).forEach(link => {
+                    result.linkRelations.push({
+                        rel: link.getAttribute('rel'),
+                        href: link.getAttribute('href'),
+                        resolved: link.getAttribute('href') ? link.href : null,
+                        hreflang: link.getAttribute('hreflang')
+                    });
+                });
+
                 // Favicon
                 const favicon = document.querySelector('link[rel="icon"], link[rel="shortcut icon"]');
                 if (favicon) {
@@ -146,6 +305,20 @@ impl MetadataExtractor {
                     } catch (e) {}
                 });
 
+                // DOM breadcrumbs (fallback when no BreadcrumbList JSON-LD)
+                result.domBreadcrumbs = [];
+                const crumbContainer = document.querySelector(
+                    '[aria-label="breadcrumb"], nav.breadcrumb, nav.breadcrumbs, .breadcrumb, .breadcrumbs'
+                );
+                if (crumbContainer) {
+                    crumbContainer.querySelectorAll('a').forEach(a => {
+                        const name = a.innerText.trim();
+                        if (name) {
+                            result.domBreadcrumbs.push({ name, url: a.href || null });
+                        }
+                    });
+                }
+
                 return result;
             })()
         "#;
@@ -161,7 +334,7 @@ impl MetadataExtractor {
         let og = &result["openGraph"];
         let tw = &result["twitterCard"];
 
-        let metadata = PageMetadata {
+        let mut metadata = PageMetadata {
             title: result["title"].as_str().map(String::from),
             description: result["description"].as_str().map(String::from),
             canonical: result["canonical"].as_str().map(String::from),
@@ -183,6 +356,8 @@ impl MetadataExtractor {
                 og_type: og["type"].as_str().map(String::from),
                 site_name: og["site_name"].as_str().map(String::from),
                 locale: og["locale"].as_str().map(String::from),
+                video: Self::og_media_from_map(og, "video"),
+                audio: Self::og_media_from_map(og, "audio"),
             },
             twitter_card: TwitterCardData {
                 card: tw["card"].as_str().map(String::from),
@@ -201,9 +376,30 @@ impl MetadataExtractor {
                         .collect()
                 })
                 .unwrap_or_default(),
+            meta: Self::meta_map_from_value(&result["meta"]),
             json_ld: result["jsonLd"].as_array().cloned().unwrap_or_default(),
+            breadcrumbs: Vec::new(),
+            link_relations: Self::link_relations_from_entries(&result["linkRelations"]),
         };
 
+        // Prefer JSON-LD BreadcrumbList; fall back to DOM breadcrumb markup
+        metadata.breadcrumbs = Self::breadcrumbs_from_json_ld(&metadata.json_ld);
+        if metadata.breadcrumbs.is_empty() {
+            metadata.breadcrumbs = result["domBreadcrumbs"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| {
+                            v["name"].as_str().map(|name| BreadcrumbItem {
+                                name: name.to_string(),
+                                url: v["url"].as_str().map(String::from),
+                            })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+        }
+
         debug!(
             "Extracted metadata: title={:?}, description={:?}",
             metadata.title, metadata.description
@@ -240,6 +436,600 @@ impl MetadataExtractor {
             .clone()
             .or_else(|| metadata.twitter_card.image.clone())
     }
+
+    /// Build structured `og:video` / `og:audio` metadata from the raw
+    /// Open Graph map
+    ///
+    /// `kind` is `"video"` or `"audio"`. The bare property and its `:url`
+    /// sub-property are equivalent; `:url` wins when both appear. Returns
+    /// `None` when no sub-property was present at all.
+    pub fn og_media_from_map(og: &serde_json::Value, kind: &str) -> Option<OpenGraphMedia> {
+        let sub = |prop: &str| og[format!("{}:{}", kind, prop).as_str()].as_str();
+        let dimension = |prop: &str| sub(prop).and_then(|s| s.parse::<u32>().ok());
+
+        let media = OpenGraphMedia {
+            url: sub("url").or_else(|| og[kind].as_str()).map(String::from),
+            secure_url: sub("secure_url").map(String::from),
+            media_type: sub("type").map(String::from),
+            width: dimension("width"),
+            height: dimension("height"),
+        };
+        (media != OpenGraphMedia::default()).then_some(media)
+    }
+
+    /// Collapse the per-name accumulator from the page into meta values
+    ///
+    /// Single-element arrays become [`MetaValue::Single`]; repeated names
+    /// keep all their values in document order.
+    pub fn meta_map_from_value(value: &serde_json::Value) -> HashMap<String, MetaValue> {
+        let Some(obj) = value.as_object() else {
+            return HashMap::new();
+        };
+
+        obj.iter()
+            .filter_map(|(name, contents)| {
+                let mut items: Vec<String> = contents
+                    .as_array()?
+                    .iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect();
+                let value = match items.len() {
+                    0 => return None,
+                    1 => MetaValue::Single(items.remove(0)),
+                    _ => MetaValue::Multiple(items),
+                };
+                Some((name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Flatten a JSON-LD document, resolving `@id` references within it
+    ///
+    /// Returns the nodes of `@graph` (or the document itself when there is
+    /// no graph), with reference nodes — objects whose only key is `@id` —
+    /// replaced by the node they point to, up to [`MAX_JSON_LD_DEPTH`]
+    /// levels. Cycles are detected per resolution path and left as bare
+    /// `@id` references, so self-referential graphs terminate and every
+    /// node is returned exactly once.
+    pub fn flatten_json_ld(data: &serde_json::Value) -> Vec<serde_json::Value> {
+        let nodes: Vec<&serde_json::Value> = match data["@graph"].as_array() {
+            Some(graph) => graph.iter().collect(),
+            None => vec![data],
+        };
+
+        let index: HashMap<&str, &serde_json::Value> = nodes
+            .iter()
+            .filter_map(|node| node["@id"].as_str().map(|id| (id, *node)))
+            .collect();
+
+        nodes
+            .iter()
+            .map(|node| {
+                let mut visited = HashSet::new();
+                if let Some(id) = node["@id"].as_str() {
+                    visited.insert(id.to_string());
+                }
+                Self::resolve_json_ld_node(node, &index, &mut visited, MAX_JSON_LD_DEPTH)
+            })
+            .collect()
+    }
+
+    /// Resolve one node, inlining known `@id` references up to `depth`
+    fn resolve_json_ld_node(
+        node: &serde_json::Value,
+        index: &HashMap<&str, &serde_json::Value>,
+        visited: &mut HashSet<String>,
+        depth: usize,
+    ) -> serde_json::Value {
+        match node {
+            serde_json::Value::Object(map) => {
+                // A reference node points at another node by @id alone
+                if depth > 0 && map.len() == 1 {
+                    if let Some(id) = map.get("@id").and_then(|v| v.as_str()) {
+                        if let Some(target) = index.get(id) {
+                            if visited.insert(id.to_string()) {
+                                let resolved = Self::resolve_json_ld_node(
+                                    target,
+                                    index,
+                                    visited,
+                                    depth - 1,
+                                );
+                                visited.remove(id);
+                                return resolved;
+                            }
+                            // Cycle: keep the bare reference
+                            return node.clone();
+                        }
+                    }
+                }
+                serde_json::Value::Object(
+                    map.iter()
+                        .map(|(key, value)| {
+                            (
+                                key.clone(),
+                                Self::resolve_json_ld_node(value, index, visited, depth),
+                            )
+                        })
+                        .collect(),
+                )
+            }
+            serde_json::Value::Array(items) => serde_json::Value::Array(
+                items
+                    .iter()
+                    .map(|item| Self::resolve_json_ld_node(item, index, visited, depth))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Parse breadcrumbs from JSON-LD `BreadcrumbList` structured data
+    ///
+    /// Items are ordered by their `position` property. Handles both the
+    /// expanded form (`item` as an object with `@id`/`name`) and the compact
+    /// form (`item` as a URL string).
+    pub fn breadcrumbs_from_json_ld(json_ld: &[serde_json::Value]) -> Vec<BreadcrumbItem> {
+        for data in json_ld {
+            // BreadcrumbList may be top-level or inside an @graph array
+            let candidates: Vec<&serde_json::Value> = match data["@graph"].as_array() {
+                Some(graph) => graph.iter().collect(),
+                None => vec![data],
+            };
+
+            for candidate in candidates {
+                if candidate["@type"].as_str() != Some("BreadcrumbList") {
+                    continue;
+                }
+
+                let Some(elements) = candidate["itemListElement"].as_array() else {
+                    continue;
+                };
+
+                let mut items: Vec<(u64, BreadcrumbItem)> = elements
+                    .iter()
+                    .filter_map(|el| {
+                        let position = el["position"].as_u64()?;
+                        let name = el["name"]
+                            .as_str()
+                            .or_else(|| el["item"]["name"].as_str())?
+                            .to_string();
+                        let url = el["item"]
+                            .as_str()
+                            .or_else(|| el["item"]["@id"].as_str())
+                            .map(String::from);
+                        Some((position, BreadcrumbItem { name, url }))
+                    })
+                    .collect();
+
+                if !items.is_empty() {
+                    items.sort_by_key(|(position, _)| *position);
+                    return items.into_iter().map(|(_, item)| item).collect();
+                }
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// Fetch the page's best favicon as bytes
+    ///
+    /// Picks the highest-resolution `<link rel="icon">` variant (falling back
+    /// to `/favicon.ico` on http(s) pages) and fetches it from within the
+    /// page, so cookies and auth apply. Returns `Ok(None)` when the page
+    /// declares no icon and has no fallback, or when the fetch fails.
+    #[instrument(skip(page))]
+    pub async fn extract_favicon(page: &PageHandle) -> Result<Option<FaviconData>> {
+        info!("Extracting favicon bytes");
+
+        let script = r#"
+            (() => {
+                const candidates = [];
+                document.querySelectorAll('link[rel~="icon"]').forEach(link => {
+                    const href = link.getAttribute('href');
+                    if (!href) return;
+                    try {
+                        candidates.push({
+                            href: new URL(href, document.baseURI).href,
+                            sizes: link.getAttribute('sizes')
+                        });
+                    } catch (e) {}
+                });
+                return {
+                    candidates,
+                    fallback: location.origin.startsWith('http')
+                        ? location.origin + '/favicon.ico'
+                        : null
+                };
+            })()
+        "#;
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let candidates: Vec<IconCandidate> =
+            serde_json::from_value(result["candidates"].clone()).unwrap_or_default();
+
+        let url = match Self::best_icon_candidate(&candidates) {
+            Some(candidate) => candidate.href.clone(),
+            None => match result["fallback"].as_str() {
+                Some(fallback) => fallback.to_string(),
+                None => return Ok(None),
+            },
+        };
+
+        Self::fetch_favicon_bytes(page, &url).await
+    }
+
+    /// Fetch icon bytes from within the page and decode the response
+    async fn fetch_favicon_bytes(page: &PageHandle, url: &str) -> Result<Option<FaviconData>> {
+        // serde_json escaping gives us a valid JS string literal
+        let url_literal = serde_json::to_string(url).unwrap_or_else(|_| "\"\"".to_string());
+
+        let script = format!(
+            r#"
+            (async () => {{
+                try {{
+                    const response = await fetch({url_literal});
+                    if (!response.ok) return null;
+                    const blob = await response.blob();
+                    const bytes = new Uint8Array(await blob.arrayBuffer());
+                    if (bytes.length === 0) return null;
+
+                    let binary = '';
+                    for (let i = 0; i < bytes.length; i++) {{
+                        binary += String.fromCharCode(bytes[i]);
+                    }}
+
+                    let width = null, height = null;
+                    try {{
+                        const bitmap = await createImageBitmap(blob);
+                        width = bitmap.width;
+                        height = bitmap.height;
+                        bitmap.close();
+                    }} catch (e) {{}}
+
+                    return {{
+                        data: btoa(binary),
+                        mimeType: blob.type || null,
+                        width,
+                        height
+                    }};
+                }} catch (e) {{
+                    return null;
+                }}
+            }})()
+            "#
+        );
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let Some(data) = result["data"].as_str() else {
+            return Ok(None);
+        };
+
+        // Prefer the response content type; sniff magic bytes when absent
+        let mime_type = result["mimeType"]
+            .as_str()
+            .map(String::from)
+            .or_else(|| {
+                BASE64
+                    .decode(data)
+                    .ok()
+                    .and_then(|bytes| Self::sniff_image_mime(&bytes).map(String::from))
+            })
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        Ok(Some(FaviconData {
+            data: data.to_string(),
+            mime_type,
+            width: result["width"].as_u64().map(|w| w as u32),
+            height: result["height"].as_u64().map(|h| h as u32),
+        }))
+    }
+
+    /// Pick the highest-resolution icon candidate
+    ///
+    /// Candidates are scored by the largest area in their `sizes` attribute;
+    /// `"any"` (scalable, e.g. SVG) scores highest and a missing attribute
+    /// lowest. The first candidate wins ties.
+    pub fn best_icon_candidate(candidates: &[IconCandidate]) -> Option<&IconCandidate> {
+        let mut best: Option<(&IconCandidate, u64)> = None;
+
+        for candidate in candidates {
+            let score = Self::icon_size_score(candidate.sizes.as_deref());
+            let better = match best {
+                Some((_, existing)) => score > existing,
+                None => true,
+            };
+            if better {
+                best = Some((candidate, score));
+            }
+        }
+
+        best.map(|(candidate, _)| candidate)
+    }
+
+    /// Score a `sizes` attribute by its largest declared area
+    pub fn icon_size_score(sizes: Option<&str>) -> u64 {
+        let Some(sizes) = sizes else {
+            return 0;
+        };
+
+        sizes
+            .split_whitespace()
+            .map(|token| {
+                if token.eq_ignore_ascii_case("any") {
+                    return u64::MAX;
+                }
+                let Some((w, h)) = token.split_once(['x', 'X']) else {
+                    return 0;
+                };
+                match (w.parse::<u64>(), h.parse::<u64>()) {
+                    (Ok(w), Ok(h)) => w.saturating_mul(h),
+                    _ => 0,
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Build [`LinkRelations`] from the raw `<link>` entries the page script
+    /// collected
+    ///
+    /// Each entry carries `rel`, the raw `href`, the document-resolved
+    /// `resolved` URL, and `hreflang`. Declarations without a usable target
+    /// and duplicates of single-valued relations are reported as issues.
+    pub fn link_relations_from_entries(entries: &serde_json::Value) -> LinkRelations {
+        let mut relations = LinkRelations::default();
+        let Some(entries) = entries.as_array() else {
+            return relations;
+        };
+
+        for entry in entries {
+            let rel = entry["rel"].as_str().unwrap_or_default();
+            let resolved = entry["resolved"].as_str().map(String::from);
+
+            match rel {
+                "canonical" => {
+                    let Some(url) = resolved else {
+                        relations
+                            .issues
+                            .push("canonical link has no href".to_string());
+                        continue;
+                    };
+                    if let Some(existing) = &relations.canonical {
+                        if *existing != url {
+                            relations
+                                .issues
+                                .push(format!("duplicate canonical: {}", url));
+                        }
+                        continue;
+                    }
+                    relations.canonical = Some(url);
+                }
+                "alternate" => {
+                    // Alternates without hreflang (feeds, media types) are
+                    // out of scope here
+                    let Some(hreflang) = entry["hreflang"].as_str().filter(|h| !h.is_empty())
+                    else {
+                        continue;
+                    };
+                    let Some(url) = resolved else {
+                        relations
+                            .issues
+                            .push(format!("hreflang '{}' alternate has no href", hreflang));
+                        continue;
+                    };
+                    if relations.hreflang.contains_key(hreflang) {
+                        relations
+                            .issues
+                            .push(format!("duplicate hreflang '{}': {}", hreflang, url));
+                        continue;
+                    }
+                    relations.hreflang.insert(hreflang.to_string(), url);
+                }
+                "prev" | "next" => {
+                    let Some(url) = resolved else {
+                        relations
+                            .issues
+                            .push(format!("{} link has no href", rel));
+                        continue;
+                    };
+                    let slot = if rel == "prev" {
+                        &mut relations.prev
+                    } else {
+                        &mut relations.next
+                    };
+                    if slot.is_some() {
+                        relations.issues.push(format!("duplicate {}: {}", rel, url));
+                        continue;
+                    }
+                    *slot = Some(url);
+                }
+                _ => {}
+            }
+        }
+
+        relations
+    }
+
+    /// Diff the SEO-relevant fields of two metadata captures
+    ///
+    /// Reports every field that was added, removed, or changed between
+    /// `old` and `new`; identical fields produce no entry.
+    pub fn diff_metadata(old: &PageMetadata, new: &PageMetadata) -> MetadataDiff {
+        let mut diff = MetadataDiff::default();
+
+        let keywords = |meta: &PageMetadata| -> Option<String> {
+            if meta.keywords.is_empty() {
+                None
+            } else {
+                Some(meta.keywords.join(", "))
+            }
+        };
+
+        let fields: [(&str, Option<&String>, Option<&String>); 21] = [
+            ("title", old.title.as_ref(), new.title.as_ref()),
+            (
+                "description",
+                old.description.as_ref(),
+                new.description.as_ref(),
+            ),
+            ("canonical", old.canonical.as_ref(), new.canonical.as_ref()),
+            ("language", old.language.as_ref(), new.language.as_ref()),
+            ("author", old.author.as_ref(), new.author.as_ref()),
+            ("favicon", old.favicon.as_ref(), new.favicon.as_ref()),
+            (
+                "og:title",
+                old.open_graph.title.as_ref(),
+                new.open_graph.title.as_ref(),
+            ),
+            (
+                "og:description",
+                old.open_graph.description.as_ref(),
+                new.open_graph.description.as_ref(),
+            ),
+            (
+                "og:image",
+                old.open_graph.image.as_ref(),
+                new.open_graph.image.as_ref(),
+            ),
+            (
+                "og:url",
+                old.open_graph.url.as_ref(),
+                new.open_graph.url.as_ref(),
+            ),
+            (
+                "og:type",
+                old.open_graph.og_type.as_ref(),
+                new.open_graph.og_type.as_ref(),
+            ),
+            (
+                "og:site_name",
+                old.open_graph.site_name.as_ref(),
+                new.open_graph.site_name.as_ref(),
+            ),
+            (
+                "og:locale",
+                old.open_graph.locale.as_ref(),
+                new.open_graph.locale.as_ref(),
+            ),
+            // Media fields are compared by URL; sub-property changes
+            // without a URL change are not SEO-relevant
+            (
+                "og:video",
+                old.open_graph.video.as_ref().and_then(|m| m.url.as_ref()),
+                new.open_graph.video.as_ref().and_then(|m| m.url.as_ref()),
+            ),
+            (
+                "og:audio",
+                old.open_graph.audio.as_ref().and_then(|m| m.url.as_ref()),
+                new.open_graph.audio.as_ref().and_then(|m| m.url.as_ref()),
+            ),
+            (
+                "twitter:card",
+                old.twitter_card.card.as_ref(),
+                new.twitter_card.card.as_ref(),
+            ),
+            (
+                "twitter:title",
+                old.twitter_card.title.as_ref(),
+                new.twitter_card.title.as_ref(),
+            ),
+            (
+                "twitter:description",
+                old.twitter_card.description.as_ref(),
+                new.twitter_card.description.as_ref(),
+            ),
+            (
+                "twitter:image",
+                old.twitter_card.image.as_ref(),
+                new.twitter_card.image.as_ref(),
+            ),
+            (
+                "twitter:site",
+                old.twitter_card.site.as_ref(),
+                new.twitter_card.site.as_ref(),
+            ),
+            (
+                "twitter:creator",
+                old.twitter_card.creator.as_ref(),
+                new.twitter_card.creator.as_ref(),
+            ),
+        ];
+
+        for (field, old_value, new_value) in fields {
+            Self::diff_field(&mut diff.changes, field, old_value, new_value);
+        }
+
+        // Keywords are a list; compared as one joined field
+        let old_keywords = keywords(old);
+        let new_keywords = keywords(new);
+        Self::diff_field(
+            &mut diff.changes,
+            "keywords",
+            old_keywords.as_ref(),
+            new_keywords.as_ref(),
+        );
+
+        diff
+    }
+
+    /// Record one field's difference, if any
+    fn diff_field(
+        changes: &mut Vec<MetadataChange>,
+        field: &str,
+        old: Option<&String>,
+        new: Option<&String>,
+    ) {
+        let kind = match (old, new) {
+            (None, Some(_)) => MetadataChangeKind::Added,
+            (Some(_), None) => MetadataChangeKind::Removed,
+            (Some(a), Some(b)) if a != b => MetadataChangeKind::Changed,
+            _ => return,
+        };
+
+        changes.push(MetadataChange {
+            field: field.to_string(),
+            kind,
+            old: old.cloned(),
+            new: new.cloned(),
+        });
+    }
+
+    /// Detect an image MIME type from magic bytes
+    pub fn sniff_image_mime(bytes: &[u8]) -> Option<&'static str> {
+        if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+            return Some("image/png");
+        }
+        if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some("image/jpeg");
+        }
+        if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            return Some("image/gif");
+        }
+        if bytes.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+            return Some("image/x-icon");
+        }
+        if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+            return Some("image/webp");
+        }
+        if bytes.starts_with(b"<svg") || bytes.starts_with(b"<?xml") {
+            return Some("image/svg+xml");
+        }
+        None
+    }
 }
 
 #[cfg(test)]
@@ -276,6 +1066,153 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_link_relations_collects_canonical_hreflang_and_pagination() {
+        let entries = serde_json::json!([
+            { "rel": "canonical", "href": "/page", "resolved": "https://example.com/page", "hreflang": null },
+            { "rel": "alternate", "href": "/page", "resolved": "https://example.com/page", "hreflang": "en" },
+            { "rel": "alternate", "href": "/de/page", "resolved": "https://example.com/de/page", "hreflang": "de" },
+            { "rel": "next", "href": "/page?p=2", "resolved": "https://example.com/page?p=2", "hreflang": null },
+        ]);
+
+        let relations = MetadataExtractor::link_relations_from_entries(&entries);
+        assert_eq!(
+            relations.canonical.as_deref(),
+            Some("https://example.com/page")
+        );
+        assert_eq!(relations.hreflang.len(), 2);
+        assert_eq!(
+            relations.hreflang["de"].as_str(),
+            "https://example.com/de/page"
+        );
+        assert_eq!(
+            relations.next.as_deref(),
+            Some("https://example.com/page?p=2")
+        );
+        assert!(relations.prev.is_none());
+        assert!(relations.issues.is_empty());
+    }
+
+    #[test]
+    fn test_link_relations_reports_duplicate_canonical() {
+        let entries = serde_json::json!([
+            { "rel": "canonical", "href": "/a", "resolved": "https://example.com/a", "hreflang": null },
+            { "rel": "canonical", "href": "/b", "resolved": "https://example.com/b", "hreflang": null },
+        ]);
+
+        let relations = MetadataExtractor::link_relations_from_entries(&entries);
+        // First declaration wins; the conflicting one is reported
+        assert_eq!(relations.canonical.as_deref(), Some("https://example.com/a"));
+        assert_eq!(relations.issues.len(), 1);
+        assert!(relations.issues[0].contains("duplicate canonical"));
+    }
+
+    #[test]
+    fn test_link_relations_reports_malformed_entries() {
+        let entries = serde_json::json!([
+            { "rel": "canonical", "href": null, "resolved": null, "hreflang": null },
+            { "rel": "alternate", "href": null, "resolved": null, "hreflang": "fr" },
+        ]);
+
+        let relations = MetadataExtractor::link_relations_from_entries(&entries);
+        assert!(relations.canonical.is_none());
+        assert!(relations.hreflang.is_empty());
+        assert_eq!(relations.issues.len(), 2);
+        assert!(relations.issues[0].contains("canonical"));
+        assert!(relations.issues[1].contains("'fr'"));
+    }
+
+    #[test]
+    fn test_link_relations_ignores_feed_alternates() {
+        let entries = serde_json::json!([
+            { "rel": "alternate", "href": "/feed.xml", "resolved": "https://example.com/feed.xml", "hreflang": null },
+        ]);
+
+        let relations = MetadataExtractor::link_relations_from_entries(&entries);
+        assert!(relations.hreflang.is_empty());
+        assert!(relations.issues.is_empty());
+    }
+
+    #[test]
+    fn test_diff_metadata_reports_changed_and_removed() {
+        let mut old = PageMetadata {
+            title: Some("Old Title".to_string()),
+            description: Some("Same description".to_string()),
+            ..Default::default()
+        };
+        old.open_graph.image = Some("https://example.com/old.jpg".to_string());
+
+        let mut new = old.clone();
+        new.title = Some("New Title".to_string());
+        new.open_graph.image = None;
+
+        let diff = MetadataExtractor::diff_metadata(&old, &new);
+        assert_eq!(diff.changes.len(), 2);
+
+        assert_eq!(diff.changes[0].field, "title");
+        assert_eq!(diff.changes[0].kind, MetadataChangeKind::Changed);
+        assert_eq!(diff.changes[0].old.as_deref(), Some("Old Title"));
+        assert_eq!(diff.changes[0].new.as_deref(), Some("New Title"));
+
+        assert_eq!(diff.changes[1].field, "og:image");
+        assert_eq!(diff.changes[1].kind, MetadataChangeKind::Removed);
+        assert_eq!(
+            diff.changes[1].old.as_deref(),
+            Some("https://example.com/old.jpg")
+        );
+        assert_eq!(diff.changes[1].new, None);
+    }
+
+    #[test]
+    fn test_diff_metadata_reports_added_fields() {
+        let old = PageMetadata::default();
+        let mut new = PageMetadata {
+            canonical: Some("https://example.com/".to_string()),
+            ..Default::default()
+        };
+        new.twitter_card.card = Some("summary".to_string());
+
+        let diff = MetadataExtractor::diff_metadata(&old, &new);
+        assert_eq!(diff.changes.len(), 2);
+        assert!(diff
+            .changes
+            .iter()
+            .all(|c| c.kind == MetadataChangeKind::Added));
+        assert_eq!(diff.changes[0].field, "canonical");
+        assert_eq!(diff.changes[1].field, "twitter:card");
+    }
+
+    #[test]
+    fn test_diff_metadata_identical_captures_are_empty() {
+        let mut meta = PageMetadata {
+            title: Some("Title".to_string()),
+            keywords: vec!["a".to_string(), "b".to_string()],
+            ..Default::default()
+        };
+        meta.open_graph.url = Some("https://example.com/".to_string());
+
+        let diff = MetadataExtractor::diff_metadata(&meta, &meta.clone());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_metadata_compares_keywords_as_one_field() {
+        let old = PageMetadata {
+            keywords: vec!["rust".to_string()],
+            ..Default::default()
+        };
+        let new = PageMetadata {
+            keywords: vec!["rust".to_string(), "web".to_string()],
+            ..Default::default()
+        };
+
+        let diff = MetadataExtractor::diff_metadata(&old, &new);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].field, "keywords");
+        assert_eq!(diff.changes[0].kind, MetadataChangeKind::Changed);
+        assert_eq!(diff.changes[0].new.as_deref(), Some("rust, web"));
+    }
+
     #[test]
     fn test_open_graph_data() {
         let og = OpenGraphData {
@@ -286,11 +1223,233 @@ mod tests {
             og_type: Some("article".to_string()),
             site_name: Some("Example".to_string()),
             locale: Some("en_US".to_string()),
+            video: None,
+            audio: None,
         };
 
         assert_eq!(og.og_type, Some("article".to_string()));
     }
 
+    #[test]
+    fn test_og_media_from_map_populates_video() {
+        let og = serde_json::json!({
+            "video": "https://example.com/movie.mp4",
+            "video:secure_url": "https://secure.example.com/movie.mp4",
+            "video:type": "video/mp4",
+            "video:width": "1280",
+            "video:height": "720",
+        });
+
+        let video = MetadataExtractor::og_media_from_map(&og, "video").unwrap();
+        assert_eq!(video.url.as_deref(), Some("https://example.com/movie.mp4"));
+        assert_eq!(
+            video.secure_url.as_deref(),
+            Some("https://secure.example.com/movie.mp4")
+        );
+        assert_eq!(video.media_type.as_deref(), Some("video/mp4"));
+        assert_eq!(video.width, Some(1280));
+        assert_eq!(video.height, Some(720));
+
+        assert!(MetadataExtractor::og_media_from_map(&og, "audio").is_none());
+    }
+
+    #[test]
+    fn test_og_media_url_sub_property_wins() {
+        let og = serde_json::json!({
+            "audio": "https://example.com/a.mp3",
+            "audio:url": "https://example.com/b.mp3",
+            "audio:width": "not a number",
+        });
+
+        let audio = MetadataExtractor::og_media_from_map(&og, "audio").unwrap();
+        assert_eq!(audio.url.as_deref(), Some("https://example.com/b.mp3"));
+        assert_eq!(audio.width, None);
+    }
+
+    #[test]
+    fn test_og_media_omitted_from_serialization_when_absent() {
+        let og = OpenGraphData::default();
+        let json = serde_json::to_string(&og).unwrap();
+        assert!(!json.contains("video"));
+        assert!(!json.contains("audio"));
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_json_ld() {
+        let json_ld = vec![serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "BreadcrumbList",
+            "itemListElement": [
+                {
+                    "@type": "ListItem",
+                    "position": 2,
+                    "name": "Widgets",
+                    "item": "https://example.com/products/widgets"
+                },
+                {
+                    "@type": "ListItem",
+                    "position": 1,
+                    "name": "Products",
+                    "item": "https://example.com/products"
+                },
+                {
+                    "@type": "ListItem",
+                    "position": 3,
+                    "name": "Blue Widget"
+                }
+            ]
+        })];
+
+        let crumbs = MetadataExtractor::breadcrumbs_from_json_ld(&json_ld);
+
+        assert_eq!(crumbs.len(), 3);
+        // Ordered by position, not document order
+        assert_eq!(crumbs[0].name, "Products");
+        assert_eq!(
+            crumbs[0].url,
+            Some("https://example.com/products".to_string())
+        );
+        assert_eq!(crumbs[1].name, "Widgets");
+        // Final crumb (current page) has no URL
+        assert_eq!(crumbs[2].name, "Blue Widget");
+        assert!(crumbs[2].url.is_none());
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_json_ld_expanded_item() {
+        let json_ld = vec![serde_json::json!({
+            "@type": "BreadcrumbList",
+            "itemListElement": [{
+                "@type": "ListItem",
+                "position": 1,
+                "item": {
+                    "@id": "https://example.com/",
+                    "name": "Home"
+                }
+            }]
+        })];
+
+        let crumbs = MetadataExtractor::breadcrumbs_from_json_ld(&json_ld);
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].name, "Home");
+        assert_eq!(crumbs[0].url, Some("https://example.com/".to_string()));
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_json_ld_graph() {
+        let json_ld = vec![serde_json::json!({
+            "@context": "https://schema.org",
+            "@graph": [
+                { "@type": "WebPage", "name": "Irrelevant" },
+                {
+                    "@type": "BreadcrumbList",
+                    "itemListElement": [{
+                        "@type": "ListItem",
+                        "position": 1,
+                        "name": "Home",
+                        "item": "https://example.com/"
+                    }]
+                }
+            ]
+        })];
+
+        let crumbs = MetadataExtractor::breadcrumbs_from_json_ld(&json_ld);
+        assert_eq!(crumbs.len(), 1);
+        assert_eq!(crumbs[0].name, "Home");
+    }
+
+    #[test]
+    fn test_breadcrumbs_from_json_ld_none() {
+        let json_ld = vec![serde_json::json!({ "@type": "Article", "name": "Post" })];
+        assert!(MetadataExtractor::breadcrumbs_from_json_ld(&json_ld).is_empty());
+        assert!(MetadataExtractor::breadcrumbs_from_json_ld(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_best_icon_candidate_prefers_largest() {
+        let candidates = vec![
+            IconCandidate {
+                href: "https://example.com/icon-16.png".to_string(),
+                sizes: Some("16x16".to_string()),
+            },
+            IconCandidate {
+                href: "https://example.com/icon-64.png".to_string(),
+                sizes: Some("32x32 64x64".to_string()),
+            },
+            IconCandidate {
+                href: "https://example.com/favicon.ico".to_string(),
+                sizes: None,
+            },
+        ];
+
+        let best = MetadataExtractor::best_icon_candidate(&candidates).unwrap();
+        assert_eq!(best.href, "https://example.com/icon-64.png");
+    }
+
+    #[test]
+    fn test_best_icon_candidate_any_wins() {
+        let candidates = vec![
+            IconCandidate {
+                href: "https://example.com/icon-512.png".to_string(),
+                sizes: Some("512x512".to_string()),
+            },
+            IconCandidate {
+                href: "https://example.com/icon.svg".to_string(),
+                sizes: Some("any".to_string()),
+            },
+        ];
+
+        let best = MetadataExtractor::best_icon_candidate(&candidates).unwrap();
+        assert_eq!(best.href, "https://example.com/icon.svg");
+    }
+
+    #[test]
+    fn test_best_icon_candidate_first_wins_ties() {
+        let candidates = vec![
+            IconCandidate {
+                href: "https://example.com/a.ico".to_string(),
+                sizes: None,
+            },
+            IconCandidate {
+                href: "https://example.com/b.ico".to_string(),
+                sizes: None,
+            },
+        ];
+
+        let best = MetadataExtractor::best_icon_candidate(&candidates).unwrap();
+        assert_eq!(best.href, "https://example.com/a.ico");
+        assert!(MetadataExtractor::best_icon_candidate(&[]).is_none());
+    }
+
+    #[test]
+    fn test_icon_size_score() {
+        assert_eq!(MetadataExtractor::icon_size_score(None), 0);
+        assert_eq!(MetadataExtractor::icon_size_score(Some("32x32")), 1024);
+        assert_eq!(
+            MetadataExtractor::icon_size_score(Some("16x16 48x48")),
+            2304
+        );
+        assert_eq!(MetadataExtractor::icon_size_score(Some("any")), u64::MAX);
+        assert_eq!(MetadataExtractor::icon_size_score(Some("garbage")), 0);
+    }
+
+    #[test]
+    fn test_sniff_image_mime() {
+        assert_eq!(
+            MetadataExtractor::sniff_image_mime(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A]),
+            Some("image/png")
+        );
+        assert_eq!(
+            MetadataExtractor::sniff_image_mime(&[0x00, 0x00, 0x01, 0x00, 0x01, 0x00]),
+            Some("image/x-icon")
+        );
+        assert_eq!(
+            MetadataExtractor::sniff_image_mime(b"<svg xmlns=\"http://www.w3.org/2000/svg\">"),
+            Some("image/svg+xml")
+        );
+        assert_eq!(MetadataExtractor::sniff_image_mime(b"not an image"), None);
+    }
+
     #[test]
     fn test_twitter_card_data() {
         let tw = TwitterCardData {
@@ -304,4 +1463,132 @@ mod tests {
 
         assert_eq!(tw.card, Some("summary_large_image".to_string()));
     }
+
+    #[test]
+    fn test_flatten_json_ld_resolves_references() {
+        let data = serde_json::json!({
+            "@graph": [
+                { "@id": "#article", "@type": "Article", "author": { "@id": "#person" } },
+                { "@id": "#person", "@type": "Person", "name": "Jane Doe" }
+            ]
+        });
+
+        let flattened = MetadataExtractor::flatten_json_ld(&data);
+        assert_eq!(flattened.len(), 2);
+        assert_eq!(flattened[0]["author"]["name"], "Jane Doe");
+        assert_eq!(flattened[1]["name"], "Jane Doe");
+    }
+
+    #[test]
+    fn test_flatten_json_ld_self_reference_terminates() {
+        let data = serde_json::json!({
+            "@graph": [
+                { "@id": "#node", "@type": "Thing", "sameAs": { "@id": "#node" } }
+            ]
+        });
+
+        let flattened = MetadataExtractor::flatten_json_ld(&data);
+        assert_eq!(flattened.len(), 1);
+        // The cyclic reference stays a bare @id instead of recursing
+        assert_eq!(flattened[0]["sameAs"]["@id"], "#node");
+        assert!(flattened[0]["sameAs"]["sameAs"].is_null());
+    }
+
+    #[test]
+    fn test_flatten_json_ld_two_node_cycle_terminates() {
+        let data = serde_json::json!({
+            "@graph": [
+                { "@id": "#a", "@type": "Thing", "related": { "@id": "#b" } },
+                { "@id": "#b", "@type": "Thing", "related": { "@id": "#a" } }
+            ]
+        });
+
+        let flattened = MetadataExtractor::flatten_json_ld(&data);
+        assert_eq!(flattened.len(), 2);
+        // Each node inlines the other one level deep, then the back-edge
+        // stays a reference
+        assert_eq!(flattened[0]["related"]["@id"], "#b");
+        assert_eq!(flattened[0]["related"]["related"]["@id"], "#a");
+        assert!(flattened[0]["related"]["related"]["related"].is_null());
+        assert_eq!(flattened[1]["related"]["@id"], "#a");
+    }
+
+    #[test]
+    fn test_flatten_json_ld_without_graph_passes_through() {
+        let data = serde_json::json!({ "@type": "Article", "headline": "Test" });
+        let flattened = MetadataExtractor::flatten_json_ld(&data);
+        assert_eq!(flattened, vec![data]);
+    }
+
+    #[test]
+    fn test_flatten_json_ld_respects_depth_cap() {
+        // A reference chain longer than the cap: node0 → node1 → … → node11
+        let mut graph = Vec::new();
+        for i in 0..12 {
+            graph.push(serde_json::json!({
+                "@id": format!("#node{}", i),
+                "next": { "@id": format!("#node{}", i + 1) }
+            }));
+        }
+        let data = serde_json::json!({ "@graph": graph });
+
+        let flattened = MetadataExtractor::flatten_json_ld(&data);
+        let mut cursor = &flattened[0];
+        let mut inlined = 0;
+        while !cursor["next"]["next"].is_null() {
+            cursor = &cursor["next"];
+            inlined += 1;
+        }
+        assert!(inlined <= MAX_JSON_LD_DEPTH);
+    }
+
+    #[test]
+    fn test_meta_map_collects_all_names() {
+        let value = serde_json::json!({
+            "author": ["Jane Doe"],
+            "robots": ["index, follow"],
+            "x-custom-tag": ["custom value"]
+        });
+
+        let meta = MetadataExtractor::meta_map_from_value(&value);
+        assert_eq!(meta.len(), 3);
+        assert_eq!(
+            meta["author"],
+            MetaValue::Single("Jane Doe".to_string())
+        );
+        assert_eq!(
+            meta["robots"],
+            MetaValue::Single("index, follow".to_string())
+        );
+        assert_eq!(
+            meta["x-custom-tag"],
+            MetaValue::Single("custom value".to_string())
+        );
+    }
+
+    #[test]
+    fn test_meta_map_keeps_duplicates_as_arrays() {
+        let value = serde_json::json!({
+            "article:tag": ["rust", "browser"],
+            "description": ["only one"]
+        });
+
+        let meta = MetadataExtractor::meta_map_from_value(&value);
+        assert_eq!(
+            meta["article:tag"],
+            MetaValue::Multiple(vec!["rust".to_string(), "browser".to_string()])
+        );
+        assert_eq!(meta["description"], MetaValue::Single("only one".to_string()));
+    }
+
+    #[test]
+    fn test_meta_value_serializes_untagged() {
+        let single = serde_json::to_value(MetaValue::Single("a".to_string())).unwrap();
+        assert_eq!(single, serde_json::json!("a"));
+
+        let multiple =
+            serde_json::to_value(MetaValue::Multiple(vec!["a".to_string(), "b".to_string()]))
+                .unwrap();
+        assert_eq!(multiple, serde_json::json!(["a", "b"]));
+    }
 }
@@ -3,10 +3,50 @@
 //! This module provides intelligent content extraction from web pages,
 //! including main content, metadata, and link extraction.
 
+pub mod cache;
+pub mod classify;
 pub mod content;
+pub mod dates;
+pub mod dom;
+pub mod forms;
+pub mod images;
+pub mod link_check;
 pub mod links;
 pub mod metadata;
+pub mod pagination;
+pub mod resources;
+pub mod search;
+pub mod sitemap;
+pub mod tables;
 
-pub use content::{ContentExtractor, ExtractedContent};
+pub use cache::{content_hash, ExtractionCache, DEFAULT_EXTRACTION_CACHE_CAPACITY};
+pub use classify::{ClassCandidate, ClassSignals, PageClass, PageClassification, PageClassifier};
+pub use content::{
+    BlockProvenance, ContentExtractor, ContentLink, ExtractedContent, OutlineEntry,
+    VisibleTextOptions, COMMENT_SECTION_SELECTORS, DEFAULT_READING_WPM, DEFAULT_SCROLL_SETTLE_MS,
+};
+pub use dates::{DateExtractor, DateOptions, ExtractedDate};
+pub use dom::{DomNode, DomTree, DomTreeExtractor, DomTreeOptions};
+pub use forms::{ExtractedForm, FormExtractor, FormField, SelectOption};
+pub use images::{
+    ImageRegion, ImageRegionExtractor, ImageRegionOptions, PrimaryImage, DEFAULT_IMAGE_SELECTORS,
+    DEFAULT_MIN_REGION_SIZE,
+};
+pub use link_check::{
+    LinkCheckOptions, LinkCheckResult, LinkCheckSession, LinkChecker, DEFAULT_LINK_CHECK_TTL_SECS,
+    DEFAULT_SESSION_CONCURRENCY,
+};
 pub use links::{ExtractedLink, LinkExtractor, LinkType};
-pub use metadata::{MetadataExtractor, OpenGraphData, PageMetadata, TwitterCardData};
+pub use metadata::{
+    BreadcrumbItem, FaviconData, IconCandidate, LinkRelations, MetaValue, MetadataChange,
+    MetadataChangeKind, MetadataDiff, MetadataExtractor, OpenGraphData, OpenGraphMedia,
+    PageMetadata, TwitterCardData, MAX_JSON_LD_DEPTH,
+};
+pub use pagination::{PageLink, PaginationDetector, PaginationInfo};
+pub use resources::{ExtractedResource, ResourceExtractor, ResourceKind, ResourceOptions};
+pub use search::{SearchMatch, SearchOptions, TextSearcher};
+pub use sitemap::{
+    SitemapDocument, SitemapEntry, SitemapFetcher, SitemapOptions, DEFAULT_MAX_SITEMAPS,
+    DEFAULT_MAX_SITEMAP_URLS,
+};
+pub use tables::{ExtractedTable, TableExtractor};
@@ -0,0 +1,215 @@
+//! Pagination structure detection
+//!
+//! Crawlers paging through a listing need to know where "next" lives. This
+//! module inspects `rel=next/prev` links, common pagination containers with
+//! numbered page links, and load-more / infinite-scroll affordances, and
+//! reports them as one structure.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// A numbered page link inside a pagination control
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageLink {
+    /// Visible label, e.g. `2`
+    pub label: String,
+    /// Absolute URL of the page
+    pub url: String,
+}
+
+/// Detected pagination structure of a listing page
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaginationInfo {
+    /// URL of the next page, when one was found
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_url: Option<String>,
+    /// URL of the previous page, when one was found
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_url: Option<String>,
+    /// Numbered page links in document order
+    #[serde(default)]
+    pub page_numbers: Vec<PageLink>,
+    /// True when the page appears to load more content on scroll or via a
+    /// load-more button instead of (or in addition to) links
+    pub infinite_scroll: bool,
+}
+
+impl PaginationInfo {
+    /// True when nothing pagination-like was found
+    pub fn is_empty(&self) -> bool {
+        self.next_url.is_none()
+            && self.prev_url.is_none()
+            && self.page_numbers.is_empty()
+            && !self.infinite_scroll
+    }
+}
+
+/// Pagination detection functionality
+pub struct PaginationDetector;
+
+impl PaginationDetector {
+    /// Detect the pagination structure of the current page
+    ///
+    /// `rel=next/prev` (on `<link>` or `<a>`) wins for next/previous URLs;
+    /// otherwise anchors with next/previous-style text or classes are used.
+    /// Numbered links come from the usual pagination containers.
+    #[instrument(skip(page))]
+    pub async fn detect(page: &PageHandle) -> Result<PaginationInfo> {
+        info!("Detecting pagination structure");
+
+        let script = r#"
+            (() => {
+                const href = (el) => (el && el.href) ? el.href : null;
+
+                // rel attributes are the explicit, reliable signal
+                let next = href(document.querySelector('link[rel~="next"], a[rel~="next"]'));
+                let prev = href(document.querySelector('link[rel~="prev"], a[rel~="prev"]'));
+
+                const containers = document.querySelectorAll(
+                    'nav[aria-label*="pag" i], .pagination, .pager, .page-numbers, [class*="pagination"]'
+                );
+                const pageNumbers = [];
+                const seen = new Set();
+                for (const container of containers) {
+                    for (const a of container.querySelectorAll('a[href]')) {
+                        const label = a.innerText.trim();
+                        if (!/^\d+$/.test(label)) continue;
+                        if (seen.has(a.href)) continue;
+                        seen.add(a.href);
+                        pageNumbers.push({ label, url: a.href });
+                    }
+                }
+
+                // Fall back to next/previous-looking anchors
+                if (!next || !prev) {
+                    for (const a of document.querySelectorAll('a[href]')) {
+                        const text = a.innerText.trim().toLowerCase();
+                        const cls = a.className && a.className.toLowerCase ?
+                            a.className.toLowerCase() : '';
+                        if (!next && (/^(next|older)\b/.test(text) || text === '»' ||
+                            text === '›' || cls.includes('next'))) {
+                            next = a.href;
+                        }
+                        if (!prev && (/^(prev|previous|newer)\b/.test(text) || text === '«' ||
+                            text === '‹' || cls.includes('prev'))) {
+                            prev = a.href;
+                        }
+                    }
+                }
+
+                // Load-more buttons and scroll sentinels mean more content
+                // arrives without a URL change
+                let infiniteScroll = !!document.querySelector(
+                    '[data-infinite-scroll], .infinite-scroll, [class*="load-more"], [data-load-more]'
+                );
+                if (!infiniteScroll) {
+                    for (const el of document.querySelectorAll('button, a')) {
+                        if (/\b(load|show)\s+more\b/i.test(el.innerText)) {
+                            infiniteScroll = true;
+                            break;
+                        }
+                    }
+                }
+
+                return { nextUrl: next, prevUrl: prev, pageNumbers, infiniteScroll };
+            })()
+        "#;
+
+        let result: serde_json::Value = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let info = Self::info_from_value(&result);
+        debug!(
+            "Pagination: next={:?}, {} numbered links, infinite_scroll={}",
+            info.next_url,
+            info.page_numbers.len(),
+            info.infinite_scroll
+        );
+        Ok(info)
+    }
+
+    /// Build a [`PaginationInfo`] from the JSON produced by the page script
+    pub fn info_from_value(value: &serde_json::Value) -> PaginationInfo {
+        let link_from = |entry: &serde_json::Value| -> Option<PageLink> {
+            Some(PageLink {
+                label: entry.get("label")?.as_str()?.to_string(),
+                url: entry.get("url")?.as_str()?.to_string(),
+            })
+        };
+
+        PaginationInfo {
+            next_url: value
+                .get("nextUrl")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            prev_url: value
+                .get("prevUrl")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            page_numbers: value
+                .get("pageNumbers")
+                .and_then(|v| v.as_array())
+                .map(|entries| entries.iter().filter_map(link_from).collect())
+                .unwrap_or_default(),
+            infinite_scroll: value
+                .get("infiniteScroll")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_info_from_value_full() {
+        let info = PaginationDetector::info_from_value(&json!({
+            "nextUrl": "https://example.com/page/3",
+            "prevUrl": "https://example.com/page/1",
+            "pageNumbers": [
+                {"label": "1", "url": "https://example.com/page/1"},
+                {"label": "2", "url": "https://example.com/page/2"},
+                {"label": "3", "url": "https://example.com/page/3"},
+            ],
+            "infiniteScroll": false,
+        }));
+
+        assert_eq!(info.next_url.as_deref(), Some("https://example.com/page/3"));
+        assert_eq!(info.prev_url.as_deref(), Some("https://example.com/page/1"));
+        assert_eq!(info.page_numbers.len(), 3);
+        assert_eq!(info.page_numbers[1].label, "2");
+        assert!(!info.infinite_scroll);
+        assert!(!info.is_empty());
+    }
+
+    #[test]
+    fn test_info_from_value_infinite_scroll_only() {
+        let info = PaginationDetector::info_from_value(&json!({
+            "nextUrl": null,
+            "prevUrl": null,
+            "pageNumbers": [],
+            "infiniteScroll": true,
+        }));
+
+        assert_eq!(info.next_url, None);
+        assert!(info.page_numbers.is_empty());
+        assert!(info.infinite_scroll);
+        assert!(!info.is_empty());
+    }
+
+    #[test]
+    fn test_info_from_value_empty() {
+        let info = PaginationDetector::info_from_value(&json!({}));
+        assert!(info.is_empty());
+    }
+}
@@ -0,0 +1,415 @@
+//! Downloadable resource extraction
+//!
+//! This module scans pages for links to downloadable files (documents,
+//! archives, audio, video, images) and categorizes them by kind.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Kind of downloadable resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResourceKind {
+    /// Document (PDF, Word, Excel, PowerPoint, text)
+    Document,
+    /// Archive (zip, tar, rar, 7z)
+    Archive,
+    /// Audio file
+    Audio,
+    /// Video file
+    Video,
+    /// Image file
+    Image,
+}
+
+/// A downloadable resource found on a page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedResource {
+    /// The resource URL (resolved to absolute)
+    pub url: String,
+    /// Kind of resource
+    pub kind: ResourceKind,
+    /// Filename derived from the URL path
+    pub filename: String,
+    /// Size in bytes (resolved via HEAD request, if requested and available)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+}
+
+/// Options for resource extraction
+#[derive(Debug, Clone, Default)]
+pub struct ResourceOptions {
+    /// Resolve file sizes via HEAD requests (default: false)
+    pub resolve_sizes: bool,
+    /// Timeout per HEAD request in milliseconds (default: 3000)
+    pub head_timeout_ms: Option<u64>,
+    /// Also scan computed styles of visible elements for CSS
+    /// `background-image` URLs (default: false)
+    pub include_css_backgrounds: bool,
+}
+
+/// Downloadable resource extraction functionality
+pub struct ResourceExtractor;
+
+impl ResourceExtractor {
+    /// Extract downloadable resources from the page
+    #[instrument(skip(page))]
+    pub async fn extract(page: &PageHandle) -> Result<Vec<ExtractedResource>> {
+        Self::extract_with_options(page, &ResourceOptions::default()).await
+    }
+
+    /// Extract downloadable resources with custom options
+    #[instrument(skip(page, options))]
+    pub async fn extract_with_options(
+        page: &PageHandle,
+        options: &ResourceOptions,
+    ) -> Result<Vec<ExtractedResource>> {
+        info!("Extracting downloadable resources");
+
+        let script = r#"
+            (() => {
+                const urls = [];
+                const seen = new Set();
+                const baseUrl = window.location.href;
+
+                const push = (href) => {
+                    if (!href) return;
+                    try {
+                        const url = new URL(href, baseUrl).href;
+                        if (!url.startsWith('http') || seen.has(url)) return;
+                        seen.add(url);
+                        urls.push(url);
+                    } catch (e) {}
+                };
+
+                document.querySelectorAll('a[href], [download]').forEach((el) => {
+                    push(el.getAttribute('href'));
+                });
+                document.querySelectorAll('audio[src], video[src], source[src], embed[src]').forEach((el) => {
+                    push(el.getAttribute('src'));
+                });
+                document.querySelectorAll('object[data]').forEach((el) => {
+                    push(el.getAttribute('data'));
+                });
+
+                return urls;
+            })()
+        "#;
+
+        let urls: Vec<String> = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let mut resources: Vec<ExtractedResource> = urls
+            .into_iter()
+            .filter_map(|url| {
+                let kind = Self::classify_url(&url)?;
+                let filename = Self::filename_from_url(&url);
+                Some(ExtractedResource {
+                    url,
+                    kind,
+                    filename,
+                    size: None,
+                })
+            })
+            .collect();
+
+        if options.include_css_backgrounds {
+            Self::extract_css_backgrounds(page, &mut resources).await?;
+        }
+
+        if options.resolve_sizes {
+            let timeout = Duration::from_millis(options.head_timeout_ms.unwrap_or(3000));
+            Self::resolve_sizes(&mut resources, timeout).await;
+        }
+
+        debug!("Extracted {} downloadable resources", resources.len());
+        Ok(resources)
+    }
+
+    /// Append CSS `background-image` URLs of visible elements to `resources`
+    ///
+    /// Computed styles already carry absolute `url(...)` values, so only
+    /// parsing happens on this side. Background URLs without a recognized
+    /// image extension are still reported as images: that is what a
+    /// background renders as.
+    async fn extract_css_backgrounds(
+        page: &PageHandle,
+        resources: &mut Vec<ExtractedResource>,
+    ) -> Result<()> {
+        let script = r#"
+            (() => {
+                const values = [];
+                const seen = new Set();
+                document.querySelectorAll('*').forEach((el) => {
+                    const rect = el.getBoundingClientRect();
+                    if (rect.width === 0 || rect.height === 0) return;
+                    const value = getComputedStyle(el).backgroundImage;
+                    if (!value || value === 'none' || seen.has(value)) return;
+                    seen.add(value);
+                    values.push(value);
+                });
+                return values;
+            })()
+        "#;
+
+        let values: Vec<String> = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let mut seen: std::collections::HashSet<String> =
+            resources.iter().map(|r| r.url.clone()).collect();
+        for value in values {
+            for url in Self::css_image_urls(&value) {
+                if !seen.insert(url.clone()) {
+                    continue;
+                }
+                let kind = Self::classify_url(&url).unwrap_or(ResourceKind::Image);
+                let filename = Self::filename_from_url(&url);
+                resources.push(ExtractedResource {
+                    url,
+                    kind,
+                    filename,
+                    size: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Extract image URLs from a CSS `background-image` value
+    ///
+    /// Keeps `url(...)` entries pointing at http(s) resources; gradients,
+    /// `none`, and data URIs fall through unmatched.
+    pub fn css_image_urls(value: &str) -> Vec<String> {
+        let mut urls = Vec::new();
+        let mut rest = value;
+        while let Some(pos) = rest.find("url(") {
+            rest = &rest[pos + 4..];
+            let Some(end) = rest.find(')') else { break };
+            let raw = rest[..end].trim().trim_matches(['"', '\'']);
+            if raw.starts_with("http://") || raw.starts_with("https://") {
+                urls.push(raw.to_string());
+            }
+            rest = &rest[end + 1..];
+        }
+        urls
+    }
+
+    /// Classify a URL by its file extension; returns None for non-downloadable links
+    pub fn classify_url(url: &str) -> Option<ResourceKind> {
+        // Strip query string and fragment before looking at the extension
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let ext = path.rsplit('.').next()?.to_lowercase();
+
+        // An "extension" containing a slash means the path had no real extension
+        if ext.contains('/') {
+            return None;
+        }
+
+        match ext.as_str() {
+            "pdf" | "doc" | "docx" | "xls" | "xlsx" | "ppt" | "pptx" | "odt" | "ods" | "odp"
+            | "rtf" | "csv" | "txt" | "epub" => Some(ResourceKind::Document),
+            "zip" | "tar" | "gz" | "tgz" | "bz2" | "xz" | "rar" | "7z" => {
+                Some(ResourceKind::Archive)
+            }
+            "mp3" | "wav" | "ogg" | "flac" | "m4a" | "aac" | "opus" => Some(ResourceKind::Audio),
+            "mp4" | "webm" | "mkv" | "avi" | "mov" | "m4v" | "mpeg" | "mpg" => {
+                Some(ResourceKind::Video)
+            }
+            "png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" | "bmp" | "tiff" | "ico" => {
+                Some(ResourceKind::Image)
+            }
+            _ => None,
+        }
+    }
+
+    /// Derive a filename from the URL path
+    pub fn filename_from_url(url: &str) -> String {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        path.rsplit('/').next().unwrap_or("").to_string()
+    }
+
+    /// Resolve sizes via HEAD requests (best effort)
+    async fn resolve_sizes(resources: &mut [ExtractedResource], timeout: Duration) {
+        let client = match reqwest::Client::builder().timeout(timeout).build() {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("Failed to build HTTP client for size resolution: {}", e);
+                return;
+            }
+        };
+
+        for resource in resources.iter_mut() {
+            match client.head(&resource.url).send().await {
+                Ok(response) => {
+                    resource.size = response
+                        .headers()
+                        .get(reqwest::header::CONTENT_LENGTH)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse().ok());
+                }
+                Err(e) => {
+                    debug!(
+                        "HEAD request failed for {}: {}",
+                        crate::logging::sanitize_url(&resource.url),
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_pdf_as_document() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/report.pdf"),
+            Some(ResourceKind::Document)
+        );
+    }
+
+    #[test]
+    fn test_classify_image() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/photo.png"),
+            Some(ResourceKind::Image)
+        );
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/photo.jpeg"),
+            Some(ResourceKind::Image)
+        );
+    }
+
+    #[test]
+    fn test_classify_archive() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/bundle.zip"),
+            Some(ResourceKind::Archive)
+        );
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/source.tar.gz"),
+            Some(ResourceKind::Archive)
+        );
+    }
+
+    #[test]
+    fn test_classify_audio_video() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/song.mp3"),
+            Some(ResourceKind::Audio)
+        );
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/clip.mp4"),
+            Some(ResourceKind::Video)
+        );
+    }
+
+    #[test]
+    fn test_classify_non_downloadable() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/about"),
+            None
+        );
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/page.html"),
+            None
+        );
+        assert_eq!(ResourceExtractor::classify_url("https://example.com/"), None);
+    }
+
+    #[test]
+    fn test_classify_with_query_string() {
+        assert_eq!(
+            ResourceExtractor::classify_url("https://example.com/report.pdf?version=2"),
+            Some(ResourceKind::Document)
+        );
+    }
+
+    #[test]
+    fn test_filename_from_url() {
+        assert_eq!(
+            ResourceExtractor::filename_from_url("https://example.com/docs/report.pdf"),
+            "report.pdf"
+        );
+        assert_eq!(
+            ResourceExtractor::filename_from_url("https://example.com/file.zip?dl=1"),
+            "file.zip"
+        );
+    }
+
+    #[test]
+    fn test_css_image_urls_simple() {
+        assert_eq!(
+            ResourceExtractor::css_image_urls("url(\"https://example.com/bg.png\")"),
+            vec!["https://example.com/bg.png"]
+        );
+    }
+
+    #[test]
+    fn test_css_image_urls_multiple_layers() {
+        let value = "url('https://example.com/a.png'), linear-gradient(red, blue), \
+                     url(https://example.com/b.jpg)";
+        assert_eq!(
+            ResourceExtractor::css_image_urls(value),
+            vec!["https://example.com/a.png", "https://example.com/b.jpg"]
+        );
+    }
+
+    #[test]
+    fn test_css_image_urls_excludes_gradients_and_none() {
+        assert!(ResourceExtractor::css_image_urls("none").is_empty());
+        assert!(
+            ResourceExtractor::css_image_urls("linear-gradient(to right, #fff, #000)").is_empty()
+        );
+    }
+
+    #[test]
+    fn test_css_image_urls_excludes_data_uris() {
+        assert!(
+            ResourceExtractor::css_image_urls("url(data:image/png;base64,iVBORw0KGgo=)")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_resource_kind_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ResourceKind::Document).unwrap(),
+            "\"document\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ResourceKind::Archive).unwrap(),
+            "\"archive\""
+        );
+    }
+
+    #[test]
+    fn test_extracted_resource_serialization() {
+        let resource = ExtractedResource {
+            url: "https://example.com/report.pdf".to_string(),
+            kind: ResourceKind::Document,
+            filename: "report.pdf".to_string(),
+            size: None,
+        };
+        let json = serde_json::to_string(&resource).unwrap();
+        assert!(json.contains("\"kind\":\"document\""));
+        // size is omitted when not resolved
+        assert!(!json.contains("\"size\""));
+    }
+}
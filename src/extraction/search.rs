@@ -0,0 +1,274 @@
+//! In-page text search
+//!
+//! This module searches extracted page text for a query and returns matches
+//! with surrounding context snippets and, when provenance is available, an
+//! approximate DOM location.
+
+use crate::error::{ExtractionError, Result};
+use crate::extraction::content::BlockProvenance;
+use crate::extraction::ContentExtractor;
+use serde::{Deserialize, Serialize};
+
+/// Default number of context characters on each side of a match
+const DEFAULT_CONTEXT_CHARS: usize = 80;
+
+/// Default maximum number of matches returned
+const DEFAULT_MAX_MATCHES: usize = 20;
+
+/// Maximum compiled size for user-supplied regex patterns
+///
+/// The regex crate guarantees linear-time matching (no catastrophic
+/// backtracking); this bounds memory for pathological patterns instead.
+const REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Options for text search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOptions {
+    /// Match case exactly (default: false)
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Treat the query as a regular expression (default: false)
+    #[serde(default)]
+    pub regex: bool,
+    /// Maximum number of matches to return (default: 20)
+    #[serde(default = "default_max_matches")]
+    pub max_matches: usize,
+    /// Context characters to include on each side of a match (default: 80)
+    #[serde(default = "default_context_chars")]
+    pub context_chars: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            case_sensitive: false,
+            regex: false,
+            max_matches: DEFAULT_MAX_MATCHES,
+            context_chars: DEFAULT_CONTEXT_CHARS,
+        }
+    }
+}
+
+fn default_max_matches() -> usize {
+    DEFAULT_MAX_MATCHES
+}
+
+fn default_context_chars() -> usize {
+    DEFAULT_CONTEXT_CHARS
+}
+
+/// A single search match with context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchMatch {
+    /// The matched text as it appears in the page
+    pub matched: String,
+    /// The match with surrounding context
+    pub snippet: String,
+    /// Start character offset of the match in the searched text
+    pub start_offset: usize,
+    /// End character offset (exclusive) of the match
+    pub end_offset: usize,
+    /// Approximate DOM location (CSS selector path), when provenance
+    /// was available for the searched text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector_path: Option<String>,
+}
+
+/// Text search functionality
+pub struct TextSearcher;
+
+impl TextSearcher {
+    /// Search text for a query, returning matches with context snippets
+    ///
+    /// The query is matched literally unless [`SearchOptions::regex`] is
+    /// set. Offsets in the returned matches are character positions, so
+    /// they can be resolved against [`BlockProvenance`] records.
+    pub fn search(text: &str, query: &str, options: &SearchOptions) -> Result<Vec<SearchMatch>> {
+        Self::search_with_provenance(text, query, options, &[])
+    }
+
+    /// Search text, resolving each match to a selector path via provenance
+    pub fn search_with_provenance(
+        text: &str,
+        query: &str,
+        options: &SearchOptions,
+        provenance: &[BlockProvenance],
+    ) -> Result<Vec<SearchMatch>> {
+        if query.is_empty() {
+            return Err(ExtractionError::InvalidPattern("empty query".to_string()).into());
+        }
+
+        let pattern = if options.regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+
+        let re = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(!options.case_sensitive)
+            .size_limit(REGEX_SIZE_LIMIT)
+            .build()
+            .map_err(|e| ExtractionError::InvalidPattern(e.to_string()))?;
+
+        let mut matches = Vec::new();
+        for m in re.find_iter(text).take(options.max_matches) {
+            if m.is_empty() {
+                continue;
+            }
+
+            let start_offset = text[..m.start()].chars().count();
+            let end_offset = start_offset + m.as_str().chars().count();
+
+            let before: String = {
+                let chars: Vec<char> = text[..m.start()]
+                    .chars()
+                    .rev()
+                    .take(options.context_chars)
+                    .collect();
+                chars.into_iter().rev().collect()
+            };
+            let after: String = text[m.end()..].chars().take(options.context_chars).collect();
+
+            matches.push(SearchMatch {
+                matched: m.as_str().to_string(),
+                snippet: format!("{}{}{}", before, m.as_str(), after),
+                start_offset,
+                end_offset,
+                selector_path: ContentExtractor::provenance_at(provenance, start_offset)
+                    .map(|p| p.selector_path.clone()),
+            });
+        }
+
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========================================================================
+    // Search Tests
+    // ========================================================================
+
+    #[test]
+    fn test_search_finds_all_occurrences() {
+        let text = "The widget is blue. Another widget arrived today.";
+        let matches = TextSearcher::search(text, "widget", &SearchOptions::default()).unwrap();
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].matched, "widget");
+        assert!(matches[0].snippet.contains("The widget is blue"));
+        assert!(matches[1].snippet.contains("Another widget arrived"));
+    }
+
+    #[test]
+    fn test_search_case_insensitive_by_default() {
+        let text = "Rust and RUST and rust";
+        let matches = TextSearcher::search(text, "rust", &SearchOptions::default()).unwrap();
+        assert_eq!(matches.len(), 3);
+    }
+
+    #[test]
+    fn test_search_case_sensitive() {
+        let text = "Rust and RUST and rust";
+        let options = SearchOptions {
+            case_sensitive: true,
+            ..Default::default()
+        };
+        let matches = TextSearcher::search(text, "rust", &options).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].start_offset, 18);
+    }
+
+    #[test]
+    fn test_search_respects_max_matches() {
+        let text = "a a a a a";
+        let options = SearchOptions {
+            max_matches: 2,
+            ..Default::default()
+        };
+        let matches = TextSearcher::search(text, "a", &options).unwrap();
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_search_literal_escapes_regex_metacharacters() {
+        let text = "price is $4.99 today";
+        let matches = TextSearcher::search(text, "$4.99", &SearchOptions::default()).unwrap();
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[test]
+    fn test_search_regex_mode() {
+        let text = "Orders: #123 and #456";
+        let options = SearchOptions {
+            regex: true,
+            ..Default::default()
+        };
+        let matches = TextSearcher::search(text, r"#\d+", &options).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].matched, "#123");
+    }
+
+    #[test]
+    fn test_search_invalid_regex_is_rejected() {
+        let options = SearchOptions {
+            regex: true,
+            ..Default::default()
+        };
+        assert!(TextSearcher::search("text", "(unclosed", &options).is_err());
+    }
+
+    #[test]
+    fn test_search_empty_query_is_rejected() {
+        assert!(TextSearcher::search("text", "", &SearchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_search_offsets_are_char_based() {
+        let text = "héllo wörld héllo";
+        let matches = TextSearcher::search(text, "héllo", &SearchOptions::default()).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].start_offset, 0);
+        assert_eq!(matches[0].end_offset, 5);
+        assert_eq!(matches[1].start_offset, 12);
+    }
+
+    #[test]
+    fn test_search_resolves_selector_via_provenance() {
+        let blocks = vec![
+            ("#main > p:nth-of-type(1)".to_string(), "Nothing here.".to_string()),
+            (
+                "#main > p:nth-of-type(2)".to_string(),
+                "The query lives here.".to_string(),
+            ),
+        ];
+        let (text, provenance) = ContentExtractor::assign_block_offsets(&blocks);
+
+        let matches = TextSearcher::search_with_provenance(
+            &text,
+            "query",
+            &SearchOptions::default(),
+            &provenance,
+        )
+        .unwrap();
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].selector_path.as_deref(),
+            Some("#main > p:nth-of-type(2)")
+        );
+    }
+
+    #[test]
+    fn test_search_snippet_bounded_by_context_chars() {
+        let text = format!("{}needle{}", "x".repeat(200), "y".repeat(200));
+        let options = SearchOptions {
+            context_chars: 10,
+            ..Default::default()
+        };
+        let matches = TextSearcher::search(&text, "needle", &options).unwrap();
+        assert_eq!(matches[0].snippet.chars().count(), 6 + 20);
+    }
+}
@@ -0,0 +1,282 @@
+//! Sitemap fetching
+//!
+//! This module fetches a site's `/sitemap.xml` (following sitemap index
+//! files recursively, bounded), parses `<loc>` entries with their
+//! `lastmod` timestamps, and returns the URL list as a crawl seed source.
+//! Gzipped sitemaps (`.xml.gz`) are decompressed transparently.
+
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::io::Read;
+use std::time::Duration;
+use tracing::{debug, info, instrument, warn};
+
+/// Maximum number of sitemap files fetched per site, index included
+pub const DEFAULT_MAX_SITEMAPS: usize = 10;
+
+/// Maximum number of URL entries returned
+pub const DEFAULT_MAX_SITEMAP_URLS: usize = 5000;
+
+/// A single URL entry from a sitemap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SitemapEntry {
+    /// The page URL from `<loc>`
+    pub url: String,
+    /// The `<lastmod>` value as written in the sitemap, when present
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastmod: Option<String>,
+}
+
+/// Options for sitemap fetching
+#[derive(Debug, Clone)]
+pub struct SitemapOptions {
+    /// Timeout per sitemap fetch in milliseconds (default: 5000)
+    pub timeout_ms: u64,
+    /// Maximum sitemap files fetched, index included (default:
+    /// [`DEFAULT_MAX_SITEMAPS`])
+    pub max_sitemaps: usize,
+    /// Maximum URL entries returned (default:
+    /// [`DEFAULT_MAX_SITEMAP_URLS`])
+    pub max_urls: usize,
+}
+
+impl Default for SitemapOptions {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 5000,
+            max_sitemaps: DEFAULT_MAX_SITEMAPS,
+            max_urls: DEFAULT_MAX_SITEMAP_URLS,
+        }
+    }
+}
+
+/// A parsed sitemap file: either an index of further sitemaps or a URL set
+#[derive(Debug, Clone, PartialEq)]
+pub enum SitemapDocument {
+    /// `<sitemapindex>`: locations of child sitemaps
+    Index(Vec<String>),
+    /// `<urlset>`: the page URLs themselves
+    UrlSet(Vec<SitemapEntry>),
+}
+
+/// Sitemap fetching functionality
+pub struct SitemapFetcher;
+
+impl SitemapFetcher {
+    /// Fetch the sitemap URL list for a site
+    ///
+    /// Starts from `<base_url>/sitemap.xml`, follows sitemap index files
+    /// breadth-first up to `options.max_sitemaps` fetches, and returns the
+    /// union of their URL entries capped at `options.max_urls`. A failing
+    /// child sitemap is logged and skipped; a failing root sitemap is an
+    /// error.
+    #[instrument(skip(options))]
+    pub async fn fetch_sitemap(base_url: &str, options: &SitemapOptions) -> Result<Vec<SitemapEntry>> {
+        let base = url::Url::parse(base_url)
+            .map_err(|e| ExtractionError::ExtractionFailed(format!("Invalid base URL: {}", e)))?;
+        let root = base
+            .join("/sitemap.xml")
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        info!("Fetching sitemap from: {}", crate::logging::sanitize_url(root.as_str()));
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(options.timeout_ms))
+            .build()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let mut queue = VecDeque::from([root.to_string()]);
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        let mut fetched = 0usize;
+
+        while let Some(url) = queue.pop_front() {
+            if !seen.insert(url.clone()) {
+                continue;
+            }
+            if fetched >= options.max_sitemaps {
+                debug!("Sitemap fetch budget of {} reached", options.max_sitemaps);
+                break;
+            }
+            fetched += 1;
+
+            let xml = match Self::fetch_body(&client, &url).await {
+                Ok(xml) => xml,
+                Err(e) if fetched == 1 => {
+                    return Err(ExtractionError::ExtractionFailed(format!(
+                        "Failed to fetch sitemap: {}",
+                        e
+                    ))
+                    .into());
+                }
+                Err(e) => {
+                    warn!("Skipping child sitemap {}: {}", crate::logging::sanitize_url(&url), e);
+                    continue;
+                }
+            };
+
+            match Self::parse(&xml) {
+                SitemapDocument::Index(children) => {
+                    debug!("Sitemap index with {} children", children.len());
+                    queue.extend(children);
+                }
+                SitemapDocument::UrlSet(urls) => {
+                    entries.extend(urls);
+                    if entries.len() >= options.max_urls {
+                        entries.truncate(options.max_urls);
+                        debug!("Sitemap URL cap of {} reached", options.max_urls);
+                        break;
+                    }
+                }
+            }
+        }
+
+        info!("Sitemap yielded {} URLs from {} files", entries.len(), fetched);
+        Ok(entries)
+    }
+
+    /// Fetch one sitemap file, decompressing gzip when needed
+    async fn fetch_body(
+        client: &reqwest::Client,
+        url: &str,
+    ) -> std::result::Result<String, String> {
+        let response = client.get(url).send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(format!("HTTP {}", status.as_u16()));
+        }
+        let bytes = response.bytes().await.map_err(|e| e.to_string())?;
+
+        // `.xml.gz` sitemaps are served as gzip bodies, not with a
+        // Content-Encoding header, so sniff the magic bytes
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            let mut xml = String::new();
+            flate2::read::GzDecoder::new(bytes.as_ref())
+                .read_to_string(&mut xml)
+                .map_err(|e| format!("Invalid gzip: {}", e))?;
+            Ok(xml)
+        } else {
+            String::from_utf8(bytes.to_vec()).map_err(|e| e.to_string())
+        }
+    }
+
+    /// Parse one sitemap file into an index or a URL set
+    ///
+    /// Unrecognized documents parse as an empty URL set. Entries without a
+    /// `<loc>` are dropped.
+    pub fn parse(xml: &str) -> SitemapDocument {
+        if xml.contains("<sitemapindex") {
+            let children = Self::blocks(xml, "sitemap")
+                .iter()
+                .filter_map(|block| Self::tag_content(block, "loc"))
+                .collect();
+            SitemapDocument::Index(children)
+        } else {
+            let entries = Self::blocks(xml, "url")
+                .iter()
+                .filter_map(|block| {
+                    Some(SitemapEntry {
+                        url: Self::tag_content(block, "loc")?,
+                        lastmod: Self::tag_content(block, "lastmod"),
+                    })
+                })
+                .collect();
+            SitemapDocument::UrlSet(entries)
+        }
+    }
+
+    /// Collect the contents of each `<tag>...</tag>` block
+    fn blocks<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+        let mut blocks = Vec::new();
+        let mut rest = xml;
+        while let Some(start) = rest.find(&open) {
+            let after = &rest[start + open.len()..];
+            let Some(end) = after.find(&close) else { break };
+            blocks.push(&after[..end]);
+            rest = &after[end + close.len()..];
+        }
+        blocks
+    }
+
+    /// The trimmed, entity-decoded text of the first `<tag>` in a block
+    fn tag_content(block: &str, tag: &str) -> Option<String> {
+        let open = format!("<{}>", tag);
+        let close = format!("</{}>", tag);
+        let start = block.find(&open)? + open.len();
+        let end = block[start..].find(&close)? + start;
+        let raw = block[start..end].trim();
+        if raw.is_empty() {
+            return None;
+        }
+        Some(htmlescape::decode_html(raw).unwrap_or_else(|_| raw.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_urlset_with_lastmod() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                <url>
+                    <loc>https://example.com/</loc>
+                    <lastmod>2024-01-15</lastmod>
+                </url>
+                <url>
+                    <loc>https://example.com/about?a=1&amp;b=2</loc>
+                </url>
+                <url>
+                    <lastmod>2024-01-16</lastmod>
+                </url>
+            </urlset>"#;
+
+        let SitemapDocument::UrlSet(entries) = SitemapFetcher::parse(xml) else {
+            panic!("expected a URL set");
+        };
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].url, "https://example.com/");
+        assert_eq!(entries[0].lastmod.as_deref(), Some("2024-01-15"));
+        assert_eq!(entries[1].url, "https://example.com/about?a=1&b=2");
+        assert_eq!(entries[1].lastmod, None);
+    }
+
+    #[test]
+    fn test_parse_sitemap_index() {
+        let xml = r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                <sitemap><loc>https://example.com/sitemap-posts.xml</loc></sitemap>
+                <sitemap><loc>https://example.com/sitemap-pages.xml.gz</loc></sitemap>
+            </sitemapindex>"#;
+
+        let SitemapDocument::Index(children) = SitemapFetcher::parse(xml) else {
+            panic!("expected an index");
+        };
+        assert_eq!(
+            children,
+            vec![
+                "https://example.com/sitemap-posts.xml",
+                "https://example.com/sitemap-pages.xml.gz",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_unrecognized_document_is_empty() {
+        let parsed = SitemapFetcher::parse("<html><body>not a sitemap</body></html>");
+        assert_eq!(parsed, SitemapDocument::UrlSet(Vec::new()));
+    }
+
+    #[test]
+    fn test_entry_serialization_omits_missing_lastmod() {
+        let entry = SitemapEntry {
+            url: "https://example.com/".to_string(),
+            lastmod: None,
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(!json.contains("lastmod"));
+    }
+}
@@ -0,0 +1,309 @@
+//! Table extraction
+//!
+//! This module extracts HTML tables from web pages and converts them to
+//! structured rows or RFC 4180 compliant CSV.
+
+use crate::browser::PageHandle;
+use crate::error::{ExtractionError, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// An extracted table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedTable {
+    /// Table caption, if present
+    pub caption: Option<String>,
+    /// Header row (from thead or first row of th cells)
+    pub headers: Vec<String>,
+    /// Data rows; spanned cells are repeated so every row has the same width
+    pub rows: Vec<Vec<String>>,
+    /// Position in document (order found)
+    pub position: usize,
+}
+
+impl ExtractedTable {
+    /// Convert the table to RFC 4180 compliant CSV
+    ///
+    /// Fields containing commas, quotes, or newlines are quoted, with
+    /// embedded quotes doubled. Records are terminated with CRLF.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        if !self.headers.is_empty() {
+            csv.push_str(&Self::csv_record(&self.headers));
+        }
+        for row in &self.rows {
+            csv.push_str(&Self::csv_record(row));
+        }
+        csv
+    }
+
+    /// Format a single CSV record with a CRLF terminator
+    fn csv_record(fields: &[String]) -> String {
+        let mut record = fields
+            .iter()
+            .map(|f| Self::csv_field(f))
+            .collect::<Vec<_>>()
+            .join(",");
+        record.push_str("\r\n");
+        record
+    }
+
+    /// Quote a single CSV field if it contains special characters
+    pub fn csv_field(field: &str) -> String {
+        if field.contains(',') || field.contains('"') || field.contains('\n') || field.contains('\r')
+        {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+/// Table extraction functionality
+pub struct TableExtractor;
+
+impl TableExtractor {
+    /// Extract all tables from the page
+    ///
+    /// Cells spanning multiple columns or rows are expanded by repeating
+    /// their value so that every row has a consistent width.
+    #[instrument(skip(page))]
+    pub async fn extract_all(page: &PageHandle) -> Result<Vec<ExtractedTable>> {
+        info!("Extracting tables");
+
+        let script = r#"
+            (() => {
+                const tables = [];
+
+                document.querySelectorAll('table').forEach((table, index) => {
+                    const caption = table.querySelector('caption');
+                    const grid = [];
+
+                    // Expand colspan/rowspan into a dense grid
+                    table.querySelectorAll('tr').forEach((tr, rowIdx) => {
+                        if (!grid[rowIdx]) grid[rowIdx] = [];
+                        let colIdx = 0;
+
+                        tr.querySelectorAll('th, td').forEach((cell) => {
+                            // Skip positions already filled by a rowspan above
+                            while (grid[rowIdx][colIdx] !== undefined) colIdx++;
+
+                            const text = (cell.innerText || '').trim();
+                            const colspan = parseInt(cell.getAttribute('colspan') || '1', 10) || 1;
+                            const rowspan = parseInt(cell.getAttribute('rowspan') || '1', 10) || 1;
+                            const isHeader = cell.tagName === 'TH';
+
+                            for (let r = 0; r < rowspan; r++) {
+                                for (let c = 0; c < colspan; c++) {
+                                    if (!grid[rowIdx + r]) grid[rowIdx + r] = [];
+                                    grid[rowIdx + r][colIdx + c] = { text, isHeader };
+                                }
+                            }
+                            colIdx += colspan;
+                        });
+                    });
+
+                    const rows = grid.map(row =>
+                        Array.from(row, cell => cell ? cell.text : '')
+                    );
+                    const headerFlags = grid.map(row =>
+                        row.length > 0 && Array.from(row).every(cell => cell && cell.isHeader)
+                    );
+
+                    tables.push({
+                        caption: caption ? caption.innerText.trim() : null,
+                        rows: rows,
+                        headerFlags: headerFlags,
+                        position: index
+                    });
+                });
+
+                return tables;
+            })()
+        "#;
+
+        let result: Vec<serde_json::Value> = page
+            .page
+            .evaluate(script)
+            .await
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?
+            .into_value()
+            .map_err(|e| ExtractionError::ExtractionFailed(e.to_string()))?;
+
+        let tables: Vec<ExtractedTable> = result
+            .into_iter()
+            .map(|v| {
+                let all_rows: Vec<Vec<String>> = v["rows"]
+                    .as_array()
+                    .map(|rows| {
+                        rows.iter()
+                            .map(|row| {
+                                row.as_array()
+                                    .map(|cells| {
+                                        cells
+                                            .iter()
+                                            .map(|c| c.as_str().unwrap_or("").to_string())
+                                            .collect()
+                                    })
+                                    .unwrap_or_default()
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let header_flags: Vec<bool> = v["headerFlags"]
+                    .as_array()
+                    .map(|flags| {
+                        flags
+                            .iter()
+                            .map(|f| f.as_bool().unwrap_or(false))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                // Treat a leading all-header row as the header
+                let (headers, rows) = if header_flags.first().copied().unwrap_or(false) {
+                    let mut iter = all_rows.into_iter();
+                    let headers = iter.next().unwrap_or_default();
+                    (headers, iter.collect())
+                } else {
+                    (Vec::new(), all_rows)
+                };
+
+                ExtractedTable {
+                    caption: v["caption"].as_str().map(String::from),
+                    headers,
+                    rows,
+                    position: v["position"].as_u64().unwrap_or(0) as usize,
+                }
+            })
+            .collect();
+
+        debug!("Extracted {} tables", tables.len());
+        Ok(tables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_table() -> ExtractedTable {
+        ExtractedTable {
+            caption: None,
+            headers: vec!["Name".to_string(), "Notes".to_string()],
+            rows: vec![
+                vec!["Alice".to_string(), "likes apples, pears".to_string()],
+                vec!["Bob".to_string(), "said \"hi\"".to_string()],
+            ],
+            position: 0,
+        }
+    }
+
+    /// Minimal RFC 4180 parser used to verify CSV output round-trips
+    fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = csv.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                if c == '"' {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    field.push(c);
+                }
+            } else {
+                match c {
+                    '"' => in_quotes = true,
+                    ',' => record.push(std::mem::take(&mut field)),
+                    '\r' => {
+                        if chars.peek() == Some(&'\n') {
+                            chars.next();
+                        }
+                        record.push(std::mem::take(&mut field));
+                        records.push(std::mem::take(&mut record));
+                    }
+                    '\n' => {
+                        record.push(std::mem::take(&mut field));
+                        records.push(std::mem::take(&mut record));
+                    }
+                    _ => field.push(c),
+                }
+            }
+        }
+        if !field.is_empty() || !record.is_empty() {
+            record.push(field);
+            records.push(record);
+        }
+        records
+    }
+
+    #[test]
+    fn test_csv_field_plain() {
+        assert_eq!(ExtractedTable::csv_field("hello"), "hello");
+    }
+
+    #[test]
+    fn test_csv_field_with_comma() {
+        assert_eq!(ExtractedTable::csv_field("a,b"), "\"a,b\"");
+    }
+
+    #[test]
+    fn test_csv_field_with_quotes() {
+        assert_eq!(ExtractedTable::csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_csv_field_with_newline() {
+        assert_eq!(ExtractedTable::csv_field("line1\nline2"), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn test_to_csv_uses_crlf() {
+        let table = sample_table();
+        let csv = table.to_csv();
+        assert!(csv.contains("\r\n"));
+        assert!(csv.starts_with("Name,Notes\r\n"));
+    }
+
+    #[test]
+    fn test_to_csv_round_trips() {
+        let table = sample_table();
+        let parsed = parse_csv(&table.to_csv());
+
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0], vec!["Name", "Notes"]);
+        assert_eq!(parsed[1], vec!["Alice", "likes apples, pears"]);
+        assert_eq!(parsed[2], vec!["Bob", "said \"hi\""]);
+    }
+
+    #[test]
+    fn test_to_csv_without_headers() {
+        let table = ExtractedTable {
+            caption: None,
+            headers: Vec::new(),
+            rows: vec![vec!["a".to_string(), "b".to_string()]],
+            position: 0,
+        };
+        assert_eq!(table.to_csv(), "a,b\r\n");
+    }
+
+    #[test]
+    fn test_extracted_table_serialization() {
+        let table = sample_table();
+        let json = serde_json::to_string(&table).unwrap();
+        assert!(json.contains("\"headers\""));
+        assert!(json.contains("\"rows\""));
+
+        let parsed: ExtractedTable = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.headers, table.headers);
+    }
+}
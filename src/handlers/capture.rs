@@ -46,6 +46,15 @@ pub const DEFAULT_MAX_CONTENT_LENGTH: usize = 10 * 1024 * 1024;
 /// Default buffer channel capacity
 pub const DEFAULT_BUFFER_CAPACITY: usize = 1000;
 
+/// Default maximum concurrent in-flight captures
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 8;
+
+/// Default maximum queued captures waiting for a slot
+pub const DEFAULT_MAX_QUEUE_LENGTH: usize = 64;
+
+/// Default maximum wait time for a capture slot in milliseconds
+pub const DEFAULT_QUEUE_WAIT_TIMEOUT_MS: u64 = 30_000;
+
 /// Configuration for the capture handler
 #[derive(Debug, Clone)]
 pub struct CaptureConfig {
@@ -61,6 +70,10 @@ pub struct CaptureConfig {
     pub decode_entities: bool,
     /// Whether to normalize whitespace
     pub normalize_whitespace: bool,
+    /// Process RSS in bytes above which new captures are rejected with
+    /// `service_unavailable` instead of risking an OOM kill (default:
+    /// none, guard disabled)
+    pub max_rss_bytes: Option<u64>,
 }
 
 impl Default for CaptureConfig {
@@ -72,6 +85,7 @@ impl Default for CaptureConfig {
             strip_styles: true,
             decode_entities: true,
             normalize_whitespace: true,
+            max_rss_bytes: None,
         }
     }
 }
@@ -162,6 +176,137 @@ pub struct ProcessedCapture {
     pub metadata: Option<serde_json::Value>,
 }
 
+// ============================================================================
+// Capture Queue
+// ============================================================================
+
+/// Configuration for the in-flight capture queue
+#[derive(Debug, Clone)]
+pub struct QueueConfig {
+    /// Maximum captures processed concurrently
+    pub max_in_flight: usize,
+    /// Maximum requests allowed to wait for a slot
+    pub max_queue_length: usize,
+    /// Maximum time a request may wait for a slot in milliseconds
+    pub wait_timeout_ms: u64,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+            max_queue_length: DEFAULT_MAX_QUEUE_LENGTH,
+            wait_timeout_ms: DEFAULT_QUEUE_WAIT_TIMEOUT_MS,
+        }
+    }
+}
+
+/// Snapshot of queue state for status reporting
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueStats {
+    /// Captures currently being processed
+    pub in_flight: usize,
+    /// Requests waiting for a processing slot
+    pub queue_depth: usize,
+    /// Configured concurrency limit
+    pub max_in_flight: usize,
+    /// Configured maximum queue length
+    pub max_queue_length: usize,
+}
+
+/// Bounded queue limiting concurrent in-flight captures
+///
+/// Requests beyond the concurrency limit wait (FIFO via the semaphore's fair
+/// queueing) up to the configured queue length and wait timeout. Requests
+/// beyond the queue length are rejected immediately with
+/// [`CaptureError::QueueFull`].
+pub struct CaptureQueue {
+    semaphore: Arc<tokio::sync::Semaphore>,
+    waiting: std::sync::atomic::AtomicUsize,
+    config: QueueConfig,
+}
+
+/// Permit representing an acquired capture slot; reports the queue position
+/// the request had when it was enqueued
+pub struct QueuePermit {
+    _permit: tokio::sync::OwnedSemaphorePermit,
+    /// Position in the queue at enqueue time (0 = ran immediately)
+    pub position: usize,
+}
+
+impl CaptureQueue {
+    /// Create a new queue with the given configuration
+    pub fn new(config: QueueConfig) -> Self {
+        Self {
+            semaphore: Arc::new(tokio::sync::Semaphore::new(config.max_in_flight)),
+            waiting: std::sync::atomic::AtomicUsize::new(0),
+            config,
+        }
+    }
+
+    /// Acquire a capture slot, waiting in the queue if necessary
+    pub async fn acquire(&self) -> Result<QueuePermit, CaptureError> {
+        use std::sync::atomic::Ordering;
+
+        // Reject immediately if the queue is already full
+        let position = self.waiting.load(Ordering::SeqCst);
+        if self.semaphore.available_permits() == 0 && position >= self.config.max_queue_length {
+            counter!("capture_queue_rejections_total").increment(1);
+            return Err(CaptureError::QueueFull {
+                depth: position,
+                max: self.config.max_queue_length,
+            });
+        }
+
+        self.waiting.fetch_add(1, Ordering::SeqCst);
+        let result = tokio::time::timeout(
+            std::time::Duration::from_millis(self.config.wait_timeout_ms),
+            Arc::clone(&self.semaphore).acquire_owned(),
+        )
+        .await;
+        self.waiting.fetch_sub(1, Ordering::SeqCst);
+
+        match result {
+            Ok(Ok(permit)) => Ok(QueuePermit {
+                _permit: permit,
+                position,
+            }),
+            Ok(Err(_)) => Err(CaptureError::InternalError(
+                "Capture queue is closed".to_string(),
+            )),
+            Err(_) => {
+                counter!("capture_queue_timeouts_total").increment(1);
+                Err(CaptureError::QueueFull {
+                    depth: self.waiting.load(Ordering::SeqCst),
+                    max: self.config.max_queue_length,
+                })
+            }
+        }
+    }
+
+    /// Number of requests currently waiting for a slot
+    pub fn queue_depth(&self) -> usize {
+        self.waiting.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Number of captures currently being processed
+    pub fn in_flight(&self) -> usize {
+        self.config
+            .max_in_flight
+            .saturating_sub(self.semaphore.available_permits())
+    }
+
+    /// Snapshot of the current queue state
+    pub fn stats(&self) -> QueueStats {
+        QueueStats {
+            in_flight: self.in_flight(),
+            queue_depth: self.queue_depth(),
+            max_in_flight: self.config.max_in_flight,
+            max_queue_length: self.config.max_queue_length,
+        }
+    }
+}
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -194,6 +339,24 @@ pub enum CaptureError {
     #[error("Storage error: {0}")]
     StorageError(String),
 
+    /// Capture queue is full or the wait timed out
+    #[error("Capture queue full: {depth} waiting (max {max})")]
+    QueueFull {
+        /// Requests currently waiting
+        depth: usize,
+        /// Maximum queue length
+        max: usize,
+    },
+
+    /// Process memory too high to safely start another capture
+    #[error("Memory pressure: RSS {rss} bytes exceeds limit {max} bytes")]
+    MemoryPressure {
+        /// Current process RSS in bytes
+        rss: u64,
+        /// Configured RSS limit in bytes
+        max: u64,
+    },
+
     /// Internal server error
     #[error("Internal error: {0}")]
     InternalError(String),
@@ -221,6 +384,16 @@ impl IntoResponse for CaptureError {
                 "storage_error",
                 msg.clone(),
             ),
+            CaptureError::QueueFull { depth, max } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service_unavailable",
+                format!("Capture queue full: {} waiting (max {})", depth, max),
+            ),
+            CaptureError::MemoryPressure { rss, max } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "service_unavailable",
+                format!("Memory pressure: RSS {} bytes exceeds limit {} bytes", rss, max),
+            ),
             CaptureError::InternalError(msg) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "internal_error",
@@ -246,6 +419,9 @@ impl IntoResponse for CaptureError {
 // Shared State
 // ============================================================================
 
+/// Future returned by a memory trim hook
+pub type TrimFuture = futures::future::BoxFuture<'static, ()>;
+
 /// Shared state for the capture handler
 #[derive(Clone)]
 pub struct CaptureState {
@@ -253,18 +429,53 @@ pub struct CaptureState {
     pub config: CaptureConfig,
     /// Channel sender for storing captures
     pub sender: mpsc::Sender<ProcessedCapture>,
+    /// In-flight capture queue
+    pub queue: Arc<CaptureQueue>,
+    /// Invoked when the memory guard rejects a capture, to shed
+    /// reclaimable memory
+    trim_hook: Option<Arc<dyn Fn() -> TrimFuture + Send + Sync>>,
 }
 
 impl CaptureState {
     /// Create a new capture state with the given configuration
     pub fn new(config: CaptureConfig, sender: mpsc::Sender<ProcessedCapture>) -> Self {
-        Self { config, sender }
+        Self {
+            config,
+            sender,
+            queue: Arc::new(CaptureQueue::new(QueueConfig::default())),
+            trim_hook: None,
+        }
     }
 
     /// Create a new capture state with default configuration
     pub fn with_defaults(sender: mpsc::Sender<ProcessedCapture>) -> Self {
         Self::new(CaptureConfig::default(), sender)
     }
+
+    /// Create a new capture state with a custom queue configuration
+    pub fn with_queue(
+        config: CaptureConfig,
+        sender: mpsc::Sender<ProcessedCapture>,
+        queue_config: QueueConfig,
+    ) -> Self {
+        Self {
+            config,
+            sender,
+            queue: Arc::new(CaptureQueue::new(queue_config)),
+            trim_hook: None,
+        }
+    }
+
+    /// Register a hook spawned when the memory guard rejects a capture
+    ///
+    /// Use it to trim memory pools that can be rebuilt — expired capture
+    /// buffer entries, caches — so pressure can resolve without a restart.
+    pub fn set_memory_trim_hook<F>(&mut self, hook: F)
+    where
+        F: Fn() -> TrimFuture + Send + Sync + 'static,
+    {
+        self.trim_hook = Some(Arc::new(hook));
+    }
 }
 
 /// Create a new bounded channel for capture storage
@@ -484,6 +695,30 @@ pub async fn capture_handler(
     let start_time = Instant::now();
     info!("Processing capture request for URL: {}", request.url);
 
+    // Memory guard: under pressure, one more capture risks the OOM killer
+    // taking the whole process down, so shed load instead
+    if let Some(max) = state.config.max_rss_bytes {
+        let rss = crate::handlers::status::current_rss_bytes();
+        if rss > max {
+            warn!(
+                "Rejecting capture under memory pressure: RSS {} bytes exceeds limit {} bytes",
+                rss, max
+            );
+            counter!("capture_memory_pressure_rejections_total").increment(1);
+            if let Some(hook) = &state.trim_hook {
+                tokio::spawn(hook());
+            }
+            return Err(CaptureError::MemoryPressure { rss, max });
+        }
+    }
+
+    // Acquire an in-flight slot, queueing if the server is busy
+    let permit = state.queue.acquire().await?;
+    if permit.position > 0 {
+        debug!("Capture waited in queue at position {}", permit.position);
+    }
+    histogram!("capture_queue_depth").record(state.queue.queue_depth() as f64);
+
     // Validate URL format
     let validated_url = validate_url(&request.url)?;
     debug!("URL validated: {}", validated_url);
@@ -580,11 +815,12 @@ pub async fn capture_handler(
 }
 
 /// Health check endpoint for the capture service
-pub async fn capture_health() -> impl IntoResponse {
+pub async fn capture_health(State(state): State<Arc<CaptureState>>) -> impl IntoResponse {
     Json(serde_json::json!({
         "status": "healthy",
         "service": "capture",
-        "timestamp": Utc::now().to_rfc3339()
+        "timestamp": Utc::now().to_rfc3339(),
+        "queue": state.queue.stats(),
     }))
 }
 
@@ -786,6 +1022,172 @@ mod tests {
         assert_eq!(received.content, "Hello World");
     }
 
+    #[test]
+    fn test_queue_config_default() {
+        let config = QueueConfig::default();
+        assert_eq!(config.max_in_flight, DEFAULT_MAX_IN_FLIGHT);
+        assert_eq!(config.max_queue_length, DEFAULT_MAX_QUEUE_LENGTH);
+        assert_eq!(config.wait_timeout_ms, DEFAULT_QUEUE_WAIT_TIMEOUT_MS);
+    }
+
+    #[tokio::test]
+    async fn test_queue_acquire_under_limit() {
+        let queue = CaptureQueue::new(QueueConfig {
+            max_in_flight: 2,
+            max_queue_length: 4,
+            wait_timeout_ms: 1000,
+        });
+
+        let p1 = queue.acquire().await.unwrap();
+        assert_eq!(p1.position, 0);
+        assert_eq!(queue.in_flight(), 1);
+
+        let p2 = queue.acquire().await.unwrap();
+        assert_eq!(queue.in_flight(), 2);
+        assert_eq!(queue.queue_depth(), 0);
+
+        drop(p1);
+        drop(p2);
+        assert_eq!(queue.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_queue_processes_in_order() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let queue = Arc::new(CaptureQueue::new(QueueConfig {
+            max_in_flight: 1,
+            max_queue_length: 8,
+            wait_timeout_ms: 5000,
+        }));
+        let completed = Arc::new(AtomicUsize::new(0));
+
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let queue = Arc::clone(&queue);
+            let completed = Arc::clone(&completed);
+            handles.push(tokio::spawn(async move {
+                let _permit = queue.acquire().await.unwrap();
+                completed.fetch_add(1, Ordering::SeqCst);
+            }));
+        }
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        assert_eq!(completed.load(Ordering::SeqCst), 4);
+        assert_eq!(queue.in_flight(), 0);
+        assert_eq!(queue.queue_depth(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_queue_rejects_over_queue() {
+        let queue = Arc::new(CaptureQueue::new(QueueConfig {
+            max_in_flight: 1,
+            max_queue_length: 0,
+            wait_timeout_ms: 100,
+        }));
+
+        // Hold the only slot
+        let _held = queue.acquire().await.unwrap();
+
+        // With a zero-length queue, the next request is rejected immediately
+        let result = queue.acquire().await;
+        assert!(matches!(result, Err(CaptureError::QueueFull { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_queue_wait_timeout() {
+        let queue = Arc::new(CaptureQueue::new(QueueConfig {
+            max_in_flight: 1,
+            max_queue_length: 4,
+            wait_timeout_ms: 50,
+        }));
+
+        let _held = queue.acquire().await.unwrap();
+        let result = queue.acquire().await;
+        assert!(matches!(result, Err(CaptureError::QueueFull { .. })));
+    }
+
+    #[test]
+    fn test_queue_stats_serialization() {
+        let queue = CaptureQueue::new(QueueConfig::default());
+        let stats = queue.stats();
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(json.contains("\"in_flight\":0"));
+        assert!(json.contains("\"queue_depth\":0"));
+    }
+
+    #[test]
+    fn test_queue_full_error_into_response() {
+        let error = CaptureError::QueueFull { depth: 10, max: 10 };
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    fn test_request() -> CaptureRequest {
+        CaptureRequest {
+            url: "https://example.com/page".to_string(),
+            content: "<html><body>hello</body></html>".to_string(),
+            title: None,
+            description: None,
+            captured_at: None,
+            metadata: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_guard_rejects_capture_under_pressure() {
+        let (sender, _receiver) = create_capture_buffer(10);
+        let config = CaptureConfig {
+            // Any real process exceeds one byte of RSS
+            max_rss_bytes: Some(1),
+            ..Default::default()
+        };
+        let state = Arc::new(CaptureState::new(config, sender));
+
+        let result = capture_handler(State(state), Json(test_request())).await;
+        let err = result.expect_err("guard should reject the capture");
+        assert!(matches!(err, CaptureError::MemoryPressure { max: 1, .. }));
+        assert_eq!(err.into_response().status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_memory_guard_triggers_trim_hook() {
+        let (sender, _receiver) = create_capture_buffer(10);
+        let config = CaptureConfig {
+            max_rss_bytes: Some(1),
+            ..Default::default()
+        };
+        let mut state = CaptureState::new(config, sender);
+
+        let (trim_tx, mut trim_rx) = mpsc::channel::<()>(1);
+        state.set_memory_trim_hook(move || {
+            let trim_tx = trim_tx.clone();
+            Box::pin(async move {
+                let _ = trim_tx.send(()).await;
+            })
+        });
+
+        let result = capture_handler(State(Arc::new(state)), Json(test_request())).await;
+        assert!(result.is_err());
+        tokio::time::timeout(std::time::Duration::from_secs(1), trim_rx.recv())
+            .await
+            .expect("trim hook was not invoked");
+    }
+
+    #[tokio::test]
+    async fn test_memory_guard_disabled_by_default() {
+        let (sender, mut receiver) = create_capture_buffer(10);
+        let state = Arc::new(CaptureState::new(CaptureConfig::default(), sender));
+
+        let response = capture_handler(State(state), Json(test_request()))
+            .await
+            .expect("capture should succeed without a threshold");
+        assert_eq!(response.0.url, "https://example.com/page");
+        assert!(receiver.recv().await.is_some());
+    }
+
     #[test]
     fn test_capture_error_into_response() {
         let error = CaptureError::InvalidRequest("test error".to_string());
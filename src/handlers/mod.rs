@@ -52,8 +52,9 @@ pub use feed::{
 // Re-export commonly used items from capture
 pub use capture::{
     capture_handler, capture_health, capture_router, create_capture_buffer, CaptureConfig,
-    CaptureError, CaptureRequest, CaptureResponse, CaptureState, ContentProcessor,
-    ProcessedCapture, DEFAULT_BUFFER_CAPACITY, DEFAULT_MAX_CONTENT_LENGTH,
+    CaptureError, CaptureQueue, CaptureRequest, CaptureResponse, CaptureState, ContentProcessor,
+    ProcessedCapture, QueueConfig, QueueStats, TrimFuture, DEFAULT_BUFFER_CAPACITY,
+    DEFAULT_MAX_CONTENT_LENGTH,
 };
 
 // Re-export commonly used items from status
@@ -70,8 +71,10 @@ pub use status::{
     LatencyHistogram,
     LatencyMetrics,
     MemoryMetrics,
+    OperationLatencies,
     StatusResponse,
     // Constants
+    OVERALL_LATENCY_KEY,
     SERVER_NAME,
     SERVER_VERSION,
 };
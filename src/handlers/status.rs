@@ -30,13 +30,13 @@
 //!     "heap_bytes": 41943040
 //!   },
 //!   "latency": {
-//!     "p50_ms": 12.5,
-//!     "p95_ms": 45.2,
-//!     "p99_ms": 98.7
+//!     "overall": { "p50_ms": 12.5, "p95_ms": 45.2, "p99_ms": 98.7 },
+//!     "navigation": { "p50_ms": 230.0, "p95_ms": 890.1, "p99_ms": 1450.0 }
 //!   }
 //! }
 //! ```
 
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
@@ -78,6 +78,37 @@ impl Default for HealthResponse {
     }
 }
 
+/// Status of one readiness dependency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    /// Dependency name (e.g. `browser`, `redis`, `store`)
+    pub name: String,
+    /// `ok` when the check passed, otherwise the failure reason category
+    /// (`failed` or `timed out`)
+    pub status: String,
+    /// Whether a failure of this dependency makes the server not ready
+    pub required: bool,
+    /// Error detail, when the check did not pass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DependencyStatus {
+    /// Whether the check passed
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
+/// Readiness probe response with per-dependency detail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessResponse {
+    /// `ready` when every required dependency passed, else `not_ready`
+    pub status: String,
+    /// Result of each registered health check, in registration order
+    pub dependencies: Vec<DependencyStatus>,
+}
+
 /// Detailed server status response with runtime metrics.
 ///
 /// Provides comprehensive information about the server's current state,
@@ -102,8 +133,13 @@ pub struct StatusResponse {
     /// Memory usage metrics
     pub memory: MemoryMetrics,
 
-    /// Request latency statistics (percentiles)
-    pub latency: LatencyMetrics,
+    /// Request latency statistics (percentiles), keyed by operation
+    ///
+    /// The `overall` key aggregates every recorded latency; further keys
+    /// (e.g. `navigation`, `capture`, `extraction`) appear once something
+    /// has been recorded under them, so slow captures do not skew the
+    /// percentiles of fast operations.
+    pub latency: BTreeMap<String, LatencyMetrics>,
 
     /// Server status (always "running" if responding)
     pub status: String,
@@ -263,10 +299,93 @@ impl Default for LatencyHistogram {
     }
 }
 
+/// Key under which the aggregate of all recorded latencies is reported.
+pub const OVERALL_LATENCY_KEY: &str = "overall";
+
+/// Per-operation latency histograms.
+///
+/// Keeps one [`LatencyHistogram`] per operation key (e.g. `navigation`,
+/// `capture`, `extraction`) so percentiles are not distorted by mixing slow
+/// and fast operations. Histograms are created lazily on first record.
+#[derive(Debug, Default)]
+pub struct OperationLatencies {
+    histograms: RwLock<HashMap<String, Arc<LatencyHistogram>>>,
+}
+
+impl OperationLatencies {
+    /// Create an empty set of per-operation histograms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the histogram for an operation, creating it if needed.
+    pub fn histogram(&self, operation: &str) -> Arc<LatencyHistogram> {
+        if let Some(histogram) = self.histograms.read().get(operation) {
+            return Arc::clone(histogram);
+        }
+        let mut histograms = self.histograms.write();
+        Arc::clone(
+            histograms
+                .entry(operation.to_string())
+                .or_insert_with(|| Arc::new(LatencyHistogram::new())),
+        )
+    }
+
+    /// Record a latency in microseconds under an operation key.
+    pub fn record(&self, operation: &str, latency_us: u64) {
+        self.histogram(operation).record(latency_us);
+    }
+
+    /// Get metrics for every operation recorded so far, sorted by key.
+    pub fn metrics(&self) -> BTreeMap<String, LatencyMetrics> {
+        self.histograms
+            .read()
+            .iter()
+            .map(|(operation, histogram)| (operation.clone(), histogram.metrics()))
+            .collect()
+    }
+
+    /// Reset all per-operation histograms, removing their keys.
+    pub fn reset(&self) {
+        self.histograms.write().clear();
+    }
+}
+
 // ============================================================================
 // Application State
 // ============================================================================
 
+/// Future returned by a registered health check
+///
+/// Resolves to `Ok(())` when the dependency is healthy, or `Err` with a
+/// human-readable reason.
+pub type HealthCheckFuture = futures::future::BoxFuture<'static, std::result::Result<(), String>>;
+
+/// Maximum number of health checks probed at once
+const READINESS_CHECK_CONCURRENCY: usize = 4;
+
+/// A registered readiness dependency check
+struct HealthCheck {
+    /// Dependency name reported in the readiness response
+    name: String,
+    /// Whether a failure makes the server not ready
+    required: bool,
+    /// Budget for one probe of this dependency, in milliseconds
+    timeout_ms: u64,
+    /// Produces a fresh probe future per readiness request
+    check: Box<dyn Fn() -> HealthCheckFuture + Send + Sync>,
+}
+
+impl std::fmt::Debug for HealthCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HealthCheck")
+            .field("name", &self.name)
+            .field("required", &self.required)
+            .field("timeout_ms", &self.timeout_ms)
+            .finish()
+    }
+}
+
 /// Shared application state for metrics and status tracking.
 ///
 /// This struct holds all the counters and state needed for the status endpoint.
@@ -312,11 +431,17 @@ pub struct AppState {
     /// Request latency histogram for percentile calculations
     latency_histogram: LatencyHistogram,
 
+    /// Per-operation latency histograms (navigation, capture, ...)
+    operation_latencies: OperationLatencies,
+
     /// Total number of HTTP requests processed
     total_requests: AtomicU64,
 
     /// Total number of errors encountered
     error_count: AtomicU64,
+
+    /// Registered readiness dependency checks, in registration order
+    health_checks: RwLock<Vec<HealthCheck>>,
 }
 
 impl AppState {
@@ -329,11 +454,80 @@ impl AppState {
             captures_processed: AtomicU64::new(0),
             active_sse_connections: AtomicU64::new(0),
             latency_histogram: LatencyHistogram::new(),
+            operation_latencies: OperationLatencies::new(),
             total_requests: AtomicU64::new(0),
             error_count: AtomicU64::new(0),
+            health_checks: RwLock::new(Vec::new()),
         }
     }
 
+    /// Register a readiness dependency check
+    ///
+    /// The closure produces a fresh probe future per `/ready` request,
+    /// which must resolve within `timeout_ms`. Required dependencies gate
+    /// readiness; optional ones are reported but do not fail the probe.
+    pub fn register_health_check<F>(&self, name: &str, required: bool, timeout_ms: u64, check: F)
+    where
+        F: Fn() -> HealthCheckFuture + Send + Sync + 'static,
+    {
+        self.health_checks.write().push(HealthCheck {
+            name: name.to_string(),
+            required,
+            timeout_ms,
+            check: Box::new(check),
+        });
+    }
+
+    /// Probe every registered dependency
+    ///
+    /// At most [`READINESS_CHECK_CONCURRENCY`] probes run at once; results
+    /// come back in registration order.
+    pub async fn run_health_checks(&self) -> Vec<DependencyStatus> {
+        use futures::StreamExt;
+
+        async fn probe_one(
+            name: String,
+            required: bool,
+            timeout_ms: u64,
+            probe: HealthCheckFuture,
+        ) -> DependencyStatus {
+            let timeout = std::time::Duration::from_millis(timeout_ms);
+            match tokio::time::timeout(timeout, probe).await {
+                Ok(Ok(())) => DependencyStatus {
+                    name,
+                    status: "ok".to_string(),
+                    required,
+                    error: None,
+                },
+                Ok(Err(e)) => DependencyStatus {
+                    name,
+                    status: "failed".to_string(),
+                    required,
+                    error: Some(e),
+                },
+                Err(_) => DependencyStatus {
+                    name,
+                    status: "timed out".to_string(),
+                    required,
+                    error: Some(format!("no response within {}ms", timeout_ms)),
+                },
+            }
+        }
+
+        // Create the probe futures under the lock, run them outside it
+        let pending: Vec<_> = self
+            .health_checks
+            .read()
+            .iter()
+            .map(|c| probe_one(c.name.clone(), c.required, c.timeout_ms, (c.check)()))
+            .collect();
+
+        futures::stream::iter(pending)
+            .buffered(READINESS_CHECK_CONCURRENCY)
+            .collect()
+            .await
+    }
+
     /// Get the server uptime in seconds.
     #[inline]
     pub fn uptime_seconds(&self) -> u64 {
@@ -407,12 +601,53 @@ impl AppState {
         self.total_requests.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record a latency in microseconds under an operation key.
+    ///
+    /// The value is also recorded into the overall histogram, so per-operation
+    /// tracking never hides a request from the aggregate percentiles.
+    #[inline]
+    pub fn record_operation_latency_us(&self, operation: &str, latency_us: u64) {
+        self.operation_latencies.record(operation, latency_us);
+        self.record_latency_us(latency_us);
+    }
+
+    /// Record a latency duration under an operation key.
+    #[inline]
+    pub fn record_operation_latency(&self, operation: &str, duration: std::time::Duration) {
+        self.record_operation_latency_us(operation, duration.as_micros() as u64);
+    }
+
+    /// Run a future and record its wall time under an operation key.
+    ///
+    /// The instrumentation wrapper for handlers: timing covers the whole
+    /// future, including failures, so error paths count toward percentiles.
+    pub async fn time_operation<F, T>(&self, operation: &str, fut: F) -> T
+    where
+        F: std::future::Future<Output = T>,
+    {
+        let start = Instant::now();
+        let output = fut.await;
+        self.record_operation_latency(operation, start.elapsed());
+        output
+    }
+
     /// Get the latency metrics.
     #[inline]
     pub fn latency_metrics(&self) -> LatencyMetrics {
         self.latency_histogram.metrics()
     }
 
+    /// Get latency metrics keyed by operation, with the aggregate under
+    /// [`OVERALL_LATENCY_KEY`].
+    pub fn latency_breakdown(&self) -> BTreeMap<String, LatencyMetrics> {
+        let mut breakdown = self.operation_latencies.metrics();
+        breakdown.insert(
+            OVERALL_LATENCY_KEY.to_string(),
+            self.latency_histogram.metrics(),
+        );
+        breakdown
+    }
+
     /// Get the total number of requests processed.
     #[inline]
     pub fn total_requests(&self) -> u64 {
@@ -438,6 +673,7 @@ impl AppState {
         self.total_requests.store(0, Ordering::Relaxed);
         self.error_count.store(0, Ordering::Relaxed);
         self.latency_histogram.reset();
+        self.operation_latencies.reset();
     }
 }
 
@@ -476,6 +712,11 @@ fn collect_memory_metrics() -> MemoryMetrics {
     }
 }
 
+/// RSS of the current process in bytes, for memory-pressure guards
+pub(crate) fn current_rss_bytes() -> u64 {
+    collect_memory_metrics().rss_bytes
+}
+
 // ============================================================================
 // HTTP Handlers
 // ============================================================================
@@ -535,12 +776,15 @@ pub async fn health_handler() -> impl IntoResponse {
 /// #     "virtual_bytes": 268435456
 /// #   },
 /// #   "latency": {
-/// #     "p50_ms": 12.5,
-/// #     "p95_ms": 45.2,
-/// #     "p99_ms": 98.7,
-/// #     "total_requests": 5000,
-/// #     "mean_ms": 18.3,
-/// #     "max_ms": 250.0
+/// #     "overall": {
+/// #       "p50_ms": 12.5,
+/// #       "p95_ms": 45.2,
+/// #       "p99_ms": 98.7,
+/// #       "total_requests": 5000,
+/// #       "mean_ms": 18.3,
+/// #       "max_ms": 250.0
+/// #     },
+/// #     "navigation": { "p50_ms": 230.0, "p95_ms": 890.1, "p99_ms": 1450.0 }
 /// #   },
 /// #   "status": "running",
 /// #   "timestamp": "2026-01-01T12:00:00Z"
@@ -551,7 +795,7 @@ pub async fn status_handler(State(state): State<Arc<AppState>>) -> impl IntoResp
     debug!("Status check requested");
 
     let memory = collect_memory_metrics();
-    let latency = state.latency_metrics();
+    let latency = state.latency_breakdown();
 
     let response = StatusResponse {
         version: SERVER_VERSION.to_string(),
@@ -570,21 +814,37 @@ pub async fn status_handler(State(state): State<Arc<AppState>>) -> impl IntoResp
 
 /// Readiness check endpoint handler.
 ///
-/// Similar to health check but can include additional readiness criteria
-/// such as database connections or external service availability.
-/// For now, it mirrors the health check behavior.
+/// Probes every health check registered on [`AppState`] (browser, store,
+/// redis, ...) with its individual timeout and reports per-dependency
+/// status. With no registered checks the server is trivially ready.
 ///
 /// # Route
 /// `GET /ready`
 ///
 /// # Response
-/// - `200 OK` - If ready to serve traffic
-/// - `503 Service Unavailable` - If not ready (future implementation)
+/// - `200 OK` - Every required dependency passed
+/// - `503 Service Unavailable` - At least one required dependency failed;
+///   the body names it
 #[instrument(skip_all)]
-pub async fn readiness_handler() -> impl IntoResponse {
+pub async fn readiness_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     debug!("Readiness check requested");
-    // Future: Add checks for browser pool, external services, etc.
-    (StatusCode::OK, Json(HealthResponse::default()))
+
+    let dependencies = state.run_health_checks().await;
+    let ready = dependencies.iter().all(|d| d.is_ok() || !d.required);
+    let code = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let status = if ready { "ready" } else { "not_ready" };
+
+    (
+        code,
+        Json(ReadinessResponse {
+            status: status.to_string(),
+            dependencies,
+        }),
+    )
 }
 
 // ============================================================================
@@ -727,6 +987,70 @@ mod tests {
         assert!(metrics.total_requests == 2);
     }
 
+    #[test]
+    fn test_operation_latencies_separate_percentiles() {
+        let state = AppState::new();
+
+        // Slow captures must not skew navigation percentiles
+        for _ in 0..10 {
+            state.record_operation_latency_us("navigation", 5_000); // 5ms
+            state.record_operation_latency_us("capture", 500_000); // 500ms
+        }
+
+        let breakdown = state.latency_breakdown();
+        let navigation = &breakdown["navigation"];
+        let capture = &breakdown["capture"];
+        assert!(navigation.p95_ms < 10.0, "got {}", navigation.p95_ms);
+        assert!(capture.p95_ms > 400.0, "got {}", capture.p95_ms);
+        assert_eq!(navigation.total_requests, 10);
+        assert_eq!(capture.total_requests, 10);
+
+        // The aggregate still covers every recording
+        let overall = &breakdown[OVERALL_LATENCY_KEY];
+        assert_eq!(overall.total_requests, 20);
+        assert_eq!(state.total_requests(), 20);
+    }
+
+    #[test]
+    fn test_latency_breakdown_omits_unrecorded_operations() {
+        let state = AppState::new();
+        state.record_operation_latency_us("extraction", 1_000);
+
+        let breakdown = state.latency_breakdown();
+        assert_eq!(
+            breakdown.keys().collect::<Vec<_>>(),
+            vec!["extraction", OVERALL_LATENCY_KEY]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_time_operation_records_latency() {
+        let state = AppState::new();
+
+        let value = state
+            .time_operation("navigation", async {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                42
+            })
+            .await;
+
+        assert_eq!(value, 42);
+        let breakdown = state.latency_breakdown();
+        assert!(breakdown["navigation"].max_ms >= 5.0);
+    }
+
+    #[test]
+    fn test_operation_latencies_reset_on_metrics_reset() {
+        let state = AppState::new();
+        state.record_operation_latency_us("capture", 1_000);
+
+        state.reset_metrics();
+
+        let breakdown = state.latency_breakdown();
+        assert_eq!(breakdown.keys().collect::<Vec<_>>(), vec![OVERALL_LATENCY_KEY]);
+        assert_eq!(breakdown[OVERALL_LATENCY_KEY].total_requests, 0);
+    }
+
     #[test]
     fn test_app_state_error_tracking() {
         let state = AppState::new();
@@ -788,7 +1112,10 @@ mod tests {
             captures_processed: 100,
             active_sse_connections: 5,
             memory: MemoryMetrics::default(),
-            latency: LatencyMetrics::default(),
+            latency: BTreeMap::from([(
+                OVERALL_LATENCY_KEY.to_string(),
+                LatencyMetrics::default(),
+            )]),
             status: "running".to_string(),
             timestamp: "2026-01-01T00:00:00Z".to_string(),
         };
@@ -828,10 +1155,71 @@ mod tests {
 
     #[tokio::test]
     async fn test_readiness_handler() {
-        let response = readiness_handler().await.into_response();
+        let state = Arc::new(AppState::new());
+        let response = readiness_handler(State(state)).await.into_response();
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_readiness_names_failed_required_dependency() {
+        let state = Arc::new(AppState::new());
+        state.register_health_check("browser", true, 1_000, || {
+            Box::pin(async { Ok(()) })
+        });
+        state.register_health_check("redis", true, 1_000, || {
+            Box::pin(async { Err("connection refused".to_string()) })
+        });
+
+        let response = readiness_handler(State(state)).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: ReadinessResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.status, "not_ready");
+        assert_eq!(body.dependencies.len(), 2);
+        assert_eq!(body.dependencies[0].name, "browser");
+        assert!(body.dependencies[0].is_ok());
+        assert_eq!(body.dependencies[1].name, "redis");
+        assert_eq!(body.dependencies[1].status, "failed");
+        assert_eq!(
+            body.dependencies[1].error.as_deref(),
+            Some("connection refused")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_readiness_optional_failure_stays_ready() {
+        let state = Arc::new(AppState::new());
+        state.register_health_check("store", false, 1_000, || {
+            Box::pin(async { Err("bucket missing".to_string()) })
+        });
+
+        let response = readiness_handler(State(state)).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_check_timeout_reported() {
+        let state = Arc::new(AppState::new());
+        state.register_health_check("slow", true, 20, || {
+            Box::pin(async {
+                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                Ok(())
+            })
+        });
+
+        let response = readiness_handler(State(state)).await.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: ReadinessResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.dependencies[0].status, "timed out");
+    }
+
     // Thread safety tests
     #[test]
     fn test_app_state_thread_safety() {
@@ -33,9 +33,12 @@ pub mod cors;
 pub mod error;
 pub mod extraction;
 pub mod handlers;
+pub mod logging;
 pub mod mcp;
 pub mod metrics;
 pub mod processing;
+pub mod retry;
+pub mod security;
 pub mod shutdown;
 pub mod stripe;
 
@@ -70,12 +73,31 @@ pub mod config {
     pub const DEFAULT_VIEWPORT_HEIGHT: u32 = 1080;
 }
 
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
 /// Browser connector state for WASM environments
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 pub struct WasmBrowserConnector {
     connected: bool,
     url: String,
+    /// Set while an operation runs; reentrant calls get a busy error
+    busy: std::cell::Cell<bool>,
+}
+
+/// Clears the connector's busy flag when the operation ends, including
+/// when the operation panics or returns early
+#[cfg(target_arch = "wasm32")]
+struct WasmOperationGuard<'a> {
+    busy: &'a std::cell::Cell<bool>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for WasmOperationGuard<'_> {
+    fn drop(&mut self) {
+        self.busy.set(false);
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -87,9 +109,16 @@ impl WasmBrowserConnector {
         Self {
             connected: false,
             url: String::new(),
+            busy: std::cell::Cell::new(false),
         }
     }
 
+    /// Whether an operation is currently in progress
+    #[wasm_bindgen(getter)]
+    pub fn busy(&self) -> bool {
+        self.busy.get()
+    }
+
     /// Check if connected
     #[wasm_bindgen(getter)]
     pub fn connected(&self) -> bool {
@@ -114,6 +143,31 @@ impl WasmBrowserConnector {
         self.connected = false;
         self.url.clear();
     }
+
+    /// Evaluate a JavaScript snippet in the current page context
+    ///
+    /// Returns the evaluated value, or the thrown value if the snippet
+    /// throws. Note that this runs in the extension's content-script
+    /// context: the snippet sees the page DOM but shares the extension's
+    /// privileges, so only evaluate trusted code.
+    ///
+    /// Only one operation may run at a time: a snippet that calls back
+    /// into the connector gets a busy error instead of racing the DOM
+    /// read already in progress.
+    pub fn eval(&self, script: &str) -> Result<JsValue, JsValue> {
+        let _guard = self.begin_operation()?;
+        js_sys::eval(script)
+    }
+
+    /// Mark an operation as in progress, refusing reentrant calls
+    fn begin_operation(&self) -> Result<WasmOperationGuard<'_>, JsValue> {
+        if self.busy.replace(true) {
+            return Err(JsValue::from_str(
+                "Connector busy: another operation is in progress",
+            ));
+        }
+        Ok(WasmOperationGuard { busy: &self.busy })
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,293 @@
+//! Structured logging initialization
+//!
+//! This module initializes the global tracing subscriber, choosing between
+//! human-readable and JSON-lines output. The format comes from the
+//! `REASONKIT_WEB_LOG_FORMAT` environment variable (`json` or `pretty`,
+//! default pretty) and the level filter from `RUST_LOG` as usual. JSON mode
+//! flattens event fields (request IDs, tool names) into the top-level log
+//! object so aggregators can index them directly.
+
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable selecting the log output format
+pub const LOG_FORMAT_ENV_VAR: &str = "REASONKIT_WEB_LOG_FORMAT";
+
+/// Log output format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// One JSON object per line, for log aggregation
+    Json,
+    /// Human-readable output
+    #[default]
+    Pretty,
+}
+
+impl LogFormat {
+    /// Parse a format name, case-insensitively
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "pretty" => Some(Self::Pretty),
+            _ => None,
+        }
+    }
+
+    /// Read the format from `REASONKIT_WEB_LOG_FORMAT`
+    ///
+    /// Unset or unrecognized values fall back to [`LogFormat::Pretty`].
+    pub fn from_env() -> Self {
+        std::env::var(LOG_FORMAT_ENV_VAR)
+            .ok()
+            .and_then(|value| Self::parse(&value))
+            .unwrap_or_default()
+    }
+}
+
+/// Initialize global logging from the environment
+///
+/// The level filter comes from `RUST_LOG` when set; otherwise `debug` in
+/// verbose mode and `info` by default. Output goes to stderr.
+pub fn init(verbose: bool) {
+    init_with_format(LogFormat::from_env(), verbose);
+}
+
+/// Initialize global logging with an explicit format
+pub fn init_with_format(format: LogFormat, verbose: bool) {
+    let filter = env_filter(verbose);
+
+    match format {
+        LogFormat::Json => json_subscriber(filter, std::io::stderr).init(),
+        LogFormat::Pretty => tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(std::io::stderr)
+            .init(),
+    }
+}
+
+/// Build the level filter from `RUST_LOG`, with a verbose-aware fallback
+pub fn env_filter(verbose: bool) -> EnvFilter {
+    let default_level = if verbose { "debug" } else { "info" };
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default_level))
+}
+
+/// Build the JSON-lines subscriber
+///
+/// Event fields are flattened into the top-level object and the current
+/// span's fields are included, so structured fields like `tool` and
+/// `request_id` are directly indexable. Exposed with a pluggable writer so
+/// tests can capture output.
+pub fn json_subscriber<W>(
+    filter: EnvFilter,
+    writer: W,
+) -> impl tracing::Subscriber + Send + Sync + 'static
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .json()
+        .flatten_event(true)
+        .with_current_span(true)
+        .with_env_filter(filter)
+        .with_writer(writer)
+        .finish()
+}
+
+/// Environment variable naming extra query parameters to mask in logged URLs
+///
+/// A comma-separated list, added on top of [`DEFAULT_SENSITIVE_PARAMS`].
+pub const SENSITIVE_PARAMS_ENV_VAR: &str = "REASONKIT_WEB_SENSITIVE_PARAMS";
+
+/// Query parameter names whose values are masked in logged URLs
+pub const DEFAULT_SENSITIVE_PARAMS: &[&str] = &[
+    "token",
+    "access_token",
+    "key",
+    "api_key",
+    "apikey",
+    "auth",
+    "signature",
+    "sig",
+    "password",
+    "secret",
+];
+
+/// Mask sensitive query parameter values before logging a URL
+///
+/// Uses [`DEFAULT_SENSITIVE_PARAMS`] plus any names from
+/// `REASONKIT_WEB_SENSITIVE_PARAMS` (read once per process). The real URL is
+/// untouched; only the logged form is masked.
+pub fn sanitize_url(url: &str) -> String {
+    sanitize_url_with(url, sensitive_params())
+}
+
+/// Mask the values of the named query parameters in a URL
+///
+/// Name matching is case-insensitive. URLs that do not parse, or that carry
+/// no sensitive parameter, are returned unchanged: logging must never fail
+/// or reformat on odd input.
+pub fn sanitize_url_with(url: &str, params: &[impl AsRef<str>]) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+    if parsed.query().is_none() {
+        return url.to_string();
+    }
+
+    let is_sensitive = |name: &str| {
+        params
+            .iter()
+            .any(|param| param.as_ref().eq_ignore_ascii_case(name))
+    };
+
+    let pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if !pairs.iter().any(|(name, _)| is_sensitive(name)) {
+        return url.to_string();
+    }
+
+    {
+        let mut query = parsed.query_pairs_mut();
+        query.clear();
+        for (name, value) in &pairs {
+            if is_sensitive(name) {
+                query.append_pair(name, "***");
+            } else {
+                query.append_pair(name, value);
+            }
+        }
+    }
+    parsed.to_string()
+}
+
+/// The sensitive parameter list: defaults plus the environment extension
+fn sensitive_params() -> &'static [String] {
+    static PARAMS: std::sync::OnceLock<Vec<String>> = std::sync::OnceLock::new();
+    PARAMS.get_or_init(|| {
+        let mut params: Vec<String> = DEFAULT_SENSITIVE_PARAMS
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        if let Ok(extra) = std::env::var(SENSITIVE_PARAMS_ENV_VAR) {
+            params.extend(
+                extra
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(String::from),
+            );
+        }
+        params
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    /// Writer that appends into a shared buffer for assertions
+    #[derive(Clone)]
+    struct CaptureWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CaptureWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_log_format_parse() {
+        assert_eq!(LogFormat::parse("json"), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse(" JSON "), Some(LogFormat::Json));
+        assert_eq!(LogFormat::parse("pretty"), Some(LogFormat::Pretty));
+        assert_eq!(LogFormat::parse("xml"), None);
+        assert_eq!(LogFormat::parse(""), None);
+    }
+
+    #[test]
+    fn test_log_format_default_is_pretty() {
+        assert_eq!(LogFormat::default(), LogFormat::Pretty);
+    }
+
+    #[test]
+    fn test_json_mode_emits_parseable_objects_with_fields() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let writer_buffer = Arc::clone(&buffer);
+
+        let subscriber = json_subscriber(EnvFilter::new("info"), move || {
+            CaptureWriter(Arc::clone(&writer_buffer))
+        });
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(tool = "web_navigate", request_id = 7, "Executing tool");
+        });
+
+        let output = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().expect("one log line");
+        let entry: serde_json::Value = serde_json::from_str(line).expect("valid JSON log line");
+
+        assert_eq!(entry["message"], "Executing tool");
+        assert_eq!(entry["tool"], "web_navigate");
+        assert_eq!(entry["request_id"], 7);
+        assert_eq!(entry["level"], "INFO");
+    }
+
+    #[test]
+    fn test_sanitize_url_masks_default_params() {
+        let masked = sanitize_url("https://x/?token=abc&page=2");
+        assert_eq!(masked, "https://x/?token=***&page=2");
+    }
+
+    #[test]
+    fn test_sanitize_url_case_insensitive() {
+        let masked = sanitize_url("https://example.com/cb?Access_Token=abc&SIG=xyz");
+        assert_eq!(masked, "https://example.com/cb?Access_Token=***&SIG=***");
+    }
+
+    #[test]
+    fn test_sanitize_url_without_sensitive_params_unchanged() {
+        // No re-encoding or normalization when nothing needs masking
+        let url = "https://example.com/search?q=a%20b&page=2";
+        assert_eq!(sanitize_url(url), url);
+    }
+
+    #[test]
+    fn test_sanitize_url_unparseable_unchanged() {
+        assert_eq!(sanitize_url("not a url?token=abc"), "not a url?token=abc");
+    }
+
+    #[test]
+    fn test_sanitize_url_with_custom_params() {
+        let masked = sanitize_url_with("https://x/?session=abc&token=t", &["session"]);
+        assert_eq!(masked, "https://x/?session=***&token=t");
+    }
+
+    #[test]
+    fn test_json_mode_respects_level_filter() {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let writer_buffer = Arc::clone(&buffer);
+
+        let subscriber = json_subscriber(EnvFilter::new("warn"), move || {
+            CaptureWriter(Arc::clone(&writer_buffer))
+        });
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("filtered out");
+            tracing::warn!("kept");
+        });
+
+        let output = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("filtered out"));
+        assert!(output.contains("kept"));
+    }
+}
@@ -66,10 +66,9 @@ struct Args {
 fn main() {
     let args = Args::parse();
 
-    // Initialize tracing
-    let filter = if args.verbose { "debug" } else { "info" };
-
-    tracing_subscriber::fmt().with_env_filter(filter).init();
+    // Initialize tracing: level from RUST_LOG (or --verbose), format from
+    // REASONKIT_WEB_LOG_FORMAT (json|pretty)
+    reasonkit_web::logging::init(args.verbose);
 
     tracing::info!(
         "ReasonKit Web MCP Server starting on {}:{}",
@@ -3,14 +3,20 @@
 //! This module implements the MCP server for AI agent integration,
 //! providing web sensing tools through the MCP protocol.
 
+/// Named snippet allowlisting for script execution
+pub mod scripts;
 mod server;
 mod tools;
 /// MCP protocol types
 pub mod types;
 
-pub use server::McpServer;
-pub use tools::{McpTool, ToolRegistry, AVAILABLE_TOOLS};
+pub use scripts::ScriptAllowlist;
+pub use server::{McpServer, ServerConfig};
+pub use tools::{
+    BrowserGuard, McpTool, ToolArgs, ToolCategory, ToolContext, ToolRegistry, AVAILABLE_TOOLS,
+};
 pub use types::{
-    JsonRpcError, JsonRpcRequest, JsonRpcResponse, McpCapabilities, McpServerInfo,
-    McpToolDefinition, ToolCallParams, ToolCallResult, ToolContent,
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse, LoggableToolCallResult, McpCapabilities,
+    McpServerInfo, McpToolDefinition, ServerLimits, ToolCallParams, ToolCallResult, ToolContent,
+    ToolsListParams,
 };
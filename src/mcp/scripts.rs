@@ -0,0 +1,154 @@
+//! Script allowlisting for `web_execute_js`
+//!
+//! Arbitrary script execution is the riskiest capability the server
+//! exposes, but disabling it entirely removes real utility. This module
+//! provides the middle ground: operators pre-register named snippets, and
+//! in allowlist mode clients may only invoke those by name — with
+//! parameters substituted as JSON literals so they cannot smuggle in code.
+
+use parking_lot::RwLock;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Named, operator-registered JavaScript snippets with an enable switch
+///
+/// Templates may contain `{{name}}` placeholders; on invocation each is
+/// replaced with the JSON encoding of the matching parameter, so parameter
+/// values always arrive in the script as data, never as code. Disabled by
+/// default: arbitrary scripts run as before until an operator opts in.
+#[derive(Debug, Default)]
+pub struct ScriptAllowlist {
+    enabled: AtomicBool,
+    snippets: RwLock<HashMap<String, String>>,
+}
+
+impl ScriptAllowlist {
+    /// Create an empty, disabled allowlist
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turn allowlist-only mode on or off
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Whether only registered snippets may run
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::SeqCst)
+    }
+
+    /// Register (or replace) a named snippet template
+    pub fn register(&self, name: &str, template: &str) {
+        self.snippets
+            .write()
+            .insert(name.to_string(), template.to_string());
+    }
+
+    /// Names of all registered snippets, sorted
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.snippets.read().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Render a registered snippet with its parameters substituted
+    ///
+    /// Each `{{name}}` placeholder is replaced by the JSON encoding of the
+    /// matching parameter. Unknown snippet names and placeholders without a
+    /// parameter are errors; extra parameters are ignored.
+    pub fn render(
+        &self,
+        name: &str,
+        params: Option<&serde_json::Map<String, Value>>,
+    ) -> Result<String, String> {
+        let template = self
+            .snippets
+            .read()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Unknown script snippet: {}", name))?;
+
+        let mut script = template;
+        if let Some(params) = params {
+            for (key, value) in params {
+                let placeholder = format!("{{{{{}}}}}", key);
+                // JSON encoding makes the value a JS literal, not code
+                let literal =
+                    serde_json::to_string(value).map_err(|e| format!("Invalid parameter: {}", e))?;
+                script = script.replace(&placeholder, &literal);
+            }
+        }
+
+        if let Some(start) = script.find("{{") {
+            let rest = &script[start..];
+            let end = rest.find("}}").map(|i| i + 2).unwrap_or(rest.len());
+            return Err(format!(
+                "Missing parameter for placeholder {}",
+                &rest[..end]
+            ));
+        }
+
+        Ok(script)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_allowlist_disabled_by_default() {
+        let allowlist = ScriptAllowlist::new();
+        assert!(!allowlist.is_enabled());
+        allowlist.set_enabled(true);
+        assert!(allowlist.is_enabled());
+    }
+
+    #[test]
+    fn test_render_substitutes_params_as_json_literals() {
+        let allowlist = ScriptAllowlist::new();
+        allowlist.register(
+            "query_text",
+            "document.querySelector({{selector}}).innerText",
+        );
+
+        let params = json!({"selector": "#main"});
+        let script = allowlist
+            .render("query_text", params.as_object())
+            .unwrap();
+        assert_eq!(script, "document.querySelector(\"#main\").innerText");
+
+        // A malicious value stays a harmless string literal
+        let params = json!({"selector": "x\"); alert(1); (\""});
+        let script = allowlist
+            .render("query_text", params.as_object())
+            .unwrap();
+        assert!(script.contains("\"x\\\"); alert(1); (\\\"\""));
+    }
+
+    #[test]
+    fn test_render_rejects_unknown_snippet_and_missing_param() {
+        let allowlist = ScriptAllowlist::new();
+        allowlist.register("greet", "hello({{name}})");
+
+        let err = allowlist.render("nope", None).unwrap_err();
+        assert!(err.contains("nope"));
+
+        let err = allowlist.render("greet", None).unwrap_err();
+        assert!(err.contains("{{name}}"));
+    }
+
+    #[test]
+    fn test_render_without_placeholders() {
+        let allowlist = ScriptAllowlist::new();
+        allowlist.register("get_title", "document.title");
+        assert_eq!(
+            allowlist.render("get_title", None).unwrap(),
+            "document.title"
+        );
+        assert_eq!(allowlist.names(), vec!["get_title".to_string()]);
+    }
+}
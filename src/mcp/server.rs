@@ -21,6 +21,7 @@ use crate::error::Result;
 use crate::mcp::tools::ToolRegistry;
 use crate::mcp::types::{
     JsonRpcRequest, JsonRpcResponse, McpCapabilities, McpServerInfo, ToolCallParams,
+    ToolsListParams,
 };
 use serde_json::{json, Value};
 use std::io::{self, BufRead, Write};
@@ -33,6 +34,31 @@ const MCP_TOKEN_ENV_VAR: &str = "REASONKIT_MCP_TOKEN";
 /// JSON-RPC error code for authentication failure (using -32000 range for server errors)
 const AUTH_ERROR_CODE: i32 = -32001;
 
+/// Operator-facing server configuration
+///
+/// Applied to the tool registry at construction. Separate from
+/// [`ServerLimits`](crate::mcp::types::ServerLimits), which only advertises
+/// effective values to clients.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Execution budget for tools without their own entry, in milliseconds
+    pub default_tool_timeout_ms: u64,
+    /// Per-tool execution budgets, in milliseconds
+    ///
+    /// Lets operators give a long-running tool (crawl, full-page PDF) a
+    /// large budget while keeping quick tools on a short leash.
+    pub tool_timeouts: std::collections::HashMap<String, u64>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            default_tool_timeout_ms: crate::mcp::tools::DEFAULT_TOOL_TIMEOUT_MS,
+            tool_timeouts: std::collections::HashMap::new(),
+        }
+    }
+}
+
 /// MCP server state
 pub struct McpServer {
     /// Tool registry
@@ -53,6 +79,14 @@ impl McpServer {
     /// When a token is configured, all incoming requests must include a matching
     /// `auth_token` field in their params object.
     pub fn new() -> Self {
+        Self::with_config(ServerConfig::default())
+    }
+
+    /// Create a new MCP server with operator configuration
+    ///
+    /// Loads the authentication token like [`new`](Self::new) and applies
+    /// the configured tool timeouts to the registry.
+    pub fn with_config(config: ServerConfig) -> Self {
         let auth_token = std::env::var(MCP_TOKEN_ENV_VAR)
             .ok()
             .filter(|t| !t.is_empty());
@@ -69,8 +103,14 @@ impl McpServer {
             );
         }
 
+        let mut tools = ToolRegistry::new();
+        tools.set_default_timeout(config.default_tool_timeout_ms);
+        for (tool, timeout_ms) in &config.tool_timeouts {
+            tools.set_tool_timeout(tool, *timeout_ms);
+        }
+
         Self {
-            tools: ToolRegistry::new(),
+            tools,
             info: McpServerInfo::default(),
             initialized: RwLock::new(false),
             auth_token,
@@ -235,7 +275,7 @@ impl McpServer {
         let id = request.id.clone();
         let method = request.method.as_str();
 
-        info!("Handling method: {}", method);
+        info!(method, request_id = ?id, "Handling method");
 
         // Validate authentication BEFORE processing any method
         // This prevents unauthenticated access to any server functionality
@@ -253,7 +293,7 @@ impl McpServer {
             "shutdown" => self.handle_shutdown().await,
 
             // Tool methods
-            "tools/list" => self.handle_tools_list().await,
+            "tools/list" => self.handle_tools_list(request.params).await,
             "tools/call" => self.handle_tools_call(request.params).await,
 
             // Ping (for testing)
@@ -297,14 +337,42 @@ impl McpServer {
     async fn handle_shutdown(&self) -> Result<Value> {
         info!("Handling shutdown");
         *self.initialized.write().await = false;
+        if let Err(e) = self.tools.shutdown().await {
+            warn!("Failed to close shared browser on shutdown: {}", e);
+        }
         Ok(json!(null))
     }
 
     /// Handle tools/list request
-    async fn handle_tools_list(&self) -> Result<Value> {
-        let definitions = self.tools.definitions();
+    ///
+    /// With no params this returns the flat list older clients expect. A
+    /// `category` param restricts the list to that category; `grouped: true`
+    /// returns the tools grouped by category instead.
+    async fn handle_tools_list(&self, params: Option<Value>) -> Result<Value> {
+        let list_params: ToolsListParams = match params {
+            Some(p) => serde_json::from_value(p)
+                .map_err(|e| crate::error::Error::generic(format!("Invalid params: {}", e)))?,
+            None => ToolsListParams::default(),
+        };
+
+        if let Some(category) = list_params.category {
+            return Ok(json!({
+                "tools": self.tools.definitions_in(category)
+            }));
+        }
+
+        if list_params.grouped {
+            let categories: Vec<Value> = self
+                .tools
+                .definitions_grouped()
+                .into_iter()
+                .map(|(category, tools)| json!({ "category": category, "tools": tools }))
+                .collect();
+            return Ok(json!({ "categories": categories }));
+        }
+
         Ok(json!({
-            "tools": definitions
+            "tools": self.tools.definitions()
         }))
     }
 
@@ -566,6 +634,42 @@ mod tests {
         assert!(result["serverInfo"].is_object());
     }
 
+    #[tokio::test]
+    async fn test_initialize_advertises_limits() {
+        std::env::remove_var(MCP_TOKEN_ENV_VAR);
+        let server = McpServer::new();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "initialize".to_string(),
+            params: Some(json!({
+                "protocolVersion": "2024-11-05"
+            })),
+            id: Some(json!(1)),
+        };
+
+        let response = server.handle_request(request).await.unwrap();
+        let result = response.result.unwrap();
+        let limits = &result["serverInfo"]["limits"];
+        assert_eq!(
+            limits["maxContentBytes"].as_u64().unwrap(),
+            crate::handlers::capture::DEFAULT_MAX_CONTENT_LENGTH as u64
+        );
+        assert_eq!(limits["defaultTimeoutMs"].as_u64().unwrap(), 30_000);
+        assert_eq!(
+            limits["rateLimitRpm"].as_u64().unwrap(),
+            u64::from(crate::security::DEFAULT_RATE_LIMIT_RPM)
+        );
+        let formats: Vec<&str> = limits["captureFormats"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|v| v.as_str())
+            .collect();
+        assert!(formats.contains(&"png"));
+        assert!(formats.contains(&"webp"));
+        assert!(formats.contains(&"mhtml"));
+    }
+
     #[tokio::test]
     async fn test_handle_tools_list() {
         std::env::remove_var(MCP_TOKEN_ENV_VAR);
@@ -584,6 +688,79 @@ mod tests {
         assert!(!result["tools"].as_array().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_with_config_applies_tool_timeouts() {
+        std::env::remove_var(MCP_TOKEN_ENV_VAR);
+        let config = ServerConfig {
+            default_tool_timeout_ms: 10_000,
+            tool_timeouts: std::collections::HashMap::from([("web_pdf".to_string(), 60_000)]),
+        };
+
+        let server = McpServer::with_config(config);
+        assert_eq!(server.tools.timeout_for("web_pdf"), 60_000);
+        assert_eq!(server.tools.timeout_for("web_navigate"), 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_handle_tools_list_by_category() {
+        std::env::remove_var(MCP_TOKEN_ENV_VAR);
+        let server = McpServer::new();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "tools/list".to_string(),
+            params: Some(json!({ "category": "extraction" })),
+            id: Some(json!(2)),
+        };
+
+        let response = server.handle_request(request).await.unwrap();
+        let result = response.result.unwrap();
+        let tools = result["tools"].as_array().unwrap();
+        assert!(!tools.is_empty());
+        assert!(tools
+            .iter()
+            .all(|t| t["name"].as_str().unwrap().starts_with("web_extract")
+                || t["name"] == "web_search_text"
+                || t["name"] == "web_classify"
+                || t["name"] == "web_capture_response"
+                || t["name"] == "web_check_links"));
+        assert!(!tools.iter().any(|t| t["name"] == "web_navigate"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_tools_list_grouped() {
+        std::env::remove_var(MCP_TOKEN_ENV_VAR);
+        let server = McpServer::new();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "tools/list".to_string(),
+            params: Some(json!({ "grouped": true })),
+            id: Some(json!(2)),
+        };
+
+        let response = server.handle_request(request).await.unwrap();
+        let result = response.result.unwrap();
+        let categories = result["categories"].as_array().unwrap();
+        assert!(categories.iter().any(|c| c["category"] == "capture"));
+        assert!(categories
+            .iter()
+            .all(|c| !c["tools"].as_array().unwrap().is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_handle_tools_list_unknown_category() {
+        std::env::remove_var(MCP_TOKEN_ENV_VAR);
+        let server = McpServer::new();
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "tools/list".to_string(),
+            params: Some(json!({ "category": "telemetry" })),
+            id: Some(json!(2)),
+        };
+
+        let response = server.handle_request(request).await.unwrap();
+        assert!(response.error.is_some());
+    }
+
     #[tokio::test]
     async fn test_handle_unknown_method() {
         std::env::remove_var(MCP_TOKEN_ENV_VAR);
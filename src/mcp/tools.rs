@@ -2,22 +2,65 @@
 //!
 //! This module defines the available MCP tools and their implementations.
 
-use crate::browser::{BrowserController, CaptureFormat, CaptureOptions, PageCapture};
+use crate::browser::{
+    BrowserController, CaptureFormat, CaptureOptions, FrameEvaluator, HtmlInlineOptions,
+    PageCapture,
+};
 use crate::error::Result;
 use crate::extraction::{ContentExtractor, LinkExtractor, MetadataExtractor};
 use crate::mcp::types::{McpToolDefinition, ToolCallResult, ToolContent};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
+
+/// Category a tool belongs to
+///
+/// Used by `tools/list` to let clients present tools grouped rather than
+/// as one flat list. Serialized in lowercase (e.g. `"extraction"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolCategory {
+    /// Page navigation
+    Navigation,
+    /// Screenshots, PDFs, and page archives
+    Capture,
+    /// Content, link, metadata, and table extraction
+    Extraction,
+    /// JavaScript execution
+    Scripting,
+    /// Tools that declare no category
+    Other,
+}
+
+impl ToolCategory {
+    /// All categories in presentation order
+    pub const ALL: &'static [ToolCategory] = &[
+        ToolCategory::Navigation,
+        ToolCategory::Capture,
+        ToolCategory::Extraction,
+        ToolCategory::Scripting,
+        ToolCategory::Other,
+    ];
+}
 
 /// A registered MCP tool
+#[async_trait::async_trait]
 pub trait McpTool: Send + Sync {
     /// Tool name
     fn name(&self) -> &str;
     /// Tool description
     fn description(&self) -> &str;
+    /// Category for grouped `tools/list` output
+    ///
+    /// Defaults to [`ToolCategory::Other`] so existing third-party tools
+    /// keep working without changes.
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Other
+    }
     /// Input schema as JSON
     fn input_schema(&self) -> Value;
     /// Get tool definition
@@ -28,13 +71,153 @@ pub trait McpTool: Send + Sync {
             input_schema: self.input_schema(),
         }
     }
+    /// Execute the tool with the given arguments
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult;
+}
+
+/// Shared state available to tool executions
+///
+/// Holds the lazily-launched shared browser. Third-party tools receive this
+/// context so they can reuse the same browser as the built-in tools.
+#[derive(Clone)]
+pub struct ToolContext {
+    browser: Arc<RwLock<Option<BrowserController>>>,
+    launch_count: Arc<AtomicUsize>,
+    extraction_cache: Arc<crate::extraction::ExtractionCache>,
+    link_check_session: Arc<crate::extraction::LinkCheckSession>,
+    script_allowlist: Arc<super::scripts::ScriptAllowlist>,
+}
+
+/// Read guard providing access to the shared browser
+///
+/// Dereferences to [`BrowserController`]; the browser stays open at least as
+/// long as the guard is held.
+pub struct BrowserGuard(tokio::sync::OwnedRwLockReadGuard<Option<BrowserController>>);
+
+impl std::ops::Deref for BrowserGuard {
+    type Target = BrowserController;
+
+    fn deref(&self) -> &BrowserController {
+        self.0
+            .as_ref()
+            .expect("browser present while read guard is held")
+    }
+}
+
+impl ToolContext {
+    fn new() -> Self {
+        Self {
+            browser: Arc::new(RwLock::new(None)),
+            launch_count: Arc::new(AtomicUsize::new(0)),
+            extraction_cache: Arc::new(crate::extraction::ExtractionCache::new()),
+            link_check_session: Arc::new(crate::extraction::LinkCheckSession::new()),
+            script_allowlist: Arc::new(super::scripts::ScriptAllowlist::new()),
+        }
+    }
+
+    /// Operator-registered snippet allowlist for `web_execute_js`
+    ///
+    /// When enabled, only snippets registered here may run; arbitrary
+    /// inline scripts are refused.
+    pub fn script_allowlist(&self) -> &super::scripts::ScriptAllowlist {
+        &self.script_allowlist
+    }
+
+    /// Cache of extraction output keyed by content hash
+    ///
+    /// Shared across tool calls so mirror URLs serving identical HTML reuse
+    /// processed output instead of re-running extraction.
+    pub fn extraction_cache(&self) -> &crate::extraction::ExtractionCache {
+        &self.extraction_cache
+    }
+
+    /// Session-scoped link-check cache and concurrency bound
+    ///
+    /// Shared across tool calls so a URL appearing on many pages is checked
+    /// once per TTL and total link-check fan-out stays bounded.
+    pub fn link_check_session(&self) -> &crate::extraction::LinkCheckSession {
+        &self.link_check_session
+    }
+
+    /// Get the shared browser, launching it on first use
+    ///
+    /// Each tool call creates a new page on this browser rather than a new
+    /// browser, so a sequence of calls pays the launch cost only once.
+    pub async fn browser(&self) -> Result<BrowserGuard> {
+        loop {
+            let guard = self.browser.clone().read_owned().await;
+            if guard.is_some() {
+                return Ok(BrowserGuard(guard));
+            }
+            drop(guard);
+
+            let mut write = self.browser.write().await;
+            // Another caller may have launched while we waited for the lock
+            if write.is_none() {
+                info!("Launching shared browser (first tool call)");
+                *write = Some(BrowserController::new().await?);
+                self.launch_count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Number of times a browser has been launched
+    pub fn launch_count(&self) -> usize {
+        self.launch_count.load(Ordering::Relaxed)
+    }
+
+    /// Discard the shared browser so the next call launches a fresh one
+    ///
+    /// Used when the browser is suspected dead; close errors are ignored
+    /// because a lost connection cannot be closed cleanly anyway.
+    pub async fn recycle_browser(&self) {
+        if let Some(browser) = self.browser.write().await.take() {
+            info!("Discarding shared browser after transient failure");
+            let _ = browser.close().await;
+        }
+    }
+
+    /// Close the shared browser, if running
+    ///
+    /// A subsequent tool call will lazily launch a fresh browser.
+    pub async fn shutdown(&self) -> Result<()> {
+        if let Some(browser) = self.browser.write().await.take() {
+            info!("Closing shared browser");
+            browser.close().await?;
+        }
+        Ok(())
+    }
 }
 
+/// Default budget for a single tool execution, in milliseconds
+///
+/// Generous because one call can cover a navigation plus extraction; tools
+/// with very different expected durations get their own budget via
+/// [`ToolRegistry::set_tool_timeout`].
+pub const DEFAULT_TOOL_TIMEOUT_MS: u64 = 120_000;
+
+/// Default number of retries after a transient browser failure
+///
+/// One retry covers the common case — a browser that died between calls —
+/// without masking a browser that keeps crashing.
+pub const DEFAULT_TRANSIENT_RETRIES: u32 = 1;
+
 /// Tool registry holding all available tools
 pub struct ToolRegistry {
     tools: HashMap<String, Box<dyn McpTool>>,
-    #[allow(dead_code)]
-    browser: Arc<RwLock<Option<BrowserController>>>,
+    /// Shared context passed to every tool execution
+    context: ToolContext,
+    /// Per-tool default arguments merged under client-supplied arguments
+    default_args: HashMap<String, Value>,
+    /// Definitions cached at registration; building JSON schemas per call
+    /// is too expensive for hot paths like `tools/list`
+    definitions: Vec<McpToolDefinition>,
+    /// Execution budget applied when a tool has no entry in `tool_timeouts`
+    default_timeout_ms: u64,
+    /// Per-tool execution budgets, in milliseconds
+    tool_timeouts: HashMap<String, u64>,
+    /// How many times a call may retry after a transient browser failure
+    transient_retries: u32,
 }
 
 impl ToolRegistry {
@@ -42,7 +225,12 @@ impl ToolRegistry {
     pub fn new() -> Self {
         let mut registry = Self {
             tools: HashMap::new(),
-            browser: Arc::new(RwLock::new(None)),
+            context: ToolContext::new(),
+            default_args: HashMap::new(),
+            definitions: Vec::new(),
+            default_timeout_ms: DEFAULT_TOOL_TIMEOUT_MS,
+            tool_timeouts: HashMap::new(),
+            transient_retries: DEFAULT_TRANSIENT_RETRIES,
         };
 
         // Register all built-in tools
@@ -53,301 +241,327 @@ impl ToolRegistry {
         registry.register(Box::new(WebExtractLinksTool));
         registry.register(Box::new(WebExtractMetadataTool));
         registry.register(Box::new(WebExecuteJsTool));
+        registry.register(Box::new(WebAssertTool));
         registry.register(Box::new(WebCaptureMhtmlTool));
+        registry.register(Box::new(WebCaptureHtmlTool));
+        registry.register(Box::new(WebExtractResourcesTool));
+        registry.register(Box::new(WebExtractTablesTool));
+        registry.register(Box::new(WebExtractDomTreeTool));
+        registry.register(Box::new(WebExtractFormTool));
+        registry.register(Box::new(WebCheckLinksTool));
+        registry.register(Box::new(WebSearchTextTool));
+        registry.register(Box::new(WebClassifyTool));
+        registry.register(Box::new(WebExtractBatchTool));
+        registry.register(Box::new(WebDownloadTool));
+        registry.register(Box::new(WebCaptureResponseTool));
+        registry.register(Box::new(WebInspectElementTool));
+        registry.register(Box::new(WebResponsiveScreenshotTool));
+        registry.register(Box::new(WebCaptureImageRegionsTool));
 
         registry
     }
 
     /// Register a tool
+    ///
+    /// Re-registering a name replaces the previous tool and its definition.
     pub fn register(&mut self, tool: Box<dyn McpTool>) {
+        self.definitions.retain(|d| d.name != tool.name());
+        self.definitions.push(tool.definition());
         self.tools.insert(tool.name().to_string(), tool);
     }
 
-    /// Get all tool definitions
-    pub fn definitions(&self) -> Vec<McpToolDefinition> {
-        self.tools.values().map(|t| t.definition()).collect()
+    /// Set default arguments for a tool
+    ///
+    /// Defaults are merged under client-supplied arguments on every call
+    /// (the client always wins), letting operators enforce policies like
+    /// "always webp screenshots" without client changes. `defaults` must be
+    /// a JSON object.
+    pub fn set_default_args(&mut self, tool: &str, defaults: Value) {
+        self.default_args.insert(tool.to_string(), defaults);
     }
 
-    /// Execute a tool by name
-    #[instrument(skip(self, args))]
-    pub async fn execute(&self, name: &str, args: Value) -> ToolCallResult {
-        info!("Executing tool: {}", name);
-
-        if !self.tools.contains_key(name) {
-            return ToolCallResult::error(format!("Tool not found: {}", name));
-        }
-
-        // Ensure browser is available
-        let browser = self.get_or_create_browser().await;
-        let browser = match browser {
-            Ok(b) => b,
-            Err(e) => return ToolCallResult::error(format!("Failed to create browser: {}", e)),
-        };
+    /// Set the execution budget for one tool, in milliseconds
+    ///
+    /// Tools without an entry use the default budget; see
+    /// [`set_default_timeout`](Self::set_default_timeout).
+    pub fn set_tool_timeout(&mut self, tool: &str, timeout_ms: u64) {
+        self.tool_timeouts.insert(tool.to_string(), timeout_ms);
+    }
 
-        match name {
-            "web_navigate" => self.execute_navigate(&browser, args).await,
-            "web_screenshot" => self.execute_screenshot(&browser, args).await,
-            "web_pdf" => self.execute_pdf(&browser, args).await,
-            "web_extract_content" => self.execute_extract_content(&browser, args).await,
-            "web_extract_links" => self.execute_extract_links(&browser, args).await,
-            "web_extract_metadata" => self.execute_extract_metadata(&browser, args).await,
-            "web_execute_js" => self.execute_js(&browser, args).await,
-            "web_capture_mhtml" => self.execute_capture_mhtml(&browser, args).await,
-            _ => ToolCallResult::error(format!("Unknown tool: {}", name)),
-        }
+    /// Set the execution budget applied to tools without their own
+    pub fn set_default_timeout(&mut self, timeout_ms: u64) {
+        self.default_timeout_ms = timeout_ms;
     }
 
-    /// Get or create browser instance
-    async fn get_or_create_browser(&self) -> Result<BrowserController> {
-        // For simplicity, create a new browser each time
-        // In production, you'd want to pool/reuse browsers
-        BrowserController::new().await
+    /// The execution budget for a tool, in milliseconds
+    pub fn timeout_for(&self, tool: &str) -> u64 {
+        self.tool_timeouts
+            .get(tool)
+            .copied()
+            .unwrap_or(self.default_timeout_ms)
     }
 
-    async fn execute_navigate(&self, browser: &BrowserController, args: Value) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// Get all tool definitions
+    pub fn definitions(&self) -> &[McpToolDefinition] {
+        &self.definitions
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => {
-                let current_url = page.url().await;
-                ToolCallResult::text(format!("Successfully navigated to: {}", current_url))
-            }
-            Err(e) => {
-                error!("Navigation failed: {}", e);
-                ToolCallResult::error(format!("Navigation failed: {}", e))
-            }
-        }
+    /// Get the definitions of tools in one category, sorted by name
+    pub fn definitions_in(&self, category: ToolCategory) -> Vec<McpToolDefinition> {
+        let mut definitions: Vec<McpToolDefinition> = self
+            .tools
+            .values()
+            .filter(|tool| tool.category() == category)
+            .map(|tool| tool.definition())
+            .collect();
+        definitions.sort_by(|a, b| a.name.cmp(&b.name));
+        definitions
     }
 
-    async fn execute_screenshot(&self, browser: &BrowserController, args: Value) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// Get all tool definitions grouped by category
+    ///
+    /// Categories follow [`ToolCategory::ALL`] order; empty categories are
+    /// omitted.
+    pub fn definitions_grouped(&self) -> Vec<(ToolCategory, Vec<McpToolDefinition>)> {
+        ToolCategory::ALL
+            .iter()
+            .map(|&category| (category, self.definitions_in(category)))
+            .filter(|(_, definitions)| !definitions.is_empty())
+            .collect()
+    }
 
-        let full_page = args
-            .get("fullPage")
-            .and_then(|v| v.as_bool())
-            .unwrap_or(true);
-        let format_str = args.get("format").and_then(|v| v.as_str()).unwrap_or("png");
+    /// Execute a tool by name
+    ///
+    /// Dispatch is generic: any registered [`McpTool`] (including third-party
+    /// tools) is invoked through its own `execute` implementation.
+    #[instrument(skip(self, args))]
+    pub async fn execute(&self, name: &str, args: Value) -> ToolCallResult {
+        info!(tool = name, "Executing tool");
 
-        let format = match format_str {
-            "jpeg" | "jpg" => CaptureFormat::Jpeg,
-            "webp" => CaptureFormat::Webp,
-            _ => CaptureFormat::Png,
+        let args = match self.default_args.get(name) {
+            Some(defaults) => merge_args(defaults, args),
+            None => args,
         };
 
-        match browser.navigate(url).await {
-            Ok(page) => {
-                let options = CaptureOptions {
-                    format,
-                    full_page,
-                    as_base64: true,
-                    ..Default::default()
-                };
-
-                match PageCapture::capture(&page, &options).await {
-                    Ok(result) => {
-                        let base64 = result.base64.clone().unwrap_or_else(|| result.to_base64());
-                        ToolCallResult::image(base64, result.mime_type())
+        match self.tools.get(name) {
+            Some(tool) => {
+                let timeout_ms = self.timeout_for(name);
+                let mut attempt = 0;
+                loop {
+                    let execution = tool.execute(&self.context, args.clone());
+                    let result = match tokio::time::timeout(
+                        std::time::Duration::from_millis(timeout_ms),
+                        execution,
+                    )
+                    .await
+                    {
+                        Ok(result) => result,
+                        Err(_) => {
+                            warn!(tool = name, timeout_ms, "Tool execution timed out");
+                            return ToolCallResult::error(format!(
+                                "Tool '{}' timed out after {}ms",
+                                name, timeout_ms
+                            ));
+                        }
+                    };
+
+                    // A browser that died mid-call gets one more chance on a
+                    // fresh instance; ordinary failures return as-is
+                    if attempt < self.transient_retries
+                        && Self::is_transient_browser_failure(&result)
+                    {
+                        attempt += 1;
+                        warn!(
+                            tool = name,
+                            attempt, "Transient browser failure, retrying on a fresh browser"
+                        );
+                        self.context.recycle_browser().await;
+                        continue;
                     }
-                    Err(e) => ToolCallResult::error(format!("Screenshot failed: {}", e)),
+
+                    // Blob fields elided: logs must never carry full captures
+                    debug!(tool = name, result = %result.for_logging(), "Tool execution finished");
+                    return result;
                 }
             }
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+            None => ToolCallResult::error(format!("Tool not found: {}", name)),
         }
     }
 
-    async fn execute_pdf(&self, browser: &BrowserController, args: Value) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// Whether a failed call looks like a transient browser fault
+    ///
+    /// Matches the messages produced by [`crate::error::BrowserError`]
+    /// variants that a fresh browser can cure — a lost connection, a page
+    /// or session that went away, or Chrome rejecting the protocol.
+    /// Anything else (invalid URLs, missing parameters, timeouts) is a
+    /// deterministic failure and must not retry.
+    fn is_transient_browser_failure(result: &ToolCallResult) -> bool {
+        if !result.is_error {
+            return false;
+        }
+        result.content.iter().any(|content| match content {
+            ToolContent::Text { text } => {
+                let text = text.to_lowercase();
+                text.contains("browser connection lost")
+                    || text.contains("browser already closed")
+                    || text.contains("page closed")
+                    || text.contains("session closed")
+                    || text.contains("not supported by chrome")
+            }
+            _ => false,
+        })
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => {
-                let options = CaptureOptions::pdf();
+    /// Set how many times a call may retry after a transient browser failure
+    ///
+    /// The default is [`DEFAULT_TRANSIENT_RETRIES`]; zero disables retrying.
+    pub fn set_transient_retries(&mut self, retries: u32) {
+        self.transient_retries = retries;
+    }
 
-                match PageCapture::capture(&page, &options).await {
-                    Ok(result) => {
-                        let base64 = result.to_base64();
-                        ToolCallResult::multi(vec![
-                            ToolContent::text(format!("PDF generated: {} bytes", result.size)),
-                            ToolContent::Resource {
-                                uri: format!("pdf://{}", url),
-                                resource: crate::mcp::types::ResourceContent {
-                                    mime_type: "application/pdf".to_string(),
-                                    text: None,
-                                    blob: Some(base64),
-                                },
-                            },
-                        ])
-                    }
-                    Err(e) => ToolCallResult::error(format!("PDF generation failed: {}", e)),
-                }
-            }
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
-        }
+    /// Number of times a browser has been launched
+    pub fn launch_count(&self) -> usize {
+        self.context.launch_count()
     }
 
-    async fn execute_extract_content(
-        &self,
-        browser: &BrowserController,
-        args: Value,
-    ) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// Cache of extraction output shared by this registry's tools
+    pub fn extraction_cache(&self) -> &crate::extraction::ExtractionCache {
+        self.context.extraction_cache()
+    }
 
-        let selector = args.get("selector").and_then(|v| v.as_str());
-        let format = args
-            .get("format")
-            .and_then(|v| v.as_str())
-            .unwrap_or("markdown");
+    /// Snippet allowlist governing `web_execute_js` for this registry
+    pub fn script_allowlist(&self) -> &super::scripts::ScriptAllowlist {
+        self.context.script_allowlist()
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => {
-                let content = if let Some(sel) = selector {
-                    ContentExtractor::extract_from_selector(&page, sel).await
-                } else {
-                    ContentExtractor::extract_main_content(&page).await
-                };
+    /// Close the shared browser, if running
+    pub async fn shutdown(&self) -> Result<()> {
+        self.context.shutdown().await
+    }
+}
 
-                match content {
-                    Ok(c) => {
-                        let output = match format {
-                            "text" => c.text,
-                            "html" => c.html,
-                            _ => c.markdown.unwrap_or(c.text),
-                        };
-                        ToolCallResult::text(output)
-                    }
-                    Err(e) => ToolCallResult::error(format!("Content extraction failed: {}", e)),
-                }
-            }
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+/// Merge default arguments under client-supplied arguments
+///
+/// A shallow merge of two JSON objects: every key from `defaults` that the
+/// client did not supply is copied in, while client-supplied keys are left
+/// untouched. Non-object arguments are returned as-is.
+pub fn merge_args(defaults: &Value, args: Value) -> Value {
+    let Some(default_map) = defaults.as_object() else {
+        return args;
+    };
+
+    // Clients may omit arguments entirely; treat that as an empty object
+    let mut map = match args {
+        Value::Object(map) => map,
+        Value::Null => serde_json::Map::new(),
+        other => return other,
+    };
+
+    for (key, value) in default_map {
+        if !map.contains_key(key) {
+            map.insert(key.clone(), value.clone());
         }
     }
 
-    async fn execute_extract_links(
-        &self,
-        browser: &BrowserController,
-        args: Value,
-    ) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    Value::Object(map)
+}
 
-        let link_type = args.get("type").and_then(|v| v.as_str());
-        let selector = args.get("selector").and_then(|v| v.as_str());
+/// Acquire the shared browser or return an error result
+///
+/// Shared preamble for the built-in browser-backed tools.
+async fn require_browser(ctx: &ToolContext) -> std::result::Result<BrowserGuard, ToolCallResult> {
+    ctx.browser()
+        .await
+        .map_err(|e| ToolCallResult::error(format!("Failed to create browser: {}", e)))
+}
 
-        match browser.navigate(url).await {
-            Ok(page) => {
-                let links = if let Some(sel) = selector {
-                    LinkExtractor::extract_from_selector(&page, sel).await
-                } else {
-                    match link_type {
-                        Some("internal") => LinkExtractor::extract_internal(&page).await,
-                        Some("external") => LinkExtractor::extract_external(&page).await,
-                        _ => LinkExtractor::extract_all(&page).await,
-                    }
-                };
+/// Typed view over a tool call's JSON arguments
+///
+/// Handlers used to repeat `args.get("url").and_then(|v| v.as_str())` with
+/// ad-hoc error strings. This wraps the raw arguments with typed getters
+/// whose errors are uniform: a missing required parameter reports
+/// "Missing required parameter: <name>" and a wrong-typed value reports
+/// "Invalid parameter: <name> must be <type>", so clients see the same
+/// message shape from every tool. `null` counts as absent, matching how
+/// clients clear an argument.
+pub struct ToolArgs {
+    value: Value,
+}
 
-                match links {
-                    Ok(links) => {
-                        let json = serde_json::to_string_pretty(&links)
-                            .unwrap_or_else(|_| "[]".to_string());
-                        ToolCallResult::text(json)
-                    }
-                    Err(e) => ToolCallResult::error(format!("Link extraction failed: {}", e)),
-                }
-            }
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
-        }
+impl ToolArgs {
+    /// Wrap a tool call's arguments
+    pub fn new(value: Value) -> Self {
+        Self { value }
     }
 
-    async fn execute_extract_metadata(
-        &self,
-        browser: &BrowserController,
-        args: Value,
-    ) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// The raw value for a key, for arguments with bespoke shapes
+    pub fn raw(&self, name: &str) -> Option<&Value> {
+        self.value.get(name).filter(|v| !v.is_null())
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => match MetadataExtractor::extract(&page).await {
-                Ok(meta) => {
-                    let json =
-                        serde_json::to_string_pretty(&meta).unwrap_or_else(|_| "{}".to_string());
-                    ToolCallResult::text(json)
-                }
-                Err(e) => ToolCallResult::error(format!("Metadata extraction failed: {}", e)),
-            },
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+    /// A required string argument
+    pub fn require_str(&self, name: &str) -> std::result::Result<&str, ToolCallResult> {
+        match self.opt_str(name)? {
+            Some(value) => Ok(value),
+            None => Err(ToolCallResult::error(format!(
+                "Missing required parameter: {}",
+                name
+            ))),
         }
     }
 
-    async fn execute_js(&self, browser: &BrowserController, args: Value) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+    /// An optional string argument
+    pub fn opt_str(&self, name: &str) -> std::result::Result<Option<&str>, ToolCallResult> {
+        self.opt(name, "a string", Value::as_str)
+    }
 
-        let script = match args.get("script").and_then(|v| v.as_str()) {
-            Some(s) => s,
-            None => return ToolCallResult::error("Missing required parameter: script"),
-        };
+    /// An optional boolean argument
+    pub fn opt_bool(&self, name: &str) -> std::result::Result<Option<bool>, ToolCallResult> {
+        self.opt(name, "a boolean", Value::as_bool)
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => match page.page.evaluate(script).await {
-                Ok(result) => {
-                    let value: Value = result.into_value().unwrap_or(Value::Null);
-                    let output =
-                        serde_json::to_string_pretty(&value).unwrap_or_else(|_| "null".to_string());
-                    ToolCallResult::text(output)
-                }
-                Err(e) => ToolCallResult::error(format!("JavaScript execution failed: {}", e)),
-            },
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
-        }
+    /// An optional non-negative integer argument
+    pub fn opt_u64(&self, name: &str) -> std::result::Result<Option<u64>, ToolCallResult> {
+        self.opt(name, "a non-negative integer", Value::as_u64)
     }
 
-    async fn execute_capture_mhtml(
+    /// An optional array-of-strings argument
+    pub fn opt_str_array(
         &self,
-        browser: &BrowserController,
-        args: Value,
-    ) -> ToolCallResult {
-        let url = match args.get("url").and_then(|v| v.as_str()) {
-            Some(u) => u,
-            None => return ToolCallResult::error("Missing required parameter: url"),
-        };
+        name: &str,
+    ) -> std::result::Result<Option<Vec<String>>, ToolCallResult> {
+        self.opt(name, "an array of strings", |v| {
+            v.as_array()?
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect()
+        })
+    }
 
-        match browser.navigate(url).await {
-            Ok(page) => match PageCapture::mhtml(&page).await {
-                Ok(result) => {
-                    let base64 = result.to_base64();
-                    ToolCallResult::multi(vec![
-                        ToolContent::text(format!("MHTML captured: {} bytes", result.size)),
-                        ToolContent::Resource {
-                            uri: format!("mhtml://{}", url),
-                            resource: crate::mcp::types::ResourceContent {
-                                mime_type: "multipart/related".to_string(),
-                                text: None,
-                                blob: Some(base64),
-                            },
-                        },
-                    ])
-                }
-                Err(e) => ToolCallResult::error(format!("MHTML capture failed: {}", e)),
+    /// An optional object argument
+    pub fn opt_object(
+        &self,
+        name: &str,
+    ) -> std::result::Result<Option<&serde_json::Map<String, Value>>, ToolCallResult> {
+        self.opt(name, "an object", Value::as_object)
+    }
+
+    /// Shared present-but-wrong-type handling behind the typed getters
+    fn opt<'a, T>(
+        &'a self,
+        name: &str,
+        expected: &str,
+        convert: impl Fn(&'a Value) -> Option<T>,
+    ) -> std::result::Result<Option<T>, ToolCallResult> {
+        match self.raw(name) {
+            None => Ok(None),
+            Some(value) => match convert(value) {
+                Some(converted) => Ok(Some(converted)),
+                None => Err(ToolCallResult::error(format!(
+                    "Invalid parameter: {} must be {}",
+                    name, expected
+                ))),
             },
-            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
         }
     }
 }
@@ -365,11 +579,16 @@ impl Default for ToolRegistry {
 /// Navigate to a URL
 struct WebNavigateTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebNavigateTool {
     fn name(&self) -> &str {
         "web_navigate"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Navigation
+    }
+
     fn description(&self) -> &str {
         "Navigate to a URL using a headless browser"
     }
@@ -391,16 +610,45 @@ impl McpTool for WebNavigateTool {
             "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                let current_url = page.url().await;
+                ToolCallResult::text(format!("Successfully navigated to: {}", current_url))
+            }
+            Err(e) => {
+                error!("Navigation failed: {}", e);
+                ToolCallResult::error(format!("Navigation failed: {}", e))
+            }
+        }
+    }
 }
 
 /// Capture screenshot
 struct WebScreenshotTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebScreenshotTool {
     fn name(&self) -> &str {
         "web_screenshot"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
     fn description(&self) -> &str {
         "Capture a screenshot of a web page"
     }
@@ -427,52 +675,172 @@ impl McpTool for WebScreenshotTool {
                 "selector": {
                     "type": "string",
                     "description": "CSS selector to capture specific element"
+                },
+                "preCaptureDelayMs": {
+                    "type": "integer",
+                    "description": "Delay in milliseconds after load, before capturing (default: 0)",
+                    "default": 0
                 }
             },
             "required": ["url"]
         })
     }
-}
 
-/// Generate PDF
-struct WebPdfTool;
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
 
-impl McpTool for WebPdfTool {
-    fn name(&self) -> &str {
-        "web_pdf"
-    }
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
 
-    fn description(&self) -> &str {
-        "Generate a PDF of a web page"
-    }
+        let full_page = match args.opt_bool("fullPage") {
+            Ok(v) => v.unwrap_or(true),
+            Err(result) => return result,
+        };
+        let format_str = match args.opt_str("format") {
+            Ok(v) => v.unwrap_or("png"),
+            Err(result) => return result,
+        };
 
-    fn input_schema(&self) -> Value {
-        json!({
-            "type": "object",
-            "properties": {
-                "url": {
-                    "type": "string",
-                    "description": "The URL to convert to PDF"
-                },
+        let format = match format_str {
+            "jpeg" | "jpg" => CaptureFormat::Jpeg,
+            "webp" => CaptureFormat::Webp,
+            _ => CaptureFormat::Png,
+        };
+
+        let pre_capture_delay_ms = match args.opt_u64("preCaptureDelayMs") {
+            Ok(v) => v.unwrap_or(0),
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                let options = CaptureOptions {
+                    format,
+                    full_page,
+                    as_base64: true,
+                    pre_capture_delay_ms,
+                    ..Default::default()
+                };
+
+                match PageCapture::capture(&page, &options).await {
+                    Ok(result) => {
+                        let base64 = result.base64();
+                        ToolCallResult::image(base64, result.mime_type())
+                    }
+                    Err(e) => ToolCallResult::error(format!("Screenshot failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Generate PDF
+struct WebPdfTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebPdfTool {
+    fn name(&self) -> &str {
+        "web_pdf"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Generate a PDF of a web page"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to convert to PDF"
+                },
                 "printBackground": {
                     "type": "boolean",
                     "description": "Print background graphics (default: true)",
                     "default": true
+                },
+                "readable": {
+                    "type": "boolean",
+                    "description": "Strip page chrome and print only the readable main content (default: false)",
+                    "default": false
                 }
             },
             "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let readable = match args.opt_bool("readable") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                let options = CaptureOptions {
+                    readable,
+                    ..CaptureOptions::pdf()
+                };
+
+                match PageCapture::capture(&page, &options).await {
+                    Ok(result) => {
+                        let base64 = result.base64();
+                        ToolCallResult::multi(vec![
+                            ToolContent::text(format!("PDF generated: {} bytes", result.size)),
+                            ToolContent::Resource {
+                                uri: format!("pdf://{}", url),
+                                resource: crate::mcp::types::ResourceContent {
+                                    mime_type: "application/pdf".to_string(),
+                                    text: None,
+                                    blob: Some(base64),
+                                },
+                            },
+                        ])
+                    }
+                    Err(e) => ToolCallResult::error(format!("PDF generation failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
 }
 
 /// Extract content
 struct WebExtractContentTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebExtractContentTool {
     fn name(&self) -> &str {
         "web_extract_content"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
     fn description(&self) -> &str {
         "Extract main content from a web page as text or markdown"
     }
@@ -494,21 +862,184 @@ impl McpTool for WebExtractContentTool {
                     "enum": ["text", "markdown", "html"],
                     "description": "Output format (default: markdown)",
                     "default": "markdown"
+                },
+                "scrollToSelector": {
+                    "type": "string",
+                    "description": "Scroll this element into view and wait for lazy content to settle before extracting"
+                },
+                "includeOutline": {
+                    "type": "boolean",
+                    "description": "Also return the h1-h6 heading outline with anchors (default: false)",
+                    "default": false
+                },
+                "includeLinks": {
+                    "type": "boolean",
+                    "description": "Also return anchors within the main content with resolved URLs and context (default: false)",
+                    "default": false
+                },
+                "includeComments": {
+                    "type": "boolean",
+                    "description": "Also return detected comment-section text, which is always excluded from the main content (default: false)",
+                    "default": false
                 }
             },
             "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let selector = match args.opt_str("selector") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let format = match args.opt_str("format") {
+            Ok(v) => v.unwrap_or("markdown"),
+            Err(result) => return result,
+        };
+        let scroll_to = match args.opt_str("scrollToSelector") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let include_outline = match args.opt_bool("includeOutline") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let include_links = match args.opt_bool("includeLinks") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let include_comments = match args.opt_bool("includeComments") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                // Bring the lazy section into view before reading the DOM,
+                // so the content hash sees what will be extracted
+                if let Some(sel) = scroll_to {
+                    if let Err(e) = ContentExtractor::scroll_to_selector(
+                        &page,
+                        sel,
+                        crate::extraction::DEFAULT_SCROLL_SETTLE_MS,
+                    )
+                    .await
+                    {
+                        return ToolCallResult::error(format!("Scroll to selector failed: {}", e));
+                    }
+                }
+
+                // Identical HTML (mirrors, CDN frontends) reuses the cached
+                // extraction instead of re-processing
+                let operation = format!(
+                    "content:{}:{}:{}:{}:{}:{}",
+                    selector.unwrap_or(""),
+                    format,
+                    scroll_to.unwrap_or(""),
+                    include_outline,
+                    include_links,
+                    include_comments
+                );
+                let content_hash = match page.inner().content().await {
+                    Ok(html) => Some(crate::extraction::content_hash(&html)),
+                    Err(_) => None,
+                };
+                if let Some(hash) = content_hash {
+                    if let Some(Value::String(cached)) =
+                        ctx.extraction_cache().get(hash, &operation)
+                    {
+                        debug!(tool = self.name(), "Extraction cache hit");
+                        return ToolCallResult::text(cached);
+                    }
+                }
+
+                let content = if let Some(sel) = selector {
+                    ContentExtractor::extract_from_selector(&page, sel).await
+                } else {
+                    ContentExtractor::extract_main_content(&page).await
+                };
+
+                match content {
+                    Ok(mut c) => {
+                        let comments = c.comments.take();
+                        let mut output = match format {
+                            // Cleaned main region; c.text is the full page
+                            "text" => c.main_text,
+                            "html" => c.html,
+                            _ => c.markdown.unwrap_or(c.main_text),
+                        };
+                        if include_outline || include_links || include_comments {
+                            let mut wrapped = json!({ "content": output });
+                            if include_outline {
+                                match ContentExtractor::extract_outline(&page).await {
+                                    Ok(outline) => wrapped["outline"] = json!(outline),
+                                    Err(e) => {
+                                        return ToolCallResult::error(format!(
+                                            "Outline extraction failed: {}",
+                                            e
+                                        ))
+                                    }
+                                }
+                            }
+                            if include_links {
+                                match ContentExtractor::extract_content_links(&page).await {
+                                    Ok(links) => wrapped["links_in_content"] = json!(links),
+                                    Err(e) => {
+                                        return ToolCallResult::error(format!(
+                                            "Link extraction failed: {}",
+                                            e
+                                        ))
+                                    }
+                                }
+                            }
+                            if include_comments {
+                                // Null when the page had no comment section
+                                wrapped["comments"] = json!(comments);
+                            }
+                            output = serde_json::to_string_pretty(&wrapped)
+                                .unwrap_or_else(|_| "{}".to_string());
+                        }
+                        if let Some(hash) = content_hash {
+                            ctx.extraction_cache().insert(
+                                hash,
+                                &operation,
+                                Value::String(output.clone()),
+                            );
+                        }
+                        ToolCallResult::text(output)
+                    }
+                    Err(e) => ToolCallResult::error(format!("Content extraction failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
 }
 
 /// Extract links
 struct WebExtractLinksTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebExtractLinksTool {
     fn name(&self) -> &str {
         "web_extract_links"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
     fn description(&self) -> &str {
         "Extract all links from a web page with context"
     }
@@ -535,16 +1066,67 @@ impl McpTool for WebExtractLinksTool {
             "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let link_type = match args.opt_str("type") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let selector = match args.opt_str("selector") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                let links = if let Some(sel) = selector {
+                    LinkExtractor::extract_from_selector(&page, sel).await
+                } else {
+                    match link_type {
+                        Some("internal") => LinkExtractor::extract_internal(&page).await,
+                        Some("external") => LinkExtractor::extract_external(&page).await,
+                        _ => LinkExtractor::extract_all(&page).await,
+                    }
+                };
+
+                match links {
+                    Ok(links) => {
+                        let json = serde_json::to_string_pretty(&links)
+                            .unwrap_or_else(|_| "[]".to_string());
+                        ToolCallResult::text(json)
+                    }
+                    Err(e) => ToolCallResult::error(format!("Link extraction failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
 }
 
 /// Extract metadata
 struct WebExtractMetadataTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebExtractMetadataTool {
     fn name(&self) -> &str {
         "web_extract_metadata"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
     fn description(&self) -> &str {
         "Extract page metadata (title, description, Open Graph, Twitter Card, etc.)"
     }
@@ -561,16 +1143,46 @@ impl McpTool for WebExtractMetadataTool {
             "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => match MetadataExtractor::extract(&page).await {
+                Ok(meta) => {
+                    let json =
+                        serde_json::to_string_pretty(&meta).unwrap_or_else(|_| "{}".to_string());
+                    ToolCallResult::text(json)
+                }
+                Err(e) => ToolCallResult::error(format!("Metadata extraction failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
 }
 
 /// Execute JavaScript
 struct WebExecuteJsTool;
 
+#[async_trait::async_trait]
 impl McpTool for WebExecuteJsTool {
     fn name(&self) -> &str {
         "web_execute_js"
     }
 
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Scripting
+    }
+
     fn description(&self) -> &str {
         "Execute JavaScript on a web page and return the result"
     }
@@ -585,24 +1197,145 @@ impl McpTool for WebExecuteJsTool {
                 },
                 "script": {
                     "type": "string",
-                    "description": "The JavaScript code to execute"
+                    "description": "The JavaScript code to execute (refused when the server runs in snippet-allowlist mode)"
+                },
+                "snippet": {
+                    "type": "string",
+                    "description": "Name of an operator-registered snippet to run instead of inline code"
+                },
+                "params": {
+                    "type": "object",
+                    "description": "Values substituted into the snippet's placeholders as JSON literals"
+                },
+                "allFrames": {
+                    "type": "boolean",
+                    "description": "Run the script in every frame (including nested iframes) and return per-frame results (default: false)"
                 }
             },
-            "required": ["url", "script"]
+            "required": ["url"]
         })
     }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let args = ToolArgs::new(args);
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        // Resolve the script before touching the browser so allowlist
+        // refusals do not launch one
+        let allowlist = ctx.script_allowlist();
+        let snippet = match args.opt_str("snippet") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let inline = match args.opt_str("script") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let params = match args.opt_object("params") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let script = match (snippet, inline) {
+            (Some(snippet), _) => match allowlist.render(snippet, params) {
+                Ok(script) => script,
+                Err(e) => return ToolCallResult::error(e),
+            },
+            (None, Some(script)) => {
+                if allowlist.is_enabled() {
+                    return ToolCallResult::error(
+                        "Inline scripts are disabled: this server only runs registered \
+                         snippets (pass 'snippet' instead of 'script')",
+                    );
+                }
+                script.to_string()
+            }
+            (None, None) => {
+                return ToolCallResult::error("Missing required parameter: script or snippet")
+            }
+        };
+        let script = script.as_str();
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+
+        let all_frames = match args.opt_bool("allFrames") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                if all_frames {
+                    return match FrameEvaluator::evaluate_all(&page, script).await {
+                        Ok(results) => {
+                            let output = serde_json::to_string_pretty(&json!({
+                                "frames": results
+                            }))
+                            .unwrap_or_else(|_| "null".to_string());
+                            ToolCallResult::text(output)
+                        }
+                        Err(e) => {
+                            ToolCallResult::error(format!("JavaScript execution failed: {}", e))
+                        }
+                    };
+                }
+
+                match page.page.evaluate(script).await {
+                    Ok(result) => {
+                        let value: Value = result.into_value().unwrap_or(Value::Null);
+                        let output = serde_json::to_string_pretty(&value)
+                            .unwrap_or_else(|_| "null".to_string());
+                        ToolCallResult::text(output)
+                    }
+                    Err(e) => ToolCallResult::error(format!("JavaScript execution failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
 }
 
-/// Capture MHTML
-struct WebCaptureMhtmlTool;
+/// Navigate and assert a JavaScript expression
+struct WebAssertTool;
+
+impl WebAssertTool {
+    /// Default budget for the optional selector wait, in milliseconds
+    const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+    /// Wrap an expression so evaluation yields `{ pass, value }`
+    ///
+    /// `pass` is the JS truthiness of the evaluated value; exceptions are
+    /// left to propagate so they surface as execution errors, distinct
+    /// from a failing assertion.
+    fn assert_script(expression: &str) -> String {
+        format!(
+            r#"
+            (() => {{
+                const value = ({expression});
+                return {{ pass: Boolean(value), value: value === undefined ? null : value }};
+            }})()
+            "#
+        )
+    }
+}
 
-impl McpTool for WebCaptureMhtmlTool {
+#[async_trait::async_trait]
+impl McpTool for WebAssertTool {
     fn name(&self) -> &str {
-        "web_capture_mhtml"
+        "web_assert"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Scripting
     }
 
     fn description(&self) -> &str {
-        "Capture a complete web page as an MHTML archive"
+        "Navigate to a page and assert that a JavaScript expression is truthy, for monitoring checks"
     }
 
     fn input_schema(&self) -> Value {
@@ -611,25 +1344,1858 @@ impl McpTool for WebCaptureMhtmlTool {
             "properties": {
                 "url": {
                     "type": "string",
-                    "description": "The URL to capture"
+                    "description": "The URL to check"
+                },
+                "expression": {
+                    "type": "string",
+                    "description": "JavaScript expression whose truthiness decides the assertion (refused when the server runs in snippet-allowlist mode)"
+                },
+                "waitSelector": {
+                    "type": "string",
+                    "description": "CSS selector to wait for before evaluating the expression"
+                },
+                "timeoutMs": {
+                    "type": "number",
+                    "description": "Budget for the selector wait in milliseconds (default: 10000)"
                 }
             },
-            "required": ["url"]
+            "required": ["url", "expression"]
         })
     }
-}
 
-/// List of all available tools (for documentation)
-pub const AVAILABLE_TOOLS: &[&str] = &[
-    "web_navigate",
-    "web_screenshot",
-    "web_pdf",
-    "web_extract_content",
-    "web_extract_links",
-    "web_extract_metadata",
-    "web_execute_js",
-    "web_capture_mhtml",
-];
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let args = ToolArgs::new(args);
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+        let expression = match args.require_str("expression") {
+            Ok(e) => e,
+            Err(result) => return result,
+        };
+
+        // Assertions are arbitrary JS, so allowlist mode refuses them just
+        // like inline scripts
+        if ctx.script_allowlist().is_enabled() {
+            return ToolCallResult::error(
+                "Inline expressions are disabled: this server only runs registered snippets",
+            );
+        }
+
+        let wait_selector = match args.opt_str("waitSelector") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let timeout_ms = match args.opt_u64("timeoutMs") {
+            Ok(v) => v.unwrap_or(Self::DEFAULT_TIMEOUT_MS),
+            Err(result) => return result,
+        };
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                if let Some(selector) = wait_selector {
+                    if let Err(e) =
+                        crate::browser::PageNavigator::wait_for_selector(&page, selector, timeout_ms)
+                            .await
+                    {
+                        return ToolCallResult::error(format!(
+                            "Wait for selector '{}' failed: {}",
+                            selector, e
+                        ));
+                    }
+                }
+
+                let script = Self::assert_script(expression);
+                match page.inner().evaluate(script.as_str()).await {
+                    Ok(result) => {
+                        let value: Value = result.into_value().unwrap_or(Value::Null);
+                        let output = serde_json::to_string_pretty(&json!({
+                            "pass": value["pass"].as_bool().unwrap_or(false),
+                            "value": value["value"],
+                            "expression": expression,
+                        }))
+                        .unwrap_or_else(|_| "null".to_string());
+                        ToolCallResult::text(output)
+                    }
+                    Err(e) => {
+                        ToolCallResult::error(format!("Assertion evaluation failed: {}", e))
+                    }
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Capture MHTML
+struct WebCaptureMhtmlTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebCaptureMhtmlTool {
+    fn name(&self) -> &str {
+        "web_capture_mhtml"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Capture a complete web page as an MHTML archive"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to capture"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => match PageCapture::mhtml(&page).await {
+                Ok(result) => {
+                    let base64 = result.base64();
+                    ToolCallResult::multi(vec![
+                        ToolContent::text(format!("MHTML captured: {} bytes", result.size)),
+                        ToolContent::Resource {
+                            uri: format!("mhtml://{}", url),
+                            resource: crate::mcp::types::ResourceContent {
+                                mime_type: "multipart/related".to_string(),
+                                text: None,
+                                blob: Some(base64),
+                            },
+                        },
+                    ])
+                }
+                Err(e) => ToolCallResult::error(format!("MHTML capture failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Capture self-contained HTML
+struct WebCaptureHtmlTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebCaptureHtmlTool {
+    fn name(&self) -> &str {
+        "web_capture_html"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Capture a web page as a single self-contained HTML file with inlined styles and images"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to capture"
+                },
+                "maxInlineBytes": {
+                    "type": "number",
+                    "description": "Maximum size of a single asset to inline, in bytes (default: 262144)"
+                },
+                "stripTrackers": {
+                    "type": "boolean",
+                    "description": "Remove analytics, tag manager, and consent scripts from the captured HTML (default: false)",
+                    "default": false
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let mut options = HtmlInlineOptions::default();
+        match args.opt_u64("maxInlineBytes") {
+            Ok(Some(max)) => options.max_inline_bytes = max as usize,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+        match args.opt_bool("stripTrackers") {
+            Ok(Some(strip)) => options.strip_trackers = strip,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+
+        match browser.navigate(url).await {
+            Ok(page) => match PageCapture::self_contained_html(&page, &options).await {
+                Ok(result) => {
+                    let size = result.size;
+                    let html = String::from_utf8(result.data).unwrap_or_default();
+                    ToolCallResult::multi(vec![
+                        ToolContent::text(format!("HTML captured: {} bytes", size)),
+                        ToolContent::Resource {
+                            uri: format!("html://{}", url),
+                            resource: crate::mcp::types::ResourceContent {
+                                mime_type: "text/html".to_string(),
+                                text: Some(html),
+                                blob: None,
+                            },
+                        },
+                    ])
+                }
+                Err(e) => ToolCallResult::error(format!("HTML capture failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Extract downloadable resources
+struct WebExtractResourcesTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebExtractResourcesTool {
+    fn name(&self) -> &str {
+        "web_extract_resources"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Extract downloadable resource links (documents, archives, audio, video, images) from a web page"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to scan for downloadable resources"
+                },
+                "resolveSizes": {
+                    "type": "boolean",
+                    "description": "Resolve file sizes via HEAD requests (default: false)",
+                    "default": false
+                },
+                "includeCssBackgrounds": {
+                    "type": "boolean",
+                    "description": "Also report CSS background-image URLs of visible elements (default: false)",
+                    "default": false
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let resolve_sizes = match args.opt_bool("resolveSizes") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let include_css_backgrounds = match args.opt_bool("includeCssBackgrounds") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let options = crate::extraction::ResourceOptions {
+            resolve_sizes,
+            head_timeout_ms: None,
+            include_css_backgrounds,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                match crate::extraction::ResourceExtractor::extract_with_options(&page, &options)
+                    .await
+                {
+                    Ok(resources) => {
+                        let json = serde_json::to_string_pretty(&resources)
+                            .unwrap_or_else(|_| "[]".to_string());
+                        ToolCallResult::text(json)
+                    }
+                    Err(e) => ToolCallResult::error(format!("Resource extraction failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Extract tables
+struct WebExtractTablesTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebExtractTablesTool {
+    fn name(&self) -> &str {
+        "web_extract_tables"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Extract HTML tables from a web page as structured JSON or RFC 4180 CSV"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to extract tables from"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["json", "csv"],
+                    "description": "Output format (default: json)",
+                    "default": "json"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let format = match args.opt_str("format") {
+            Ok(v) => v.unwrap_or("json"),
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => match crate::extraction::TableExtractor::extract_all(&page).await {
+                Ok(tables) => match format {
+                    "csv" => {
+                        // One content part per table so consumers can pipe
+                        // each CSV document separately
+                        let content = tables
+                            .iter()
+                            .map(|t| ToolContent::text(t.to_csv()))
+                            .collect::<Vec<_>>();
+                        if content.is_empty() {
+                            ToolCallResult::text("No tables found")
+                        } else {
+                            ToolCallResult::multi(content)
+                        }
+                    }
+                    _ => {
+                        let json = serde_json::to_string_pretty(&tables)
+                            .unwrap_or_else(|_| "[]".to_string());
+                        ToolCallResult::text(json)
+                    }
+                },
+                Err(e) => ToolCallResult::error(format!("Table extraction failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Extract form structure for auto-fill planning
+struct WebExtractFormTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebExtractFormTool {
+    fn name(&self) -> &str {
+        "web_extract_form"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Extract each form's action, method, and field metadata (name, type, label, required, select options)"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to extract forms from"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => match crate::extraction::FormExtractor::extract_all(&page).await {
+                Ok(forms) => {
+                    let json =
+                        serde_json::to_string_pretty(&forms).unwrap_or_else(|_| "[]".to_string());
+                    ToolCallResult::text(json)
+                }
+                Err(e) => ToolCallResult::error(format!("Form extraction failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Extract the DOM as a structured tree
+struct WebExtractDomTreeTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebExtractDomTreeTool {
+    fn name(&self) -> &str {
+        "web_extract_dom_tree"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Extract the DOM as a nested tag/attrs/text/children tree, bounded by depth and node count"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to extract the DOM tree from"
+                },
+                "selector": {
+                    "type": "string",
+                    "description": "CSS selector for the tree root (default: body)",
+                    "default": "body"
+                },
+                "maxDepth": {
+                    "type": "number",
+                    "description": "Maximum nesting depth below the root (default: 25)",
+                    "default": 25
+                },
+                "maxNodes": {
+                    "type": "number",
+                    "description": "Maximum total number of nodes (default: 5000)",
+                    "default": 5000
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let selector = match args.opt_str("selector") {
+            Ok(v) => v.unwrap_or("body"),
+            Err(result) => return result,
+        };
+        let defaults = crate::extraction::DomTreeOptions::default();
+        let max_depth = match args.opt_u64("maxDepth") {
+            Ok(v) => v.unwrap_or(defaults.max_depth as u64) as usize,
+            Err(result) => return result,
+        };
+        let max_nodes = match args.opt_u64("maxNodes") {
+            Ok(v) => v.unwrap_or(defaults.max_nodes as u64) as usize,
+            Err(result) => return result,
+        };
+        let options = crate::extraction::DomTreeOptions {
+            max_depth,
+            max_nodes,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                match crate::extraction::DomTreeExtractor::extract(&page, selector, &options).await
+                {
+                    Ok(tree) => {
+                        let json = serde_json::to_string_pretty(&tree)
+                            .unwrap_or_else(|_| "{}".to_string());
+                        ToolCallResult::text(json)
+                    }
+                    Err(e) => ToolCallResult::error(format!("DOM tree extraction failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Check a page's links for dead targets
+struct WebCheckLinksTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebCheckLinksTool {
+    fn name(&self) -> &str {
+        "web_check_links"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Check each link on a page with bounded-concurrency HEAD requests and report the final status"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL whose links to check"
+                },
+                "sameDomainOnly": {
+                    "type": "boolean",
+                    "description": "Only check links on the page's own domain (default: false)",
+                    "default": false
+                },
+                "timeoutMs": {
+                    "type": "integer",
+                    "description": "Timeout per link in milliseconds (default: 5000)",
+                    "default": 5000
+                },
+                "concurrency": {
+                    "type": "integer",
+                    "description": "Maximum requests in flight at once (default: 8)",
+                    "default": 8
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let same_domain_only = match args.opt_bool("sameDomainOnly") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let defaults = crate::extraction::LinkCheckOptions::default();
+        let timeout_ms = match args.opt_u64("timeoutMs") {
+            Ok(v) => v.unwrap_or(defaults.timeout_ms),
+            Err(result) => return result,
+        };
+        let concurrency = match args.opt_u64("concurrency") {
+            Ok(v) => v.unwrap_or(defaults.concurrency as u64) as usize,
+            Err(result) => return result,
+        };
+        let options = crate::extraction::LinkCheckOptions {
+            same_domain_only,
+            timeout_ms,
+            concurrency,
+        };
+
+        let links = match browser.navigate(url).await {
+            Ok(page) => match crate::extraction::LinkExtractor::extract_all(&page).await {
+                Ok(links) => links,
+                Err(e) => return ToolCallResult::error(format!("Link extraction failed: {}", e)),
+            },
+            Err(e) => return ToolCallResult::error(format!("Navigation failed: {}", e)),
+        };
+
+        let urls = crate::extraction::LinkChecker::checkable_urls(&links, same_domain_only);
+        let results = crate::extraction::LinkChecker::check_urls_in_session(
+            urls,
+            &options,
+            ctx.link_check_session(),
+        )
+        .await;
+        let broken = results.iter().filter(|r| !r.ok).count();
+        let json = serde_json::to_string_pretty(&json!({
+            "checked": results.len(),
+            "broken": broken,
+            "results": results,
+        }))
+        .unwrap_or_else(|_| "{}".to_string());
+        ToolCallResult::text(json)
+    }
+}
+
+/// Search within page text
+struct WebSearchTextTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebSearchTextTool {
+    fn name(&self) -> &str {
+        "web_search_text"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Search the visible text of a web page and return matching context snippets"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to search"
+                },
+                "query": {
+                    "type": "string",
+                    "description": "Text to search for"
+                },
+                "caseSensitive": {
+                    "type": "boolean",
+                    "description": "Match case exactly (default: false)",
+                    "default": false
+                },
+                "regex": {
+                    "type": "boolean",
+                    "description": "Treat the query as a regular expression (default: false)",
+                    "default": false
+                },
+                "maxMatches": {
+                    "type": "integer",
+                    "description": "Maximum number of matches to return (default: 20)",
+                    "default": 20
+                }
+            },
+            "required": ["url", "query"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let query = match args.require_str("query") {
+            Ok(q) => q,
+            Err(result) => return result,
+        };
+
+        let case_sensitive = match args.opt_bool("caseSensitive") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let regex = match args.opt_bool("regex") {
+            Ok(v) => v.unwrap_or(false),
+            Err(result) => return result,
+        };
+        let mut options = crate::extraction::SearchOptions {
+            case_sensitive,
+            regex,
+            ..Default::default()
+        };
+        match args.opt_u64("maxMatches") {
+            Ok(Some(max)) => options.max_matches = max as usize,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+
+        match browser.navigate(url).await {
+            Ok(page) => {
+                // Extract with provenance so matches carry a DOM location
+                match ContentExtractor::extract_main_content_with_provenance(&page).await {
+                    Ok(content) => {
+                        let provenance = content.provenance.unwrap_or_default();
+                        match crate::extraction::TextSearcher::search_with_provenance(
+                            &content.text,
+                            query,
+                            &options,
+                            &provenance,
+                        ) {
+                            Ok(matches) => {
+                                let json = serde_json::to_string_pretty(&matches)
+                                    .unwrap_or_else(|_| "[]".to_string());
+                                ToolCallResult::text(json)
+                            }
+                            Err(e) => ToolCallResult::error(format!("Search failed: {}", e)),
+                        }
+                    }
+                    Err(e) => ToolCallResult::error(format!("Content extraction failed: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Tool: Capture a download
+struct WebDownloadTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebDownloadTool {
+    fn name(&self) -> &str {
+        "web_download"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Navigate to a file-serving URL (optionally clicking a link) and return the downloaded file"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL serving (or linking to) the file"
+                },
+                "clickSelector": {
+                    "type": "string",
+                    "description": "CSS selector to click after navigation to trigger the download"
+                },
+                "maxBytes": {
+                    "type": "integer",
+                    "description": "Maximum file size in bytes (default: 16777216)"
+                },
+                "timeoutMs": {
+                    "type": "integer",
+                    "description": "Capture timeout in milliseconds (default: 30000)"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let click_selector = match args.opt_str("clickSelector") {
+            Ok(v) => v.map(String::from),
+            Err(result) => return result,
+        };
+        let mut options = crate::browser::DownloadOptions {
+            click_selector,
+            ..Default::default()
+        };
+        match args.opt_u64("maxBytes") {
+            Ok(Some(max)) => options.max_bytes = max,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+        match args.opt_u64("timeoutMs") {
+            Ok(Some(timeout)) => options.timeout_ms = timeout,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+
+        match browser.download(url, &options).await {
+            Ok(file) => {
+                let base64 = BASE64.encode(&file.data);
+                ToolCallResult::multi(vec![
+                    ToolContent::text(format!(
+                        "Downloaded {}: {} bytes from {}",
+                        file.suggested_filename,
+                        file.data.len(),
+                        file.url
+                    )),
+                    ToolContent::Resource {
+                        uri: file.url.clone(),
+                        resource: crate::mcp::types::ResourceContent {
+                            mime_type: "application/octet-stream".to_string(),
+                            text: None,
+                            blob: Some(base64),
+                        },
+                    },
+                ])
+            }
+            Err(e) => ToolCallResult::error(format!("Download failed: {}", e)),
+        }
+    }
+}
+
+/// Extraction to run against each URL in a batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BatchExtraction {
+    /// Main content (or a selector), in the given output format
+    Content {
+        selector: Option<String>,
+        format: String,
+    },
+    /// All links with context
+    Links,
+    /// Page metadata
+    Metadata,
+    /// All tables
+    Tables,
+}
+
+impl BatchExtraction {
+    /// Parse the `extraction` argument; absent means main-content markdown
+    fn from_args(extraction: Option<&Value>) -> std::result::Result<Self, String> {
+        let Some(spec) = extraction else {
+            return Ok(Self::Content {
+                selector: None,
+                format: "markdown".to_string(),
+            });
+        };
+
+        let kind = spec
+            .get("type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("content");
+        match kind {
+            "content" => Ok(Self::Content {
+                selector: spec
+                    .get("selector")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                format: spec
+                    .get("format")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("markdown")
+                    .to_string(),
+            }),
+            "links" => Ok(Self::Links),
+            "metadata" => Ok(Self::Metadata),
+            "tables" => Ok(Self::Tables),
+            other => Err(format!("Unknown extraction type: {}", other)),
+        }
+    }
+
+    /// Run this extraction against an already-navigated page
+    async fn run(&self, page: &crate::browser::PageHandle) -> Result<Value> {
+        match self {
+            Self::Content { selector, format } => {
+                let content = match selector {
+                    Some(sel) => ContentExtractor::extract_from_selector(page, sel).await?,
+                    None => ContentExtractor::extract_main_content(page).await?,
+                };
+                let output = match format.as_str() {
+                    // Cleaned main region; content.text is the full page
+                    "text" => content.main_text,
+                    "html" => content.html,
+                    _ => content.markdown.unwrap_or(content.main_text),
+                };
+                Ok(Value::String(output))
+            }
+            Self::Links => {
+                let links = LinkExtractor::extract_all(page).await?;
+                serde_json::to_value(links).map_err(|e| crate::error::Error::generic(e.to_string()))
+            }
+            Self::Metadata => {
+                let metadata = MetadataExtractor::extract(page).await?;
+                serde_json::to_value(metadata)
+                    .map_err(|e| crate::error::Error::generic(e.to_string()))
+            }
+            Self::Tables => {
+                let tables = crate::extraction::TableExtractor::extract_all(page).await?;
+                serde_json::to_value(tables)
+                    .map_err(|e| crate::error::Error::generic(e.to_string()))
+            }
+        }
+    }
+}
+
+/// Session-wide stop conditions shared by every page of a batch crawl
+///
+/// Tracks cumulative page and byte totals across concurrent workers; once
+/// a budget is hit the first reason wins and remaining URLs are skipped so
+/// the batch halts cleanly with partial results.
+struct CrawlBudget {
+    /// Page budget, when set
+    max_pages: Option<u64>,
+    /// Byte budget over downloaded document HTML, when set
+    max_total_bytes: Option<u64>,
+    /// Pages started so far
+    pages: AtomicU64,
+    /// Document bytes downloaded so far
+    bytes: AtomicU64,
+    /// Why the crawl stopped, set once by whichever budget trips first
+    stopped_reason: parking_lot::Mutex<Option<String>>,
+}
+
+impl CrawlBudget {
+    fn new(max_pages: Option<u64>, max_total_bytes: Option<u64>) -> Self {
+        Self {
+            max_pages,
+            max_total_bytes,
+            pages: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            stopped_reason: parking_lot::Mutex::new(None),
+        }
+    }
+
+    /// Claim a page slot; `false` means the crawl is over budget
+    fn try_start_page(&self) -> bool {
+        if self.stopped_reason.lock().is_some() {
+            return false;
+        }
+        let page_number = self.pages.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(max) = self.max_pages {
+            if page_number > max {
+                self.stop(format!("page budget of {} reached", max));
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Add downloaded bytes to the running total, tripping the byte budget
+    /// when exceeded (the page that crossed the line keeps its result)
+    fn record_bytes(&self, count: u64) {
+        let total = self.bytes.fetch_add(count, Ordering::SeqCst) + count;
+        if let Some(max) = self.max_total_bytes {
+            if total > max {
+                self.stop(format!(
+                    "byte budget of {} exceeded ({} bytes downloaded)",
+                    max, total
+                ));
+            }
+        }
+    }
+
+    fn stop(&self, reason: String) {
+        let mut stopped = self.stopped_reason.lock();
+        if stopped.is_none() {
+            *stopped = Some(reason);
+        }
+    }
+
+    fn stopped_reason(&self) -> Option<String> {
+        self.stopped_reason.lock().clone()
+    }
+}
+
+/// Tool: Extract from many URLs into one combined JSON document
+struct WebExtractBatchTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebExtractBatchTool {
+    fn name(&self) -> &str {
+        "web_extract_batch"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Extract from multiple URLs concurrently as one combined JSON array or NDJSON lines"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "urls": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "URLs to extract from"
+                },
+                "sitemap": {
+                    "type": "string",
+                    "description": "Site URL whose /sitemap.xml seeds the batch when urls is omitted; sitemap indexes are followed"
+                },
+                "extraction": {
+                    "type": "object",
+                    "description": "What to extract from each page (default: main content as markdown)",
+                    "properties": {
+                        "type": {
+                            "type": "string",
+                            "enum": ["content", "links", "metadata", "tables"],
+                            "description": "Extraction to run (default: content)",
+                            "default": "content"
+                        },
+                        "selector": {
+                            "type": "string",
+                            "description": "CSS selector to extract content from"
+                        },
+                        "format": {
+                            "type": "string",
+                            "enum": ["text", "markdown", "html"],
+                            "description": "Output format for content extraction (default: markdown)",
+                            "default": "markdown"
+                        }
+                    }
+                },
+                "concurrency": {
+                    "type": "integer",
+                    "description": "Maximum URLs processed at once (default: 4)",
+                    "default": 4
+                },
+                "output": {
+                    "type": "string",
+                    "enum": ["json", "ndjson"],
+                    "description": "Output shape: one pretty JSON array, or newline-delimited JSON with one object per URL in completion order (default: json)",
+                    "default": "json"
+                },
+                "maxPages": {
+                    "type": "integer",
+                    "description": "Stop after this many pages across the whole batch; remaining URLs are skipped and stopped_reason is reported"
+                },
+                "maxTotalBytes": {
+                    "type": "integer",
+                    "description": "Stop once cumulative downloaded document HTML exceeds this many bytes; stopped_reason is reported"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        use futures::StreamExt;
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let urls: Vec<String> = match args.opt_str_array("urls") {
+            Ok(Some(list)) => list,
+            Ok(None) => {
+                let sitemap = match args.opt_str("sitemap") {
+                    Ok(v) => v,
+                    Err(result) => return result,
+                };
+                match sitemap {
+                    Some(site) => {
+                        let options = crate::extraction::SitemapOptions::default();
+                        match crate::extraction::SitemapFetcher::fetch_sitemap(site, &options).await
+                        {
+                            Ok(entries) => entries.into_iter().map(|e| e.url).collect(),
+                            Err(e) => return ToolCallResult::error(format!("{}", e)),
+                        }
+                    }
+                    None => return ToolCallResult::error("Missing required parameter: urls"),
+                }
+            }
+            Err(result) => return result,
+        };
+        if urls.is_empty() {
+            return ToolCallResult::error("urls must contain at least one URL");
+        }
+
+        let extraction = match BatchExtraction::from_args(args.raw("extraction")) {
+            Ok(e) => e,
+            Err(msg) => return ToolCallResult::error(msg),
+        };
+
+        let concurrency = match args.opt_u64("concurrency") {
+            Ok(v) => v.unwrap_or(4).max(1) as usize,
+            Err(result) => return result,
+        };
+
+        let output = match args.opt_str("output") {
+            Ok(v) => v.unwrap_or("json"),
+            Err(result) => return result,
+        };
+        let ndjson = match output {
+            "ndjson" => true,
+            "json" => false,
+            other => return ToolCallResult::error(format!("Unknown output mode: {}", other)),
+        };
+
+        let max_pages = match args.opt_u64("maxPages") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let max_total_bytes = match args.opt_u64("maxTotalBytes") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let budget = CrawlBudget::new(max_pages, max_total_bytes);
+
+        // Each URL gets its own page from the pool; failures are captured
+        // inline so one bad URL never fails the batch. URLs past a tripped
+        // budget yield no entry.
+        let browser = &*browser;
+        let extraction = &extraction;
+        let budget = &budget;
+        let entry_stream = futures::stream::iter(urls).map(|url| async move {
+            if !budget.try_start_page() {
+                return None;
+            }
+            let entry = match browser.navigate(&url).await {
+                Ok(page) => {
+                    if let Ok(html) = page.inner().content().await {
+                        budget.record_bytes(html.len() as u64);
+                    }
+                    let entry = match extraction.run(&page).await {
+                        Ok(data) => json!({ "url": url, "status": "ok", "data": data }),
+                        Err(e) => {
+                            json!({ "url": url, "status": "error", "error": e.to_string() })
+                        }
+                    };
+                    let _ = browser.close_page(page).await;
+                    entry
+                }
+                Err(e) => json!({
+                    "url": url,
+                    "status": "error",
+                    "error": format!("Navigation failed: {}", e)
+                }),
+            };
+            Some(entry)
+        });
+
+        // NDJSON lines land in completion order — streaming consumers see
+        // each URL as it finishes — while the JSON array keeps input order.
+        // A tripped budget is reported after the partial results.
+        if ndjson {
+            let mut lines: Vec<String> = entry_stream
+                .buffer_unordered(concurrency)
+                .filter_map(|entry| async move { entry.map(|e| e.to_string()) })
+                .collect()
+                .await;
+            if let Some(reason) = budget.stopped_reason() {
+                lines.push(json!({ "stopped_reason": reason }).to_string());
+            }
+            ToolCallResult::text(lines.join("\n"))
+        } else {
+            let entries: Vec<Value> = entry_stream
+                .buffered(concurrency)
+                .filter_map(|entry| async move { entry })
+                .collect()
+                .await;
+            let json = match budget.stopped_reason() {
+                Some(reason) => serde_json::to_string_pretty(&json!({
+                    "results": entries,
+                    "stopped_reason": reason,
+                }))
+                .unwrap_or_else(|_| "{}".to_string()),
+                None => {
+                    serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string())
+                }
+            };
+            ToolCallResult::text(json)
+        }
+    }
+}
+
+/// Tool: Classify the page type
+struct WebClassifyTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebClassifyTool {
+    fn name(&self) -> &str {
+        "web_classify"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Classify a web page as article, product, listing, or login with confidence scores"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to classify"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        match browser.navigate(url).await {
+            Ok(page) => match crate::extraction::PageClassifier::classify_page(&page).await {
+                Ok(classification) => {
+                    let json = serde_json::to_string_pretty(&classification)
+                        .unwrap_or_else(|_| "{}".to_string());
+                    ToolCallResult::text(json)
+                }
+                Err(e) => ToolCallResult::error(format!("Classification failed: {}", e)),
+            },
+            Err(e) => ToolCallResult::error(format!("Navigation failed: {}", e)),
+        }
+    }
+}
+
+/// Tool: Capture a network response body during navigation
+struct WebCaptureResponseTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebCaptureResponseTool {
+    fn name(&self) -> &str {
+        "web_capture_response"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Extraction
+    }
+
+    fn description(&self) -> &str {
+        "Navigate to a URL and return the body of the first network response matching a pattern"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to navigate to"
+                },
+                "responsePattern": {
+                    "type": "string",
+                    "description": "Regex matched against response URLs (e.g. '/api/items')"
+                },
+                "timeoutMs": {
+                    "type": "integer",
+                    "description": "How long to wait for a matching response in milliseconds (default: 10000)"
+                }
+            },
+            "required": ["url", "responsePattern"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+        let pattern = match args.require_str("responsePattern") {
+            Ok(p) => match regex::Regex::new(p) {
+                Ok(regex) => regex,
+                Err(e) => {
+                    return ToolCallResult::error(format!("Invalid responsePattern: {}", e))
+                }
+            },
+            Err(result) => return result,
+        };
+        let timeout_ms = match args.opt_u64("timeoutMs") {
+            Ok(v) => v.unwrap_or(10000),
+            Err(result) => return result,
+        };
+
+        match browser.capture_response(url, pattern, timeout_ms).await {
+            Ok(response) => {
+                let json = serde_json::to_string_pretty(&json!({
+                    "url": response.url,
+                    "status": response.status,
+                    "contentType": response.content_type,
+                    "body": response.text(),
+                }))
+                .unwrap_or_else(|_| "{}".to_string());
+                ToolCallResult::text(json)
+            }
+            Err(e) => ToolCallResult::error(format!("Response capture failed: {}", e)),
+        }
+    }
+}
+
+/// Inspect an element's geometry, computed styles, and rendering
+struct WebInspectElementTool;
+
+#[async_trait::async_trait]
+impl McpTool for WebInspectElementTool {
+    fn name(&self) -> &str {
+        "web_inspect_element"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Inspect the first element matching a selector: bounding box, computed styles, text, and a clipped screenshot"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to navigate to"
+                },
+                "selector": {
+                    "type": "string",
+                    "description": "CSS selector of the element to inspect"
+                },
+                "styleProperties": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Computed style properties to return (default: all)"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["png", "jpeg", "webp"],
+                    "description": "Screenshot image format (default: png)",
+                    "default": "png"
+                }
+            },
+            "required": ["url", "selector"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+        let args = ToolArgs::new(args);
+
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+        let selector = match args.require_str("selector") {
+            Ok(s) => s,
+            Err(result) => return result,
+        };
+        let style_properties = match args.opt_str_array("styleProperties") {
+            Ok(v) => v,
+            Err(result) => return result,
+        };
+        let format_str = match args.opt_str("format") {
+            Ok(v) => v.unwrap_or("png"),
+            Err(result) => return result,
+        };
+        let format = match format_str {
+            "jpeg" | "jpg" => CaptureFormat::Jpeg,
+            "webp" => CaptureFormat::Webp,
+            _ => CaptureFormat::Png,
+        };
+
+        let page = match browser.navigate(url).await {
+            Ok(page) => page,
+            Err(e) => return ToolCallResult::error(format!("Navigation failed: {}", e)),
+        };
+
+        let escaped = selector.replace('\'', "\\'");
+        let properties_json = style_properties
+            .as_ref()
+            .and_then(|p| serde_json::to_string(p).ok())
+            .unwrap_or_else(|| "null".to_string());
+        let script = format!(
+            r#"
+            (() => {{
+                const el = document.querySelector('{escaped}');
+                if (!el) return null;
+                const rect = el.getBoundingClientRect();
+                const computed = window.getComputedStyle(el);
+                const requested = {properties_json};
+                const styles = {{}};
+                if (requested) {{
+                    for (const name of requested) {{
+                        styles[name] = computed.getPropertyValue(name);
+                    }}
+                }} else {{
+                    for (let i = 0; i < computed.length; i++) {{
+                        const name = computed[i];
+                        styles[name] = computed.getPropertyValue(name);
+                    }}
+                }}
+                return {{
+                    boundingBox: {{
+                        x: rect.x,
+                        y: rect.y,
+                        width: rect.width,
+                        height: rect.height
+                    }},
+                    styles: styles,
+                    text: el.innerText
+                }};
+            }})()
+            "#
+        );
+
+        let inspected: Value = match page.inner().evaluate(script).await {
+            Ok(result) => result.into_value().unwrap_or(Value::Null),
+            Err(e) => return ToolCallResult::error(format!("Element inspection failed: {}", e)),
+        };
+        if inspected.is_null() {
+            return ToolCallResult::error(format!("Element not found: {}", selector));
+        }
+
+        let screenshot = match PageCapture::element_screenshot(&page, selector, format).await {
+            Ok(result) => result.base64(),
+            Err(e) => return ToolCallResult::error(format!("Element screenshot failed: {}", e)),
+        };
+
+        let json = serde_json::to_string_pretty(&json!({
+            "selector": selector,
+            "bounding_box": inspected["boundingBox"],
+            "computed_styles": inspected["styles"],
+            "text": inspected["text"],
+            "screenshot_base64": screenshot,
+        }))
+        .unwrap_or_else(|_| "{}".to_string());
+        ToolCallResult::text(json)
+    }
+}
+
+/// Capture a page at several viewport sizes in one call
+struct WebResponsiveScreenshotTool;
+
+impl WebResponsiveScreenshotTool {
+    /// Resolve one viewport entry into `(label, width, height)`
+    ///
+    /// Accepts a preset name (`phone`, `tablet`, `desktop`) or an object
+    /// with explicit `width`/`height` and an optional `label`.
+    fn viewport_from_value(value: &Value) -> std::result::Result<(String, u32, u32), String> {
+        if let Some(preset) = value.as_str() {
+            return match preset {
+                "phone" => Ok(("phone".to_string(), 375, 667)),
+                "tablet" => Ok(("tablet".to_string(), 768, 1024)),
+                "desktop" => Ok(("desktop".to_string(), 1920, 1080)),
+                other => Err(format!(
+                    "Unknown viewport preset '{}' (expected phone, tablet, or desktop)",
+                    other
+                )),
+            };
+        }
+
+        let width = value
+            .get("width")
+            .and_then(|v| v.as_u64())
+            .ok_or("Viewport entries need a preset name or a width/height object")?;
+        let height = value
+            .get("height")
+            .and_then(|v| v.as_u64())
+            .ok_or("Viewport entries need a preset name or a width/height object")?;
+        let label = value
+            .get("label")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .unwrap_or_else(|| format!("{}x{}", width, height));
+        Ok((label, width as u32, height as u32))
+    }
+}
+
+#[async_trait::async_trait]
+impl McpTool for WebResponsiveScreenshotTool {
+    fn name(&self) -> &str {
+        "web_responsive_screenshot"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Capture a page at multiple viewport sizes in one call, one image per size"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to capture"
+                },
+                "viewports": {
+                    "type": "array",
+                    "items": {
+                        "anyOf": [
+                            {
+                                "type": "string",
+                                "enum": ["phone", "tablet", "desktop"],
+                                "description": "Preset: phone 375x667, tablet 768x1024, desktop 1920x1080"
+                            },
+                            {
+                                "type": "object",
+                                "properties": {
+                                    "width": {"type": "number"},
+                                    "height": {"type": "number"},
+                                    "label": {"type": "string"}
+                                },
+                                "required": ["width", "height"]
+                            }
+                        ]
+                    },
+                    "description": "Viewport sizes to capture (default: phone, tablet, desktop)",
+                    "default": ["phone", "tablet", "desktop"]
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["png", "jpeg", "webp"],
+                    "description": "Image format (default: png)",
+                    "default": "png"
+                },
+                "settleMs": {
+                    "type": "integer",
+                    "description": "Delay after each resize so the layout can reflow (default: 300)",
+                    "default": 300
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let args = ToolArgs::new(args);
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let default_viewports = json!(["phone", "tablet", "desktop"]);
+        // Entries mix preset names and custom objects, so the shape check
+        // stays in viewport_from_value
+        let entries = args
+            .raw("viewports")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_else(|| default_viewports.as_array().unwrap().clone());
+        if entries.is_empty() {
+            return ToolCallResult::error("viewports must not be empty");
+        }
+        let mut viewports = Vec::with_capacity(entries.len());
+        for entry in &entries {
+            match Self::viewport_from_value(entry) {
+                Ok(viewport) => viewports.push(viewport),
+                Err(e) => return ToolCallResult::error(e),
+            }
+        }
+
+        let format_str = match args.opt_str("format") {
+            Ok(v) => v.unwrap_or("png"),
+            Err(result) => return result,
+        };
+        let format = match format_str {
+            "jpeg" | "jpg" => CaptureFormat::Jpeg,
+            "webp" => CaptureFormat::Webp,
+            _ => CaptureFormat::Png,
+        };
+        let settle_ms = match args.opt_u64("settleMs") {
+            Ok(v) => v.unwrap_or(300),
+            Err(result) => return result,
+        };
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+
+        let page = match browser.navigate(url).await {
+            Ok(page) => page,
+            Err(e) => return ToolCallResult::error(format!("Navigation failed: {}", e)),
+        };
+
+        let mut content = Vec::with_capacity(viewports.len() + 1);
+        let mut manifest = Vec::with_capacity(viewports.len());
+        for (label, width, height) in &viewports {
+            let viewport = crate::browser::ViewportOverride::new(*width, *height);
+            if let Err(e) = viewport.apply(page.inner()).await {
+                return ToolCallResult::error(format!("Viewport emulation failed: {}", e));
+            }
+            // Give the layout a moment to reflow at the new size
+            tokio::time::sleep(std::time::Duration::from_millis(settle_ms)).await;
+
+            let options = CaptureOptions {
+                format,
+                full_page: false,
+                as_base64: true,
+                ..Default::default()
+            };
+            match PageCapture::capture(&page, &options).await {
+                Ok(result) => {
+                    content.push(ToolContent::image(result.base64(), result.mime_type()));
+                    manifest.push(json!({
+                        "label": label,
+                        "width": width,
+                        "height": height,
+                    }));
+                }
+                Err(e) => {
+                    return ToolCallResult::error(format!(
+                        "Screenshot at {} failed: {}",
+                        label, e
+                    ))
+                }
+            }
+        }
+
+        // Images are in manifest order; the trailing text maps them to sizes
+        let summary = serde_json::to_string_pretty(&json!({"viewports": manifest}))
+            .unwrap_or_else(|_| "{}".to_string());
+        content.push(ToolContent::text(summary));
+        ToolCallResult::multi(content)
+    }
+}
+
+/// Capture cropped screenshots of rendered image regions
+struct WebCaptureImageRegionsTool;
+
+impl WebCaptureImageRegionsTool {
+    /// Cap on regions captured per call, keeping responses bounded
+    const DEFAULT_MAX_REGIONS: usize = 10;
+}
+
+#[async_trait::async_trait]
+impl McpTool for WebCaptureImageRegionsTool {
+    fn name(&self) -> &str {
+        "web_capture_image_regions"
+    }
+
+    fn category(&self) -> ToolCategory {
+        ToolCategory::Capture
+    }
+
+    fn description(&self) -> &str {
+        "Capture cropped screenshots of rendered image regions for downstream OCR"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to capture image regions from"
+                },
+                "selectors": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "CSS selectors to inspect (default: auto-detect img, canvas, svg, [role=img])"
+                },
+                "minWidth": {
+                    "type": "number",
+                    "description": "Minimum rendered width in pixels for auto-detection (default: 64)"
+                },
+                "minHeight": {
+                    "type": "number",
+                    "description": "Minimum rendered height in pixels for auto-detection (default: 64)"
+                },
+                "maxRegions": {
+                    "type": "number",
+                    "description": "Maximum number of regions to capture (default: 10)"
+                }
+            },
+            "required": ["url"]
+        })
+    }
+
+    async fn execute(&self, ctx: &ToolContext, args: Value) -> ToolCallResult {
+        let args = ToolArgs::new(args);
+        let url = match args.require_str("url") {
+            Ok(u) => u,
+            Err(result) => return result,
+        };
+
+        let mut options = crate::extraction::ImageRegionOptions::default();
+        match args.opt_str_array("selectors") {
+            Ok(Some(selectors)) if !selectors.is_empty() => {
+                options.selectors = Some(selectors);
+            }
+            Ok(_) => {}
+            Err(result) => return result,
+        }
+        match args.opt_u64("minWidth") {
+            Ok(Some(min)) => options.min_width = min as u32,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+        match args.opt_u64("minHeight") {
+            Ok(Some(min)) => options.min_height = min as u32,
+            Ok(None) => {}
+            Err(result) => return result,
+        }
+        let max_regions = match args.opt_u64("maxRegions") {
+            Ok(v) => v.map(|v| v as usize).unwrap_or(Self::DEFAULT_MAX_REGIONS),
+            Err(result) => return result,
+        };
+
+        let browser = match require_browser(ctx).await {
+            Ok(b) => b,
+            Err(result) => return result,
+        };
+
+        let page = match browser.navigate(url).await {
+            Ok(page) => page,
+            Err(e) => return ToolCallResult::error(format!("Navigation failed: {}", e)),
+        };
+
+        let regions =
+            match crate::extraction::ImageRegionExtractor::extract_regions(&page, &options).await {
+                Ok(regions) => regions,
+                Err(e) => {
+                    return ToolCallResult::error(format!("Image region detection failed: {}", e))
+                }
+            };
+
+        let mut content = Vec::new();
+        let mut captured = Vec::new();
+        for region in regions.into_iter().take(max_regions) {
+            // Crop the rendered box, not the raw src — sprites and scaled
+            // images come back as displayed
+            match PageCapture::element_screenshot(&page, &region.selector_path, CaptureFormat::Png)
+                .await
+            {
+                Ok(result) => {
+                    content.push(ToolContent::image(result.to_base64(), result.mime_type()));
+                    captured.push(region);
+                }
+                Err(e) => {
+                    return ToolCallResult::error(format!(
+                        "Crop of '{}' failed: {}",
+                        region.selector_path, e
+                    ))
+                }
+            }
+        }
+
+        // Images are in manifest order; the trailing text maps them to
+        // their rendered boxes
+        let summary = serde_json::to_string_pretty(&json!({"regions": captured}))
+            .unwrap_or_else(|_| "{}".to_string());
+        content.push(ToolContent::text(summary));
+        ToolCallResult::multi(content)
+    }
+}
+
+/// List of all available tools (for documentation)
+pub const AVAILABLE_TOOLS: &[&str] = &[
+    "web_navigate",
+    "web_screenshot",
+    "web_pdf",
+    "web_extract_content",
+    "web_extract_links",
+    "web_extract_metadata",
+    "web_execute_js",
+    "web_assert",
+    "web_capture_mhtml",
+    "web_capture_html",
+    "web_extract_resources",
+    "web_extract_tables",
+    "web_extract_dom_tree",
+    "web_extract_form",
+    "web_check_links",
+    "web_search_text",
+    "web_classify",
+    "web_extract_batch",
+    "web_download",
+    "web_capture_response",
+    "web_inspect_element",
+    "web_responsive_screenshot",
+    "web_capture_image_regions",
+];
 
 #[cfg(test)]
 mod tests {
@@ -641,6 +3207,386 @@ mod tests {
         assert!(registry.tools.len() >= 8);
     }
 
+    #[test]
+    fn test_registry_starts_without_browser() {
+        // The browser is launched lazily on the first tool call, not at
+        // registry construction
+        let registry = ToolRegistry::new();
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_without_browser_is_noop() {
+        let registry = ToolRegistry::new();
+        registry.shutdown().await.unwrap();
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    /// A custom tool that needs no browser
+    struct EchoTool;
+
+    #[async_trait::async_trait]
+    impl McpTool for EchoTool {
+        fn name(&self) -> &str {
+            "echo_test"
+        }
+
+        fn description(&self) -> &str {
+            "Echo the message argument back"
+        }
+
+        fn input_schema(&self) -> Value {
+            json!({
+                "type": "object",
+                "properties": {
+                    "message": { "type": "string" }
+                },
+                "required": ["message"]
+            })
+        }
+
+        async fn execute(&self, _ctx: &ToolContext, args: Value) -> ToolCallResult {
+            let message = args.get("message").and_then(|v| v.as_str()).unwrap_or("");
+            ToolCallResult::text(format!("echo: {}", message))
+        }
+    }
+
+    /// Test tool sleeping long enough to trip a tight execution budget
+    struct SleepTool {
+        name: &'static str,
+    }
+
+    #[async_trait::async_trait]
+    impl McpTool for SleepTool {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn description(&self) -> &str {
+            "Sleep for 100ms"
+        }
+
+        fn input_schema(&self) -> Value {
+            json!({ "type": "object", "properties": {} })
+        }
+
+        async fn execute(&self, _ctx: &ToolContext, _args: Value) -> ToolCallResult {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            ToolCallResult::text("slept")
+        }
+    }
+
+    #[test]
+    fn test_timeout_for_falls_back_to_default() {
+        let mut registry = ToolRegistry::new();
+        assert_eq!(registry.timeout_for("web_navigate"), DEFAULT_TOOL_TIMEOUT_MS);
+
+        registry.set_default_timeout(1_000);
+        registry.set_tool_timeout("web_pdf", 90_000);
+        assert_eq!(registry.timeout_for("web_pdf"), 90_000);
+        assert_eq!(registry.timeout_for("web_navigate"), 1_000);
+    }
+
+    #[tokio::test]
+    async fn test_per_tool_timeouts_enforced_independently() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(SleepTool { name: "sleep_a" }));
+        registry.register(Box::new(SleepTool { name: "sleep_b" }));
+        registry.set_tool_timeout("sleep_a", 20);
+        registry.set_tool_timeout("sleep_b", 5_000);
+
+        let tight = registry.execute("sleep_a", json!({})).await;
+        assert!(tight.is_error);
+        let message = format!("{:?}", tight.content);
+        assert!(message.contains("timed out after 20ms"), "got: {}", message);
+
+        let roomy = registry.execute("sleep_b", json!({})).await;
+        assert!(!roomy.is_error);
+    }
+
+    /// Test tool whose first `failures` calls fail with the given message
+    struct FlakyTool {
+        failures: usize,
+        message: &'static str,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl McpTool for FlakyTool {
+        fn name(&self) -> &str {
+            "flaky_test"
+        }
+
+        fn description(&self) -> &str {
+            "Fail a configured number of times, then succeed"
+        }
+
+        fn input_schema(&self) -> Value {
+            json!({ "type": "object", "properties": {} })
+        }
+
+        async fn execute(&self, _ctx: &ToolContext, _args: Value) -> ToolCallResult {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                ToolCallResult::error(self.message)
+            } else {
+                ToolCallResult::text("recovered")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transient_browser_failure_retried_on_fresh_browser() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(FlakyTool {
+            failures: 1,
+            message: "Navigation failed: Browser connection lost",
+            calls: calls.clone(),
+        }));
+
+        let result = registry.execute("flaky_test", json!({})).await;
+        assert!(!result.is_error);
+        // First attempt hit the dead browser, the retry succeeded
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_browser_error_not_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(FlakyTool {
+            failures: 1,
+            message: "Navigation failed: Invalid URL: not-a-url",
+            calls: calls.clone(),
+        }));
+
+        let result = registry.execute("flaky_test", json!({})).await;
+        assert!(result.is_error);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_transient_retries_bounded_and_configurable() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(FlakyTool {
+            failures: usize::MAX,
+            message: "Capture failed: Browser connection lost",
+            calls: calls.clone(),
+        }));
+
+        // Default: one retry, so two attempts in total
+        let result = registry.execute("flaky_test", json!({})).await;
+        assert!(result.is_error);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Zero disables retrying entirely
+        registry.set_transient_retries(0);
+        let result = registry.execute("flaky_test", json!({})).await;
+        assert!(result.is_error);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_custom_tool_dispatched_through_registry() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(EchoTool));
+
+        let result = registry
+            .execute("echo_test", json!({"message": "hello"}))
+            .await;
+
+        assert!(!result.is_error);
+        // The custom tool ran without launching a browser
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    #[test]
+    fn test_merge_args_defaults_apply_when_omitted() {
+        // Operator policy: web_screenshot always produces webp...
+        let defaults = json!({"format": "webp"});
+        let merged = merge_args(&defaults, json!({"url": "https://example.com"}));
+        assert_eq!(merged["format"], "webp");
+        assert_eq!(merged["url"], "https://example.com");
+    }
+
+    #[test]
+    fn test_merge_args_client_wins() {
+        // ...unless the client explicitly asks for something else
+        let defaults = json!({"format": "webp"});
+        let merged = merge_args(&defaults, json!({"format": "png"}));
+        assert_eq!(merged["format"], "png");
+    }
+
+    #[test]
+    fn test_merge_args_null_args_become_defaults() {
+        let defaults = json!({"format": "webp"});
+        let merged = merge_args(&defaults, Value::Null);
+        assert_eq!(merged, json!({"format": "webp"}));
+    }
+
+    #[tokio::test]
+    async fn test_allowlist_mode_refuses_inline_script_without_browser() {
+        let registry = ToolRegistry::new();
+        registry.script_allowlist().set_enabled(true);
+
+        let result = registry
+            .execute(
+                "web_execute_js",
+                json!({"url": "https://example.com", "script": "document.title"}),
+            )
+            .await;
+        assert!(result.is_error);
+        let message = format!("{:?}", result.content);
+        assert!(message.contains("registered"), "got: {}", message);
+        // The refusal happens before any browser is launched
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_snippet_refused_without_browser() {
+        let registry = ToolRegistry::new();
+        registry.script_allowlist().set_enabled(true);
+
+        let result = registry
+            .execute(
+                "web_execute_js",
+                json!({"url": "https://example.com", "snippet": "get_title"}),
+            )
+            .await;
+        assert!(result.is_error);
+        let message = format!("{:?}", result.content);
+        assert!(message.contains("get_title"), "got: {}", message);
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    #[test]
+    fn test_responsive_viewport_parsing() {
+        let (label, width, height) =
+            WebResponsiveScreenshotTool::viewport_from_value(&json!("phone")).unwrap();
+        assert_eq!((label.as_str(), width, height), ("phone", 375, 667));
+
+        let (label, width, height) =
+            WebResponsiveScreenshotTool::viewport_from_value(&json!({"width": 400, "height": 300}))
+                .unwrap();
+        assert_eq!((label.as_str(), width, height), ("400x300", 400, 300));
+
+        let (label, _, _) = WebResponsiveScreenshotTool::viewport_from_value(
+            &json!({"width": 400, "height": 300, "label": "narrow"}),
+        )
+        .unwrap();
+        assert_eq!(label, "narrow");
+
+        assert!(WebResponsiveScreenshotTool::viewport_from_value(&json!("watch")).is_err());
+        assert!(WebResponsiveScreenshotTool::viewport_from_value(&json!({"width": 400})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_default_args_applied_through_registry() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(EchoTool));
+        registry.set_default_args("echo_test", json!({"message": "default"}));
+
+        let result = registry.execute("echo_test", json!({})).await;
+        assert!(matches!(
+            &result.content[0],
+            ToolContent::Text { text } if text == "echo: default"
+        ));
+
+        let result = registry
+            .execute("echo_test", json!({"message": "explicit"}))
+            .await;
+        assert!(matches!(
+            &result.content[0],
+            ToolContent::Text { text } if text == "echo: explicit"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool_is_rejected() {
+        let registry = ToolRegistry::new();
+        let result = registry.execute("no_such_tool", json!({})).await;
+        assert!(result.is_error);
+    }
+
+    #[test]
+    fn test_tool_args_require_str() {
+        let args = ToolArgs::new(json!({"url": "https://example.com"}));
+        assert_eq!(args.require_str("url").unwrap(), "https://example.com");
+
+        let args = ToolArgs::new(json!({}));
+        let err = args.require_str("url").unwrap_err();
+        assert!(err.is_error);
+        assert!(matches!(
+            &err.content[0],
+            ToolContent::Text { text } if text == "Missing required parameter: url"
+        ));
+
+        let args = ToolArgs::new(json!({"url": 42}));
+        let err = args.require_str("url").unwrap_err();
+        assert!(matches!(
+            &err.content[0],
+            ToolContent::Text { text } if text == "Invalid parameter: url must be a string"
+        ));
+    }
+
+    #[test]
+    fn test_tool_args_null_counts_as_absent() {
+        let args = ToolArgs::new(json!({"selector": null}));
+        assert_eq!(args.opt_str("selector").unwrap(), None);
+        assert!(args.require_str("selector").is_err());
+    }
+
+    #[test]
+    fn test_tool_args_optional_getters_report_wrong_types() {
+        let args = ToolArgs::new(json!({
+            "fullPage": "yes",
+            "timeoutMs": -1,
+            "urls": ["https://example.com", 42],
+        }));
+
+        let err = args.opt_bool("fullPage").unwrap_err();
+        assert!(matches!(
+            &err.content[0],
+            ToolContent::Text { text } if text == "Invalid parameter: fullPage must be a boolean"
+        ));
+
+        let err = args.opt_u64("timeoutMs").unwrap_err();
+        assert!(matches!(
+            &err.content[0],
+            ToolContent::Text { text }
+                if text == "Invalid parameter: timeoutMs must be a non-negative integer"
+        ));
+
+        let err = args.opt_str_array("urls").unwrap_err();
+        assert!(matches!(
+            &err.content[0],
+            ToolContent::Text { text }
+                if text == "Invalid parameter: urls must be an array of strings"
+        ));
+
+        // Absent keys stay Ok(None) through every getter
+        assert_eq!(args.opt_bool("missing").unwrap(), None);
+        assert_eq!(args.opt_u64("missing").unwrap(), None);
+        assert_eq!(args.opt_str_array("missing").unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_typed_argument_gets_uniform_error() {
+        // web_execute_js parses arguments before touching the browser, so
+        // the helper's message comes back without Chrome installed
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute("web_execute_js", json!({"url": 42, "script": "1"}))
+            .await;
+        assert!(result.is_error);
+        assert!(matches!(
+            &result.content[0],
+            ToolContent::Text { text } if text == "Invalid parameter: url must be a string"
+        ));
+    }
+
     #[test]
     fn test_tool_definitions() {
         let registry = ToolRegistry::new();
@@ -652,6 +3598,121 @@ mod tests {
         assert!(nav.is_some());
     }
 
+    #[test]
+    fn test_builtin_tool_categories() {
+        assert_eq!(WebNavigateTool.category(), ToolCategory::Navigation);
+        assert_eq!(WebScreenshotTool.category(), ToolCategory::Capture);
+        assert_eq!(WebExtractContentTool.category(), ToolCategory::Extraction);
+        assert_eq!(WebExecuteJsTool.category(), ToolCategory::Scripting);
+        // Tools without an override fall back to Other
+        assert_eq!(EchoTool.category(), ToolCategory::Other);
+    }
+
+    #[test]
+    fn test_assert_script_wraps_expression() {
+        let script = WebAssertTool::assert_script("document.title.length > 0");
+        assert!(script.contains("(document.title.length > 0)"));
+        assert!(script.contains("Boolean(value)"));
+    }
+
+    #[tokio::test]
+    async fn test_assert_refused_in_allowlist_mode_without_browser() {
+        let registry = ToolRegistry::new();
+        registry.script_allowlist().set_enabled(true);
+
+        let result = registry
+            .execute(
+                "web_assert",
+                json!({"url": "https://example.com", "expression": "true"}),
+            )
+            .await;
+        assert!(result.is_error);
+        assert_eq!(registry.launch_count(), 0);
+    }
+
+    #[test]
+    fn test_batch_extraction_defaults_to_markdown_content() {
+        let extraction = BatchExtraction::from_args(None).unwrap();
+        assert_eq!(
+            extraction,
+            BatchExtraction::Content {
+                selector: None,
+                format: "markdown".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_batch_extraction_parses_spec() {
+        let spec = json!({ "type": "content", "selector": "main", "format": "text" });
+        let extraction = BatchExtraction::from_args(Some(&spec)).unwrap();
+        assert_eq!(
+            extraction,
+            BatchExtraction::Content {
+                selector: Some("main".to_string()),
+                format: "text".to_string(),
+            }
+        );
+
+        let spec = json!({ "type": "links" });
+        assert_eq!(
+            BatchExtraction::from_args(Some(&spec)).unwrap(),
+            BatchExtraction::Links
+        );
+    }
+
+    #[test]
+    fn test_batch_extraction_rejects_unknown_type() {
+        let spec = json!({ "type": "screenshots" });
+        let err = BatchExtraction::from_args(Some(&spec)).unwrap_err();
+        assert!(err.contains("screenshots"));
+    }
+
+    #[test]
+    fn test_definitions_in_extraction_category() {
+        let registry = ToolRegistry::new();
+        let defs = registry.definitions_in(ToolCategory::Extraction);
+        let names: Vec<&str> = defs.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "web_capture_response",
+                "web_check_links",
+                "web_classify",
+                "web_extract_batch",
+                "web_extract_content",
+                "web_extract_dom_tree",
+                "web_extract_form",
+                "web_extract_links",
+                "web_extract_metadata",
+                "web_extract_resources",
+                "web_extract_tables",
+                "web_search_text",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_definitions_grouped_covers_all_tools() {
+        let registry = ToolRegistry::new();
+        let grouped = registry.definitions_grouped();
+
+        let total: usize = grouped.iter().map(|(_, defs)| defs.len()).sum();
+        assert_eq!(total, registry.definitions().len());
+
+        // No built-in tool is uncategorized, so Other is omitted
+        assert!(!grouped
+            .iter()
+            .any(|(category, _)| *category == ToolCategory::Other));
+    }
+
+    #[test]
+    fn test_tool_category_serializes_lowercase() {
+        assert_eq!(json!(ToolCategory::Extraction), json!("extraction"));
+        let parsed: ToolCategory = serde_json::from_value(json!("capture")).unwrap();
+        assert_eq!(parsed, ToolCategory::Capture);
+    }
+
     #[test]
     fn test_web_navigate_tool() {
         let tool = WebNavigateTool;
@@ -667,5 +3728,61 @@ mod tests {
         assert!(AVAILABLE_TOOLS.contains(&"web_navigate"));
         assert!(AVAILABLE_TOOLS.contains(&"web_screenshot"));
         assert!(AVAILABLE_TOOLS.contains(&"web_execute_js"));
+        assert!(AVAILABLE_TOOLS.contains(&"web_search_text"));
+    }
+
+    #[test]
+    fn test_web_search_text_tool() {
+        let tool = WebSearchTextTool;
+        assert_eq!(tool.name(), "web_search_text");
+
+        let schema = tool.input_schema();
+        assert!(schema["properties"]["query"].is_object());
+        assert_eq!(schema["required"][1], "query");
+    }
+
+    #[test]
+    fn test_crawl_budget_page_limit() {
+        let budget = CrawlBudget::new(Some(2), None);
+        assert!(budget.try_start_page());
+        assert!(budget.try_start_page());
+        assert!(!budget.try_start_page());
+
+        let reason = budget.stopped_reason().expect("stopped");
+        assert!(reason.contains("page budget of 2"), "got: {}", reason);
+    }
+
+    #[test]
+    fn test_crawl_budget_byte_limit_stops_further_pages() {
+        let budget = CrawlBudget::new(None, Some(100));
+        assert!(budget.try_start_page());
+        budget.record_bytes(60);
+        assert!(budget.stopped_reason().is_none());
+
+        // Crossing the budget keeps the current page but stops the rest
+        budget.record_bytes(60);
+        let reason = budget.stopped_reason().expect("stopped");
+        assert!(reason.contains("byte budget of 100"), "got: {}", reason);
+        assert!(!budget.try_start_page());
+    }
+
+    #[test]
+    fn test_crawl_budget_first_reason_wins() {
+        let budget = CrawlBudget::new(Some(1), Some(10));
+        assert!(budget.try_start_page());
+        assert!(!budget.try_start_page());
+        budget.record_bytes(1000);
+        let reason = budget.stopped_reason().expect("stopped");
+        assert!(reason.contains("page budget"), "got: {}", reason);
+    }
+
+    #[test]
+    fn test_crawl_budget_unlimited_by_default() {
+        let budget = CrawlBudget::new(None, None);
+        for _ in 0..100 {
+            assert!(budget.try_start_page());
+        }
+        budget.record_bytes(u64::MAX / 2);
+        assert!(budget.stopped_reason().is_none());
     }
 }
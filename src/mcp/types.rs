@@ -136,6 +136,9 @@ pub struct McpServerInfo {
     pub name: String,
     /// Server version
     pub version: String,
+    /// Operational limits advertised to clients
+    #[serde(default)]
+    pub limits: ServerLimits,
 }
 
 impl Default for McpServerInfo {
@@ -143,6 +146,42 @@ impl Default for McpServerInfo {
         Self {
             name: "reasonkit-web".to_string(),
             version: env!("CARGO_PKG_VERSION").to_string(),
+            limits: ServerLimits::default(),
+        }
+    }
+}
+
+/// Operational limits advertised through the `initialize` handshake
+///
+/// Purely additive extension to `serverInfo` so clients can adapt to the
+/// server's configuration (e.g. chunk large pages, back off before hitting
+/// the rate limit) instead of discovering limits through errors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerLimits {
+    /// Maximum content size in bytes before truncation
+    #[serde(rename = "maxContentBytes")]
+    pub max_content_bytes: usize,
+    /// Default navigation timeout in milliseconds
+    #[serde(rename = "defaultTimeoutMs")]
+    pub default_timeout_ms: u64,
+    /// Rate limit in requests per minute
+    #[serde(rename = "rateLimitRpm")]
+    pub rate_limit_rpm: u32,
+    /// Supported capture formats
+    #[serde(rename = "captureFormats")]
+    pub capture_formats: Vec<String>,
+}
+
+impl Default for ServerLimits {
+    fn default() -> Self {
+        Self {
+            max_content_bytes: crate::handlers::capture::DEFAULT_MAX_CONTENT_LENGTH,
+            default_timeout_ms: crate::browser::NavigationOptions::default().timeout_ms,
+            rate_limit_rpm: crate::security::DEFAULT_RATE_LIMIT_RPM,
+            capture_formats: ["png", "jpeg", "webp", "pdf", "mhtml", "html"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         }
     }
 }
@@ -159,6 +198,20 @@ pub struct McpToolDefinition {
     pub input_schema: Value,
 }
 
+/// Parameters for tools/list method
+///
+/// All fields are optional; an absent or empty params object yields the
+/// flat listing that older clients expect.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolsListParams {
+    /// Only list tools in this category
+    #[serde(default)]
+    pub category: Option<crate::mcp::tools::ToolCategory>,
+    /// Group the listing by category instead of returning a flat list
+    #[serde(default)]
+    pub grouped: bool,
+}
+
 /// Parameters for tools/call method
 #[derive(Debug, Clone, Deserialize)]
 pub struct ToolCallParams {
@@ -257,6 +310,96 @@ impl ToolContent {
     }
 }
 
+/// Maximum blob length kept verbatim in log output
+pub const DEFAULT_LOG_BLOB_LIMIT: usize = 256;
+
+/// Replace a large blob with its size and a short hash
+fn elide_blob(data: &str, limit: usize) -> String {
+    if data.len() <= limit {
+        return data.to_string();
+    }
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    data.hash(&mut hasher);
+    format!("<{} bytes elided, hash {:016x}>", data.len(), hasher.finish())
+}
+
+/// Log-safe view of a [`ToolCallResult`]
+///
+/// Image data and resource bodies above the blob limit are replaced with
+/// their size and a short hash, so serializing a result into logs or audit
+/// entries cannot balloon them or leak captured content. The result sent to
+/// the client is untouched; this is a borrowed view for logging only.
+pub struct LoggableToolCallResult<'a> {
+    result: &'a ToolCallResult,
+    blob_limit: usize,
+}
+
+impl ToolCallResult {
+    /// Log-safe view of this result with the default blob limit
+    pub fn for_logging(&self) -> LoggableToolCallResult<'_> {
+        self.for_logging_with_limit(DEFAULT_LOG_BLOB_LIMIT)
+    }
+
+    /// Log-safe view of this result, keeping blobs up to `blob_limit` bytes
+    pub fn for_logging_with_limit(&self, blob_limit: usize) -> LoggableToolCallResult<'_> {
+        LoggableToolCallResult {
+            result: self,
+            blob_limit,
+        }
+    }
+}
+
+impl LoggableToolCallResult<'_> {
+    /// Build the elided copy that gets serialized
+    fn elided(&self) -> ToolCallResult {
+        let content = self
+            .result
+            .content
+            .iter()
+            .map(|item| match item {
+                ToolContent::Image { data, mime_type } => ToolContent::Image {
+                    data: elide_blob(data, self.blob_limit),
+                    mime_type: mime_type.clone(),
+                },
+                ToolContent::Resource { uri, resource } => ToolContent::Resource {
+                    uri: uri.clone(),
+                    resource: ResourceContent {
+                        mime_type: resource.mime_type.clone(),
+                        text: resource
+                            .text
+                            .as_deref()
+                            .map(|t| elide_blob(t, self.blob_limit)),
+                        blob: resource
+                            .blob
+                            .as_deref()
+                            .map(|b| elide_blob(b, self.blob_limit)),
+                    },
+                },
+                text => text.clone(),
+            })
+            .collect();
+
+        ToolCallResult {
+            is_error: self.result.is_error,
+            content,
+        }
+    }
+}
+
+impl std::fmt::Display for LoggableToolCallResult<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string(&self.elided()).map_err(|_| std::fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+impl Serialize for LoggableToolCallResult<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.elided().serialize(serializer)
+    }
+}
+
 /// Resource content in tool result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceContent {
@@ -342,6 +485,13 @@ pub struct FeedEvent {
     pub event_type: FeedEventType,
     /// Event timestamp (Unix epoch seconds)
     pub timestamp: u64,
+    /// Request the event belongs to, for correlating interleaved
+    /// tool events from concurrent calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Tool the event refers to, for tool events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
     /// Event data
     pub data: Value,
 }
@@ -373,6 +523,8 @@ impl FeedEvent {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs(),
+            request_id: None,
+            tool_name: None,
             data: serde_json::json!({"status": "ok"}),
         }
     }
@@ -385,30 +537,36 @@ impl FeedEvent {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs(),
+            request_id: None,
+            tool_name: None,
             data: serde_json::to_value(status).unwrap_or(Value::Null),
         }
     }
 
     /// Create a tool start event
-    pub fn tool_start(tool_name: &str) -> Self {
+    pub fn tool_start(request_id: &str, tool_name: &str) -> Self {
         Self {
             event_type: FeedEventType::ToolStart,
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs(),
+            request_id: Some(request_id.to_string()),
+            tool_name: Some(tool_name.to_string()),
             data: serde_json::json!({"tool": tool_name}),
         }
     }
 
     /// Create a tool complete event
-    pub fn tool_complete(tool_name: &str, success: bool, duration_ms: u64) -> Self {
+    pub fn tool_complete(request_id: &str, tool_name: &str, success: bool, duration_ms: u64) -> Self {
         Self {
             event_type: FeedEventType::ToolComplete,
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs(),
+            request_id: Some(request_id.to_string()),
+            tool_name: Some(tool_name.to_string()),
             data: serde_json::json!({
                 "tool": tool_name,
                 "success": success,
@@ -425,11 +583,81 @@ impl FeedEvent {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or(Duration::ZERO)
                 .as_secs(),
+            request_id: None,
+            tool_name: None,
             data: serde_json::json!({"error": message}),
         }
     }
 }
 
+/// Broadcast publisher for [`FeedEvent`]s with per-request ordering
+///
+/// Concurrent tool calls publish through a single broadcast channel, so
+/// subscribers see one global event order. The publisher additionally
+/// guarantees that a `ToolStart` for a given `request_id` is always
+/// delivered before its `ToolComplete`: completing a request whose start
+/// was never published synthesizes the missing start first.
+pub struct FeedPublisher {
+    /// Broadcast sender shared by all publishers
+    sender: tokio::sync::broadcast::Sender<FeedEvent>,
+    /// Request IDs with a published, not-yet-completed `ToolStart`
+    started: parking_lot::Mutex<std::collections::HashSet<String>>,
+}
+
+impl FeedPublisher {
+    /// Create a publisher buffering up to `capacity` events per subscriber
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = tokio::sync::broadcast::channel(capacity);
+        Self {
+            sender,
+            started: parking_lot::Mutex::new(std::collections::HashSet::new()),
+        }
+    }
+
+    /// Subscribe to all events published after this call
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<FeedEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Publish an event as-is, returning the number of receivers
+    pub fn publish(&self, event: FeedEvent) -> usize {
+        self.sender.send(event).unwrap_or(0)
+    }
+
+    /// Publish a `ToolStart` for a request
+    pub fn tool_start(&self, request_id: &str, tool_name: &str) -> usize {
+        // Held across the send so a concurrent tool_complete for the same
+        // request cannot slip its events in between
+        let mut started = self.started.lock();
+        started.insert(request_id.to_string());
+        self.publish(FeedEvent::tool_start(request_id, tool_name))
+    }
+
+    /// Publish a `ToolComplete` for a request
+    ///
+    /// If no `ToolStart` was published for `request_id`, one is
+    /// synthesized immediately before the completion so subscribers
+    /// never see an unpaired `ToolComplete`.
+    pub fn tool_complete(
+        &self,
+        request_id: &str,
+        tool_name: &str,
+        success: bool,
+        duration_ms: u64,
+    ) -> usize {
+        let mut started = self.started.lock();
+        if !started.remove(request_id) {
+            self.publish(FeedEvent::tool_start(request_id, tool_name));
+        }
+        self.publish(FeedEvent::tool_complete(
+            request_id,
+            tool_name,
+            success,
+            duration_ms,
+        ))
+    }
+}
+
 /// Heartbeat configuration
 pub struct HeartbeatConfig {
     /// Interval between heartbeats
@@ -614,6 +842,24 @@ mod tests {
         assert!(!info.version.is_empty());
     }
 
+    #[test]
+    fn test_server_limits_default() {
+        let limits = ServerLimits::default();
+        assert_eq!(limits.max_content_bytes, 10 * 1024 * 1024);
+        assert_eq!(limits.default_timeout_ms, 30_000);
+        assert_eq!(limits.rate_limit_rpm, 100);
+        assert_eq!(limits.capture_formats.len(), 6);
+    }
+
+    #[test]
+    fn test_server_limits_serialization() {
+        let json = serde_json::to_string(&ServerLimits::default()).unwrap();
+        assert!(json.contains("\"maxContentBytes\""));
+        assert!(json.contains("\"defaultTimeoutMs\""));
+        assert!(json.contains("\"rateLimitRpm\""));
+        assert!(json.contains("\"captureFormats\""));
+    }
+
     // ========================================================================
     // ServerStatus Tests
     // ========================================================================
@@ -730,20 +976,37 @@ mod tests {
 
     #[test]
     fn test_feed_event_tool_start() {
-        let event = FeedEvent::tool_start("web_navigate");
+        let event = FeedEvent::tool_start("req-1", "web_navigate");
         assert_eq!(event.event_type, FeedEventType::ToolStart);
+        assert_eq!(event.request_id.as_deref(), Some("req-1"));
+        assert_eq!(event.tool_name.as_deref(), Some("web_navigate"));
         assert_eq!(event.data["tool"], "web_navigate");
     }
 
     #[test]
     fn test_feed_event_tool_complete() {
-        let event = FeedEvent::tool_complete("web_screenshot", true, 500);
+        let event = FeedEvent::tool_complete("req-2", "web_screenshot", true, 500);
         assert_eq!(event.event_type, FeedEventType::ToolComplete);
+        assert_eq!(event.request_id.as_deref(), Some("req-2"));
+        assert_eq!(event.tool_name.as_deref(), Some("web_screenshot"));
         assert_eq!(event.data["tool"], "web_screenshot");
         assert_eq!(event.data["success"], true);
         assert_eq!(event.data["duration_ms"], 500);
     }
 
+    #[test]
+    fn test_feed_event_request_id_serialization() {
+        let event = FeedEvent::tool_start("req-3", "web_extract_content");
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"request_id\":\"req-3\""));
+        assert!(json.contains("\"tool_name\":\"web_extract_content\""));
+
+        // Non-tool events omit the correlation fields entirely
+        let heartbeat = serde_json::to_string(&FeedEvent::heartbeat()).unwrap();
+        assert!(!heartbeat.contains("request_id"));
+        assert!(!heartbeat.contains("tool_name"));
+    }
+
     #[test]
     fn test_feed_event_error() {
         let event = FeedEvent::error("Connection failed");
@@ -779,6 +1042,91 @@ mod tests {
         );
     }
 
+    // ========================================================================
+    // FeedPublisher Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn test_feed_publisher_correlates_overlapping_tool_calls() {
+        use std::sync::Arc;
+
+        let publisher = Arc::new(FeedPublisher::new(16));
+        let mut receiver = publisher.subscribe();
+
+        let first = {
+            let publisher = publisher.clone();
+            tokio::spawn(async move {
+                publisher.tool_start("req-a", "web_navigate");
+                tokio::time::sleep(Duration::from_millis(30)).await;
+                publisher.tool_complete("req-a", "web_navigate", true, 30);
+            })
+        };
+        let second = {
+            let publisher = publisher.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                publisher.tool_start("req-b", "web_screenshot");
+                tokio::time::sleep(Duration::from_millis(40)).await;
+                publisher.tool_complete("req-b", "web_screenshot", false, 40);
+            })
+        };
+        first.await.unwrap();
+        second.await.unwrap();
+
+        let mut events = Vec::new();
+        for _ in 0..4 {
+            events.push(receiver.recv().await.unwrap());
+        }
+
+        for request_id in ["req-a", "req-b"] {
+            let positions: Vec<(usize, FeedEventType)> = events
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| e.request_id.as_deref() == Some(request_id))
+                .map(|(i, e)| (i, e.event_type))
+                .collect();
+            assert_eq!(positions.len(), 2, "two events for {}", request_id);
+            assert_eq!(positions[0].1, FeedEventType::ToolStart);
+            assert_eq!(positions[1].1, FeedEventType::ToolComplete);
+        }
+
+        // The interleaved events still carry the right tool per request
+        for event in &events {
+            let expected = match event.request_id.as_deref() {
+                Some("req-a") => "web_navigate",
+                Some("req-b") => "web_screenshot",
+                other => panic!("unexpected request_id: {:?}", other),
+            };
+            assert_eq!(event.tool_name.as_deref(), Some(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_feed_publisher_synthesizes_missing_tool_start() {
+        let publisher = FeedPublisher::new(16);
+        let mut receiver = publisher.subscribe();
+
+        // Completion without a prior start still yields an ordered pair
+        publisher.tool_complete("req-orphan", "web_navigate", true, 5);
+
+        let start = receiver.recv().await.unwrap();
+        assert_eq!(start.event_type, FeedEventType::ToolStart);
+        assert_eq!(start.request_id.as_deref(), Some("req-orphan"));
+
+        let complete = receiver.recv().await.unwrap();
+        assert_eq!(complete.event_type, FeedEventType::ToolComplete);
+        assert_eq!(complete.request_id.as_deref(), Some("req-orphan"));
+    }
+
+    #[tokio::test]
+    async fn test_feed_publisher_counts_receivers() {
+        let publisher = FeedPublisher::new(4);
+        assert_eq!(publisher.publish(FeedEvent::heartbeat()), 0);
+
+        let _receiver = publisher.subscribe();
+        assert_eq!(publisher.tool_start("req-1", "web_navigate"), 1);
+    }
+
     // ========================================================================
     // HeartbeatConfig Tests
     // ========================================================================
@@ -858,4 +1206,64 @@ mod tests {
         let event = FeedEvent::status(&status);
         assert_eq!(event.event_type, FeedEventType::Status);
     }
+
+    // ========================================================================
+    // Loggable Result Tests
+    // ========================================================================
+
+    #[test]
+    fn test_large_image_is_elided_in_logs() {
+        let data = "A".repeat(100_000);
+        let result = ToolCallResult::image(data.clone(), "image/png");
+
+        let logged = result.for_logging().to_string();
+        assert!(!logged.contains(&data));
+        assert!(logged.contains("100000 bytes elided"));
+        assert!(logged.contains("image/png"));
+
+        // The result itself stays complete for the client
+        match &result.content[0] {
+            ToolContent::Image { data: d, .. } => assert_eq!(d.len(), 100_000),
+            _ => panic!("Expected Image content"),
+        }
+    }
+
+    #[test]
+    fn test_small_blobs_are_kept_verbatim() {
+        let result = ToolCallResult::image("abc123".to_string(), "image/png");
+        let logged = result.for_logging().to_string();
+        assert!(logged.contains("abc123"));
+    }
+
+    #[test]
+    fn test_resource_bodies_are_elided_in_logs() {
+        let result = ToolCallResult::multi(vec![ToolContent::Resource {
+            uri: "https://example.com/page".to_string(),
+            resource: ResourceContent {
+                mime_type: "text/html".to_string(),
+                text: Some("x".repeat(10_000)),
+                blob: Some("y".repeat(10_000)),
+            },
+        }]);
+
+        let logged = serde_json::to_value(result.for_logging()).unwrap();
+        let resource = &logged["content"][0]["resource"];
+        assert!(resource["text"]
+            .as_str()
+            .unwrap()
+            .contains("10000 bytes elided"));
+        assert!(resource["blob"]
+            .as_str()
+            .unwrap()
+            .contains("10000 bytes elided"));
+        assert_eq!(resource["mimeType"], "text/html");
+    }
+
+    #[test]
+    fn test_custom_blob_limit() {
+        let result = ToolCallResult::image("z".repeat(500), "image/png");
+        // Under the custom limit nothing is elided
+        let logged = result.for_logging_with_limit(1000).to_string();
+        assert!(logged.contains(&"z".repeat(500)));
+    }
 }
@@ -0,0 +1,206 @@
+//! Shared retry and backoff policy
+//!
+//! Navigation, Stripe webhook processing, and future network-facing code
+//! all need the same loop: try, classify the error, back off, try again.
+//! This module centralizes that loop so each call site only states its
+//! policy — attempts, delays, and which errors are worth retrying.
+
+use std::time::Duration;
+use tracing::warn;
+
+/// Retry policy shared across navigation, capture, and webhook processing
+///
+/// Delays grow exponentially from `base_delay`, doubling per attempt, capped
+/// at `max_delay`. Setting `base_delay == max_delay` yields a constant delay.
+/// The `retryable` predicate short-circuits retries for errors that cannot
+/// succeed on a second try (bad input, authentication).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy<E> {
+    /// Total attempts, including the first (minimum 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound on any single delay
+    pub max_delay: Duration,
+    /// Add 10–20% random jitter to each delay, de-correlating concurrent
+    /// retry storms
+    pub jitter: bool,
+    /// Whether an error is worth retrying
+    pub retryable: fn(&E) -> bool,
+}
+
+impl<E> RetryPolicy<E> {
+    /// A policy with the given attempt count and default backoff: 1s base,
+    /// 30s cap, jitter on, every error retryable
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retryable: |_| true,
+        }
+    }
+
+    /// Replace the retryable-error predicate
+    pub fn retry_if(mut self, retryable: fn(&E) -> bool) -> Self {
+        self.retryable = retryable;
+        self
+    }
+
+    /// The delay before retry number `retry` (0-based)
+    pub fn delay_for(&self, retry: u32) -> Duration {
+        let base = self.base_delay.as_millis() as u64;
+        let capped = base
+            .saturating_mul(2u64.saturating_pow(retry))
+            .min(self.max_delay.as_millis() as u64);
+
+        let delay = if self.jitter {
+            let jitter = capped / 10 + (rand::random::<u64>() % (capped / 10 + 1));
+            capped.saturating_add(jitter)
+        } else {
+            capped
+        };
+
+        Duration::from_millis(delay)
+    }
+}
+
+/// Run an operation under a retry policy
+///
+/// The operation is re-invoked until it succeeds, returns an error the
+/// policy deems non-retryable, or the attempt budget runs out; the last
+/// error is returned as-is in the latter two cases.
+pub async fn execute_with_policy<T, E, F, Fut>(
+    policy: &RetryPolicy<E>,
+    mut operation: F,
+) -> std::result::Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let mut attempt = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                attempt += 1;
+                if attempt >= policy.max_attempts || !(policy.retryable)(&e) {
+                    return Err(e);
+                }
+                let delay = policy.delay_for(attempt - 1);
+                warn!(
+                    "Attempt {} of {} failed, retrying in {:?}: {}",
+                    attempt, policy.max_attempts, delay, e
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_policy<E>(max_attempts: u32) -> RetryPolicy<E> {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            jitter: false,
+            retryable: |_| true,
+        }
+    }
+
+    #[test]
+    fn test_delay_doubles_and_caps() {
+        let policy: RetryPolicy<String> = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: false,
+            retryable: |_| true,
+        };
+        assert_eq!(policy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(300));
+        assert_eq!(policy.delay_for(10), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy: RetryPolicy<String> = RetryPolicy {
+            jitter: true,
+            ..fast_policy(3)
+        };
+        let base = Duration::from_millis(1);
+        for retry in 0..3 {
+            let delay = policy.delay_for(retry);
+            assert!(delay >= base.min(policy.max_delay));
+            // At most 20% above the capped exponential delay
+            assert!(delay <= Duration::from_millis(3));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_succeeds_after_transient_navigation_error() {
+        use crate::error::NavigationError;
+
+        let attempts = AtomicU32::new(0);
+        let result: std::result::Result<&str, NavigationError> =
+            execute_with_policy(&fast_policy(3), || {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if attempt < 2 {
+                        Err(NavigationError::Timeout(100))
+                    } else {
+                        Ok("loaded")
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "loaded");
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_same_policy_type_covers_stripe_errors() {
+        use crate::stripe::StripeWebhookError;
+
+        // Non-retryable per the predicate: a bad payload fails immediately
+        let policy = fast_policy::<StripeWebhookError>(3)
+            .retry_if(StripeWebhookError::should_retry);
+
+        let attempts = AtomicU32::new(0);
+        let result: std::result::Result<(), _> = execute_with_policy(&policy, || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(StripeWebhookError::InvalidPayload("bad".to_string())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Retryable processing errors use the full attempt budget
+        let attempts = AtomicU32::new(0);
+        let result: std::result::Result<(), _> = execute_with_policy(&policy, || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(StripeWebhookError::ProcessingFailed("flaky".to_string())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_attempts_return_last_error() {
+        let result: std::result::Result<(), String> =
+            execute_with_policy(&fast_policy(2), || async { Err("always".to_string()) }).await;
+        assert_eq!(result.unwrap_err(), "always");
+    }
+}
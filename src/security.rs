@@ -22,17 +22,26 @@
 //! - `REASONKIT_WEB_TOKEN`: Required authentication token
 //! - `REASONKIT_WEB_BIND_ALL`: Set to "true" for Docker (binds to 0.0.0.0)
 //! - `REASONKIT_WEB_RATE_LIMIT`: Requests per minute per IP (default: 100)
+//! - `REASONKIT_WEB_AUTH_BYPASS_PATHS`: Comma-separated unauthenticated paths (default: /health,/healthz)
 //!
 //! # Example
 //!
 //! ```rust,no_run
-//! use reasonkit_web::security::{SecurityConfig, SecurityLayer};
-//! use axum::Router;
+//! use reasonkit_web::security::{SecurityCheck, SecurityConfig, SecurityLayer};
 //!
+//! # async fn example() {
 //! let config = SecurityConfig::from_env().expect("Security config error");
-//! let app = Router::new()
-//!     // ... routes
-//!     .layer(SecurityLayer::new(config));
+//! let check = SecurityCheck::new(SecurityLayer::new(config));
+//!
+//! let result = check
+//!     .validate(
+//!         "127.0.0.1".parse().unwrap(),
+//!         "/api/capture",
+//!         Some("Bearer my-token"),
+//!         None,
+//!     )
+//!     .await;
+//! # }
 //! ```
 
 use std::collections::HashMap;
@@ -72,6 +81,9 @@ pub type SecurityResult<T> = std::result::Result<T, SecurityError>;
 // Security Configuration
 // =============================================================================
 
+/// Default rate limit in requests per minute per IP
+pub const DEFAULT_RATE_LIMIT_RPM: u32 = 100;
+
 /// Security configuration loaded from environment variables
 ///
 /// CONS-003 COMPLIANT: No hardcoded secrets. All sensitive values from env vars.
@@ -106,6 +118,8 @@ impl SecurityConfig {
     /// - `REASONKIT_WEB_TOKEN` (required): Authentication bearer token
     /// - `REASONKIT_WEB_BIND_ALL` (optional): Set to "true" for Docker
     /// - `REASONKIT_WEB_RATE_LIMIT` (optional): Requests per minute (default: 100)
+    /// - `REASONKIT_WEB_AUTH_BYPASS_PATHS` (optional): Comma-separated paths that
+    ///   skip authentication (default: `/health,/healthz`)
     ///
     /// # Errors
     ///
@@ -142,7 +156,7 @@ impl SecurityConfig {
 
         // Parse rate limit
         let rate_limit_rpm = env::var("REASONKIT_WEB_RATE_LIMIT")
-            .unwrap_or_else(|_| "100".to_string())
+            .unwrap_or_else(|_| DEFAULT_RATE_LIMIT_RPM.to_string())
             .parse::<u32>()
             .map_err(|e| SecurityError::InvalidRateLimit(e.to_string()))?;
 
@@ -157,6 +171,16 @@ impl SecurityConfig {
             rate_limit_rpm
         );
 
+        // Parse additional auth bypass paths (e.g., "/metrics,/ready")
+        let auth_bypass_paths = match env::var("REASONKIT_WEB_AUTH_BYPASS_PATHS") {
+            Ok(raw) => {
+                let paths = Self::parse_auth_bypass_paths(&raw)?;
+                info!("SECURITY: Auth bypass paths: {:?}", paths);
+                paths
+            }
+            Err(_) => vec!["/health".to_string(), "/healthz".to_string()],
+        };
+
         Ok(Self {
             token_hash,
             bind_all,
@@ -170,10 +194,50 @@ impl SecurityConfig {
                 "http://127.0.0.1:3000".to_string(),
                 "http://127.0.0.1:9100".to_string(),
             ],
-            auth_bypass_paths: vec!["/health".to_string(), "/healthz".to_string()],
+            auth_bypass_paths,
         })
     }
 
+    /// Parse and validate a comma-separated auth bypass path list
+    ///
+    /// Paths must be absolute and must not shadow the protected `/api` or
+    /// `/mcp` prefixes (bypass matching is prefix-based, so `/a` would
+    /// otherwise bypass `/api/...`).
+    pub fn parse_auth_bypass_paths(raw: &str) -> SecurityResult<Vec<String>> {
+        const PROTECTED_PREFIXES: &[&str] = &["/api", "/mcp"];
+
+        let mut paths = Vec::new();
+        for path in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            if !path.starts_with('/') {
+                return Err(SecurityError::ConfigError(format!(
+                    "Auth bypass path must start with '/': {}",
+                    path
+                )));
+            }
+
+            for protected in PROTECTED_PREFIXES {
+                // Reject both "/api/..." entries and shorter prefixes like
+                // "/" or "/a" that would match protected paths
+                if path.starts_with(protected) || protected.starts_with(path) {
+                    return Err(SecurityError::ConfigError(format!(
+                        "Auth bypass path '{}' would bypass protected prefix '{}'",
+                        path, protected
+                    )));
+                }
+            }
+
+            paths.push(path.to_string());
+        }
+
+        if paths.is_empty() {
+            return Err(SecurityError::ConfigError(
+                "REASONKIT_WEB_AUTH_BYPASS_PATHS is set but contains no paths".to_string(),
+            ));
+        }
+
+        Ok(paths)
+    }
+
     /// Create a test configuration (for testing only)
     #[cfg(test)]
     pub fn test_config() -> Self {
@@ -838,6 +902,56 @@ mod tests {
         assert!(!config.is_auth_bypass_path("/api/tools"));
     }
 
+    #[test]
+    fn test_parse_auth_bypass_paths_valid() {
+        let paths = SecurityConfig::parse_auth_bypass_paths("/health,/healthz,/metrics").unwrap();
+        assert_eq!(paths, vec!["/health", "/healthz", "/metrics"]);
+
+        // Whitespace and empty entries are tolerated
+        let paths = SecurityConfig::parse_auth_bypass_paths(" /ready , /metrics ,").unwrap();
+        assert_eq!(paths, vec!["/ready", "/metrics"]);
+    }
+
+    #[test]
+    fn test_parse_auth_bypass_paths_rejects_relative() {
+        let err = SecurityConfig::parse_auth_bypass_paths("health").unwrap_err();
+        assert!(matches!(err, SecurityError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_parse_auth_bypass_paths_rejects_protected_prefixes() {
+        // Direct protected paths
+        assert!(SecurityConfig::parse_auth_bypass_paths("/api").is_err());
+        assert!(SecurityConfig::parse_auth_bypass_paths("/mcp").is_err());
+        assert!(SecurityConfig::parse_auth_bypass_paths("/api/tools").is_err());
+
+        // Prefixes that would shadow protected paths via starts_with matching
+        assert!(SecurityConfig::parse_auth_bypass_paths("/").is_err());
+        assert!(SecurityConfig::parse_auth_bypass_paths("/a").is_err());
+
+        // A valid path mixed with a bad one still fails
+        assert!(SecurityConfig::parse_auth_bypass_paths("/metrics,/api").is_err());
+    }
+
+    #[test]
+    fn test_parse_auth_bypass_paths_rejects_empty() {
+        assert!(SecurityConfig::parse_auth_bypass_paths("").is_err());
+        assert!(SecurityConfig::parse_auth_bypass_paths(" , ").is_err());
+    }
+
+    #[test]
+    fn test_configured_bypass_path_skips_auth() {
+        let mut config = SecurityConfig::test_config();
+        config.auth_bypass_paths = SecurityConfig::parse_auth_bypass_paths("/health,/metrics")
+            .unwrap();
+
+        assert!(config.is_auth_bypass_path("/metrics"));
+
+        let auth = TokenAuthenticator::new(Arc::new(config));
+        assert_eq!(auth.authenticate("/metrics", None), AuthResult::Bypassed);
+        assert_eq!(auth.authenticate("/api/x", None), AuthResult::MissingHeader);
+    }
+
     #[test]
     fn test_security_config_origin_allowed() {
         let config = SecurityConfig::test_config();
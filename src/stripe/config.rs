@@ -36,8 +36,16 @@ pub struct StripeWebhookConfig {
     /// Base delay for exponential backoff (doubles each retry)
     pub retry_base_delay: Duration,
 
+    /// Cap on in-flight event handlers, counting both actively running
+    /// ones and those sleeping between retries
+    pub max_concurrent_handlers: usize,
+
     /// Whether to log event payloads (DISABLE in production for PII)
     pub log_payloads: bool,
+
+    /// How long shutdown waits for queued and in-flight events to drain
+    /// before logging the remainder for replay
+    pub shutdown_grace_period: Duration,
 }
 
 impl StripeWebhookConfig {
@@ -50,7 +58,9 @@ impl StripeWebhookConfig {
     /// - `STRIPE_WEBHOOK_IDEMPOTENCY_TTL` (optional): Idempotency TTL in seconds (default: 86400)
     /// - `STRIPE_WEBHOOK_PROCESSING_TIMEOUT` (optional): Processing timeout in seconds (default: 30)
     /// - `STRIPE_WEBHOOK_MAX_RETRIES` (optional): Max retry attempts (default: 3)
+    /// - `STRIPE_WEBHOOK_MAX_CONCURRENT` (optional): Max in-flight handlers (default: 16)
     /// - `STRIPE_WEBHOOK_LOG_PAYLOADS` (optional): Log payloads - DISABLE IN PROD (default: false)
+    /// - `STRIPE_WEBHOOK_SHUTDOWN_GRACE` (optional): Shutdown drain grace in seconds (default: 30)
     ///
     /// # Errors
     ///
@@ -85,10 +95,22 @@ impl StripeWebhookConfig {
             .and_then(|v| v.parse::<u32>().ok())
             .unwrap_or(3);
 
+        let max_concurrent_handlers = env::var("STRIPE_WEBHOOK_MAX_CONCURRENT")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(16);
+
         let log_payloads = env::var("STRIPE_WEBHOOK_LOG_PAYLOADS")
             .map(|v| v.to_lowercase() == "true")
             .unwrap_or(false);
 
+        let shutdown_grace_period = env::var("STRIPE_WEBHOOK_SHUTDOWN_GRACE")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(30));
+
         Ok(Self {
             webhook_secret,
             max_timestamp_age,
@@ -98,7 +120,9 @@ impl StripeWebhookConfig {
             processing_timeout,
             max_retries,
             retry_base_delay: Duration::from_secs(1),
+            max_concurrent_handlers,
             log_payloads,
+            shutdown_grace_period,
         })
     }
 
@@ -114,7 +138,9 @@ impl StripeWebhookConfig {
             processing_timeout: Duration::from_secs(5),
             max_retries: 3,
             retry_base_delay: Duration::from_millis(100),
+            max_concurrent_handlers: 4,
             log_payloads: true, // OK for tests
+            shutdown_grace_period: Duration::from_millis(500),
         }
     }
 
@@ -150,6 +176,21 @@ impl StripeWebhookConfig {
         &self.webhook_secret
     }
 
+    /// The shared retry policy for event processing
+    ///
+    /// Matches [`Self::retry_delay`]: exponential backoff from
+    /// `retry_base_delay` with jitter, capped at 30s. Errors that
+    /// [`StripeWebhookError::should_retry`] rejects fail immediately.
+    pub fn retry_policy(&self) -> crate::retry::RetryPolicy<StripeWebhookError> {
+        crate::retry::RetryPolicy {
+            max_attempts: self.max_retries + 1,
+            base_delay: self.retry_base_delay,
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retryable: StripeWebhookError::should_retry,
+        }
+    }
+
     /// Calculate retry delay with exponential backoff and jitter
     pub fn retry_delay(&self, attempt: u32) -> Duration {
         let base = self.retry_base_delay.as_millis() as u64;
@@ -32,18 +32,19 @@
 //! ```rust,no_run
 //! use reasonkit_web::stripe::{
 //!     StripeWebhookConfig, StripeWebhookState, stripe_webhook_router,
-//!     EventProcessor, SubscriptionHandler,
+//!     SubscriptionHandler, SubscriptionEvent, InvoiceEvent, CustomerEvent,
 //! };
 //! use std::sync::Arc;
 //!
 //! #[tokio::main]
 //! async fn main() -> anyhow::Result<()> {
 //!     let config = StripeWebhookConfig::from_env()?;
-//!     let processor = Arc::new(MySubscriptionHandler);
-//!     let state = StripeWebhookState::new(config, processor);
+//!     let handler = Arc::new(MySubscriptionHandler);
+//!     let (state, _processor_handle) = StripeWebhookState::new(config, handler);
 //!
 //!     let app = stripe_webhook_router(Arc::new(state));
 //!     // ... serve with axum
+//!     let _ = app;
 //!     Ok(())
 //! }
 //!
@@ -51,11 +52,25 @@
 //!
 //! #[async_trait::async_trait]
 //! impl SubscriptionHandler for MySubscriptionHandler {
-//!     async fn on_subscription_created(&self, subscription: &Subscription) -> anyhow::Result<()> {
+//!     async fn on_subscription_created(&self, _event: &SubscriptionEvent) -> anyhow::Result<()> {
 //!         // Handle new subscription
 //!         Ok(())
 //!     }
-//!     // ... other handlers
+//!     async fn on_subscription_updated(&self, _event: &SubscriptionEvent) -> anyhow::Result<()> {
+//!         Ok(())
+//!     }
+//!     async fn on_subscription_deleted(&self, _event: &SubscriptionEvent) -> anyhow::Result<()> {
+//!         Ok(())
+//!     }
+//!     async fn on_payment_succeeded(&self, _event: &InvoiceEvent) -> anyhow::Result<()> {
+//!         Ok(())
+//!     }
+//!     async fn on_payment_failed(&self, _event: &InvoiceEvent) -> anyhow::Result<()> {
+//!         Ok(())
+//!     }
+//!     async fn on_customer_created(&self, _event: &CustomerEvent) -> anyhow::Result<()> {
+//!         Ok(())
+//!     }
 //! }
 //! ```
 
@@ -27,7 +27,7 @@
 
 use std::sync::Arc;
 
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch, Semaphore};
 use tokio::time::timeout;
 
 use crate::stripe::config::StripeWebhookConfig;
@@ -66,6 +66,8 @@ pub struct EventProcessor<H: SubscriptionHandler, S: IdempotencyStore> {
     config: StripeWebhookConfig,
     /// Channel for background task processing
     task_sender: mpsc::Sender<ProcessingTask>,
+    /// Flipped to `true` by [`Self::shutdown`]
+    shutdown_tx: watch::Sender<bool>,
 }
 
 /// A processing task sent to background workers
@@ -84,12 +86,14 @@ impl<H: SubscriptionHandler, S: IdempotencyStore> EventProcessor<H, S> {
         config: StripeWebhookConfig,
     ) -> (Self, ProcessorHandle<H, S>) {
         let (tx, rx) = mpsc::channel(1000);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
 
         let processor = Self {
             handler: handler.clone(),
             idempotency_store: idempotency_store.clone(),
             config: config.clone(),
             task_sender: tx,
+            shutdown_tx,
         };
 
         let handle = ProcessorHandle {
@@ -97,6 +101,7 @@ impl<H: SubscriptionHandler, S: IdempotencyStore> EventProcessor<H, S> {
             idempotency_store,
             config,
             task_receiver: rx,
+            shutdown_rx,
         };
 
         (processor, handle)
@@ -107,6 +112,12 @@ impl<H: SubscriptionHandler, S: IdempotencyStore> EventProcessor<H, S> {
     /// This returns immediately after queuing. The actual processing
     /// happens in a background task.
     pub async fn queue_event(&self, event: StripeEvent) -> StripeWebhookResult<()> {
+        if *self.shutdown_tx.borrow() {
+            return Err(crate::stripe::error::StripeWebhookError::InternalError(
+                "Processor is shutting down".to_string(),
+            ));
+        }
+
         let task = ProcessingTask { event, attempt: 0 };
 
         self.task_sender.send(task).await.map_err(|e| {
@@ -123,6 +134,18 @@ impl<H: SubscriptionHandler, S: IdempotencyStore> EventProcessor<H, S> {
     pub async fn process_event_sync(&self, event: &StripeEvent) -> StripeWebhookResult<()> {
         process_single_event(&self.handler, &self.idempotency_store, event, &self.config).await
     }
+
+    /// Begin graceful shutdown
+    ///
+    /// New events are rejected immediately. The background processor keeps
+    /// handing already-queued events to workers and waits for in-flight
+    /// handlers, up to [`StripeWebhookConfig::shutdown_grace_period`]; any
+    /// events still unprocessed when the grace expires are logged by ID for
+    /// replay. Await the task running [`ProcessorHandle::run`] to know when
+    /// the drain has completed.
+    pub fn shutdown(&self) {
+        let _ = self.shutdown_tx.send(true);
+    }
 }
 
 /// Handle for running the background processor
@@ -131,6 +154,7 @@ pub struct ProcessorHandle<H: SubscriptionHandler, S: IdempotencyStore> {
     idempotency_store: Arc<S>,
     config: StripeWebhookConfig,
     task_receiver: mpsc::Receiver<ProcessingTask>,
+    shutdown_rx: watch::Receiver<bool>,
 }
 
 impl<H: SubscriptionHandler, S: IdempotencyStore> ProcessorHandle<H, S> {
@@ -146,19 +170,107 @@ impl<H: SubscriptionHandler, S: IdempotencyStore> ProcessorHandle<H, S> {
     pub async fn run(mut self) {
         tracing::info!("Starting Stripe webhook event processor");
 
-        while let Some(task) = self.task_receiver.recv().await {
-            let handler = self.handler.clone();
-            let store = self.idempotency_store.clone();
-            let config = self.config.clone();
-
-            // Spawn each event processing in its own task
-            tokio::spawn(async move {
-                process_with_retry(handler, store, task.event, &config).await;
-            });
+        // One permit per in-flight handler, held across retries and their
+        // backoff sleeps: a flood of failing events queues here instead of
+        // piling up unbounded delayed tasks
+        let semaphore = Arc::new(Semaphore::new(self.config.max_concurrent_handlers));
+
+        loop {
+            tokio::select! {
+                task = self.task_receiver.recv() => {
+                    let Some(task) = task else { break };
+                    let permit = Arc::clone(&semaphore)
+                        .acquire_owned()
+                        .await
+                        .expect("processor semaphore is never closed");
+                    let handler = self.handler.clone();
+                    let store = self.idempotency_store.clone();
+                    let config = self.config.clone();
+
+                    // Spawn each event processing in its own task
+                    tokio::spawn(async move {
+                        let _permit = permit;
+                        process_with_retry(handler, store, task.event, &config).await;
+                    });
+                }
+                _ = self.shutdown_rx.changed() => {
+                    self.drain(&semaphore).await;
+                    break;
+                }
+            }
         }
 
         tracing::info!("Stripe webhook event processor shutting down");
     }
+
+    /// Drain queued and in-flight events within the shutdown grace period
+    ///
+    /// Queued events keep being handed to workers (the webhook was already
+    /// acked with a 200, so dropping them would lose deliveries). Anything
+    /// still unprocessed when the grace expires is logged by event ID so it
+    /// can be replayed from the Stripe dashboard.
+    async fn drain(&mut self, semaphore: &Arc<Semaphore>) {
+        let grace = self.config.shutdown_grace_period;
+        tracing::info!(
+            grace_ms = grace.as_millis() as u64,
+            queued = self.task_receiver.len(),
+            "Draining Stripe webhook event processor"
+        );
+
+        // Reject further sends; recv keeps returning already-queued events
+        self.task_receiver.close();
+
+        let max_permits =
+            u32::try_from(self.config.max_concurrent_handlers).unwrap_or(u32::MAX);
+        let deadline = tokio::time::Instant::now() + grace;
+        let drained = tokio::time::timeout_at(deadline, async {
+            loop {
+                // Permit first: cancellation at the timeout must not lose
+                // an event already taken off the queue
+                let permit = Arc::clone(semaphore)
+                    .acquire_owned()
+                    .await
+                    .expect("processor semaphore is never closed");
+                let Some(task) = self.task_receiver.recv().await else {
+                    break;
+                };
+                let handler = self.handler.clone();
+                let store = self.idempotency_store.clone();
+                let config = self.config.clone();
+                tokio::spawn(async move {
+                    let _permit = permit;
+                    process_with_retry(handler, store, task.event, &config).await;
+                });
+            }
+
+            // Queue is empty; wait for in-flight handlers to finish
+            let _ = semaphore.acquire_many(max_permits).await;
+        })
+        .await;
+
+        if drained.is_ok() {
+            tracing::info!("All pending Stripe events drained before shutdown");
+            return;
+        }
+
+        let in_flight =
+            self.config.max_concurrent_handlers - semaphore.available_permits();
+        let mut unprocessed = 0u32;
+        while let Ok(task) = self.task_receiver.try_recv() {
+            unprocessed += 1;
+            tracing::warn!(
+                event_id = %task.event.id,
+                event_type = %task.event.event_type,
+                "Event not processed before shutdown; replay required"
+            );
+        }
+        tracing::warn!(
+            in_flight,
+            unprocessed,
+            grace_ms = grace.as_millis() as u64,
+            "Shutdown grace period expired with Stripe events outstanding"
+        );
+    }
 }
 
 /// Process a single event with retry logic
@@ -171,49 +283,36 @@ async fn process_with_retry<H: SubscriptionHandler, S: IdempotencyStore>(
     let event_id = event.id.clone();
     let event_type = event.event_type.clone();
 
-    for attempt in 0..=config.max_retries {
-        if attempt > 0 {
-            let delay = config.retry_delay(attempt - 1);
+    let policy = config.retry_policy();
+    let result = crate::retry::execute_with_policy(&policy, || {
+        process_single_event(&handler, &store, &event, config)
+    })
+    .await;
+
+    match result {
+        Ok(()) => {
             tracing::info!(
                 event_id = %event_id,
                 event_type = %event_type,
-                attempt,
-                delay_ms = delay.as_millis(),
-                "Retrying event processing"
+                "Event processed successfully"
             );
-            tokio::time::sleep(delay).await;
         }
+        Err(e) => {
+            tracing::warn!(
+                event_id = %event_id,
+                event_type = %event_type,
+                max_retries = config.max_retries,
+                error = %e,
+                "Event processing failed"
+            );
 
-        match process_single_event(&handler, &store, &event, config).await {
-            Ok(()) => {
-                tracing::info!(
+            // Final failure - mark as failed in idempotency store
+            if let Err(mark_err) = store.mark_failed(&event_id, &e.to_string()).await {
+                tracing::error!(
                     event_id = %event_id,
-                    event_type = %event_type,
-                    attempts = attempt + 1,
-                    "Event processed successfully"
+                    error = %mark_err,
+                    "Failed to mark event as failed in idempotency store"
                 );
-                return;
-            }
-            Err(e) => {
-                tracing::warn!(
-                    event_id = %event_id,
-                    event_type = %event_type,
-                    attempt = attempt + 1,
-                    max_retries = config.max_retries,
-                    error = %e,
-                    "Event processing failed"
-                );
-
-                if attempt == config.max_retries {
-                    // Final failure - mark as failed in idempotency store
-                    if let Err(mark_err) = store.mark_failed(&event_id, &e.to_string()).await {
-                        tracing::error!(
-                            event_id = %event_id,
-                            error = %mark_err,
-                            "Failed to mark event as failed in idempotency store"
-                        );
-                    }
-                }
             }
         }
     }
@@ -421,6 +520,8 @@ mod tests {
         payment_failed_calls: AtomicU32,
         customer_created_calls: AtomicU32,
         should_fail: std::sync::atomic::AtomicBool,
+        /// Per-call sleep in ms, to simulate slow handlers
+        delay_ms: AtomicU32,
     }
 
     impl TestHandler {
@@ -433,6 +534,7 @@ mod tests {
                 payment_failed_calls: AtomicU32::new(0),
                 customer_created_calls: AtomicU32::new(0),
                 should_fail: std::sync::atomic::AtomicBool::new(false),
+                delay_ms: AtomicU32::new(0),
             }
         }
     }
@@ -440,6 +542,10 @@ mod tests {
     #[async_trait::async_trait]
     impl SubscriptionHandler for TestHandler {
         async fn on_subscription_created(&self, _event: &SubscriptionEvent) -> anyhow::Result<()> {
+            let delay = self.delay_ms.load(Ordering::SeqCst);
+            if delay > 0 {
+                tokio::time::sleep(Duration::from_millis(u64::from(delay))).await;
+            }
             self.subscription_created_calls
                 .fetch_add(1, Ordering::SeqCst);
             if self.should_fail.load(Ordering::SeqCst) {
@@ -564,6 +670,119 @@ mod tests {
         processor_task.abort();
     }
 
+    #[tokio::test]
+    async fn test_failing_event_flood_bounded_by_concurrency_cap() {
+        let handler = Arc::new(TestHandler::new());
+        handler.should_fail.store(true, Ordering::SeqCst);
+        let store = Arc::new(InMemoryIdempotencyStore::new(
+            Duration::from_secs(3600),
+            1000,
+        ));
+        let mut config = StripeWebhookConfig::test_config();
+        config.max_concurrent_handlers = 2;
+        config.max_retries = 1;
+        config.retry_base_delay = Duration::from_millis(100);
+
+        let (processor, handle) = EventProcessor::new(handler.clone(), store, config);
+        let processor_task = tokio::spawn(async move {
+            handle.run().await;
+        });
+
+        for _ in 0..6 {
+            processor
+                .queue_event(create_test_subscription_event())
+                .await
+                .unwrap();
+        }
+
+        // Within the first backoff window each in-flight event has made at
+        // most one attempt; with the cap only two handlers can be in flight
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let early_calls = handler.subscription_created_calls.load(Ordering::SeqCst);
+        assert!(early_calls <= 2, "cap exceeded: {} early attempts", early_calls);
+
+        // The queue still drains: every event gets its full attempt budget
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert_eq!(handler.subscription_created_calls.load(Ordering::SeqCst), 12);
+
+        processor_task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_pending_events() {
+        let handler = Arc::new(TestHandler::new());
+        handler.delay_ms.store(50, Ordering::SeqCst);
+        let store = Arc::new(InMemoryIdempotencyStore::new(
+            Duration::from_secs(3600),
+            1000,
+        ));
+        let mut config = StripeWebhookConfig::test_config();
+        config.max_concurrent_handlers = 2;
+        config.shutdown_grace_period = Duration::from_secs(2);
+
+        let (processor, handle) = EventProcessor::new(handler.clone(), store, config);
+        let processor_task = tokio::spawn(async move {
+            handle.run().await;
+        });
+
+        for _ in 0..4 {
+            processor
+                .queue_event(create_test_subscription_event())
+                .await
+                .unwrap();
+        }
+
+        processor.shutdown();
+
+        // New events are rejected once shutdown has started
+        assert!(processor
+            .queue_event(create_test_subscription_event())
+            .await
+            .is_err());
+
+        // run() completes only after the queue has drained
+        tokio::time::timeout(Duration::from_secs(5), processor_task)
+            .await
+            .expect("drain did not complete within grace period")
+            .unwrap();
+        assert_eq!(handler.subscription_created_calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_grace_expiry_releases_processor() {
+        let handler = Arc::new(TestHandler::new());
+        handler.delay_ms.store(500, Ordering::SeqCst);
+        let store = Arc::new(InMemoryIdempotencyStore::new(
+            Duration::from_secs(3600),
+            1000,
+        ));
+        let mut config = StripeWebhookConfig::test_config();
+        config.max_concurrent_handlers = 1;
+        config.shutdown_grace_period = Duration::from_millis(50);
+
+        let (processor, handle) = EventProcessor::new(handler.clone(), store, config);
+        let processor_task = tokio::spawn(async move {
+            handle.run().await;
+        });
+
+        for _ in 0..3 {
+            processor
+                .queue_event(create_test_subscription_event())
+                .await
+                .unwrap();
+        }
+
+        processor.shutdown();
+
+        // The grace period is far shorter than the handler delay: run()
+        // must give up, log the remainder for replay, and still return
+        tokio::time::timeout(Duration::from_secs(2), processor_task)
+            .await
+            .expect("grace expiry did not release the processor")
+            .unwrap();
+        assert!(handler.subscription_created_calls.load(Ordering::SeqCst) < 3);
+    }
+
     #[tokio::test]
     async fn test_unknown_event_type_ignored() {
         let handler = Arc::new(TestHandler::new());
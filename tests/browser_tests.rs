@@ -115,6 +115,8 @@ fn test_capture_options_serialization() {
         height: Some(768),
         clip_selector: Some("#content".to_string()),
         as_base64: true,
+        readable: false,
+        pre_capture_delay_ms: 0,
     };
 
     let json = serde_json::to_string(&opts).unwrap();
@@ -194,6 +196,10 @@ fn test_navigation_result_structure() {
         status: Some(200),
         title: Some("Example Page".to_string()),
         duration_ms: 1500,
+        timing: None,
+        dialogs: Vec::new(),
+        blocked_mixed_content: Vec::new(),
+        redirect_attempts: Vec::new(),
     };
 
     assert_eq!(result.final_url, "https://example.com/redirected");
@@ -24,7 +24,7 @@
 mod mcp_server_tests {
     use reasonkit_web::mcp::{
         JsonRpcError, JsonRpcRequest, JsonRpcResponse, McpCapabilities, McpServerInfo,
-        McpToolDefinition, ToolCallParams, ToolCallResult, ToolContent,
+        McpToolDefinition, ServerLimits, ToolCallParams, ToolCallResult, ToolContent,
     };
 
     /// Test: MCP server info structure
@@ -33,6 +33,7 @@ mod mcp_server_tests {
         let info = McpServerInfo {
             name: "reasonkit-web".to_string(),
             version: reasonkit_web::VERSION.to_string(),
+            limits: ServerLimits::default(),
         };
 
         assert_eq!(info.name, "reasonkit-web");
@@ -10,11 +10,16 @@ use reasonkit_web::extraction::{
 fn test_extracted_content_structure() {
     let content = ExtractedContent {
         text: "Hello world. This is a test.".to_string(),
+        main_text: "Hello world. This is a test.".to_string(),
         markdown: Some("Hello world. This is a test.".to_string()),
         html: "<p>Hello world. This is a test.</p>".to_string(),
         word_count: 6,
         char_count: 28,
+        reading_time_minutes: 1,
         from_main: true,
+        provenance: None,
+        links_in_content: None,
+        comments: None,
     };
 
     assert_eq!(content.word_count, 6);
@@ -100,6 +105,8 @@ fn test_page_metadata_full() {
             og_type: Some("article".to_string()),
             site_name: Some("Example Site".to_string()),
             locale: Some("en_US".to_string()),
+            video: None,
+            audio: None,
         },
         twitter_card: TwitterCardData {
             card: Some("summary_large_image".to_string()),
@@ -116,10 +123,13 @@ fn test_page_metadata_full() {
         ]
         .into_iter()
         .collect(),
+        meta: Default::default(),
         json_ld: vec![serde_json::json!({
             "@type": "Article",
             "headline": "Test"
         })],
+        breadcrumbs: Vec::new(),
+        link_relations: Default::default(),
     };
 
     assert_eq!(meta.title, Some("Test Page".to_string()));
@@ -143,6 +153,8 @@ fn test_open_graph_data() {
         og_type: Some("website".to_string()),
         site_name: Some("Example".to_string()),
         locale: Some("en_US".to_string()),
+        video: None,
+        audio: None,
     };
 
     let json = serde_json::to_string(&og).unwrap();
@@ -520,10 +520,15 @@ mod tool_registry_tests {
             .find(|d| d.name == "web_execute_js")
             .expect("web_execute_js tool should exist");
 
-        // Should require both url and script
+        // Only url is required: either an inline script or a registered
+        // snippet name supplies the code
         let required = execute_js.input_schema["required"].as_array().unwrap();
         assert!(required.contains(&json!("url")));
-        assert!(required.contains(&json!("script")));
+        assert!(!required.contains(&json!("script")));
+        let properties = execute_js.input_schema["properties"].as_object().unwrap();
+        assert!(properties.contains_key("script"));
+        assert!(properties.contains_key("snippet"));
+        assert!(properties.contains_key("params"));
     }
 
     #[test]
@@ -703,7 +708,7 @@ mod mcp_server_tests {
         // tools/list should return array of tool definitions
         assert!(!definitions.is_empty());
 
-        for def in &definitions {
+        for def in definitions {
             assert!(!def.name.is_empty());
             assert!(!def.description.is_empty());
             assert!(def.input_schema.is_object());
@@ -1126,6 +1131,149 @@ mod integration_scenarios {
     }
 }
 
+// ============================================================================
+// Sitemap Fetching Tests (local HTTP only, no browser)
+// ============================================================================
+
+#[cfg(test)]
+mod sitemap_tests {
+    use reasonkit_web::extraction::{SitemapFetcher, SitemapOptions};
+    use std::io::Write;
+
+    fn gzip(data: &str) -> Vec<u8> {
+        let mut encoder =
+            flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_sitemap_unions_index_children() {
+        use axum::routing::get;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let index = format!(
+            "<sitemapindex>\
+             <sitemap><loc>http://{addr}/sitemap-posts.xml</loc></sitemap>\
+             <sitemap><loc>http://{addr}/sitemap-pages.xml.gz</loc></sitemap>\
+             </sitemapindex>"
+        );
+        let posts = "<urlset>\
+             <url><loc>https://example.com/post-1</loc><lastmod>2024-01-15</lastmod></url>\
+             <url><loc>https://example.com/post-2</loc></url>\
+             </urlset>";
+        let pages = gzip(
+            "<urlset><url><loc>https://example.com/about</loc></url></urlset>",
+        );
+
+        let app = axum::Router::new()
+            .route("/sitemap.xml", get(move || async move { index }))
+            .route("/sitemap-posts.xml", get(move || async move { posts }))
+            .route("/sitemap-pages.xml.gz", get(move || async move { pages }));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let entries = SitemapFetcher::fetch_sitemap(
+            &format!("http://{}/some/page", addr),
+            &SitemapOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        let urls: Vec<&str> = entries.iter().map(|e| e.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/post-1",
+                "https://example.com/post-2",
+                "https://example.com/about",
+            ]
+        );
+        assert_eq!(entries[0].lastmod.as_deref(), Some("2024-01-15"));
+        assert_eq!(entries[1].lastmod, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_sitemap_missing_root_is_an_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = axum::Router::new();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let err = SitemapFetcher::fetch_sitemap(
+            &format!("http://{}/", addr),
+            &SitemapOptions::default(),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("HTTP 404"), "got: {}", err);
+    }
+}
+
+// ============================================================================
+
+#[cfg(test)]
+mod link_check_tests {
+    use reasonkit_web::extraction::{LinkCheckOptions, LinkCheckSession, LinkChecker};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_session_checks_shared_link_once() {
+        use axum::routing::get;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let shared_hits = Arc::new(AtomicUsize::new(0));
+        let counter = shared_hits.clone();
+        let app = axum::Router::new()
+            .route(
+                "/shared",
+                get(move || {
+                    let counter = counter.clone();
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        "ok"
+                    }
+                }),
+            )
+            .route("/page-1-only", get(|| async { "ok" }))
+            .route("/page-2-only", get(|| async { "ok" }));
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let session = LinkCheckSession::new();
+        let options = LinkCheckOptions::default();
+
+        // Two pages whose link sets overlap on /shared
+        let page_one = vec![
+            format!("http://{}/shared", addr),
+            format!("http://{}/page-1-only", addr),
+        ];
+        let page_two = vec![
+            format!("http://{}/shared", addr),
+            format!("http://{}/page-2-only", addr),
+        ];
+
+        let first = LinkChecker::check_urls_in_session(page_one, &options, &session).await;
+        let second = LinkChecker::check_urls_in_session(page_two, &options, &session).await;
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2);
+        assert!(first.iter().all(|r| r.ok), "{:?}", first);
+        assert!(second.iter().all(|r| r.ok), "{:?}", second);
+        assert_eq!(shared_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(session.hits(), 1);
+    }
+}
+
 // ============================================================================
 // Browser Integration Tests (Requires Chrome)
 // ============================================================================
@@ -1216,6 +1364,2849 @@ mod browser_integration_tests {
             .iter()
             .any(|c| matches!(c, ToolContent::Text { .. })));
     }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_visible_text_excludes_hidden_div() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::{ContentExtractor, VisibleTextOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let url = "data:text/html,<body><p>visible text</p>\
+                   <div style=\"display:none\">hidden text</div></body>";
+        let page = controller.navigate(url).await.unwrap();
+
+        let text = ContentExtractor::extract_visible_text(&page, &VisibleTextOptions::default())
+            .await
+            .unwrap();
+
+        assert!(text.contains("visible text"));
+        assert!(!text.contains("hidden text"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_above_fold_only_excludes_later_screens() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::{ContentExtractor, VisibleTextOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_above_fold.html");
+        std::fs::write(
+            &file,
+            "<html><body><p>first screen text</p>\
+             <p style=\"margin-top:5000px\">later screen text</p></body></html>",
+        )
+        .unwrap();
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+
+        let fold_text = ContentExtractor::extract_visible_text(
+            &page,
+            &VisibleTextOptions {
+                above_fold_only: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(fold_text.contains("first screen text"));
+        assert!(!fold_text.contains("later screen text"));
+
+        // Without the option both screens come back
+        let all_text =
+            ContentExtractor::extract_visible_text(&page, &VisibleTextOptions::default())
+                .await
+                .unwrap();
+        assert!(all_text.contains("later screen text"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_noscript_content_included_only_when_requested() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::{ContentExtractor, VisibleTextOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_noscript.html");
+        std::fs::write(
+            &file,
+            "<html><body><p>rendered text</p>\
+             <noscript><p>fallback copy</p>\
+             <a href=\"/plain\">plain version</a></noscript></body></html>",
+        )
+        .unwrap();
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+
+        // By default noscript fallback stays out
+        let text = ContentExtractor::extract_visible_text(&page, &VisibleTextOptions::default())
+            .await
+            .unwrap();
+        assert!(text.contains("rendered text"));
+        assert!(!text.contains("fallback copy"));
+
+        let with_noscript = ContentExtractor::extract_visible_text(
+            &page,
+            &VisibleTextOptions {
+                include_noscript: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(with_noscript.contains("rendered text"));
+        assert!(with_noscript.contains("fallback copy"));
+        assert!(with_noscript.contains("plain version"));
+        // The fallback markup comes through as text, not tags
+        assert!(!with_noscript.contains("<p>"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_self_contained_html_inlines_stylesheet() {
+        use reasonkit_web::browser::{BrowserController, HtmlInlineOptions, PageCapture};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let url = "data:text/html,<head>\
+                   <link rel=\"stylesheet\" href=\"data:text/css,h1{color:red}\">\
+                   </head><body><h1>styled</h1></body>";
+        let page = controller.navigate(url).await.unwrap();
+
+        let result = PageCapture::self_contained_html(&page, &HtmlInlineOptions::default())
+            .await
+            .unwrap();
+        let html = String::from_utf8(result.data).unwrap();
+
+        // Stylesheet is inlined as a <style> block; no external link remains
+        assert!(html.contains("color:red"));
+        assert!(html.contains("<style>"));
+        assert!(!html.contains("rel=\"stylesheet\""));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_warmup_reduces_time_to_first_byte() {
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        // DNS + connect + TTFB for one navigation on a fresh browser
+        async fn first_byte_ms(warm: bool) -> Option<u64> {
+            let controller = BrowserController::new().await.ok()?;
+            if warm {
+                controller.warmup(&["example.com"]).await.ok()?;
+            }
+
+            let page = controller.new_page().await.ok()?;
+            let options = NavigationOptions {
+                collect_timing: true,
+                ..Default::default()
+            };
+            let result = PageNavigator::goto(&page, "https://example.com", Some(options))
+                .await
+                .ok()?;
+
+            let timing = result.timing?;
+            Some(
+                timing.dns_ms.unwrap_or(0)
+                    + timing.connect_ms.unwrap_or(0)
+                    + timing.ttfb_ms.unwrap_or(0),
+            )
+        }
+
+        let (Some(cold), Some(warmed)) = (first_byte_ms(false).await, first_byte_ms(true).await)
+        else {
+            println!("Browser test skipped: browser or network unavailable");
+            return;
+        };
+
+        println!("cold first byte: {}ms, warmed first byte: {}ms", cold, warmed);
+        assert!(
+            warmed <= cold,
+            "warmed navigation ({warmed}ms) should not be slower than cold ({cold}ms)"
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_execute_js_all_frames_includes_iframe() {
+        use reasonkit_web::browser::{BrowserController, FrameEvaluator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let url = "data:text/html,<body><h1>main</h1>\
+                   <iframe srcdoc=\"<h1>inner</h1>\"></iframe></body>";
+        let page = controller.navigate(url).await.unwrap();
+
+        let results = FrameEvaluator::evaluate_all(&page, "document.querySelector('h1').innerText")
+            .await
+            .unwrap();
+
+        assert!(results.len() >= 2, "expected main frame and iframe");
+        let texts: Vec<&str> = results
+            .iter()
+            .filter_map(|r| r.value.as_ref().and_then(|v| v.as_str()))
+            .collect();
+        assert!(texts.contains(&"main"));
+        assert!(texts.contains(&"inner"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_favicon_returns_largest_declared_icon() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::MetadataExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Icon payloads are base64 for "small" and "large"
+        let url = "data:text/html,<head>\
+                   <link rel=\"icon\" sizes=\"16x16\" href=\"data:image/png;base64,c21hbGw=\">\
+                   <link rel=\"icon\" sizes=\"32x32\" href=\"data:image/png;base64,bGFyZ2U=\">\
+                   </head><body></body>";
+        let page = controller.navigate(url).await.unwrap();
+
+        let favicon = MetadataExtractor::extract_favicon(&page)
+            .await
+            .unwrap()
+            .expect("favicon should be found");
+
+        assert_eq!(favicon.data, "bGFyZ2U=");
+        assert_eq!(favicon.mime_type, "image/png");
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_outline_reflects_heading_hierarchy() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ContentExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_outline.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <h1>User Guide</h1>\
+             <h2 id=\"setup\">Setup</h2>\
+             <h3>Install Steps</h3>\
+             <h2>Usage</h2>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let outline = ContentExtractor::extract_outline(&page).await.unwrap();
+
+        assert_eq!(outline.len(), 4);
+        assert_eq!(outline[0].level, 1);
+        assert_eq!(outline[0].text, "User Guide");
+        assert_eq!(outline[0].anchor_id, "user-guide");
+        assert_eq!(outline[1].anchor_id, "setup");
+        assert_eq!(outline[2].level, 3);
+        assert_eq!(outline[2].anchor_id, "install-steps");
+        assert_eq!(outline[3].level, 2);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_content_links_mapped_with_context() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ContentExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_content_links.html");
+        let filler = "Lorem ipsum filler sentence to push the article over the \
+                      length threshold for main-content detection. "
+            .repeat(5);
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body>\
+                 <nav><a href=\"/home\">Home</a></nav>\
+                 <article>\
+                 <p>{}</p>\
+                 <p>The design follows <a href=\"/docs/spec\">the spec</a> closely.</p>\
+                 <p>See also <a href=\"https://example.com/ref\">the reference</a>.</p>\
+                 </article>\
+                 <footer><a href=\"/imprint\">Imprint</a></footer>\
+                 </body></html>",
+                filler
+            ),
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let links = ContentExtractor::extract_content_links(&page).await.unwrap();
+
+        // Nav and footer anchors stay out of the mapping
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].anchor_text, "the spec");
+        assert!(links[0].url.ends_with("/docs/spec"));
+        assert!(links[0]
+            .context
+            .as_deref()
+            .unwrap()
+            .contains("follows the spec closely"));
+        assert_eq!(links[1].anchor_text, "the reference");
+        assert_eq!(links[1].url, "https://example.com/ref");
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_word_count_and_reading_time_from_article_body() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ContentExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // 300 words in the article; nav and footer add more that must not count
+        let body: String = (0..300)
+            .map(|i| format!("word{} ", i))
+            .collect::<Vec<_>>()
+            .join("");
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_reading_time.html");
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body>\
+                 <nav>Home About Contact Pricing Blog Careers</nav>\
+                 <article><p>{}</p></article>\
+                 <footer>Imprint Privacy Terms</footer>\
+                 </body></html>",
+                body.trim()
+            ),
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let content = ContentExtractor::extract_main_content(&page).await.unwrap();
+
+        assert!(content.from_main);
+        assert_eq!(content.word_count, 300);
+        // 300 words at 225 wpm rounds up to 2 minutes
+        assert_eq!(content.reading_time_minutes, 2);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_comment_section_excluded_from_main_text() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ContentExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let filler = "A proper article sentence that belongs in the body. ".repeat(8);
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_comments.html");
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body>\
+                 <article>\
+                 <p>{}</p>\
+                 <div id=\"comments\">\
+                 <p>First! Great post.</p>\
+                 <p>Actually the author is wrong about everything.</p>\
+                 </div>\
+                 </article>\
+                 </body></html>",
+                filler.trim()
+            ),
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let content = ContentExtractor::extract_main_content(&page).await.unwrap();
+
+        // The article stays clean; the thread comes back separately
+        assert!(content.main_text.contains("proper article sentence"));
+        assert!(!content.main_text.contains("Great post"));
+        assert!(!content.html.contains("Great post"));
+        let comments = content.comments.expect("comments section detected");
+        assert!(comments.contains("Great post"));
+        assert!(comments.contains("wrong about everything"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_raw_and_cleaned_text_from_one_extraction() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ContentExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Cluttered fixture: chrome text surrounds a >200-char article
+        let filler = "An actual article sentence with useful words in it. ".repeat(8);
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_raw_and_cleaned.html");
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body>\
+                 <nav>Home About Contact Pricing Blog Careers Support</nav>\
+                 <aside>Trending: one two three four five six seven</aside>\
+                 <article><p>{}</p></article>\
+                 <footer>Imprint Privacy Terms Newsletter Sitemap</footer>\
+                 </body></html>",
+                filler.trim()
+            ),
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let content = ContentExtractor::extract_main_content(&page).await.unwrap();
+
+        // text carries the full page including chrome, main_text the
+        // cleaned article only
+        assert!(!content.main_text.is_empty());
+        assert!(!content.text.is_empty());
+        assert!(content.main_text.len() < content.text.len());
+        assert!(content.text.contains("Imprint"));
+        assert!(!content.main_text.contains("Imprint"));
+        assert!(content.main_text.contains("actual article sentence"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_dates_normalized_from_time_element_and_text() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::{DateExtractor, DateOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_dates.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <article>\
+             <time datetime=\"2020-01-02T15:00:00Z\">a while back</time>\
+             <p>Published on Jan 2, 2020 by the team.</p>\
+             <p>Totally undated paragraph.</p>\
+             </article>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let options = DateOptions {
+            include_text_dates: true,
+        };
+        let dates = DateExtractor::extract(&page, &options).await.unwrap();
+
+        let time_entry = dates
+            .iter()
+            .find(|d| d.text == "a while back")
+            .expect("time element extracted");
+        assert_eq!(time_entry.iso.as_deref(), Some("2020-01-02T15:00:00+00:00"));
+        assert!(time_entry.selector.contains("time"));
+
+        let text_entry = dates
+            .iter()
+            .find(|d| d.text == "Jan 2, 2020")
+            .expect("visible date string extracted");
+        assert_eq!(text_entry.iso.as_deref(), Some("2020-01-02"));
+        assert!(text_entry.selector.contains("p:nth-of-type(1)"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_metadata_resolves_canonical_and_hreflang_links() {
+        use axum::routing::get;
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::MetadataExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Relative hrefs must come back resolved against the document
+        let app = axum::Router::new().route(
+            "/en/page",
+            get(|| async {
+                axum::response::Html(
+                    "<html><head>\
+                     <link rel=\"canonical\" href=\"/en/page\">\
+                     <link rel=\"alternate\" hreflang=\"en\" href=\"/en/page\">\
+                     <link rel=\"alternate\" hreflang=\"de\" href=\"/de/page\">\
+                     </head><body></body></html>",
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let page = controller
+            .navigate(&format!("http://{}/en/page", addr))
+            .await
+            .unwrap();
+        let metadata = MetadataExtractor::extract(&page).await.unwrap();
+
+        let relations = &metadata.link_relations;
+        assert_eq!(
+            relations.canonical.as_deref(),
+            Some(format!("http://{}/en/page", addr).as_str())
+        );
+        assert_eq!(relations.hreflang.len(), 2);
+        assert_eq!(relations.hreflang["en"], format!("http://{}/en/page", addr));
+        assert_eq!(relations.hreflang["de"], format!("http://{}/de/page", addr));
+        assert!(relations.issues.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_open_graph_video_metadata_extracted() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::MetadataExtractor;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_og_video.html");
+        std::fs::write(
+            &file,
+            "<html><head>\
+             <meta property=\"og:video\" content=\"https://example.com/movie.mp4\">\
+             <meta property=\"og:video:type\" content=\"video/mp4\">\
+             <meta property=\"og:video:width\" content=\"1280\">\
+             <meta property=\"og:video:height\" content=\"720\">\
+             </head><body></body></html>",
+        )
+        .unwrap();
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let metadata = MetadataExtractor::extract(&page).await.unwrap();
+
+        let video = metadata.open_graph.video.expect("og:video should be set");
+        assert_eq!(video.url.as_deref(), Some("https://example.com/movie.mp4"));
+        assert_eq!(video.media_type.as_deref(), Some("video/mp4"));
+        assert_eq!(video.width, Some(1280));
+        assert_eq!(video.height, Some(720));
+        assert!(metadata.open_graph.audio.is_none());
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_page_cap_serializes_new_pages() {
+        use reasonkit_web::browser::{BrowserConfig, BrowserController};
+        use std::sync::Arc;
+
+        let config = BrowserConfig::builder().max_concurrent_pages(1).build();
+        let controller = match BrowserController::with_config(config).await {
+            Ok(c) => Arc::new(c),
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let first = controller.new_page().await.unwrap();
+        assert_eq!(controller.available_page_slots(), 0);
+
+        // A second page must queue behind the cap instead of opening
+        let queued = tokio::spawn({
+            let controller = Arc::clone(&controller);
+            async move { controller.new_page().await }
+        });
+
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert_eq!(controller.page_count().await, 1);
+        assert!(!queued.is_finished());
+
+        // Closing the first page frees the slot and unblocks the queued open
+        controller.close_page(first).await.unwrap();
+        let second = queued.await.unwrap().unwrap();
+        assert_eq!(controller.page_count().await, 1);
+
+        controller.close_page(second).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_dialog_policies_applied_per_type() {
+        use reasonkit_web::browser::{
+            BrowserController, DialogAction, DialogPolicy, NavigationOptions, PageNavigator,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Page fires an alert and then a confirm while loading
+        let html = "<script>alert('hi'); confirm('leave?');</script><body>done</body>";
+        let dir = std::env::temp_dir().join("reasonkit_dialog_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("dialogs.html");
+        std::fs::write(&file, html).unwrap();
+        let url = format!("file://{}", file.display());
+
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            dialog_policy: Some(DialogPolicy {
+                alert: DialogAction::Accept,
+                confirm: DialogAction::Dismiss,
+                ..DialogPolicy::default()
+            }),
+            ..Default::default()
+        };
+        let result = PageNavigator::goto(&page, &url, Some(options))
+            .await
+            .unwrap();
+
+        assert_eq!(result.dialogs.len(), 2);
+        assert_eq!(result.dialogs[0].dialog_type, "alert");
+        assert_eq!(result.dialogs[0].message, "hi");
+        assert!(result.dialogs[0].accepted);
+        assert_eq!(result.dialogs[1].dialog_type, "confirm");
+        assert_eq!(result.dialogs[1].message, "leave?");
+        assert!(!result.dialogs[1].accepted);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_blocked_mixed_content_is_reported() {
+        use reasonkit_web::browser::{BrowserController, MixedContentMode, MixedContentMonitor};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Start from a real https origin, then inject an http script so the
+        // renderer treats it as blockable mixed content
+        let page = controller.navigate("https://example.com").await.unwrap();
+        let monitor = MixedContentMonitor::install(&page, MixedContentMode::Block)
+            .await
+            .unwrap();
+
+        page.inner()
+            .evaluate(
+                "const s = document.createElement('script'); \
+                 s.src = 'http://example.com/app.js'; \
+                 document.head.appendChild(s); true",
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        let blocked = monitor.blocked().await;
+        assert!(
+            blocked.iter().any(|r| r.url.starts_with("http://")),
+            "expected a blocked insecure subresource, got {:?}",
+            blocked
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extract_batch_captures_failures_inline() {
+        let dir = std::env::temp_dir().join("reasonkit_batch_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let first = dir.join("first.html");
+        let second = dir.join("second.html");
+        std::fs::write(&first, "<body><p>alpha page</p></body>").unwrap();
+        std::fs::write(&second, "<body><p>beta page</p></body>").unwrap();
+        let missing = dir.join("does_not_exist.html");
+
+        let registry = ToolRegistry::new();
+        let args = serde_json::json!({
+            "urls": [
+                format!("file://{}", first.display()),
+                format!("file://{}", missing.display()),
+                format!("file://{}", second.display()),
+            ],
+            "extraction": { "type": "content", "format": "text" }
+        });
+
+        let result = registry.execute("web_extract_batch", args).await;
+        let text = match &result.content[0] {
+            reasonkit_web::mcp::types::ToolContent::Text { text } => text,
+            _ => panic!("Expected text content"),
+        };
+        if result.is_error {
+            println!("Browser test skipped: {}", text);
+            return;
+        }
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(text).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["status"], "ok");
+        assert!(entries[0]["data"].as_str().unwrap().contains("alpha"));
+        assert_eq!(entries[1]["status"], "error");
+        assert!(entries[1]["error"].as_str().is_some());
+        assert_eq!(entries[2]["status"], "ok");
+        assert!(entries[2]["data"].as_str().unwrap().contains("beta"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extract_batch_ndjson_emits_one_line_per_url() {
+        let dir = std::env::temp_dir().join("reasonkit_batch_ndjson_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let pages: Vec<_> = ["one", "two", "three"]
+            .iter()
+            .map(|name| {
+                let path = dir.join(format!("{}.html", name));
+                std::fs::write(&path, format!("<body><p>{} page</p></body>", name)).unwrap();
+                format!("file://{}", path.display())
+            })
+            .collect();
+
+        let registry = ToolRegistry::new();
+        let args = serde_json::json!({
+            "urls": pages,
+            "extraction": { "type": "content", "format": "text" },
+            "output": "ndjson"
+        });
+
+        let result = registry.execute("web_extract_batch", args).await;
+        let text = match &result.content[0] {
+            reasonkit_web::mcp::types::ToolContent::Text { text } => text,
+            _ => panic!("Expected text content"),
+        };
+        if result.is_error {
+            println!("Browser test skipped: {}", text);
+            return;
+        }
+
+        // Three lines, each one standalone JSON object
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let mut seen = Vec::new();
+        for line in lines {
+            let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(entry["status"], "ok");
+            seen.push(entry["url"].as_str().unwrap().to_string());
+        }
+        // Completion order may differ from input order, but nothing is lost
+        seen.sort();
+        let mut expected: Vec<String> = ["one", "two", "three"]
+            .iter()
+            .map(|name| format!("file://{}", dir.join(format!("{}.html", name)).display()))
+            .collect();
+        expected.sort();
+        assert_eq!(seen, expected);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extract_batch_stops_at_byte_budget() {
+        let dir = std::env::temp_dir().join("reasonkit_batch_budget_test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let pages: Vec<_> = ["one", "two", "three"]
+            .iter()
+            .map(|name| {
+                let path = dir.join(format!("{}.html", name));
+                let filler = "x".repeat(4096);
+                std::fs::write(
+                    &path,
+                    format!("<body><p>{} page</p><p>{}</p></body>", name, filler),
+                )
+                .unwrap();
+                format!("file://{}", path.display())
+            })
+            .collect();
+
+        let registry = ToolRegistry::new();
+        // Each page is >4KB, so the first one already blows the budget
+        let args = serde_json::json!({
+            "urls": pages,
+            "extraction": { "type": "content", "format": "text" },
+            "concurrency": 1,
+            "maxTotalBytes": 1024
+        });
+
+        let result = registry.execute("web_extract_batch", args).await;
+        let text = match &result.content[0] {
+            reasonkit_web::mcp::types::ToolContent::Text { text } => text,
+            _ => panic!("Expected text content"),
+        };
+        if result.is_error {
+            println!("Browser test skipped: {}", text);
+            return;
+        }
+
+        let output: serde_json::Value = serde_json::from_str(text).unwrap();
+        let reason = output["stopped_reason"].as_str().expect("stopped_reason");
+        assert!(reason.contains("byte budget"), "got: {}", reason);
+        // Partial results: the crawl halted before all three pages
+        let results = output["results"].as_array().unwrap();
+        assert!(!results.is_empty() && results.len() < 3, "got: {:?}", results);
+
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_download_captures_linked_pdf() {
+        use reasonkit_web::browser::{BrowserController, DownloadOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir().join("reasonkit_download_fixture");
+        std::fs::create_dir_all(&dir).unwrap();
+        let pdf = b"%PDF-1.4\n1 0 obj<</Type/Catalog>>endobj\ntrailer<</Root 1 0 R>>\n%%EOF\n";
+        std::fs::write(dir.join("doc.pdf"), pdf).unwrap();
+        std::fs::write(
+            dir.join("page.html"),
+            "<body><a id=\"dl\" href=\"doc.pdf\" download=\"doc.pdf\">get the file</a></body>",
+        )
+        .unwrap();
+
+        let options = DownloadOptions {
+            click_selector: Some("#dl".to_string()),
+            ..Default::default()
+        };
+        let url = format!("file://{}", dir.join("page.html").display());
+        let file = controller.download(&url, &options).await.unwrap();
+
+        assert_eq!(file.suggested_filename, "doc.pdf");
+        assert_eq!(file.data, pdf);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_navigation_failure_writes_diagnostics() {
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir().join("reasonkit_diagnostics_test");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            retries: 0,
+            diagnostics_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let err = PageNavigator::goto(&page, "file:///does/not/exist.html", Some(options))
+            .await
+            .unwrap_err();
+
+        // The error references the artifacts, and the screenshot exists
+        let message = err.to_string();
+        assert!(message.contains("diagnostics:"), "got: {}", message);
+        assert!(message.contains(".png"), "got: {}", message);
+        let screenshots: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "png"))
+            .collect();
+        assert!(!screenshots.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_viewport_applied_before_navigation() {
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, PageNavigator, ViewportOverride,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // The script runs at load time, so it only sees the emulated width if
+        // the override was applied before navigating
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_viewport_test.html");
+        std::fs::write(
+            &file,
+            "<html><head><script>window.__loadWidth = window.innerWidth;</script></head>\
+             <body>viewport</body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            viewport: Some(ViewportOverride::new(400, 300)),
+            ..Default::default()
+        };
+        PageNavigator::goto(&page, &url, Some(options)).await.unwrap();
+
+        let width: u32 = page
+            .inner()
+            .evaluate("window.__loadWidth")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(width, 400);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_capture_response_returns_api_body() {
+        use axum::routing::get;
+        use reasonkit_web::browser::BrowserController;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Local server: a page that fetches /api/items on load
+        let app = axum::Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    axum::response::Html(
+                        "<html><body><script>fetch('/api/items')</script></body></html>",
+                    )
+                }),
+            )
+            .route(
+                "/api/items",
+                get(|| async {
+                    axum::Json(serde_json::json!({ "items": ["a", "b"] }))
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let response = controller
+            .capture_response(
+                &format!("http://{}/", addr),
+                regex::Regex::new("/api/items").unwrap(),
+                10000,
+            )
+            .await
+            .unwrap();
+
+        assert!(response.url.ends_with("/api/items"));
+        assert_eq!(response.status, 200);
+        assert!(response.content_type.contains("application/json"));
+        let body: serde_json::Value = serde_json::from_str(&response.text()).unwrap();
+        assert_eq!(body["items"][0], "a");
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extract_css_background_images() {
+        use axum::routing::get;
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::{ResourceExtractor, ResourceKind, ResourceOptions};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let app = axum::Router::new().route(
+            "/",
+            get(|| async {
+                axum::response::Html(
+                    "<html><body>\
+                     <div style=\"width:100px;height:100px;background-image:url('/bg.png')\"></div>\
+                     <div style=\"width:100px;height:100px;background-image:linear-gradient(red, blue)\"></div>\
+                     </body></html>",
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let page = controller.navigate(&format!("http://{}/", addr)).await.unwrap();
+        let options = ResourceOptions {
+            include_css_backgrounds: true,
+            ..Default::default()
+        };
+        let resources = ResourceExtractor::extract_with_options(&page, &options)
+            .await
+            .unwrap();
+
+        let background = resources
+            .iter()
+            .find(|r| r.url.ends_with("/bg.png"))
+            .expect("background image reported");
+        assert_eq!(background.kind, ResourceKind::Image);
+        assert!(background.url.starts_with("http://"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_navigation_completes_on_chatty_page() {
+        use reasonkit_web::browser::BrowserController;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // A page that floods the console while loading must not delay
+        // navigation-complete detection past the timeout
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_chatty_page_test.html");
+        std::fs::write(
+            &file,
+            "<html><head><title>Chatty</title>\
+             <script>for (let i = 0; i < 5000; i++) { console.log('event', i); }</script>\
+             </head><body>done</body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let title = page.inner().get_title().await.unwrap();
+        assert_eq!(title.as_deref(), Some("Chatty"));
+        assert!(controller.handler_stats().events() > 0);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_critical_resource_failure_fails_navigation() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // The tracking pixel 404s; the document itself loads fine
+        let app = axum::Router::new().route(
+            "/",
+            get(|| async {
+                axum::response::Html(
+                    "<html><body>content<img src=\"/pixel.png\"></body></html>",
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        // Without patterns the failing pixel is tolerated
+        let page = controller.new_page().await.unwrap();
+        PageNavigator::goto(&page, &url, None).await.unwrap();
+        controller.close_page(page).await.unwrap();
+
+        // Declared critical, the same failure fails the navigation
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            retries: 0,
+            critical_resource_patterns: vec!["pixel".to_string()],
+            ..Default::default()
+        };
+        let err = PageNavigator::goto(&page, &url, Some(options))
+            .await
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Critical resource failed"), "got: {}", message);
+        assert!(message.contains("pixel.png"), "got: {}", message);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_empty_body_is_reread_until_content_arrives() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // The document loads empty; content is swapped in 300ms later
+        let app = axum::Router::new().route(
+            "/",
+            get(|| async {
+                axum::response::Html(
+                    "<html><body><div id=\"c\"></div><script>\
+                     setTimeout(() => {\
+                         document.getElementById('c').textContent = 'hydrated content arrived';\
+                     }, 300);\
+                     </script></body></html>",
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        // With a minimum body length the empty first read is re-read
+        // until the delayed content lands
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            min_body_chars: 10,
+            retries: 5,
+            retry_delay_ms: 200,
+            ..Default::default()
+        };
+        PageNavigator::goto(&page, &url, Some(options)).await.unwrap();
+        let body: String = page
+            .inner()
+            .evaluate("document.body.innerText")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(body.contains("hydrated content arrived"), "got: {}", body);
+        controller.close_page(page).await.unwrap();
+
+        // A threshold the page never reaches fails after the retries
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            min_body_chars: 10_000,
+            retries: 1,
+            retry_delay_ms: 100,
+            ..Default::default()
+        };
+        let err = PageNavigator::goto(&page, &url, Some(options))
+            .await
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Body still below"), "got: {}", message);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extraction_cached_across_mirror_urls() {
+        use axum::routing::get;
+
+        // Two URLs serving byte-identical HTML
+        let mirror = || async {
+            axum::response::Html(
+                "<html><body><article><h1>Mirrored</h1><p>Same bytes everywhere.</p>\
+                 </article></body></html>",
+            )
+        };
+        let app = axum::Router::new()
+            .route("/a", get(mirror))
+            .route("/b", get(mirror));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let registry = ToolRegistry::new();
+        let first = registry
+            .execute(
+                "web_extract_content",
+                json!({ "url": format!("http://{}/a", addr) }),
+            )
+            .await;
+        if first.is_error {
+            println!("Browser test skipped: {:?}", first.content);
+            return;
+        }
+        let second = registry
+            .execute(
+                "web_extract_content",
+                json!({ "url": format!("http://{}/b", addr) }),
+            )
+            .await;
+
+        assert!(!second.is_error);
+        // The second URL was answered from the cache, not re-extracted
+        assert_eq!(registry.extraction_cache().hits(), 1);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_stealth_applies_to_first_navigated_page() {
+        use reasonkit_web::browser::BrowserController;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_stealth_first_nav.html");
+        std::fs::write(&file, "<html><body>first</body></html>").unwrap();
+
+        // Stealth overrides must already be in effect on the very first
+        // document the caller sees — no separate blank-page warmup needed
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let webdriver_hidden: bool = page
+            .inner()
+            .evaluate("navigator.webdriver === undefined")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(webdriver_hidden);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_navigate_without_stealth_skips_blank_step() {
+        use reasonkit_web::browser::{BrowserConfig, BrowserController};
+
+        let config = BrowserConfig::builder().stealth(false).build();
+        let controller = match BrowserController::with_config(config).await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_direct_nav.html");
+        std::fs::write(&file, "<html><body><p>direct</p></body></html>").unwrap();
+
+        // The direct path still lands on a fully loaded page
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let body: String = page
+            .inner()
+            .evaluate("document.body.textContent")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(body.contains("direct"));
+        assert_eq!(controller.page_count().await, 1);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_extract_after_scrolling_to_lazy_section() {
+        use axum::routing::get;
+
+        // The section renders its children only once scrolled into view
+        let app = axum::Router::new().route(
+            "/",
+            get(|| async {
+                axum::response::Html(
+                    r#"<html><body style="height:3000px">
+                    <div>TOP</div>
+                    <section id="lazy" style="margin-top:2500px">placeholder</section>
+                    <script>
+                    const obs = new IntersectionObserver(entries => {
+                        if (entries.some(e => e.isIntersecting)) {
+                            document.getElementById('lazy').innerHTML =
+                                '<p>LAZY CHILD LOADED</p>';
+                            obs.disconnect();
+                        }
+                    });
+                    obs.observe(document.getElementById('lazy'));
+                    </script>
+                    </body></html>"#,
+                )
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute(
+                "web_extract_content",
+                json!({
+                    "url": url,
+                    "selector": "#lazy",
+                    "scrollToSelector": "#lazy",
+                    "format": "text"
+                }),
+            )
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            return;
+        }
+        let text = format!("{:?}", result.content);
+        assert!(text.contains("LAZY CHILD LOADED"), "got: {}", text);
+
+        // A selector matching nothing errors instead of extracting stale DOM
+        let missing = registry
+            .execute(
+                "web_extract_content",
+                json!({ "url": url, "scrollToSelector": "#does-not-exist" }),
+            )
+            .await;
+        assert!(missing.is_error);
+        let message = format!("{:?}", missing.content);
+        assert!(message.contains("#does-not-exist"), "got: {}", message);
+
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_bypass_service_worker_fetches_live_content() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // The worker claims the page and answers /data from its own cache
+        let sw_js = "self.addEventListener('install', () => self.skipWaiting());\
+                     self.addEventListener('activate', e => e.waitUntil(self.clients.claim()));\
+                     self.addEventListener('fetch', e => {\
+                         if (e.request.url.endsWith('/data')) {\
+                             e.respondWith(new Response('STALE'));\
+                         }\
+                     });";
+        let app = axum::Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    axum::response::Html(
+                        "<html><body><script>\
+                         navigator.serviceWorker.register('/sw.js');\
+                         </script></body></html>",
+                    )
+                }),
+            )
+            .route(
+                "/sw.js",
+                get(move || async move {
+                    (
+                        [(axum::http::header::CONTENT_TYPE, "application/javascript")],
+                        sw_js,
+                    )
+                }),
+            )
+            .route("/data", get(|| async { "LIVE" }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        // First visit without bypass: let the worker install and claim
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            bypass_service_worker: false,
+            ..Default::default()
+        };
+        PageNavigator::goto(&page, &url, Some(options.clone()))
+            .await
+            .unwrap();
+        for _ in 0..50 {
+            let controlled: bool = page
+                .inner()
+                .evaluate("navigator.serviceWorker.controller !== null")
+                .await
+                .unwrap()
+                .into_value()
+                .unwrap();
+            if controlled {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+
+        // With the worker in charge, /data comes from its cache
+        PageNavigator::goto(&page, &url, Some(options)).await.unwrap();
+        let body: String = page
+            .inner()
+            .evaluate("fetch('/data').then(r => r.text())")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(body, "STALE");
+
+        // Default navigation bypasses the worker and hits the origin
+        PageNavigator::goto(&page, &url, None).await.unwrap();
+        let body: String = page
+            .inner()
+            .evaluate("fetch('/data').then(r => r.text())")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(body, "LIVE");
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_storage_state_reuses_login_in_fresh_browser() {
+        use axum::routing::get;
+        use reasonkit_web::browser::BrowserController;
+
+        // /private is the authenticated view, gated on the session cookie
+        let app = axum::Router::new()
+            .route(
+                "/",
+                get(|| async { axum::response::Html("<html><body>Login page</body></html>") }),
+            )
+            .route(
+                "/private",
+                get(|headers: axum::http::HeaderMap| async move {
+                    let logged_in = headers
+                        .get(axum::http::header::COOKIE)
+                        .and_then(|c| c.to_str().ok())
+                        .map(|c| c.contains("sid=ok"))
+                        .unwrap_or(false);
+                    if logged_in {
+                        axum::response::Html("<html><body>AUTHENTICATED</body></html>")
+                    } else {
+                        axum::response::Html("<html><body>LOGIN REQUIRED</body></html>")
+                    }
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let base = format!("http://{}", addr);
+
+        // Log in once: the fixture's "session" is a cookie plus a token
+        let first = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+        let page = first.navigate(&format!("{}/", base)).await.unwrap();
+        page.inner()
+            .evaluate("document.cookie = 'sid=ok'; localStorage.setItem('token', 't0k3n');")
+            .await
+            .unwrap();
+
+        let state = first.save_storage_state().await.unwrap();
+        assert!(state.cookies.iter().any(|c| c.name == "sid" && c.value == "ok"));
+        let origin = state
+            .origins
+            .iter()
+            .find(|o| o.origin == base)
+            .expect("origin storage captured");
+        assert_eq!(
+            origin.local_storage.get("token").map(String::as_str),
+            Some("t0k3n")
+        );
+        first.close().await.unwrap();
+
+        // A fresh browser with the loaded state sees the authenticated view
+        let second = BrowserController::new().await.unwrap();
+        second.load_storage_state(&state).await.unwrap();
+        let page = second.navigate(&format!("{}/private", base)).await.unwrap();
+        let body: String = page
+            .inner()
+            .evaluate("document.body.innerText")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(body.contains("AUTHENTICATED"), "body was: {}", body);
+
+        let token: Option<String> = page
+            .inner()
+            .evaluate("localStorage.getItem('token')")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(token.as_deref(), Some("t0k3n"));
+        second.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_slow_3g_throttling_increases_load_time() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, NetworkConditions, PageNavigator, WaitUntil,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let app = axum::Router::new().route(
+            "/",
+            get(|| async { axum::response::Html("<html><body>Throttle me</body></html>") }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        let options = NavigationOptions {
+            wait_until: WaitUntil::Load,
+            human_like: false,
+            retries: 0,
+            ..Default::default()
+        };
+
+        let page = controller.new_page().await.unwrap();
+        let unthrottled = PageNavigator::goto(&page, &url, Some(options.clone()))
+            .await
+            .unwrap();
+
+        let throttled_options = NavigationOptions {
+            network_conditions: Some(NetworkConditions::slow_3g()),
+            ..options
+        };
+        let page = controller.new_page().await.unwrap();
+        let throttled = PageNavigator::goto(&page, &url, Some(throttled_options))
+            .await
+            .unwrap();
+
+        // Slow 3G adds two full seconds of latency to every request; a
+        // local page cannot load that slowly without the throttle
+        assert!(
+            throttled.duration_ms > unthrottled.duration_ms + 1000,
+            "throttled {}ms vs unthrottled {}ms",
+            throttled.duration_ms,
+            unthrottled.duration_ms
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_disallowed_content_type_rejects_navigation() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{BrowserController, NavigationOptions, PageNavigator};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let app = axum::Router::new()
+            .route(
+                "/",
+                get(|| async { axum::response::Html("<html><body>Fine</body></html>") }),
+            )
+            .route(
+                "/archive",
+                get(|| async {
+                    (
+                        [(axum::http::header::CONTENT_TYPE, "application/zip")],
+                        vec![0x50u8, 0x4b, 0x03, 0x04],
+                    )
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let options = NavigationOptions {
+            allowed_content_types: Some(NavigationOptions::html_content_types()),
+            retries: 0,
+            ..Default::default()
+        };
+
+        // HTML documents pass the allowlist untouched
+        let page = controller.new_page().await.unwrap();
+        PageNavigator::goto(&page, &format!("http://{}/", addr), Some(options.clone()))
+            .await
+            .unwrap();
+
+        // The zip is rejected with the content type, not a transport error
+        let page = controller.new_page().await.unwrap();
+        let err = PageNavigator::goto(
+            &page,
+            &format!("http://{}/archive", addr),
+            Some(options),
+        )
+        .await
+        .unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("Disallowed content type") && message.contains("application/zip"),
+            "error was: {}",
+            message
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_form_fields_extracted_with_labels_and_options() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::FormExtractor;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_form.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <form action=\"/signup\" method=\"post\">\
+             <label for=\"email\">Email address</label>\
+             <input id=\"email\" name=\"email\" type=\"email\" required>\
+             <label>Nickname <input name=\"nick\" type=\"text\"></label>\
+             <input name=\"phone\" type=\"tel\" aria-label=\"Phone number\">\
+             <select name=\"country\">\
+             <option value=\"de\">Germany</option>\
+             <option value=\"fr\">France</option>\
+             </select>\
+             <input type=\"hidden\" name=\"csrf\" value=\"x\">\
+             <button type=\"submit\">Go</button>\
+             </form>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let forms = FormExtractor::extract_all(&page).await.unwrap();
+
+        assert_eq!(forms.len(), 1);
+        let form = &forms[0];
+        assert!(form.action.as_deref().unwrap_or("").ends_with("/signup"));
+        assert_eq!(form.method, "post");
+
+        // The hidden csrf input is omitted
+        assert_eq!(form.fields.len(), 4);
+
+        let email = &form.fields[0];
+        assert_eq!(email.name, "email");
+        assert_eq!(email.field_type, "email");
+        assert_eq!(email.label.as_deref(), Some("Email address"));
+        assert!(email.required);
+
+        assert_eq!(form.fields[1].label.as_deref(), Some("Nickname"));
+        assert_eq!(form.fields[2].label.as_deref(), Some("Phone number"));
+
+        let country = &form.fields[3];
+        assert_eq!(country.field_type, "select");
+        assert!(!country.required);
+        let labels: Vec<&str> = country.options.iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(labels, vec!["Germany", "France"]);
+        assert_eq!(country.options[0].value, "de");
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_pagination_detected_from_numbered_links_and_infinite_scroll() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::PaginationDetector;
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let numbered = dir.join("reasonkit_pagination_numbered.html");
+        std::fs::write(
+            &numbered,
+            "<html><body>\
+             <nav class=\"pagination\">\
+             <a href=\"/page/1\" rel=\"prev\">‹</a>\
+             <a href=\"/page/1\">1</a>\
+             <a href=\"/page/2\">2</a>\
+             <a href=\"/page/3\">3</a>\
+             <a href=\"/page/3\" rel=\"next\">›</a>\
+             </nav>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", numbered.display()))
+            .await
+            .unwrap();
+        let info = PaginationDetector::detect(&page).await.unwrap();
+
+        assert!(
+            info.next_url.as_deref().unwrap_or("").ends_with("/page/3"),
+            "next was {:?}",
+            info.next_url
+        );
+        assert!(info.prev_url.as_deref().unwrap_or("").ends_with("/page/1"));
+        let labels: Vec<&str> = info.page_numbers.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(labels, vec!["1", "2", "3"]);
+        assert!(!info.infinite_scroll);
+
+        let infinite = dir.join("reasonkit_pagination_infinite.html");
+        std::fs::write(
+            &infinite,
+            "<html><body>\
+             <div id=\"feed\"><article>Item</article></div>\
+             <button class=\"load-more\">Load more</button>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", infinite.display()))
+            .await
+            .unwrap();
+        let info = PaginationDetector::detect(&page).await.unwrap();
+        assert!(info.infinite_scroll);
+        assert_eq!(info.next_url, None);
+
+        let _ = std::fs::remove_file(&numbered);
+        let _ = std::fs::remove_file(&infinite);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_registered_snippet_runs_in_allowlist_mode() {
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_snippet.html");
+        std::fs::write(
+            &file,
+            "<html><head><title>Snippet Fixture</title></head><body>Body</body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        registry.script_allowlist().set_enabled(true);
+        registry.script_allowlist().register("get_title", "document.title");
+
+        let result = registry
+            .execute("web_execute_js", json!({"url": url, "snippet": "get_title"}))
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+        let text = match &result.content[0] {
+            ToolContent::Text { text } => text.clone(),
+            other => panic!("expected text content, got {:?}", other),
+        };
+        assert!(text.contains("Snippet Fixture"), "got: {}", text);
+
+        // Inline code stays refused even though the browser is up
+        let inline = registry
+            .execute("web_execute_js", json!({"url": url, "script": "1 + 1"}))
+            .await;
+        assert!(inline.is_error);
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_dom_tree_matches_document_nesting() {
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_dom_tree.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <div id=\"root\"><p class=\"lead\">Hello <em>world</em></p><span>tail</span></div>\
+             <script>var ignored = true;</script>\
+             </body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute(
+                "web_extract_dom_tree",
+                json!({ "url": url, "selector": "#root" }),
+            )
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+        let text = match &result.content[0] {
+            ToolContent::Text { text } => text.clone(),
+            other => panic!("expected text content, got {:?}", other),
+        };
+        let extracted: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(extracted["truncated"], false);
+        assert_eq!(extracted["node_count"], 4);
+        let root = &extracted["tree"];
+        assert_eq!(root["tag"], "div");
+        assert_eq!(root["attrs"]["id"], "root");
+        let children = root["children"].as_array().unwrap();
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[0]["tag"], "p");
+        assert_eq!(children[0]["attrs"]["class"], "lead");
+        assert_eq!(children[0]["text"], "Hello");
+        assert_eq!(children[0]["children"][0]["tag"], "em");
+        assert_eq!(children[0]["children"][0]["text"], "world");
+        assert_eq!(children[1]["tag"], "span");
+
+        // Depth 0 keeps only the root and flags the cut
+        let shallow = registry
+            .execute(
+                "web_extract_dom_tree",
+                json!({ "url": url, "selector": "#root", "maxDepth": 0 }),
+            )
+            .await;
+        assert!(!shallow.is_error);
+        let text = match &shallow.content[0] {
+            ToolContent::Text { text } => text.clone(),
+            other => panic!("expected text content, got {:?}", other),
+        };
+        let extracted: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(extracted["truncated"], true);
+        let children = extracted["tree"]["children"].as_array();
+        assert!(children.map_or(true, |c| c.is_empty()));
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_inspect_element_returns_box_styles_and_screenshot() {
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_inspect_element.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <div id=\"card\" style=\"width:120px;height:40px;color:rgb(255,0,0)\">Card text</div>\
+             </body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute(
+                "web_inspect_element",
+                json!({
+                    "url": url,
+                    "selector": "#card",
+                    "styleProperties": ["color", "width"]
+                }),
+            )
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+        let text = match &result.content[0] {
+            ToolContent::Text { text } => text.clone(),
+            other => panic!("expected text content, got {:?}", other),
+        };
+        let inspected: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(inspected["selector"], "#card");
+        assert_eq!(inspected["bounding_box"]["width"], 120.0);
+        assert_eq!(inspected["bounding_box"]["height"], 40.0);
+        assert_eq!(inspected["computed_styles"]["color"], "rgb(255, 0, 0)");
+        assert_eq!(inspected["text"], "Card text");
+        assert!(!inspected["screenshot_base64"].as_str().unwrap().is_empty());
+
+        // A selector matching nothing errors rather than inspecting the page
+        let missing = registry
+            .execute(
+                "web_inspect_element",
+                json!({ "url": url, "selector": "#nope" }),
+            )
+            .await;
+        assert!(missing.is_error);
+        let message = format!("{:?}", missing.content);
+        assert!(message.contains("#nope"), "got: {}", message);
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_responsive_screenshot_returns_image_per_viewport() {
+        use base64::Engine;
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_responsive_screenshot.html");
+        std::fs::write(
+            &file,
+            "<html><body><h1>Responsive page</h1></body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute(
+                "web_responsive_screenshot",
+                json!({
+                    "url": url,
+                    "viewports": [
+                        {"width": 400, "height": 300, "label": "narrow"},
+                        {"width": 800, "height": 600, "label": "wide"}
+                    ]
+                }),
+            )
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+
+        // One image per requested viewport, plus the trailing manifest
+        assert_eq!(result.content.len(), 3);
+        let mut widths = Vec::new();
+        for part in &result.content[..2] {
+            let data = match part {
+                ToolContent::Image { data, mime_type } => {
+                    assert_eq!(mime_type, "image/png");
+                    data.clone()
+                }
+                other => panic!("expected image content, got {:?}", other),
+            };
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(&data)
+                .expect("valid base64");
+            // PNG IHDR: width is the big-endian u32 at offset 16
+            let width = u32::from_be_bytes(bytes[16..20].try_into().unwrap());
+            widths.push(width);
+        }
+        assert_eq!(widths, vec![400, 800]);
+
+        let manifest = match &result.content[2] {
+            ToolContent::Text { text } => {
+                serde_json::from_str::<serde_json::Value>(text).unwrap()
+            }
+            other => panic!("expected text manifest, got {:?}", other),
+        };
+        assert_eq!(manifest["viewports"][0]["label"], "narrow");
+        assert_eq!(manifest["viewports"][1]["width"], 800);
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_image_region_crop_matches_rendered_box() {
+        use base64::Engine;
+        use reasonkit_web::mcp::types::ToolContent;
+
+        // 1x1 PNG stretched to 120x80, so the crop must follow the
+        // rendered size, not the intrinsic one
+        let pixel = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_image_regions.html");
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body style=\"margin:0\">\
+                 <img src=\"data:image/png;base64,{}\" width=\"16\" height=\"16\">\
+                 <img id=\"banner\" src=\"data:image/png;base64,{}\" \
+                 style=\"position:absolute;left:30px;top:40px;width:120px;height:80px\">\
+                 </body></html>",
+                pixel, pixel
+            ),
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute("web_capture_image_regions", json!({"url": url}))
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+
+        // The 16x16 icon is filtered out: one crop plus the manifest
+        assert_eq!(result.content.len(), 2);
+        let data = match &result.content[0] {
+            ToolContent::Image { data, mime_type } => {
+                assert_eq!(mime_type, "image/png");
+                data.clone()
+            }
+            other => panic!("expected image content, got {:?}", other),
+        };
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&data)
+            .expect("valid base64");
+        // PNG IHDR: width and height are big-endian u32s at offsets 16/20
+        let width = u32::from_be_bytes(bytes[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(bytes[20..24].try_into().unwrap());
+        assert_eq!((width, height), (120, 80));
+
+        let manifest = match &result.content[1] {
+            ToolContent::Text { text } => {
+                serde_json::from_str::<serde_json::Value>(text).unwrap()
+            }
+            other => panic!("expected text manifest, got {:?}", other),
+        };
+        assert_eq!(manifest["regions"][0]["selector_path"], "#banner");
+        assert_eq!(manifest["regions"][0]["x"], 30.0);
+        assert_eq!(manifest["regions"][0]["y"], 40.0);
+        assert_eq!(manifest["regions"][0]["width"], 120.0);
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_primary_image_prefers_og_image() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ImageRegionExtractor;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_primary_og.html");
+        std::fs::write(
+            &file,
+            "<html><head>\
+             <meta property=\"og:image\" content=\"https://example.com/hero.jpg\">\
+             <meta property=\"og:image:width\" content=\"1200\">\
+             <meta property=\"og:image:height\" content=\"630\">\
+             </head><body><img src=\"other.jpg\" style=\"width:400px;height:300px\"></body></html>",
+        )
+        .unwrap();
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let image = ImageRegionExtractor::extract_primary_image(&page)
+            .await
+            .unwrap()
+            .expect("og:image should be picked");
+        assert_eq!(image.url, "https://example.com/hero.jpg");
+        assert_eq!(image.width, Some(1200));
+        assert_eq!(image.source, "og:image");
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_primary_image_falls_back_to_largest_content_image() {
+        use reasonkit_web::browser::BrowserController;
+        use reasonkit_web::extraction::ImageRegionExtractor;
+
+        let pixel = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mP8z8BQDwAEhQGAhKmMIQAAAABJRU5ErkJggg==";
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_primary_content.html");
+        std::fs::write(
+            &file,
+            format!(
+                "<html><body style=\"margin:0\">\
+                 <img id=\"site-logo\" src=\"data:image/png;base64,{p}\" \
+                 style=\"width:300px;height:100px\">\
+                 <img id=\"hero\" src=\"data:image/png;base64,{p}\" \
+                 style=\"width:320px;height:200px\">\
+                 <img id=\"thumb\" src=\"data:image/png;base64,{p}\" \
+                 style=\"width:80px;height:80px\">\
+                 </body></html>",
+                p = pixel
+            ),
+        )
+        .unwrap();
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+        let image = ImageRegionExtractor::extract_primary_image(&page)
+            .await
+            .unwrap()
+            .expect("a content image should be picked");
+        // The logo is excluded despite its size; the hero wins over the thumb
+        assert_eq!(image.width, Some(320));
+        assert_eq!(image.height, Some(200));
+        assert_eq!(image.source, "content");
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_assert_passes_and_fails_without_erroring() {
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_assert.html");
+        std::fs::write(
+            &file,
+            "<html><head><title>Monitored page</title></head>\
+             <body><h1>Up</h1></body></html>",
+        )
+        .unwrap();
+        let url = format!("file://{}", file.display());
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute(
+                "web_assert",
+                json!({"url": url, "expression": "document.title.length > 0"}),
+            )
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            let _ = std::fs::remove_file(&file);
+            return;
+        }
+
+        let report = match &result.content[0] {
+            ToolContent::Text { text } => {
+                serde_json::from_str::<serde_json::Value>(text).unwrap()
+            }
+            other => panic!("expected text content, got {:?}", other),
+        };
+        assert_eq!(report["pass"], true);
+        assert_eq!(report["value"], true);
+
+        // A failing assertion is a pass:false result, not an error
+        let result = registry
+            .execute(
+                "web_assert",
+                json!({
+                    "url": url,
+                    "waitSelector": "h1",
+                    "expression": "document.querySelectorAll('h2').length"
+                }),
+            )
+            .await;
+        assert!(!result.is_error);
+        let report = match &result.content[0] {
+            ToolContent::Text { text } => {
+                serde_json::from_str::<serde_json::Value>(text).unwrap()
+            }
+            other => panic!("expected text content, got {:?}", other),
+        };
+        assert_eq!(report["pass"], false);
+        assert_eq!(report["value"], 0);
+
+        let _ = std::fs::remove_file(&file);
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_check_links_classifies_dead_and_live_links() {
+        use axum::routing::get;
+        use reasonkit_web::mcp::types::ToolContent;
+
+        let app = axum::Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    axum::response::Html(
+                        "<html><body>\
+                         <a href=\"/ok\">Works</a>\
+                         <a href=\"/missing\">Dead</a>\
+                         <a href=\"#top\">Anchor</a>\
+                         </body></html>",
+                    )
+                }),
+            )
+            .route("/ok", get(|| async { axum::response::Html("<html><body>OK</body></html>") }));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        let registry = ToolRegistry::new();
+        let result = registry
+            .execute("web_check_links", json!({ "url": url }))
+            .await;
+        if result.is_error {
+            println!("Browser test skipped: {:?}", result.content);
+            return;
+        }
+        let text = match &result.content[0] {
+            ToolContent::Text { text } => text.clone(),
+            other => panic!("expected text content, got {:?}", other),
+        };
+        let report: serde_json::Value = serde_json::from_str(&text).unwrap();
+
+        // The anchor link is not checkable, so only two results come back
+        assert_eq!(report["checked"], 2);
+        assert_eq!(report["broken"], 1);
+        let results = report["results"].as_array().unwrap();
+        let by_url = |suffix: &str| {
+            results
+                .iter()
+                .find(|r| r["url"].as_str().unwrap().ends_with(suffix))
+                .unwrap_or_else(|| panic!("no result for {}", suffix))
+        };
+        assert_eq!(by_url("/ok")["status"], 200);
+        assert_eq!(by_url("/ok")["ok"], true);
+        assert_eq!(by_url("/missing")["status"], 404);
+        assert_eq!(by_url("/missing")["ok"], false);
+
+        registry.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_post_navigation_renders_post_response() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, PageNavigator, PostData,
+        };
+
+        let app = axum::Router::new().route(
+            "/search",
+            get(|| async { axum::response::Html("<h1>GET page</h1>") }).post(
+                |body: String| async move {
+                    axum::response::Html(format!("<h1>POST result</h1><p>body: {}</p>", body))
+                },
+            ),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            post: Some(PostData::form("q=rust")),
+            ..Default::default()
+        };
+        PageNavigator::goto(
+            &page,
+            &format!("http://{}/search", addr),
+            Some(options),
+        )
+        .await
+        .unwrap();
+
+        let body: String = page
+            .inner()
+            .evaluate("document.body.innerText")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(body.contains("POST result"), "got: {}", body);
+        assert!(body.contains("q=rust"), "got: {}", body);
+        assert!(!body.contains("GET page"));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_wait_for_clickable_outlasts_overlay() {
+        use reasonkit_web::browser::{BrowserController, PageNavigator};
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_clickable.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <button id=\"go\" onclick=\"document.title='clicked'\">Go</button>\
+             <div id=\"overlay\" style=\"position:fixed;inset:0;background:rgba(0,0,0,.5)\"></div>\
+             <script>setTimeout(() => document.getElementById('overlay').remove(), 300);</script>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller.new_page().await.unwrap();
+        PageNavigator::goto(&page, &format!("file://{}", file.display()), None)
+            .await
+            .unwrap();
+
+        // Covered at first; the wait must ride out the overlay removal
+        PageNavigator::wait_for_clickable(&page, "#go", 5000)
+            .await
+            .unwrap();
+
+        page.inner()
+            .find_element("#go")
+            .await
+            .unwrap()
+            .click()
+            .await
+            .unwrap();
+        let title: String = page
+            .inner()
+            .evaluate("document.title")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(title, "clicked");
+
+        // A button that stays covered times out and names the blocker
+        page.inner()
+            .evaluate(
+                "document.body.insertAdjacentHTML('beforeend', \
+                 '<div id=\"wall\" style=\"position:fixed;inset:0\"></div>')",
+            )
+            .await
+            .unwrap();
+        let err = PageNavigator::wait_for_clickable(&page, "#go", 500)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("obscured"), "got: {}", err);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_spinner_wait_resolves_after_spinner_removed() {
+        use reasonkit_web::browser::{BrowserController, PageNavigator, SpinnerWait};
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_spinner.html");
+        std::fs::write(
+            &file,
+            "<html><body>\
+             <div class=\"spinner\">Loading…</div>\
+             <script>setTimeout(() => document.querySelector('.spinner').remove(), 400);</script>\
+             </body></html>",
+        )
+        .unwrap();
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        PageNavigator::wait_for_spinners_gone(&page, &SpinnerWait::default())
+            .await
+            .unwrap();
+        // The wait must ride out the spinner, not resolve immediately
+        assert!(start.elapsed() >= std::time::Duration::from_millis(300));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_dom_stable_waits_for_mutations_to_cease() {
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, PageNavigator, WaitUntil,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_dom_stable.html");
+        std::fs::write(
+            &file,
+            "<html><body><div id=\"feed\">Loading feed</div>\
+             <script>\
+             let added = 0;\
+             const timer = setInterval(() => {\
+                 const p = document.createElement('p');\
+                 p.textContent = 'item ' + added;\
+                 document.getElementById('feed').appendChild(p);\
+                 added += 1;\
+                 if (added === 6) clearInterval(timer);\
+             }, 100);\
+             </script></body></html>",
+        )
+        .unwrap();
+
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            wait_until: WaitUntil::DomStable(400),
+            ..Default::default()
+        };
+        let started = std::time::Instant::now();
+        PageNavigator::goto(&page, &format!("file://{}", file.display()), Some(options))
+            .await
+            .unwrap();
+
+        // All six mutations land over ~600ms; resolving means the quiet
+        // window only elapsed after they stopped
+        assert!(started.elapsed() >= std::time::Duration::from_millis(600));
+        let items: u64 = page
+            .inner()
+            .evaluate("document.querySelectorAll('#feed p').length")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(items, 6);
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_strip_trackers_removes_gtm_script_from_html_capture() {
+        use reasonkit_web::browser::{BrowserController, HtmlInlineOptions, PageCapture};
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        let dir = std::env::temp_dir();
+        let file = dir.join("reasonkit_strip_trackers.html");
+        std::fs::write(
+            &file,
+            "<html><head>\
+             <script src=\"https://www.googletagmanager.com/gtm.js?id=GTM-XXXX\"></script>\
+             <script>window.dataLayer = window.dataLayer || [];</script>\
+             <script>window.appBooted = true;</script>\
+             </head><body><h1>Article</h1><p>Body text.</p></body></html>",
+        )
+        .unwrap();
+
+        let page = controller
+            .navigate(&format!("file://{}", file.display()))
+            .await
+            .unwrap();
+
+        let options = HtmlInlineOptions {
+            strip_trackers: true,
+            ..Default::default()
+        };
+        let result = PageCapture::self_contained_html(&page, &options)
+            .await
+            .unwrap();
+        let html = String::from_utf8(result.data).unwrap();
+
+        // Tracker scripts are gone, structural markup and app scripts stay
+        assert!(!html.contains("googletagmanager.com"));
+        assert!(!html.contains("dataLayer"));
+        assert!(html.contains("<h1>Article</h1>"));
+        assert!(html.contains("appBooted"));
+
+        // Without the option both scripts survive
+        let result = PageCapture::self_contained_html(&page, &HtmlInlineOptions::default())
+            .await
+            .unwrap();
+        let html = String::from_utf8(result.data).unwrap();
+        assert!(html.contains("googletagmanager.com"));
+
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_redirect_guard_reports_and_blocks_cross_origin_redirect() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, PageNavigator, RedirectGuardMode,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Two ports on localhost are two origins; the destination first
+        let elsewhere = axum::Router::new().route(
+            "/other",
+            get(|| async { axum::response::Html("<html><body>ELSEWHERE</body></html>") }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let elsewhere_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, elsewhere).await.unwrap();
+        });
+
+        // The origin page JS-redirects there shortly after loading
+        let redirect_to = format!("http://{}/other", elsewhere_addr);
+        let app = axum::Router::new().route(
+            "/",
+            get(move || {
+                let redirect_to = redirect_to.clone();
+                async move {
+                    axum::response::Html(format!(
+                        "<html><body>ORIGINAL<script>\
+                         setTimeout(() => {{ location.href = '{}'; }}, 100);\
+                         </script></body></html>",
+                        redirect_to
+                    ))
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        let url = format!("http://{}/", addr);
+
+        // Report mode: the redirect proceeds but is recorded
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            redirect_guard: Some(RedirectGuardMode::Report),
+            ..Default::default()
+        };
+        let result = PageNavigator::goto(&page, &url, Some(options)).await.unwrap();
+        assert_eq!(result.redirect_attempts.len(), 1);
+        assert!(result.redirect_attempts[0].url.contains("/other"));
+        assert!(!result.redirect_attempts[0].blocked);
+        controller.close_page(page).await.unwrap();
+
+        // Block mode: the redirect is aborted and the original content stays
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            redirect_guard: Some(RedirectGuardMode::Block),
+            ..Default::default()
+        };
+        let result = PageNavigator::goto(&page, &url, Some(options)).await.unwrap();
+        assert_eq!(result.redirect_attempts.len(), 1);
+        assert!(result.redirect_attempts[0].blocked);
+
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let body: String = page
+            .inner()
+            .evaluate("document.body.textContent")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert!(body.contains("ORIGINAL"), "got: {}", body);
+        controller.close_page(page).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires Chrome/Chromium to be installed"]
+    async fn test_origin_only_referer_policy_sends_only_origin() {
+        use axum::routing::get;
+        use reasonkit_web::browser::{
+            BrowserController, NavigationOptions, PageNavigator, RefererPolicy,
+        };
+
+        let controller = match BrowserController::new().await {
+            Ok(c) => c,
+            Err(e) => {
+                println!("Browser test skipped: {}", e);
+                return;
+            }
+        };
+
+        // Echo server: the page body is whatever Referer the request carried
+        let app = axum::Router::new().route(
+            "/echo",
+            get(|headers: axum::http::HeaderMap| async move {
+                let referer = headers
+                    .get("referer")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or("(none)");
+                axum::response::Html(format!("<html><body>{}</body></html>", referer))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        // Follow a discovered link with the current page as referrer; the
+        // policy must strip its path and query down to the origin
+        let page = controller.new_page().await.unwrap();
+        let options = NavigationOptions {
+            referrer: Some(format!("http://{}/current/page?session=abc123", addr)),
+            referrer_policy: Some(RefererPolicy::OriginOnly),
+            ..Default::default()
+        };
+        PageNavigator::goto(&page, &format!("http://{}/echo", addr), Some(options))
+            .await
+            .unwrap();
+
+        let echoed: String = page
+            .inner()
+            .evaluate("document.body.textContent")
+            .await
+            .unwrap()
+            .into_value()
+            .unwrap();
+        assert_eq!(echoed, format!("http://{}/", addr));
+
+        controller.close_page(page).await.unwrap();
+    }
 }
 
 // ============================================================================
@@ -1004,6 +1004,19 @@ mod browser_connector_tests {
         connected: bool,
         url: String,
         captured_elements: u32,
+        busy: std::cell::Cell<bool>,
+    }
+
+    /// Clears the busy flag when an operation ends, mirroring the
+    /// connector's guard
+    pub struct OperationGuard<'a> {
+        busy: &'a std::cell::Cell<bool>,
+    }
+
+    impl Drop for OperationGuard<'_> {
+        fn drop(&mut self) {
+            self.busy.set(false);
+        }
     }
 
     #[wasm_bindgen]
@@ -1014,6 +1027,7 @@ mod browser_connector_tests {
                 connected: false,
                 url: String::new(),
                 captured_elements: 0,
+                busy: std::cell::Cell::new(false),
             }
         }
 
@@ -1044,6 +1058,35 @@ mod browser_connector_tests {
         pub fn get_captured_count(&self) -> u32 {
             self.captured_elements
         }
+
+        /// Evaluate a JavaScript snippet in the page context
+        ///
+        /// Mirrors `WasmBrowserConnector::eval`: returns the evaluated
+        /// value, or the thrown value if the snippet throws. Reentrant
+        /// calls while another operation runs get a busy error.
+        pub fn eval(&self, script: &str) -> Result<JsValue, JsValue> {
+            let _guard = self.begin_operation()?;
+            js_sys::eval(script)
+        }
+
+        pub fn is_busy(&self) -> bool {
+            self.busy.get()
+        }
+    }
+
+    impl BrowserConnectorState {
+        /// Mark an operation as in progress, refusing reentrant calls
+        ///
+        /// Mirrors `WasmBrowserConnector::begin_operation`; public here so
+        /// tests can hold a guard while issuing a second call.
+        pub fn begin_operation(&self) -> Result<OperationGuard<'_>, JsValue> {
+            if self.busy.replace(true) {
+                return Err(JsValue::from_str(
+                    "Connector busy: another operation is in progress",
+                ));
+            }
+            Ok(OperationGuard { busy: &self.busy })
+        }
     }
 
     #[wasm_bindgen_test]
@@ -1083,6 +1126,69 @@ mod browser_connector_tests {
         assert_eq!(state.get_captured_count(), 3);
     }
 
+    #[wasm_bindgen_test]
+    fn test_connector_eval_returns_result() {
+        let state = BrowserConnectorState::new();
+
+        let result = state.eval("1 + 2").expect("eval should succeed");
+        assert_eq!(result.as_f64(), Some(3.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_connector_reentrant_operation_gets_busy_error() {
+        let state = BrowserConnectorState::new();
+
+        // First operation is still in progress when the second arrives
+        let guard = state.begin_operation().expect("first operation starts");
+        assert!(state.is_busy());
+
+        let err = state.eval("1 + 1").expect_err("reentrant call must fail");
+        assert!(err
+            .as_string()
+            .unwrap_or_default()
+            .contains("busy"));
+
+        // Finishing the first operation clears the flag and unblocks
+        drop(guard);
+        assert!(!state.is_busy());
+        let result = state.eval("1 + 1").expect("eval works again");
+        assert_eq!(result.as_f64(), Some(2.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_connector_busy_flag_cleared_after_throwing_eval() {
+        let state = BrowserConnectorState::new();
+
+        state
+            .eval("throw new Error('boom')")
+            .expect_err("snippet throws");
+        // A failed operation must not leave the connector stuck busy
+        assert!(!state.is_busy());
+        assert!(state.eval("2 + 2").is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_connector_eval_sees_page_context() {
+        let state = BrowserConnectorState::new();
+
+        let result = state
+            .eval("document.body.tagName")
+            .expect("eval should reach the DOM");
+        assert_eq!(result.as_string().as_deref(), Some("BODY"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_connector_eval_propagates_thrown_error() {
+        let state = BrowserConnectorState::new();
+
+        let err = state
+            .eval("throw new Error('boom')")
+            .expect_err("thrown errors should surface as Err");
+        let message = js_sys::Reflect::get(&err, &"message".into())
+            .expect("thrown value should be an Error");
+        assert_eq!(message.as_string().as_deref(), Some("boom"));
+    }
+
     #[wasm_bindgen_test]
     fn test_console_logging() {
         // Verify console API is accessible